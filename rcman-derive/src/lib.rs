@@ -55,14 +55,22 @@
 //! |-----------|--------------|-------------|---------|
 //! | `rename` | *All* | Overrides the field name when constructing the schema key (`category.rename`) | `#[setting(rename = "App-Theme")]` |
 //! | `skip` | *All* | Silently ignores the field; it will not appear in the settings schema | `#[setting(skip)]` |
+//! | `required` | *All* | Rejects null, blank strings and empty lists on save | `#[setting(required)]` |
+//! | `requires_restart` | *All* | Reports changes through `SaveOutcome::requires_restart` (same as `requires_restart = true`) | `#[setting(requires_restart)]` |
 //! | `secret` | *All* | Asserts the field contains sensitive data, diverting it to the OS Keychain backing | `#[setting(secret)]` |
 //! | `category` | *All* | Overrides the container `category` specifically for this single field | `#[setting(category = "overridden")]` |
 //! | `nested` | Structs | Extracts the schema from an inner struct and flattens it upward | `#[setting(nested)]` |
+//! | `flatten` | Structs | Merges an inner struct's keys without a field prefix (also inferred from `#[serde(flatten)]`) | `#[setting(flatten)]` |
 //! | `min` | Number | Sets a numeric minimum constraint (must be `<= max`) | `#[setting(min = 1.0)]` |
 //! | `max` | Number | Sets a numeric maximum constraint (must be `>= min`) | `#[setting(max = 100.0)]` |
 //! | `step` | Number | Defines valid increment stepping | `#[setting(step = 5.0)]` |
+//! | `clamp` | Number | Clamps stored values outside `min`/`max` into range on load (needs `min` or `max`) | `#[setting(clamp)]` |
+//! | `widget` | Number | Input control hint: `"slider"`, `"spin_box"` or `"stepper"` | `#[setting(widget = "slider")]` |
 //! | `pattern` | Text | Enforces standard Regex validation string | `#[setting(pattern = "^[a-z]+$")]` |
-//! | `options` | Text/Num | Enforces strict dropdown alternatives mappings | `#[setting(options(("val", "Label")))]` |
+//! | `color` | Text | Makes a color setting holding `#RRGGBB` or `#RRGGBBAA` | `#[setting(color)]` |
+//! | `options` | Text/Num/List | Enforces strict dropdown alternatives mappings; on a `Vec<String>` makes a multi-select | `#[setting(options(("val", "Label")))]` |
+//! | `enum_options` | Enums | Makes a select setting from an enum deriving `SettingsEnum` (variants become options) | `#[setting(enum_options)]` |
+//! | `tags` | *All* | Attaches free-form tags for filtering (see `SettingsManager::find_by_tag`) | `#[setting(tags("network", "experimental"))]` |
 //!
 //! ## Dynamic Metadata
 //! Any `key = value` assignment in `#[setting(...)]` that isn't functionally reserved above is transparently forwarded into the resulting `SettingMetadata` map for your UI components to access dynamically.
@@ -108,6 +116,133 @@ pub fn derive_settings_schema(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Derive macro listing the variants of a unit-only enum as select options.
+///
+/// Option values are the variant names serde writes (honoring
+/// `#[serde(rename)]` and `#[serde(rename_all)]`); labels are the variant
+/// names split into words. Use the enum in a schema with `#[setting(enum_options)]`.
+#[proc_macro_derive(SettingsEnum, attributes(serde))]
+pub fn derive_settings_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match derive_settings_enum_impl(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+fn derive_settings_enum_impl(input: &DeriveInput) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let name = &input.ident;
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "SettingsEnum can only be derived for enums",
+        ));
+    };
+
+    let rename_all = serde_attr_str(&input.attrs, "rename_all")?;
+    let mut options = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "SettingsEnum variants cannot have fields",
+            ));
+        }
+
+        let ident = variant.ident.to_string();
+        let value = match (serde_attr_str(&variant.attrs, "rename")?, &rename_all) {
+            (Some((renamed, _)), _) => renamed,
+            (None, Some((rule, span))) => apply_rename_rule(&ident, rule).ok_or_else(|| {
+                syn::Error::new(*span, format!("unknown rename_all rule `{rule}`"))
+            })?,
+            (None, None) => ident.clone(),
+        };
+        let label = variant_label(&ident);
+        options.push(quote! { rcman::SettingOption::new(#value, #label) });
+    }
+
+    Ok(quote! {
+        impl rcman::SettingsEnum for #name {
+            fn select_options() -> Vec<rcman::SettingOption> {
+                vec![#(#options),*]
+            }
+        }
+    })
+}
+
+/// Read a string `key = "..."` from `#[serde(...)]` attributes
+fn serde_attr_str(
+    attrs: &[Attribute],
+    key: &str,
+) -> Result<Option<(String, proc_macro2::Span)>, syn::Error> {
+    for attr in attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+        let nested = attr.parse_args_with(
+            syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
+        )?;
+        for meta in nested {
+            if let Meta::NameValue(nv) = meta
+                && nv.path.is_ident(key)
+                && let Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(s), ..
+                }) = &nv.value
+            {
+                return Ok(Some((s.value(), s.span())));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Apply a serde `rename_all` rule to a variant name, as serde does
+fn apply_rename_rule(variant: &str, rule: &str) -> Option<String> {
+    let snake = || {
+        let mut out = String::new();
+        for (i, ch) in variant.char_indices() {
+            if i > 0 && ch.is_uppercase() {
+                out.push('_');
+            }
+            out.push(ch.to_ascii_lowercase());
+        }
+        out
+    };
+    Some(match rule {
+        "lowercase" => variant.to_ascii_lowercase(),
+        "UPPERCASE" => variant.to_ascii_uppercase(),
+        "PascalCase" => variant.to_string(),
+        "camelCase" => {
+            let mut chars = variant.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_lowercase().to_string() + chars.as_str()
+            })
+        }
+        "snake_case" => snake(),
+        "SCREAMING_SNAKE_CASE" => snake().to_ascii_uppercase(),
+        "kebab-case" => snake().replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => snake().to_ascii_uppercase().replace('_', "-"),
+        _ => return None,
+    })
+}
+
+/// Split a variant name into words for its label: `DarkBlue` -> `Dark Blue`
+fn variant_label(variant: &str) -> String {
+    let mut label = String::new();
+    for (i, ch) in variant.char_indices() {
+        if ch == '_' {
+            label.push(' ');
+            continue;
+        }
+        if i > 0 && ch.is_uppercase() && !label.ends_with(' ') {
+            label.push(' ');
+        }
+        label.push(ch);
+    }
+    label
+}
+
 fn derive_settings_schema_impl(
     input: &DeriveInput,
 ) -> Result<proc_macro2::TokenStream, syn::Error> {
@@ -353,7 +488,7 @@ fn generate_accessor_methods(
         #(#cfg_attrs)*
         fn #getter_name(&self) -> rcman::Result<#field_type>;
         #(#cfg_attrs)*
-        fn #setter_name(&self, value: #field_type) -> rcman::Result<()>;
+        fn #setter_name(&self, value: #field_type) -> rcman::Result<rcman::SaveOutcome>;
     };
 
     let manager_impl_method = quote! {
@@ -363,7 +498,7 @@ fn generate_accessor_methods(
         }
 
         #(#cfg_attrs)*
-        fn #setter_name(&self, value: #field_type) -> rcman::Result<()> {
+        fn #setter_name(&self, value: #field_type) -> rcman::Result<rcman::SaveOutcome> {
             self.save_setting(#category, #key_name, &rcman::serde_json::json!(value))
         }
     };
@@ -431,6 +566,10 @@ fn process_field(
     };
     let field_type = &field.ty;
 
+    if attrs.enum_options {
+        return process_enum_field(field, field_name, attrs, container_attrs);
+    }
+
     // Check if this is a nested struct.
     // We auto-detect simple structs, but allow explicit `nested`, `flatten` or explicit `object` override.
     let is_structural = match attrs.nesting {
@@ -441,7 +580,10 @@ fn process_field(
 
     if is_structural {
         let prefix = if attrs.nesting == Nesting::Flatten {
-            None
+            // Flattened fields serialize at this struct's own level, so their keys
+            // only gain a prefix when this struct itself lives under a category.
+            let category = resolve_field_category(attrs, container_attrs);
+            (!category.is_empty()).then_some(category)
         } else {
             Some(field_name.to_string())
         };
@@ -481,6 +623,65 @@ fn process_field(
     })
 }
 
+/// Generate a select setting for a `#[setting(enum_options)]` field
+fn process_enum_field(
+    field: &Field,
+    field_name: &syn::Ident,
+    attrs: &FieldAttrs,
+    container_attrs: &ContainerAttrs,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    if attrs.nesting != Nesting::Auto {
+        return Err(syn::Error::new_spanned(
+            field,
+            "`enum_options` cannot be combined with `nested`, `flatten` or `object`",
+        ));
+    }
+    if attrs.min.is_some() || attrs.max.is_some() || attrs.step.is_some() || attrs.clamp {
+        return Err(syn::Error::new_spanned(
+            field,
+            "`min/max/step/clamp` are only valid for numeric settings, not enums",
+        ));
+    }
+    if attrs.pattern.is_some() || attrs.widget.is_some() || !attrs.options.is_empty() {
+        return Err(syn::Error::new_spanned(
+            field,
+            "`enum_options` takes its options from the enum variants; remove `pattern`, `widget` and `options`",
+        ));
+    }
+
+    let field_type = &field.ty;
+    let enum_ty = extract_inner_type_from_option(field_type).unwrap_or(field_type);
+    let nullable = extract_inner_type_from_option(field_type).map(|_| quote! { .nullable(true) });
+
+    let category_str = resolve_field_category(attrs, container_attrs);
+    let final_field_name = attrs
+        .rename
+        .clone()
+        .unwrap_or_else(|| field_name.to_string());
+    let key = if category_str.is_empty() {
+        final_field_name
+    } else {
+        format!("{category_str}.{final_field_name}")
+    };
+    let modifiers = generate_field_modifiers(attrs);
+
+    Ok(quote! {
+        map.insert(
+            #key.to_string(),
+            {
+                // The default is the variant name serde writes (null for `None`)
+                let mut meta = rcman::SettingMetadata::select(
+                    "",
+                    <#enum_ty as rcman::SettingsEnum>::select_options(),
+                );
+                meta.default = rcman::serde_json::to_value(&defaults.#field_name)
+                    .unwrap_or(rcman::serde_json::Value::Null);
+                meta
+            } #nullable #(#modifiers)*
+        );
+    })
+}
+
 fn generate_nested_field_constructor(
     field_type: &syn::Type,
     prefix: Option<String>,
@@ -500,10 +701,9 @@ fn generate_nested_field_constructor(
     } else {
         quote! {
             // Merge nested struct's metadata without prefix (flatten)
+            // Keys are kept as-is so they match the serialized JSON layout
             for (key, meta) in <#field_type as rcman::SettingsSchema>::get_metadata() {
-                // Extract just the field name
-                let field_only = key.rsplit('.').next().unwrap_or(&key);
-                map.insert(field_only.to_string(), meta);
+                map.insert(key, meta);
             }
         }
     }
@@ -533,6 +733,43 @@ fn validate_field_type_constraints(
         ));
     }
 
+    if attrs.clamp {
+        if !matches!(type_info, TypeInfo::Number) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`clamp` is only valid for numeric settings",
+            ));
+        }
+        if attrs.min.is_none() && attrs.max.is_none() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`clamp` requires `min` or `max`",
+            ));
+        }
+    }
+
+    if attrs.color {
+        if !matches!(type_info, TypeInfo::Text) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`color` is only valid for text settings",
+            ));
+        }
+        if attrs.pattern.is_some() || !attrs.options.is_empty() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`color` cannot be combined with `pattern` or `options`",
+            ));
+        }
+    }
+
+    if attrs.widget.is_some() && !matches!(type_info, TypeInfo::Number) {
+        return Err(syn::Error::new_spanned(
+            field,
+            "`widget` is only valid for numeric settings",
+        ));
+    }
+
     match type_info {
         TypeInfo::Number => {
             if attrs.pattern.is_some() {
@@ -566,7 +803,7 @@ fn validate_field_type_constraints(
             if !attrs.options.is_empty() {
                 return Err(syn::Error::new_spanned(
                     field,
-                    "`options` are only valid for text/number settings, not booleans",
+                    "`options` are only valid for text/number/list settings, not booleans",
                 ));
             }
         }
@@ -583,12 +820,6 @@ fn validate_field_type_constraints(
                     "`pattern` is only valid for text settings, not lists",
                 ));
             }
-            if !attrs.options.is_empty() {
-                return Err(syn::Error::new_spanned(
-                    field,
-                    "`options` are only valid for text/number settings, not lists",
-                ));
-            }
         }
         TypeInfo::Object => {
             if attrs.min.is_some() || attrs.max.is_some() || attrs.step.is_some() {
@@ -606,7 +837,7 @@ fn validate_field_type_constraints(
             if !attrs.options.is_empty() {
                 return Err(syn::Error::new_spanned(
                     field,
-                    "`options` are only valid for text/number settings, not objects",
+                    "`options` are only valid for text/number/list settings, not objects",
                 ));
             }
         }
@@ -630,7 +861,14 @@ fn generate_field_constructor(
     type_info: TypeInfo,
     attrs: &FieldAttrs,
 ) -> proc_macro2::TokenStream {
-    if attrs.options.is_empty() {
+    if attrs.color {
+        let base = if extract_inner_type_from_option(field_type).is_some() {
+            quote! { rcman::SettingMetadata::color(defaults.#field_name.clone().unwrap_or_default()) }
+        } else {
+            quote! { rcman::SettingMetadata::color(defaults.#field_name.clone()) }
+        };
+        wrap_optional_default(field_name, field_type, base)
+    } else if attrs.options.is_empty() {
         generate_setting_type(field_name, field_type, type_info)
     } else {
         let options: Vec<_> = attrs
@@ -640,12 +878,32 @@ fn generate_field_constructor(
                 quote! { rcman::SettingOption::new(#val, #lbl) }
             })
             .collect();
-        quote! {
-            rcman::SettingMetadata::select(
-                defaults.#field_name.clone(),
-                vec![#(#options),*]
-            )
-        }
+        let is_option = extract_inner_type_from_option(field_type).is_some();
+        let base = if matches!(type_info, TypeInfo::List) {
+            let items = if is_option {
+                quote! { defaults.#field_name.clone().unwrap_or_default() }
+            } else {
+                quote! { defaults.#field_name }
+            };
+            quote! {
+                rcman::SettingMetadata::multi_select(&#items, vec![#(#options),*])
+            }
+        } else if is_option {
+            quote! {
+                rcman::SettingMetadata::select(
+                    defaults.#field_name.clone().unwrap_or_default(),
+                    vec![#(#options),*]
+                )
+            }
+        } else {
+            quote! {
+                rcman::SettingMetadata::select(
+                    defaults.#field_name.clone(),
+                    vec![#(#options),*]
+                )
+            }
+        };
+        wrap_optional_default(field_name, field_type, base)
     }
 }
 
@@ -661,12 +919,28 @@ fn generate_field_modifiers(attrs: &FieldAttrs) -> Vec<proc_macro2::TokenStream>
     if let Some(step) = attrs.step {
         modifiers.push(quote! { .step(#step) });
     }
+    if attrs.clamp {
+        modifiers.push(quote! { .clamp() });
+    }
+    if let Some(widget) = &attrs.widget {
+        let variant = syn::Ident::new(widget, proc_macro2::Span::call_site());
+        modifiers.push(quote! { .widget(rcman::Widget::#variant) });
+    }
     if let Some(pattern) = &attrs.pattern {
         modifiers.push(quote! { .pattern(#pattern) });
     }
     if attrs.secret {
         modifiers.push(quote! { .secret() });
     }
+    if attrs.required {
+        modifiers.push(quote! { .required() });
+    }
+    if attrs.requires_restart {
+        modifiers.push(quote! { .requires_restart() });
+    }
+    for tag in &attrs.tags {
+        modifiers.push(quote! { .tag(#tag) });
+    }
     if !attrs.reserved.is_empty() {
         let reserved_items = &attrs.reserved;
         modifiers.push(quote! { .reserved(vec![#(#reserved_items.to_string()),*]) });
@@ -731,6 +1005,16 @@ fn parse_single_field_attr(meta: Meta, result: &mut FieldAttrs) -> Result<(), sy
         Meta::Path(path) => {
             if path.is_ident("secret") {
                 result.secret = true;
+            } else if path.is_ident("required") {
+                result.required = true;
+            } else if path.is_ident("requires_restart") {
+                result.requires_restart = true;
+            } else if path.is_ident("clamp") {
+                result.clamp = true;
+            } else if path.is_ident("color") {
+                result.color = true;
+            } else if path.is_ident("enum_options") {
+                result.enum_options = true;
             } else if path.is_ident("skip") {
                 result.skip = true;
             } else if path.is_ident("nested") {
@@ -751,6 +1035,8 @@ fn parse_single_field_attr(meta: Meta, result: &mut FieldAttrs) -> Result<(), sy
                 result.max = parse_number_constraint(parse_lit_expr(value, "max")?, "max")?;
             } else if nv.path.is_ident("step") {
                 result.step = parse_number_constraint(parse_lit_expr(value, "step")?, "step")?;
+            } else if nv.path.is_ident("widget") {
+                result.widget = Some(parse_widget(value)?);
             } else if nv.path.is_ident("pattern") {
                 result.pattern = Some(parse_lit_str(value, "pattern")?);
             } else if nv.path.is_ident("rename") {
@@ -769,13 +1055,30 @@ fn parse_single_field_attr(meta: Meta, result: &mut FieldAttrs) -> Result<(), sy
             if list.path.is_ident("options") {
                 parse_options_list(&list, result)?;
             } else if list.path.is_ident("reserved") {
-                parse_reserved_list(&list, result)?;
+                result
+                    .reserved
+                    .extend(parse_string_list(&list, "reserved")?);
+            } else if list.path.is_ident("tags") {
+                result.tags.extend(parse_string_list(&list, "tags")?);
             }
         }
     }
     Ok(())
 }
 
+/// Map a `widget = "..."` value to its `rcman::Widget` variant name
+fn parse_widget(expr: &syn::Expr) -> Result<&'static str, syn::Error> {
+    match parse_lit_str(expr, "widget")?.as_str() {
+        "slider" => Ok("Slider"),
+        "spin_box" => Ok("SpinBox"),
+        "stepper" => Ok("Stepper"),
+        other => Err(syn::Error::new_spanned(
+            expr,
+            format!("unknown widget `{other}`, expected \"slider\", \"spin_box\" or \"stepper\""),
+        )),
+    }
+}
+
 fn parse_lit_str(expr: &syn::Expr, name: &str) -> Result<String, syn::Error> {
     if let syn::Expr::Lit(lit) = expr
         && let Lit::Str(s) = &lit.lit
@@ -821,10 +1124,17 @@ struct FieldAttrs {
     min: Option<f64>,
     max: Option<f64>,
     step: Option<f64>,
+    widget: Option<&'static str>,
     pattern: Option<String>,
     options: Vec<(String, String)>, // (value, label) pairs for select type
     reserved: Vec<String>,
+    tags: Vec<String>,
     secret: bool,
+    required: bool,
+    requires_restart: bool,
+    clamp: bool,
+    color: bool,
+    enum_options: bool,
     skip: bool,
     nesting: Nesting,
     rename: Option<String>,
@@ -979,28 +1289,30 @@ fn parse_options_list(list: &syn::MetaList, result: &mut FieldAttrs) -> Result<(
     Ok(())
 }
 
-fn parse_reserved_list(list: &syn::MetaList, result: &mut FieldAttrs) -> Result<(), syn::Error> {
+/// Parse a list of string literals such as #[setting(reserved("a", "b"))]
+fn parse_string_list(list: &syn::MetaList, name: &str) -> Result<Vec<String>, syn::Error> {
     let items = list
         .parse_args_with(syn::punctuated::Punctuated::<Expr, syn::Token![,]>::parse_terminated)?;
 
+    let mut values = Vec::new();
     for item in items {
         if let Expr::Lit(lit) = item {
             if let Lit::Str(s) = lit.lit {
-                result.reserved.push(s.value());
+                values.push(s.value());
             } else {
                 return Err(syn::Error::new_spanned(
                     lit,
-                    "#[setting(reserved)] values must be string literals",
+                    format!("#[setting({name})] values must be string literals"),
                 ));
             }
         } else {
             return Err(syn::Error::new_spanned(
                 item,
-                "#[setting(reserved)] values must be string literals",
+                format!("#[setting({name})] values must be string literals"),
             ));
         }
     }
-    Ok(())
+    Ok(values)
 }
 
 /// Classification of Rust types for settings generation
@@ -1101,7 +1413,7 @@ fn generate_setting_type(
 ) -> proc_macro2::TokenStream {
     let is_option = extract_inner_type_from_option(ty).is_some();
 
-    let mut base = match type_info {
+    let base = match type_info {
         TypeInfo::Toggle => {
             if is_option {
                 quote! { rcman::SettingMetadata::toggle(defaults.#field_name.unwrap_or_default()) }
@@ -1143,10 +1455,14 @@ fn generate_setting_type(
             }
         }
         TypeInfo::List => {
+            let items = if is_option {
+                quote! { defaults.#field_name.iter().flatten() }
+            } else {
+                quote! { defaults.#field_name.iter() }
+            };
             quote! {
                 rcman::SettingMetadata::list(
-                    &(defaults.#field_name
-                        .iter()
+                    &(#items
                         .map(|it| it.to_string())
                         .collect::<Vec<String>>())[..]
                 )
@@ -1160,9 +1476,28 @@ fn generate_setting_type(
         }
     };
 
-    if is_option {
-        base.extend(quote! { .nullable(true) });
+    wrap_optional_default(field_name, ty, base)
+}
+
+/// For `Option<T>` fields, mark the setting nullable and use `null` as the
+/// default when the struct default is `None`
+fn wrap_optional_default(
+    field_name: &syn::Ident,
+    ty: &Type,
+    base: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if extract_inner_type_from_option(ty).is_none() {
+        return base;
     }
 
-    base
+    quote! {
+        {
+            let mut meta = #base;
+            if defaults.#field_name.is_none() {
+                meta.default = rcman::serde_json::Value::Null;
+            }
+            meta
+        }
+        .nullable(true)
+    }
 }
@@ -25,6 +25,12 @@ struct ValidationSettings {
     #[setting(min = 10)]
     pub text: String, // min on text
 
+    #[setting(clamp)]
+    pub unbounded: i32, // clamp without min/max
+
+    #[setting(enum_options, min = 1)]
+    pub mode: i32, // numeric constraint on enum options
+
     pub unknown_type: Option<std::time::Duration>, // Should error as unsupported type
 }
 
@@ -4,6 +4,7 @@
 //!
 //! - **ZIP Creation**: [`create_zip_archive`] - Create compressed archives with optional AES-256 encryption
 //! - **ZIP Extraction**: [`extract_zip_archive`] - Extract archives with password support
+//! - **Data Archive Encryption**: [`encrypt_file_chacha`] / [`extract_data_archive`] - ChaCha20-Poly1305 alternative to zip AES
 //! - **File Reading**: [`read_file_from_zip`] - Read individual files from archives
 //! - **Container Creation**: [`create_rcman_container`] - Create the outer `.rcman` backup format
 //! - **Hashing**: [`calculate_file_hash`] - SHA-256 checksums for integrity verification
@@ -21,7 +22,7 @@
 //!     └── ...
 //! ```
 
-use super::types::ProgressCallback;
+use super::types::{BackupCipher, ProgressCallback};
 use crate::error::{Error, Result};
 use sha2::{Digest, Sha256};
 use std::fs::File;
@@ -150,6 +151,133 @@ fn add_directory_to_zip<W: Write + std::io::Seek>(
     Ok(())
 }
 
+#[cfg(feature = "backup-chacha")]
+const CHACHA_SALT_LEN: usize = 16;
+#[cfg(feature = "backup-chacha")]
+const CHACHA_NONCE_LEN: usize = 12;
+
+/// Derive a ChaCha20-Poly1305 key from a backup password using Argon2id
+#[cfg(feature = "backup-chacha")]
+fn derive_chacha_key(password: &str, salt: &[u8]) -> Result<chacha20poly1305::Key> {
+    let mut key = chacha20poly1305::Key::default();
+    argon2::Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| Error::BackupFailed(format!("Key derivation failed: {e}")))?;
+    Ok(key)
+}
+
+/// Encrypt a file in place with ChaCha20-Poly1305
+///
+/// The file is replaced by `salt || nonce || ciphertext`.
+#[cfg(feature = "backup-chacha")]
+pub fn encrypt_file_chacha(path: &Path, password: &str) -> Result<()> {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+    use rand::RngExt;
+
+    let plaintext = std::fs::read(path).map_err(|e| Error::FileRead {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+
+    let salt: [u8; CHACHA_SALT_LEN] = rand::rng().random();
+    let nonce: [u8; CHACHA_NONCE_LEN] = rand::rng().random();
+    let cipher = chacha20poly1305::ChaCha20Poly1305::new(&derive_chacha_key(password, &salt)?);
+    let ciphertext = cipher
+        .encrypt(&nonce.into(), plaintext.as_slice())
+        .map_err(|e| Error::BackupFailed(format!("Encryption failed: {e}")))?;
+
+    let mut output = Vec::with_capacity(salt.len() + nonce.len() + ciphertext.len());
+    output.extend_from_slice(&salt);
+    output.extend_from_slice(&nonce);
+    output.extend_from_slice(&ciphertext);
+    std::fs::write(path, output).map_err(|e| Error::FileWrite {
+        path: path.to_path_buf(),
+        source: e,
+    })
+}
+
+/// Decrypt a file written by [`encrypt_file_chacha`] in place
+#[cfg(feature = "backup-chacha")]
+fn decrypt_file_chacha(path: &Path, password: &str) -> Result<()> {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+
+    let data = std::fs::read(path).map_err(|e| Error::FileRead {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    if data.len() < CHACHA_SALT_LEN + CHACHA_NONCE_LEN {
+        return Err(Error::Archive("Encrypted data archive is truncated".into()));
+    }
+
+    let (salt, rest) = data.split_at(CHACHA_SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(CHACHA_NONCE_LEN);
+    let cipher = chacha20poly1305::ChaCha20Poly1305::new(&derive_chacha_key(password, salt)?);
+    let plaintext = cipher
+        .decrypt(chacha20poly1305::Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| Error::InvalidPassword)?;
+
+    std::fs::write(path, plaintext).map_err(|e| Error::FileWrite {
+        path: path.to_path_buf(),
+        source: e,
+    })
+}
+
+/// Extract a backup's data archive, decrypting it according to `cipher`
+///
+/// `cipher` is the effective cipher from the manifest (`None` if the backup
+/// is not encrypted).
+pub fn extract_data_archive(
+    archive_path: &Path,
+    output_dir: &Path,
+    cipher: Option<BackupCipher>,
+    password: Option<&str>,
+) -> Result<()> {
+    if cipher == Some(BackupCipher::ChaCha20Poly1305) {
+        #[cfg(feature = "backup-chacha")]
+        {
+            let password = password.ok_or(Error::PasswordRequired)?;
+            decrypt_file_chacha(archive_path, password)?;
+            return extract_zip_archive(archive_path, output_dir, None);
+        }
+        #[cfg(not(feature = "backup-chacha"))]
+        return Err(Error::RestoreFailed(
+            "ChaCha20-Poly1305 backups require the `backup-chacha` feature".into(),
+        ));
+    }
+    extract_zip_archive(archive_path, output_dir, password)
+}
+
+/// List the files below `dir` as '/'-separated paths relative to `dir`
+pub fn list_files(dir: &Path) -> Result<Vec<String>> {
+    fn walk(base_dir: &Path, current_dir: &Path, files: &mut Vec<String>) -> Result<()> {
+        for entry in crate::error::read_dir(current_dir)? {
+            let path = entry
+                .map_err(|e| Error::FileRead {
+                    path: current_dir.to_path_buf(),
+                    source: e,
+                })?
+                .path();
+            if path.is_dir() {
+                walk(base_dir, &path, files)?;
+            } else if let Ok(relative_path) = path.strip_prefix(base_dir) {
+                files.push(
+                    relative_path
+                        .components()
+                        .map(|c| c.as_os_str().to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join("/"),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    walk(dir, dir, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
 /// Extract a zip archive to a directory
 pub fn extract_zip_archive(
     archive_path: &Path,
@@ -287,20 +415,15 @@ pub fn calculate_file_hash(path: &Path) -> Result<(String, u64)> {
     Ok((hash, total_size))
 }
 
-/// Create the outer .rcman container (zip with manifest + data archive)
-pub fn create_rcman_container(
-    output_path: &Path,
+/// Write the outer .rcman container (zip with manifest + data archive) to `writer`
+pub fn create_rcman_container<W: Write + std::io::Seek>(
+    writer: W,
     manifest_json: &str,
     manifest_filename: &str,
     inner_archive_path: &Path,
     inner_archive_filename: &str,
 ) -> Result<()> {
-    let file = File::create(output_path).map_err(|e| Error::FileWrite {
-        path: output_path.to_path_buf(),
-        source: e,
-    })?;
-
-    let mut zip = ZipWriter::new(file);
+    let mut zip = ZipWriter::new(writer);
     let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored); // Don't compress the container
 
     // Add manifest
@@ -8,12 +8,14 @@ mod types;
 pub use operations::BackupManager;
 pub use restore::{
     RestorePendingItem, RestorePendingReason, RestoreResult, RestoreSkipReason, RestoreSkippedItem,
+    SettingChange,
 };
 
 pub use types::{
-    BackupAnalysis, BackupContents, BackupInfo, BackupIntegrity, BackupManifest, BackupOptions,
-    ExportCategory, ExportCategoryType, ExportSource, ExportType, ExternalConfig,
-    ExternalConfigProvider, ImportTarget, MANIFEST_VERSION_CURRENT, MANIFEST_VERSION_MAX_SUPPORTED,
-    MANIFEST_VERSION_MIN_SUPPORTED, ProfileEntry, ProgressCallback, RestoreControl, RestoreFlags,
-    RestoreOptions, RestoreScope, SubSettingsManifestEntry, is_manifest_version_supported,
+    BackupAnalysis, BackupCipher, BackupContents, BackupEntry, BackupInfo, BackupIntegrity,
+    BackupManifest, BackupOptions, BaseBackupRef, ExportCategory, ExportCategoryType, ExportSource,
+    ExportType, ExternalConfig, ExternalConfigProvider, ImportTarget, MANIFEST_VERSION_CURRENT,
+    MANIFEST_VERSION_MAX_SUPPORTED, MANIFEST_VERSION_MIN_SUPPORTED, ProfileEntry, ProgressCallback,
+    RestoreControl, RestoreFlags, RestoreJournal, RestoreMode, RestoreOptions, RestoreScope,
+    SubSettingsManifestEntry, is_manifest_version_supported,
 };
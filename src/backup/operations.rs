@@ -1,9 +1,14 @@
 //! Backup creation
 
-use super::archive::{calculate_file_hash, create_rcman_container, create_zip_archive};
+#[cfg(feature = "backup-chacha")]
+use super::archive::encrypt_file_chacha;
+use super::archive::{
+    calculate_file_hash, create_rcman_container, create_zip_archive, extract_data_archive,
+    list_files, read_file_from_zip,
+};
 use super::types::{
-    BackupAnalysis, BackupContents, BackupManifest, ExternalConfigProvider,
-    SubSettingsManifestEntry,
+    BackupAnalysis, BackupCipher, BackupContents, BackupEntry, BackupManifest, BaseBackupRef,
+    ExternalConfigProvider, SubSettingsManifestEntry,
 };
 use crate::backup::{BackupInfo, BackupIntegrity};
 use crate::config::SettingsSchema;
@@ -13,7 +18,9 @@ use crate::storage::StorageBackend;
 use crate::utils::sync::RwLockExt;
 use crate::{BackupOptions, ExportType};
 use log::{debug, info, warn};
+use std::collections::HashSet;
 use std::fs;
+use std::io::{Seek, Write};
 use std::path::{Path, PathBuf};
 use time::{OffsetDateTime, macros::format_description};
 
@@ -89,6 +96,89 @@ fn collect_settings_files<S: StorageBackend, Schema: SettingsSchema>(
     files
 }
 
+/// Whether `options` back up the main settings
+fn includes_main_settings(options: &BackupOptions) -> bool {
+    (options.include_settings || matches!(options.export_type, ExportType::Full))
+        && !matches!(options.export_type, ExportType::Single { .. })
+}
+
+/// Sub-settings types selected by `options`
+///
+/// `all_types` lists every type, for a full backup that names none. Types
+/// whose items are selected one by one are included as well.
+fn selected_sub_settings_types(
+    options: &BackupOptions,
+    all_types: impl FnOnce() -> Vec<String>,
+) -> Vec<String> {
+    let mut types = match &options.export_type {
+        ExportType::Full if options.include_sub_settings.is_empty() => all_types(),
+        ExportType::Full | ExportType::SettingsOnly => options.include_sub_settings.clone(),
+        ExportType::Single { settings_type, .. } => return vec![settings_type.clone()],
+    };
+
+    let mut item_categories: Vec<&String> = options.include_sub_settings_items.keys().collect();
+    item_categories.sort();
+    for category in item_categories {
+        if !types.contains(category) {
+            types.push(category.clone());
+        }
+    }
+    types
+}
+
+/// Manifest entry for the items backed up from one profile
+#[cfg(feature = "profiles")]
+fn profile_entry(mut items: Vec<String>) -> super::types::ProfileEntry {
+    if items.len() == 1
+        && let Some(item) = items.pop()
+    {
+        return super::types::ProfileEntry::Single(item);
+    }
+    super::types::ProfileEntry::Multiple(items)
+}
+
+/// Nest flat `category.key` values into a settings object
+fn unflatten(values: impl IntoIterator<Item = (String, serde_json::Value)>) -> serde_json::Value {
+    let mut value = serde_json::Value::Object(serde_json::Map::new());
+    for (key, setting_value) in values {
+        crate::utils::value::set_path(&mut value, &key, setting_value);
+    }
+    value
+}
+
+/// Set the secret fields of `value` from `secret`, or remove them all if
+/// secrets are not included
+///
+/// `secret` is looked up by credential key: the field path, prefixed with
+/// `prefix.` when `prefix` is not empty.
+fn apply_secrets(
+    value: &mut serde_json::Value,
+    prefix: &str,
+    metadata: &std::collections::HashMap<String, crate::SettingMetadata>,
+    should_include: bool,
+    secret: impl Fn(&str) -> Option<String>,
+) {
+    for (path, meta) in metadata {
+        if !meta.is_secret() {
+            continue;
+        }
+
+        if should_include {
+            let credential_key = if prefix.is_empty() {
+                path.clone()
+            } else {
+                format!("{prefix}.{path}")
+            };
+            if let Some(secret) = secret(&credential_key) {
+                crate::utils::value::set_path(value, path, serde_json::Value::String(secret));
+            }
+        } else {
+            // Eliminate the secret from the payload completely if it exists
+            crate::utils::value::remove_path(value, path);
+        }
+    }
+}
+
 /// Backup manager for creating and analyzing backups
 pub struct BackupManager<'a, S: StorageBackend + 'static, Schema: SettingsSchema = ()> {
     /// Reference to the settings manager
@@ -129,8 +219,93 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
     pub fn create(&self, options: &BackupOptions) -> Result<PathBuf> {
         info!("Creating backup with options: {:?}", options.export_type);
 
+        // Back up what was saved, including deferred changes
+        self.manager.flush()?;
+
+        self.write_backup(options, |export_dir| self.gather_files(export_dir, options))
+    }
+
+    /// Create a backup and write it to `writer` instead of a file.
+    ///
+    /// The archive is the same `.rcman` container [`create`](Self::create)
+    /// writes, so it can be uploaded or streamed elsewhere without touching the
+    /// output directory. `options.output_dir` and `options.filename_suffix` are
+    /// ignored. The settings are still gathered in a temporary directory.
+    ///
+    /// The writer must implement [`Seek`] because the zip format writes the
+    /// file headers after their data. To stream to a destination that can't
+    /// seek, write into a [`std::io::Cursor`] first.
+    ///
+    /// # Returns
+    ///
+    /// The manifest embedded in the written backup
+    ///
+    /// # Errors
+    ///
+    /// Same as [`create`](Self::create). Failures of the writer are reported
+    /// as `Error::Archive`.
+    pub fn create_to_writer<W: Write + Seek>(
+        &self,
+        writer: W,
+        options: &BackupOptions,
+    ) -> Result<BackupManifest> {
+        info!(
+            "Creating backup to writer with options: {:?}",
+            options.export_type
+        );
+
+        self.manager.flush()?;
+
+        self.write_backup_to(writer, options, |export_dir| {
+            self.gather_files(export_dir, options)
+        })
+    }
+
+    /// Create a backup of a snapshot taken earlier with
+    /// [`SettingsManager::snapshot`] instead of the current settings.
+    ///
+    /// The backup holds the settings and sub-settings entries as they were when
+    /// the snapshot was taken, filtered by `options` like [`create`](Self::create).
+    /// External configs are not part of a snapshot and are never included.
+    /// When the secret policy includes secrets, the values come from the
+    /// snapshot too, so secrets changed after the snapshot was taken are not
+    /// picked up.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`create`](Self::create).
+    pub fn create_from_snapshot(
+        &self,
+        snapshot: &crate::SettingsSnapshot,
+        options: &BackupOptions,
+    ) -> Result<PathBuf> {
+        info!(
+            "Creating backup from snapshot taken at {} with options: {:?}",
+            snapshot.taken_at(),
+            options.export_type
+        );
+
+        self.write_backup(options, |export_dir| {
+            self.gather_snapshot(export_dir, snapshot, options)
+        })
+    }
+
+    /// Gather files with `gather`, then archive them into a `.rcman` container
+    /// written to `writer`
+    fn write_backup_to<W: Write + Seek>(
+        &self,
+        writer: W,
+        options: &BackupOptions,
+        gather: impl FnOnce(&Path) -> Result<(BackupContents, u64)>,
+    ) -> Result<BackupManifest> {
         // Validate password if provided
         let password = validate_password(options.password.clone())?;
+        #[cfg(not(feature = "backup-chacha"))]
+        if password.is_some() && options.cipher == BackupCipher::ChaCha20Poly1305 {
+            return Err(Error::BackupFailed(
+                "ChaCha20-Poly1305 backups require the `backup-chacha` feature".into(),
+            ));
+        }
 
         // Create temp directory for gathering files
         let temp_dir = tempfile::tempdir().map_err(|e| Error::BackupFailed(e.to_string()))?;
@@ -138,19 +313,44 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
         crate::utils::security::ensure_secure_dir(&export_dir)?;
 
         // Gather files to backup
-        let (contents, total_size) = self.gather_files(&export_dir, options)?;
+        let (contents, mut total_size) = gather(&export_dir)?;
+
+        // Leave out what the base backup already has
+        let base = match &options.base {
+            Some(base_path) => {
+                let (base, stripped_size) = self.strip_base_files(
+                    base_path,
+                    password.as_deref(),
+                    temp_dir.path(),
+                    &export_dir,
+                )?;
+                total_size = total_size.saturating_sub(stripped_size);
+                Some(base)
+            }
+            None => None,
+        };
 
         // Create inner data archive
         let data_filename = "data.zip";
         let inner_archive_path = temp_dir.path().join(data_filename);
 
+        // ChaCha20-Poly1305 encrypts the finished archive instead of its entries
+        let cipher = password.as_ref().map(|_| options.cipher);
+        let zip_password = match cipher {
+            Some(BackupCipher::ChaCha20Poly1305) => None,
+            _ => password.as_deref(),
+        };
         create_zip_archive(
             &export_dir,
             &inner_archive_path,
             options.on_progress.clone(),
             total_size,
-            password.as_deref(),
+            zip_password,
         )?;
+        #[cfg(feature = "backup-chacha")]
+        if let (Some(BackupCipher::ChaCha20Poly1305), Some(password)) = (cipher, &password) {
+            encrypt_file_chacha(&inner_archive_path, password)?;
+        }
 
         // Calculate checksum
         let (checksum, _) = calculate_file_hash(&inner_archive_path)?;
@@ -166,6 +366,7 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
                 encrypted: password.is_some(),
                 user_note: options.user_note.clone(),
                 secret_policy: Some(options.secret_policy.clone()),
+                cipher,
             },
             contents,
 
@@ -174,6 +375,7 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
                 size_bytes: total_size,
                 compressed_size_bytes: fs::metadata(&inner_archive_path).ok().map(|m| m.len()),
             },
+            base,
         };
 
         // Serialize manifest using storage backend for format consistency
@@ -181,13 +383,173 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
         let manifest_json = serde_json::to_string_pretty(&manifest)
             .map_err(|e| Error::BackupFailed(e.to_string()))?;
 
-        // Generate output filename
+        create_rcman_container(
+            writer,
+            &manifest_json,
+            "manifest.json",
+            &inner_archive_path,
+            data_filename,
+        )?;
+
+        Ok(manifest)
+    }
+
+    /// Remove the files of `export_dir` that are identical in the base backup
+    ///
+    /// Returns the reference to record in the manifest and the number of
+    /// bytes left out.
+    fn strip_base_files(
+        &self,
+        base_path: &Path,
+        password: Option<&str>,
+        work_dir: &Path,
+        export_dir: &Path,
+    ) -> Result<(BaseBackupRef, u64)> {
+        let base_dir = work_dir.join("base");
+        let sha256 = self.extract_base(base_path, password, work_dir, &base_dir)?;
+
+        let current_files = list_files(export_dir)?;
+        let removed_files = list_files(&base_dir)?
+            .into_iter()
+            .filter(|file| current_files.binary_search(file).is_err())
+            .collect();
+
+        let mut stripped_size = 0;
+        for file in &current_files {
+            let ours = export_dir.join(file);
+            let theirs = base_dir.join(file);
+            if !theirs.is_file() {
+                continue;
+            }
+            let content = fs::read(&ours).map_err(|e| Error::FileRead {
+                path: ours.clone(),
+                source: e,
+            })?;
+            let base_content = fs::read(&theirs).map_err(|e| Error::FileRead {
+                path: theirs.clone(),
+                source: e,
+            })?;
+            if content == base_content {
+                stripped_size += content.len() as u64;
+                fs::remove_file(&ours).map_err(|e| Error::FileDelete {
+                    path: ours.clone(),
+                    source: e,
+                })?;
+            }
+        }
+        debug!("Incremental backup left out {stripped_size} bytes found in base");
+
+        let file_name = base_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Ok((
+            BaseBackupRef {
+                sha256,
+                file_name,
+                removed_files,
+            },
+            stripped_size,
+        ))
+    }
+
+    /// Extract the data of the full backup at `base_path` into `dest`
+    ///
+    /// Returns the checksum of the base's data archive.
+    pub(super) fn extract_base(
+        &self,
+        base_path: &Path,
+        password: Option<&str>,
+        work_dir: &Path,
+        dest: &Path,
+    ) -> Result<String> {
+        if !base_path.exists() {
+            return Err(Error::MissingBaseBackup(base_path.display().to_string()));
+        }
+        let analysis = self.analyze(base_path)?;
+        if analysis.manifest.base.is_some() {
+            return Err(Error::InvalidBackup(format!(
+                "{}: Base backup is itself incremental",
+                base_path.display()
+            )));
+        }
+        if analysis.requires_password && password.is_none() {
+            return Err(Error::PasswordRequired);
+        }
+
+        let data_archive_path = work_dir.join("base.zip");
+        let data_bytes = read_file_from_zip(base_path, "data.zip")?;
+        fs::write(&data_archive_path, data_bytes).map_err(|e| Error::FileWrite {
+            path: data_archive_path.clone(),
+            source: e,
+        })?;
+
+        let (sha256, _) = calculate_file_hash(&data_archive_path)?;
+        if analysis
+            .manifest
+            .integrity
+            .sha256
+            .as_ref()
+            .is_some_and(|expected| *expected != sha256)
+        {
+            return Err(Error::InvalidBackup(format!(
+                "{}: Data archive checksum verification failed - backup may be corrupted",
+                base_path.display()
+            )));
+        }
+
+        extract_data_archive(
+            &data_archive_path,
+            dest,
+            analysis.manifest.backup.effective_cipher(),
+            password,
+        )?;
+        Ok(sha256)
+    }
+
+    /// Write a backup into a new file in `options.output_dir`
+    fn write_backup(
+        &self,
+        options: &BackupOptions,
+        gather: impl FnOnce(&Path) -> Result<(BackupContents, u64)>,
+    ) -> Result<PathBuf> {
+        let output_path = options.output_dir.join(self.backup_filename(options));
+        if options.base.as_ref() == Some(&output_path) {
+            return Err(Error::BackupFailed(format!(
+                "Incremental backup would overwrite its base {}",
+                output_path.display()
+            )));
+        }
+
+        // Ensure output directory exists
+        fs::create_dir_all(&options.output_dir).map_err(|e| Error::DirectoryCreate {
+            path: options.output_dir.clone(),
+            source: e,
+        })?;
+
+        let file = fs::File::create(&output_path).map_err(|e| Error::FileWrite {
+            path: output_path.clone(),
+            source: e,
+        })?;
+
+        // Create final .rcman container, removing the partial file on failure
+        if let Err(e) = self.write_backup_to(file, options, gather) {
+            let _ = fs::remove_file(&output_path);
+            return Err(e);
+        }
+
+        info!("Backup created: {:?}", output_path.display());
+        Ok(output_path)
+    }
+
+    /// File name for a new backup, based on the export type and current time
+    fn backup_filename(&self, options: &BackupOptions) -> String {
         let now = OffsetDateTime::now_utc();
         let timestamp_format = format_description!("[year][month][day]_[hour][minute][second]");
         let timestamp = now
             .format(&timestamp_format)
             .unwrap_or_else(|_| "unknown".to_string());
-        let filename = if let Some(suffix) = &options.filename_suffix {
+        if let Some(suffix) = &options.filename_suffix {
             format!(
                 "{}_{}_{}.rcman",
                 self.manager.config().app_name,
@@ -207,6 +569,8 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
                         if !options.include_settings && options.include_sub_settings.len() == 1 {
                             // If exporting exactly one sub-setting (e.g. "remotes"), use that as suffix
                             &options.include_sub_settings[0]
+                        } else if options.customizations_only {
+                            "customizations"
                         } else {
                             "settings"
                         };
@@ -227,27 +591,7 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
                     )
                 }
             }
-        };
-
-        let output_path = options.output_dir.join(&filename);
-
-        // Ensure output directory exists
-        fs::create_dir_all(&options.output_dir).map_err(|e| Error::DirectoryCreate {
-            path: options.output_dir.clone(),
-            source: e,
-        })?;
-
-        // Create final .rcman container
-        create_rcman_container(
-            &output_path,
-            &manifest_json,
-            "manifest.json",
-            &inner_archive_path,
-            data_filename,
-        )?;
-
-        info!("Backup created: {:?}", output_path.display());
-        Ok(output_path)
+        }
     }
 
     /// Gather files to backup
@@ -260,11 +604,7 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
         let mut total_size = 0u64;
 
         // Main settings
-        let include_settings = (options.include_settings
-            || matches!(options.export_type, ExportType::Full))
-            && !matches!(options.export_type, ExportType::Single { .. });
-
-        if include_settings {
+        if includes_main_settings(options) {
             let (size, count) = self.gather_main_settings(export_dir, options)?;
             total_size += size;
             contents.file_count += count;
@@ -272,40 +612,28 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
         }
 
         // Sub-settings
-        let sub_settings_to_backup = match &options.export_type {
-            ExportType::Full => {
-                if options.include_sub_settings.is_empty() {
-                    self.manager.sub_settings_types()
-                } else {
-                    options.include_sub_settings.clone()
-                }
-            }
-            ExportType::SettingsOnly => options.include_sub_settings.clone(),
-            ExportType::Single {
-                settings_type,
-                name,
-            } => {
-                // Handle single entry export inline (simple case)
-                if let Ok(sub) = self.manager.sub_settings(settings_type) {
-                    let sub_export_dir = export_dir.join(settings_type);
-                    crate::error::create_dir(&sub_export_dir)?;
-
-                    let value: serde_json::Value = sub.get_value(name)?;
-                    // Use storage backend for format-agnostic export
-                    let ext = self.manager.storage().extension();
-                    let dest = sub_export_dir.join(format!("{name}.{ext}"));
-                    let content = self.manager.storage().serialize(&value)?;
-                    crate::error::write_file(&dest, &content)?;
-                    total_size += content.len() as u64;
-                    contents.file_count += 1;
-                    contents.sub_settings.insert(
-                        settings_type.clone(),
-                        SubSettingsManifestEntry::MultiFile(vec![name.clone()]),
-                    );
-                    debug!("Added single entry: {settings_type}/{name}");
-                }
-                Vec::new()
+        let sub_settings_to_backup = if let ExportType::Single {
+            settings_type,
+            name,
+        } = &options.export_type
+        {
+            // Handle single entry export inline (simple case)
+            if let Ok(sub) = self.manager.sub_settings(settings_type) {
+                let value: serde_json::Value = sub.get_value(name)?;
+                // Use storage backend for format-agnostic export
+                let ext = self.manager.storage().extension();
+                let dest = Path::new(settings_type).join(format!("{name}.{ext}"));
+                total_size += self.write_export_file(export_dir, &dest, &value)?;
+                contents.file_count += 1;
+                contents.sub_settings.insert(
+                    settings_type.clone(),
+                    SubSettingsManifestEntry::MultiFile(vec![name.clone()]),
+                );
+                debug!("Added single entry: {settings_type}/{name}");
             }
+            Vec::new()
+        } else {
+            selected_sub_settings_types(options, || self.manager.sub_settings_types())
         };
 
         // Process each sub-settings type
@@ -342,6 +670,164 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
         Ok((contents, total_size))
     }
 
+    /// Write the contents of a snapshot in the same layout as [`gather_files`](Self::gather_files)
+    fn gather_snapshot(
+        &self,
+        export_dir: &Path,
+        snapshot: &crate::SettingsSnapshot,
+        options: &BackupOptions,
+    ) -> Result<(BackupContents, u64)> {
+        let mut contents = BackupContents::default();
+        let mut total_size = 0u64;
+        let should_include_secrets = options.includes_secrets();
+        let snapshot_secret = |key: &str| snapshot.secrets.get(key).cloned();
+
+        // Main settings
+        if includes_main_settings(options) {
+            let mut value = if options.customizations_only {
+                unflatten(snapshot.customized.clone())
+            } else {
+                snapshot.settings.clone()
+            };
+            apply_secrets(
+                &mut value,
+                "",
+                &Schema::get_metadata(),
+                should_include_secrets,
+                snapshot_secret,
+            );
+
+            let relative_dest = self.main_settings_dest(snapshot.profile.as_deref());
+            total_size += self.write_export_file(export_dir, &relative_dest, &value)?;
+            contents.file_count += 1;
+            contents.settings = true;
+            debug!("Added snapshot settings file: {}", relative_dest.display());
+        }
+
+        // Sub-settings
+        let sub_settings_to_backup = selected_sub_settings_types(options, || {
+            snapshot.sub_settings.keys().cloned().collect()
+        });
+        for sub_type in sub_settings_to_backup {
+            let Some(sub) = snapshot.sub_settings.get(&sub_type) else {
+                continue;
+            };
+            let is_selected = |name: &str| match &options.export_type {
+                ExportType::Single { name: single, .. } => single == name,
+                _ => options
+                    .include_sub_settings_items
+                    .get(&sub_type)
+                    .is_none_or(|items| items.iter().any(|i| i == name)),
+            };
+            let sub_metadata = sub.schema.clone().unwrap_or_default();
+
+            let mut entries = serde_json::Map::new();
+            for (name, value) in &sub.entries {
+                if !is_selected(name) {
+                    continue;
+                }
+                let mut value = value.clone();
+                apply_secrets(
+                    &mut value,
+                    &format!("sub.{sub_type}.{name}"),
+                    &sub_metadata,
+                    should_include_secrets,
+                    snapshot_secret,
+                );
+                entries.insert(name.clone(), value);
+            }
+            if entries.is_empty() {
+                continue;
+            }
+
+            let sub_dir = match &sub.profile {
+                #[cfg(feature = "profiles")]
+                Some(profile) => Path::new(&sub_type).join(PROFILES_DIR).join(profile),
+                _ if sub.single_file.is_some() => PathBuf::new(),
+                _ => PathBuf::from(&sub_type),
+            };
+
+            let items: Vec<String> = if let Some(file_name) = &sub.single_file {
+                let value = serde_json::Value::Object(entries);
+                total_size +=
+                    self.write_export_file(export_dir, &sub_dir.join(file_name), &value)?;
+                contents.file_count += 1;
+                vec![file_name.clone()]
+            } else {
+                let mut items = Vec::new();
+                for (name, value) in entries {
+                    let dest = sub_dir.join(format!("{name}.{}", sub.extension));
+                    total_size += self.write_export_file(export_dir, &dest, &value)?;
+                    contents.file_count += 1;
+                    items.push(name);
+                }
+                items
+            };
+
+            let manifest_entry = match &sub.profile {
+                #[cfg(feature = "profiles")]
+                Some(profile) => {
+                    // Item names in profiled manifests have no extension
+                    let items = if sub.single_file.is_some() {
+                        items
+                            .iter()
+                            .map(|item| {
+                                Path::new(item)
+                                    .file_stem()
+                                    .map_or(item.clone(), |s| s.to_string_lossy().to_string())
+                            })
+                            .collect()
+                    } else {
+                        items
+                    };
+                    SubSettingsManifestEntry::Profiled {
+                        profiles: std::iter::once((profile.clone(), profile_entry(items)))
+                            .collect(),
+                    }
+                }
+                _ if sub.single_file.is_some() => {
+                    SubSettingsManifestEntry::SingleFile(items.concat())
+                }
+                _ => SubSettingsManifestEntry::MultiFile(items),
+            };
+            contents
+                .sub_settings
+                .insert(sub_type.clone(), manifest_entry);
+            debug!("Added snapshot sub-settings: {sub_type}");
+        }
+
+        Ok((contents, total_size))
+    }
+
+    /// Serialize `value` into `relative_dest` under `export_dir`, returning its size
+    fn write_export_file(
+        &self,
+        export_dir: &Path,
+        relative_dest: &Path,
+        value: &serde_json::Value,
+    ) -> Result<u64> {
+        let content = self.manager.storage().serialize(value)?;
+        let full_dest = export_dir.join(relative_dest);
+        if let Some(parent) = full_dest.parent() {
+            crate::error::create_dir(parent)?;
+        }
+        crate::error::write_file(&full_dest, &content)?;
+        Ok(content.len() as u64)
+    }
+
+    /// Path of the main settings file in a backup, inside `profile` if given
+    #[cfg_attr(not(feature = "profiles"), allow(unused_variables))]
+    fn main_settings_dest(&self, profile: Option<&str>) -> PathBuf {
+        let settings_file = PathBuf::from(&self.manager.config().settings_file);
+        #[cfg(feature = "profiles")]
+        if let Some(profile) = profile {
+            return PathBuf::from(PROFILES_DIR)
+                .join(profile)
+                .join(settings_file);
+        }
+        settings_file
+    }
+
     /// Gather main settings files
     fn gather_main_settings(
         &self,
@@ -350,6 +836,10 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
     ) -> Result<(u64, u32)> {
         use std::collections::HashSet;
 
+        if options.customizations_only {
+            return self.gather_customized_settings(export_dir, options);
+        }
+
         let mut total_size = 0u64;
         let mut file_count = 0u32;
 
@@ -368,11 +858,7 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
             }
 
             let credential_profile = Self::profile_from_backup_dest(&dest);
-            let should_include_secrets = match options.secret_policy {
-                crate::SecretBackupPolicy::Exclude => false,
-                crate::SecretBackupPolicy::Include => true,
-                crate::SecretBackupPolicy::EncryptedOnly => options.password.is_some(),
-            };
+            let should_include_secrets = options.includes_secrets();
             let ctx = SecretContext {
                 prefix: "",
                 metadata: &metadata,
@@ -446,6 +932,35 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
         Ok((total_size, file_count))
     }
 
+    /// Write a settings file holding only values that differ from defaults
+    fn gather_customized_settings(
+        &self,
+        export_dir: &Path,
+        options: &BackupOptions,
+    ) -> Result<(u64, u32)> {
+        let should_include_secrets = options.includes_secrets();
+
+        let value = unflatten(self.manager.export_flat(should_include_secrets)?);
+
+        #[cfg(feature = "profiles")]
+        let profile = if self.manager.config().profiles_enabled {
+            Some(self.manager.active_profile()?)
+        } else {
+            None
+        };
+        #[cfg(not(feature = "profiles"))]
+        let profile: Option<String> = None;
+
+        let relative_dest = self.main_settings_dest(profile.as_deref());
+        let size = self.write_export_file(export_dir, &relative_dest, &value)?;
+        debug!(
+            "Added customized settings file: {}",
+            relative_dest.display()
+        );
+
+        Ok((size, 1))
+    }
+
     fn write_synthesized_settings_file(
         &self,
         export_dir: &Path,
@@ -453,11 +968,7 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
         options: &BackupOptions,
         credential_profile: Option<&str>,
     ) -> Result<Option<u64>> {
-        let should_include_secrets = match options.secret_policy {
-            crate::SecretBackupPolicy::Exclude => false,
-            crate::SecretBackupPolicy::Include => true,
-            crate::SecretBackupPolicy::EncryptedOnly => options.password.is_some(),
-        };
+        let should_include_secrets = options.includes_secrets();
 
         // Build merged settings from manager (merge stored + defaults)
         let mut value = self.manager.get_all_data()?;
@@ -470,14 +981,11 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
             credential_profile,
         );
 
-        let content = self.manager.storage().serialize(&value)?;
-        let full_dest = export_dir.join(relative_dest);
-        if let Some(parent) = full_dest.parent() {
-            crate::error::create_dir(parent)?;
-        }
-        crate::error::write_file(&full_dest, &content)?;
-
-        Ok(Some(content.len() as u64))
+        Ok(Some(self.write_export_file(
+            export_dir,
+            relative_dest,
+            &value,
+        )?))
     }
 
     // Helper to read, process secrets, and write settings file
@@ -515,6 +1023,7 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
         Ok(serialized.len() as u64)
     }
 
+    /// Fill in secrets from the credential store, or remove them, per `should_include`
     fn inject_or_remove_secrets(
         &self,
         value: &mut serde_json::Value,
@@ -523,54 +1032,24 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
         should_include: bool,
         #[allow(unused_variables)] credential_profile: Option<&str>,
     ) {
-        #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
-        let creds_opt = self.manager.credentials();
-
-        for (full_key, meta) in metadata {
-            if !meta.is_secret() {
-                continue;
-            }
-
-            let relative_key = if prefix.is_empty() {
-                full_key.as_str()
-            } else {
-                // For sub-settings, full_key is already relative to the entry
-                // (not prefixed with the parent path)
-                full_key.as_str()
-            };
-
-            if should_include {
+        apply_secrets(
+            value,
+            prefix,
+            metadata,
+            should_include,
+            |#[allow(unused_variables)] credential_key| {
                 #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
-                if let Some(creds) = creds_opt {
-                    // Build the full credential key (prefix.field or just field if prefix is empty)
-                    let credential_key = if prefix.is_empty() {
-                        full_key.clone()
-                    } else {
-                        format!("{prefix}.{full_key}")
-                    };
-
-                    // Try to get the secret
-                    match creds.get_with_profile(&credential_key, credential_profile) {
-                        Ok(Some(secret)) => {
-                            crate::utils::value::set_path(
-                                value,
-                                relative_key,
-                                serde_json::Value::String(secret),
-                            );
-                        }
-                        Ok(None) => {}
-                        Err(err) => {
-                            debug!(
-                                "Failed to fetch secret '{relative_key}' (credential_key: '{credential_key}'): {err}"
-                            );
-                        }
-                    }
+                if let Some(creds) = self.manager.credentials() {
+                    return creds
+                        .get_with_profile(credential_key, credential_profile)
+                        .unwrap_or_else(|err| {
+                            debug!("Failed to fetch secret '{credential_key}': {err}");
+                            None
+                        });
                 }
-            } else {
-                // Eliminate the secret from the payload completely if it exists
-                crate::utils::value::remove_path(value, relative_key);
-            }
-        }
+                None
+            },
+        );
     }
 
     fn profile_from_backup_dest(dest: &Path) -> Option<&str> {
@@ -616,6 +1095,9 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
 
         // Non-profiled sub-settings
         let sub_export_dir = export_dir.join(sub_type);
+        let selected_items = options.include_sub_settings_items.get(sub_type);
+        let is_selected =
+            |name: &str| selected_items.is_none_or(|items| items.iter().any(|i| i == name));
         let sub_metadata = sub
             .schema_metadata()
             .unwrap_or_else(|| std::sync::Arc::new(std::collections::HashMap::new()));
@@ -646,13 +1128,10 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
                 let storage_impl = &self.manager.config().storage;
                 let mut root_value: serde_json::Value = storage_impl.deserialize(&raw_str)?;
 
-                let should_include_secrets = match options.secret_policy {
-                    crate::SecretBackupPolicy::Exclude => false,
-                    crate::SecretBackupPolicy::Include => true,
-                    crate::SecretBackupPolicy::EncryptedOnly => options.password.is_some(),
-                };
+                let should_include_secrets = options.includes_secrets();
 
                 if let Some(obj) = root_value.as_object_mut() {
+                    obj.retain(|entry_name, _| is_selected(entry_name));
                     for (entry_name, entry_value) in obj.iter_mut() {
                         // Build credential key prefix: "sub.connections.Local"
                         let credential_key_prefix = format!("sub.{sub_type}.{entry_name}");
@@ -687,15 +1166,14 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
             let mut items = Vec::new();
 
             for name in sub.list()? {
+                if !is_selected(&name) {
+                    continue;
+                }
                 if let Ok(mut value) = sub.get_value(&name) {
                     let ext = sub.extension();
                     let dest = sub_export_dir.join(format!("{name}.{ext}"));
 
-                    let should_include_secrets = match options.secret_policy {
-                        crate::SecretBackupPolicy::Exclude => false,
-                        crate::SecretBackupPolicy::Include => true,
-                        crate::SecretBackupPolicy::EncryptedOnly => options.password.is_some(),
-                    };
+                    let should_include_secrets = options.includes_secrets();
 
                     // Build credential key prefix: "sub.remotes.Google Drive"
                     let credential_key_prefix = format!("sub.{sub_type}.{name}");
@@ -810,26 +1288,11 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
         let manifest_entry = if profile_items.is_empty() {
             None
         } else {
-            let profiles_map = profile_items
-                .into_iter()
-                .map(|(profile_name, items)| {
-                    let entry = if items.len() == 1 {
-                        let mut single_item = items;
-                        let item = single_item.pop().ok_or_else(|| {
-                            Error::BackupFailed(
-                                "Expected single profile item but none found".to_string(),
-                            )
-                        })?;
-                        super::types::ProfileEntry::Single(item)
-                    } else {
-                        super::types::ProfileEntry::Multiple(items)
-                    };
-                    Ok((profile_name, entry))
-                })
-                .collect::<Result<_>>()?;
-
             Some(SubSettingsManifestEntry::Profiled {
-                profiles: profiles_map,
+                profiles: profile_items
+                    .into_iter()
+                    .map(|(profile_name, items)| (profile_name, profile_entry(items)))
+                    .collect(),
             })
         };
 
@@ -932,20 +1395,7 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
             return Err(Error::PathNotFound(path.display().to_string()));
         }
 
-        // Read manifest from the .rcman file
-        // Manifest is always JSON format for universal compatibility
-        let manifest_bytes = super::archive::read_file_from_zip(path, "manifest.json")?;
-        let manifest_str = String::from_utf8(manifest_bytes).map_err(|e| {
-            Error::InvalidBackup(format!(
-                "{}: Invalid manifest encoding: {}",
-                path.display(),
-                e
-            ))
-        })?;
-
-        let manifest: BackupManifest = serde_json::from_str(&manifest_str).map_err(|e| {
-            Error::InvalidBackup(format!("{}: Invalid manifest JSON: {}", path.display(), e))
-        })?;
+        let manifest = read_manifest(path)?;
 
         let mut warnings = Vec::new();
         let mut is_valid = true;
@@ -982,7 +1432,10 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
             source: e,
         })?;
 
-        let data_encrypted = super::archive::is_zip_encrypted(&data_archive_path)?;
+        // A ChaCha20-Poly1305 data archive is not a zip until decrypted
+        let data_encrypted = manifest.backup.effective_cipher()
+            == Some(BackupCipher::ChaCha20Poly1305)
+            || super::archive::is_zip_encrypted(&data_archive_path)?;
 
         // Warn if manifest and actual encryption status mismatch
         if manifest.backup.encrypted != data_encrypted {
@@ -1009,12 +1462,134 @@ impl<'a, S: StorageBackend + 'static, Schema: SettingsSchema> BackupManager<'a,
             manifest,
         })
     }
+
+    /// List the backups in a directory, newest first
+    ///
+    /// Enumerates `*.rcman` files in `dir` and reads only their manifests, so
+    /// listing is cheap even for large backups. Files that are not valid
+    /// backups are skipped with a warning. A missing directory has no backups.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` exists but cannot be read.
+    pub fn list(&self, dir: &Path) -> Result<Vec<BackupEntry>> {
+        Ok(self
+            .scan(dir)?
+            .into_iter()
+            .map(|(entry, _)| entry)
+            .collect())
+    }
+
+    /// List the backups in a directory along with their manifests, newest first
+    fn scan(&self, dir: &Path) -> Result<Vec<(BackupEntry, BackupManifest)>> {
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut backups = Vec::new();
+        for entry in crate::error::read_dir(dir)? {
+            let path = entry
+                .map_err(|e| Error::DirectoryRead {
+                    path: dir.to_path_buf(),
+                    source: e,
+                })?
+                .path();
+            if !path.is_file() || path.extension().is_none_or(|ext| ext != "rcman") {
+                continue;
+            }
+
+            match read_manifest(&path) {
+                Ok(manifest) => backups.push((
+                    BackupEntry {
+                        size_bytes: crate::error::file_size(&path),
+                        path,
+                        created_at: manifest.backup.created_at,
+                        app_version: manifest.backup.app_version.clone(),
+                        encrypted: manifest.backup.encrypted,
+                        note: manifest.backup.user_note.clone(),
+                    },
+                    manifest,
+                )),
+                Err(e) => warn!("Skipping {}: {e}", path.display()),
+            }
+        }
+
+        backups.sort_by(|(a, _), (b, _)| {
+            b.created_at
+                .cmp(&a.created_at)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        Ok(backups)
+    }
+
+    /// Delete all but the newest `keep` backups in a directory
+    ///
+    /// Backups are ordered by the creation time in their manifest, as in
+    /// [`list`](Self::list). The manifest is stored unencrypted, so encrypted
+    /// backups are ordered the same way. Files whose manifest cannot be read
+    /// have no reliable timestamp; they are never deleted and don't count
+    /// towards `keep`. A backup that is the base of a retained incremental
+    /// backup is kept as well, since the incremental one cannot be restored
+    /// without it; of several identical bases, only the newest is kept. Returns the deleted paths, oldest last.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` cannot be read or a backup cannot be deleted.
+    pub fn prune(&self, dir: &Path, keep: usize) -> Result<Vec<PathBuf>> {
+        let backups = self.scan(dir)?;
+        let mut retained_bases: HashSet<&str> = backups
+            .iter()
+            .take(keep)
+            .filter_map(|(_, manifest)| manifest.base.as_ref())
+            .map(|base| base.sha256.as_str())
+            .collect();
+
+        let mut removed = Vec::new();
+        for (backup, manifest) in backups.iter().skip(keep) {
+            if manifest
+                .integrity
+                .sha256
+                .as_deref()
+                .is_some_and(|sha256| retained_bases.remove(sha256))
+            {
+                debug!(
+                    "Keeping {}: base of a retained incremental backup",
+                    backup.path.display()
+                );
+                continue;
+            }
+            fs::remove_file(&backup.path).map_err(|e| Error::FileDelete {
+                path: backup.path.clone(),
+                source: e,
+            })?;
+            info!("Pruned old backup: {}", backup.path.display());
+            removed.push(backup.path.clone());
+        }
+        Ok(removed)
+    }
 }
 
 // =============================================================================
 // Helper Functions
 // =============================================================================
 
+/// Read the manifest of a `.rcman` file without touching its data archive
+fn read_manifest(path: &Path) -> Result<BackupManifest> {
+    // Manifest is always JSON format for universal compatibility
+    let manifest_bytes = super::archive::read_file_from_zip(path, "manifest.json")?;
+    let manifest_str = String::from_utf8(manifest_bytes).map_err(|e| {
+        Error::InvalidBackup(format!(
+            "{}: Invalid manifest encoding: {}",
+            path.display(),
+            e
+        ))
+    })?;
+
+    serde_json::from_str(&manifest_str).map_err(|e| {
+        Error::InvalidBackup(format!("{}: Invalid manifest JSON: {}", path.display(), e))
+    })
+}
+
 /// Validate password (minimum length, no whitespace-only)
 fn validate_password(password: Option<String>) -> Result<Option<String>> {
     match password {
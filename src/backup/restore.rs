@@ -1,6 +1,6 @@
 //! Backup/restore logic
 
-use super::archive::{extract_zip_archive, read_file_from_zip};
+use super::archive::{extract_data_archive, read_file_from_zip};
 use crate::config::SettingsSchema;
 use crate::error::{Error, Result};
 use crate::storage::StorageBackend;
@@ -10,8 +10,10 @@ use crate::backup::BackupAnalysis;
 #[cfg(feature = "profiles")]
 use crate::backup::SubSettingsManifestEntry;
 
-use crate::RestoreOptions;
+use crate::{RestoreJournal, RestoreOptions};
 use log::{debug, info, warn};
+use serde_json::Value;
+use std::collections::HashSet;
 use std::fs;
 use std::path::Path;
 
@@ -33,6 +35,41 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> super::BackupManager<'
     ///
     /// Returns an error if the backup cannot be read or the restore operation fails.
     pub fn restore(&self, options: &RestoreOptions) -> Result<RestoreResult> {
+        self.restore_with_journal(options, &RestoreJournal::default())
+    }
+
+    /// Resume a restore that was interrupted
+    ///
+    /// Items listed in `journal` are not touched again and are reported as
+    /// skipped with [`RestoreSkipReason::RestoredInPreviousAttempt`]; everything
+    /// else is restored as with [`restore`](Self::restore). If
+    /// `options.journal_path` is set, the journal keeps being updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the journal belongs to a different backup, the
+    /// backup cannot be read, or the restore operation fails.
+    pub fn resume_restore(
+        &self,
+        options: &RestoreOptions,
+        journal: &RestoreJournal,
+    ) -> Result<RestoreResult> {
+        if journal.backup_path != options.backup_path {
+            return Err(Error::RestoreFailed(format!(
+                "Journal belongs to {}, not {}",
+                journal.backup_path.display(),
+                options.backup_path.display()
+            )));
+        }
+
+        self.restore_with_journal(options, journal)
+    }
+
+    fn restore_with_journal(
+        &self,
+        options: &RestoreOptions,
+        journal: &RestoreJournal,
+    ) -> Result<RestoreResult> {
         let mode_str = if options.flags.control.dry_run {
             "[DRY RUN] "
         } else {
@@ -43,6 +80,11 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> super::BackupManager<'
             options.backup_path.display()
         );
 
+        // Write deferred changes first so they cannot overwrite restored files later
+        if !options.flags.control.dry_run {
+            self.manager.flush()?;
+        }
+
         // Analyze the backup first
         let analysis = self.analyze(&options.backup_path)?;
 
@@ -103,20 +145,60 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> super::BackupManager<'
             }
         }
 
+        // An incremental backup only holds what changed: lay it over its base
+        if let Some(base) = &analysis.manifest.base {
+            let base_path = options.base_path.as_ref().ok_or_else(|| {
+                Error::MissingBaseBackup(format!(
+                    "{} is incremental and needs its base backup '{}'",
+                    options.backup_path.display(),
+                    base.file_name
+                ))
+            })?;
+            let base_checksum = self.extract_base(
+                base_path,
+                options.password.as_deref(),
+                temp_dir.path(),
+                &extract_dir,
+            )?;
+            if base_checksum != base.sha256 {
+                return Err(Error::InvalidBackup(format!(
+                    "{} is not the base backup of {}",
+                    base_path.display(),
+                    options.backup_path.display()
+                )));
+            }
+        }
+
         // Extract data archive (always zip now)
-        extract_zip_archive(
+        extract_data_archive(
             &data_archive_path,
             &extract_dir,
+            analysis.manifest.backup.effective_cipher(),
             options.password.as_deref(),
         )?;
 
+        if let Some(base) = &analysis.manifest.base {
+            for file in &base.removed_files {
+                let file = Path::new(file);
+                if file
+                    .components()
+                    .all(|c| matches!(c, std::path::Component::Normal(_)))
+                {
+                    fs::remove_file(extract_dir.join(file)).ok();
+                }
+            }
+        }
+
         // Create context
+        let completed: HashSet<String> = journal.completed.iter().cloned().collect();
         let ctx = RestoreContext {
             manager: self,
             options,
             extract_dir: &extract_dir,
             analysis: &analysis,
             mode_str,
+            journal,
+            completed: &completed,
         };
 
         // Restore main settings
@@ -128,6 +210,10 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> super::BackupManager<'
         // Restore external configs
         ctx.restore_external_configs_entries(&mut result)?;
 
+        if let Some(path) = options.journal_path.as_ref().filter(|_| !result.is_dry_run) {
+            fs::remove_file(path).ok();
+        }
+
         info!(
             "Restore complete: {} restored, {} skipped",
             result.restored.len(),
@@ -173,7 +259,12 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> super::BackupManager<'
         let extract_dir = temp_dir.path().join("extracted");
 
         // Extract (always zip now)
-        extract_zip_archive(&data_archive_path, &extract_dir, password)?;
+        extract_data_archive(
+            &data_archive_path,
+            &extract_dir,
+            analysis.manifest.backup.effective_cipher(),
+            password,
+        )?;
 
         let external_dir = extract_dir.join("external");
 
@@ -243,6 +334,9 @@ struct RestoreContext<'a, S: StorageBackend + 'static, Schema: SettingsSchema> {
     extract_dir: &'a Path,
     analysis: &'a BackupAnalysis,
     mode_str: &'a str,
+    /// Journal this restore resumes from
+    journal: &'a RestoreJournal,
+    completed: &'a HashSet<String>,
 }
 
 /// Helper context for sub-settings operations to reduce argument count
@@ -253,6 +347,151 @@ struct SubSettingsContext<'a, S: StorageBackend> {
 }
 
 impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S, Schema> {
+    /// In [`RestoreMode::Merge`](super::RestoreMode::Merge), merge `value`
+    /// over the settings file already at `dest`.
+    fn merge_with_existing_file(&self, dest: &Path, value: &mut serde_json::Value) -> Result<()> {
+        if !self.options.flags.control.merge || !dest.exists() {
+            return Ok(());
+        }
+        let mut current: serde_json::Value = self.manager.manager.storage().read(dest)?;
+        crate::utils::value::deep_merge(&mut current, value);
+        *value = current;
+        Ok(())
+    }
+
+    /// Record the settings values a dry run would change by restoring
+    /// `backup` over the settings file at `dest`.
+    ///
+    /// Values are compared as [`get_value`](crate::SettingsManager::get_value)
+    /// would resolve them, so a key missing from one side counts as its
+    /// default and a key overridden by an environment variable never changes.
+    /// Secret settings are left out.
+    fn record_settings_changes(
+        &self,
+        dest: &Path,
+        backup: &Value,
+        key_prefix: &str,
+        result: &mut RestoreResult,
+    ) -> Result<()> {
+        let settings = self.manager.manager;
+        let stored: Value = if dest.exists() {
+            settings.storage().read(dest)?
+        } else {
+            Value::Object(serde_json::Map::new())
+        };
+
+        let mut incoming = backup.clone();
+        if self.options.flags.control.merge {
+            incoming = stored.clone();
+            crate::utils::value::deep_merge(&mut incoming, backup);
+        }
+
+        let current = settings.resolve_effective(&stored)?;
+        let incoming = settings.resolve_effective(&incoming)?;
+
+        let mut keys: Vec<&String> = settings
+            .schema_metadata()
+            .iter()
+            .filter(|(_, metadata)| !metadata.is_secret())
+            .map(|(key, _)| key)
+            .collect();
+        keys.sort();
+
+        for key in keys {
+            let current = crate::utils::value::get_path(&current, key);
+            let incoming = crate::utils::value::get_path(&incoming, key);
+            if current != incoming {
+                result.changes.push(SettingChange {
+                    key: format!("{key_prefix}{key}"),
+                    current: current.cloned().unwrap_or(Value::Null),
+                    incoming: incoming.cloned().unwrap_or(Value::Null),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Record the change a dry run would make by restoring `value` as the
+    /// sub-settings entry `name`. Secret fields are left out.
+    fn record_entry_change(
+        &self,
+        sub: &crate::sub_settings::SubSettings<S>,
+        name: &str,
+        id: &str,
+        value: &Value,
+        result: &mut RestoreResult,
+    ) -> Result<()> {
+        let mut current = if sub.exists(name)? {
+            sub.get_value(name)?
+        } else {
+            Value::Null
+        };
+        let mut incoming = value.clone();
+        if self.options.flags.control.merge && !current.is_null() {
+            incoming = current.clone();
+            crate::utils::value::deep_merge(&mut incoming, value);
+        }
+
+        if let Some(schema) = sub.schema_metadata() {
+            for (path, _) in schema.iter().filter(|(_, metadata)| metadata.is_secret()) {
+                crate::utils::value::remove_path(&mut current, path);
+                crate::utils::value::remove_path(&mut incoming, path);
+            }
+        }
+
+        if current != incoming {
+            result.changes.push(SettingChange {
+                key: id.to_string(),
+                current,
+                incoming,
+            });
+        }
+        Ok(())
+    }
+
+    /// Skip items that a previous (interrupted) attempt already restored
+    fn restored_previously(&self, id: &str, result: &mut RestoreResult) -> bool {
+        if !self.completed.contains(id) {
+            return false;
+        }
+        debug!(
+            "{} Skipping {id} (restored in previous attempt)",
+            self.mode_str
+        );
+        result.add_skipped(id, RestoreSkipReason::RestoredInPreviousAttempt);
+        true
+    }
+
+    /// Record `id` as restored and persist progress to the journal file
+    fn mark_restored(&self, result: &mut RestoreResult, id: impl Into<String>) {
+        result.restored.push(id.into());
+        self.write_journal(result);
+    }
+
+    /// Persist progress to the journal file, if one was requested
+    ///
+    /// Runs after every restored item so an interrupted restore can resume
+    /// where it stopped. A failed write is logged rather than returned: it
+    /// must not abort the restore or hide the error that ends it.
+    fn write_journal(&self, result: &RestoreResult) {
+        let Some(path) = &self.options.journal_path else {
+            return;
+        };
+        if result.is_dry_run {
+            return;
+        }
+
+        let mut completed = self.journal.completed.clone();
+        completed.extend(result.restored.iter().cloned());
+        let journal = RestoreJournal {
+            backup_path: self.options.backup_path.clone(),
+            completed,
+        };
+        if let Err(e) = journal.save(path) {
+            warn!("Failed to update restore journal {}: {e}", path.display());
+        }
+    }
+
     fn restore_main_settings(&self, result: &mut RestoreResult) -> Result<()> {
         if !self.options.flags.scope.restore_settings {
             return Ok(());
@@ -278,7 +517,9 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
                     .unwrap_or_default()
                     .to_string_lossy();
 
-                if settings_dest.exists() && !self.options.flags.control.overwrite_existing {
+                if self.restored_previously(&dest_filename, result) {
+                    // Nothing to do
+                } else if settings_dest.exists() && !self.options.flags.control.overwrite_existing {
                     result
                         .add_skipped(dest_filename.to_string(), RestoreSkipReason::ExistsConflict);
                     warn!(
@@ -286,9 +527,11 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
                         self.mode_str, dest_filename
                     );
                 } else if self.options.flags.control.dry_run {
-                    result.restored.push(dest_filename.to_string());
+                    self.record_settings_changes(&settings_dest, &value, "", result)?;
+                    self.mark_restored(result, dest_filename.to_string());
                     debug!("{} Would restore {}", self.mode_str, dest_filename);
                 } else {
+                    self.merge_with_existing_file(&settings_dest, &mut value)?;
                     self.hydrate_main_settings_secrets(&mut value, None);
 
                     // Write using the configured storage backend (handles conversion!)
@@ -296,7 +539,7 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
                         .manager
                         .storage()
                         .write(&settings_dest, &value)?;
-                    result.restored.push(dest_filename.to_string());
+                    self.mark_restored(result, dest_filename.to_string());
                     debug!("Restored {dest_filename}");
                 }
             }
@@ -315,19 +558,19 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
         let profiles_manifest = self.extract_dir.join(&manifest_filename);
         let target_manifest = config.config_dir.join(&manifest_filename);
 
-        if profiles_manifest.exists() {
+        if profiles_manifest.exists() && !self.restored_previously(&manifest_filename, result) {
             if target_manifest.exists() && !self.options.flags.control.overwrite_existing {
                 result.add_skipped(manifest_filename.clone(), RestoreSkipReason::ExistsConflict);
                 warn!("{} Skipping {} (exists)", self.mode_str, manifest_filename);
             } else if self.options.flags.control.dry_run {
-                result.restored.push(manifest_filename.clone());
+                self.mark_restored(result, manifest_filename.clone());
                 debug!("{} Would restore {}", self.mode_str, manifest_filename);
             } else {
                 fs::copy(&profiles_manifest, &target_manifest).map_err(|e| Error::FileWrite {
                     path: target_manifest.clone(),
                     source: e,
                 })?;
-                result.restored.push(manifest_filename);
+                self.mark_restored(result, manifest_filename);
             }
         }
 
@@ -374,6 +617,9 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
                 let target_settings_file = &self.manager.manager.config().settings_file;
                 let dest_settings = target_profile_path.join(target_settings_file);
                 let restore_id = format!("profiles/{target_profile_name}/{target_settings_file}");
+                if self.restored_previously(&restore_id, result) {
+                    continue;
+                }
 
                 if let Some((value, _ext)) = load_settings_agnostic(
                     &src_profile_path,
@@ -385,12 +631,19 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
                     if dest_settings.exists() && !self.options.flags.control.overwrite_existing {
                         result.add_skipped(restore_id, RestoreSkipReason::ExistsConflict);
                     } else if self.options.flags.control.dry_run {
-                        result.restored.push(restore_id);
+                        self.record_settings_changes(
+                            &dest_settings,
+                            &value,
+                            &format!("{PROFILES_DIR}/{target_profile_name}/"),
+                            result,
+                        )?;
+                        self.mark_restored(result, restore_id);
                         debug!(
                             "{} Would restore settings for profile {target_profile_name}",
                             self.mode_str
                         );
                     } else {
+                        self.merge_with_existing_file(&dest_settings, &mut value)?;
                         self.hydrate_main_settings_secrets(
                             &mut value,
                             Some(target_profile_name.as_str()),
@@ -400,7 +653,7 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
                             .manager
                             .storage()
                             .write(&dest_settings, &value)?;
-                        result.restored.push(restore_id);
+                        self.mark_restored(result, restore_id);
                         debug!("Restored settings for profile {target_profile_name}");
                     }
                 }
@@ -590,29 +843,39 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
         }
 
         // Process the collected entries
-        for (entry_name, value) in entries_to_restore {
+        for (entry_name, mut value) in entries_to_restore {
             // Filter by items if specified
             if !sub_ctx.items_filter.is_empty() && !sub_ctx.items_filter.contains(&entry_name) {
                 continue;
             }
 
             let entry_id = format!("{}/{}", sub_ctx.sub_type, entry_name);
+            if self.restored_previously(&entry_id, result) {
+                continue;
+            }
 
             // Check if exists
-            if !self.options.flags.control.overwrite_existing && sub_ctx.sub.exists(&entry_name)? {
+            let exists = sub_ctx.sub.exists(&entry_name)?;
+            if !self.options.flags.control.overwrite_existing && exists {
                 result.add_skipped(entry_id, RestoreSkipReason::ExistsConflict);
                 continue;
             }
 
             if self.options.flags.control.dry_run {
-                result.restored.push(entry_id.clone());
+                self.record_entry_change(sub_ctx.sub, &entry_name, &entry_id, &value, result)?;
+                self.mark_restored(result, entry_id.clone());
                 debug!("{} Would restore {entry_id}", self.mode_str);
                 continue;
             }
 
+            if self.options.flags.control.merge && exists {
+                let mut current = sub_ctx.sub.get_value(&entry_name)?;
+                crate::utils::value::deep_merge(&mut current, &value);
+                value = current;
+            }
             sub_ctx.sub.set(&entry_name, &value)?;
 
-            result.restored.push(entry_id.clone());
+            self.mark_restored(result, entry_id.clone());
             debug!("Restored {entry_id}");
         }
         Ok(())
@@ -745,15 +1008,14 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
 
                     // Target file
                     let dest = dest_profile_path.join(&file_name);
+                    let item_id = format!("{}/{target_profile_name}/{stem}", sub_ctx.sub_type);
 
-                    if dest.exists() && !self.options.flags.control.overwrite_existing {
-                        result
-                            .skipped
-                            .push(format!("{}/{target_profile_name}/{stem}", sub_ctx.sub_type));
+                    if self.restored_previously(&item_id, result) {
+                        // Nothing to do
+                    } else if dest.exists() && !self.options.flags.control.overwrite_existing {
+                        result.skipped.push(item_id);
                     } else if self.options.flags.control.dry_run {
-                        result
-                            .restored
-                            .push(format!("{}/{target_profile_name}/{stem}", sub_ctx.sub_type));
+                        self.mark_restored(result, item_id);
                         debug!(
                             "{} Would restore {stem} to profile {target_profile_name}",
                             self.mode_str
@@ -770,9 +1032,7 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
                             path: dest.clone(),
                             source: e,
                         })?;
-                        result
-                            .restored
-                            .push(format!("{}/{target_profile_name}/{stem}", sub_ctx.sub_type));
+                        self.mark_restored(result, item_id);
                         debug!("Restored {stem} to profile {target_profile_name}");
                     }
                 }
@@ -815,16 +1075,25 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
                             for (k, v) in map {
                                 let item_id = format!("{}/{k}", sub_ctx.sub_type);
 
-                                if sub_ctx.sub.exists(&k)?
+                                if self.restored_previously(&item_id, result) {
+                                    // Nothing to do
+                                } else if sub_ctx.sub.exists(&k)?
                                     && !self.options.flags.control.overwrite_existing
                                 {
                                     result.add_skipped(item_id, RestoreSkipReason::ExistsConflict);
                                 } else if self.options.flags.control.dry_run {
-                                    result.restored.push(item_id.clone());
+                                    self.record_entry_change(
+                                        sub_ctx.sub,
+                                        &k,
+                                        &item_id,
+                                        &v,
+                                        result,
+                                    )?;
+                                    self.mark_restored(result, item_id.clone());
                                     debug!("{} Would restore flattened {item_id}", self.mode_str);
                                 } else {
                                     sub_ctx.sub.set(&k, &v)?;
-                                    result.restored.push(item_id.clone());
+                                    self.mark_restored(result, item_id.clone());
                                     debug!("Restored flattened {item_id}");
                                 }
                             }
@@ -834,15 +1103,19 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
 
                     let entry_id = format!("{}/{stem}", sub_ctx.sub_type);
 
-                    if sub_ctx.sub.exists(&stem)? && !self.options.flags.control.overwrite_existing
+                    if self.restored_previously(&entry_id, result) {
+                        // Nothing to do
+                    } else if sub_ctx.sub.exists(&stem)?
+                        && !self.options.flags.control.overwrite_existing
                     {
                         result.add_skipped(entry_id, RestoreSkipReason::ExistsConflict);
                     } else if self.options.flags.control.dry_run {
-                        result.restored.push(entry_id.clone());
+                        self.record_entry_change(sub_ctx.sub, &stem, &entry_id, &value, result)?;
+                        self.mark_restored(result, entry_id.clone());
                         debug!("{} Would restore flattened {entry_id}", self.mode_str);
                     } else {
                         sub_ctx.sub.set(&stem, &value)?;
-                        result.restored.push(entry_id.clone());
+                        self.mark_restored(result, entry_id.clone());
                         debug!("Restored flattened {entry_id}");
                     }
                 }
@@ -891,6 +1164,10 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
         external_dir: &Path,
         result: &mut RestoreResult,
     ) -> Result<()> {
+        if self.restored_previously(config_name, result) {
+            return Ok(());
+        }
+
         if let Some(external_config) = self.manager.resolve_external_config(config_name) {
             let data = Self::read_external_backup_data(
                 external_dir,
@@ -916,7 +1193,7 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
                         );
                         debug!("{} Skipping external {config_name} (exists)", self.mode_str);
                     } else if self.options.flags.control.dry_run {
-                        result.restored.push(config_name.to_string());
+                        self.mark_restored(result, config_name.to_string());
                         debug!("{} Would restore external {config_name}", self.mode_str);
                     } else {
                         if let Some(parent) = dest_path.parent() {
@@ -929,13 +1206,13 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
                             path: dest_path.clone(),
                             source: e,
                         })?;
-                        result.restored.push(config_name.to_string());
+                        self.mark_restored(result, config_name.to_string());
                         debug!("Restored external {config_name}");
                     }
                 }
                 super::types::ImportTarget::Command { program, args } => {
                     if self.options.flags.control.dry_run {
-                        result.restored.push(config_name.to_string());
+                        self.mark_restored(result, config_name.to_string());
                         debug!("{} Would pipe to command: {program}", self.mode_str);
                     } else {
                         use std::io::Write;
@@ -970,20 +1247,20 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> RestoreContext<'_, S,
                             )));
                         }
 
-                        result.restored.push(config_name.to_string());
+                        self.mark_restored(result, config_name.to_string());
                         debug!("Restored external {config_name} via command");
                     }
                 }
                 super::types::ImportTarget::Handler(handler) => {
                     if self.options.flags.control.dry_run {
-                        result.restored.push(config_name.to_string());
+                        self.mark_restored(result, config_name.to_string());
                         debug!(
                             "{} Would call custom handler for {config_name}",
                             self.mode_str
                         );
                     } else {
                         handler(&data)?;
-                        result.restored.push(config_name.to_string());
+                        self.mark_restored(result, config_name.to_string());
                         debug!("Restored external {config_name} via handler");
                     }
                 }
@@ -1094,6 +1371,8 @@ pub enum RestoreSkipReason {
     ReadOnlyImportTarget,
     /// Requested sub-settings type was not registered in target manager.
     UnregisteredSubSettingsType,
+    /// Item was already restored by an earlier, interrupted attempt.
+    RestoredInPreviousAttempt,
 }
 
 /// Detailed skipped restore item with reason.
@@ -1125,6 +1404,19 @@ pub struct RestorePendingItem {
     pub reason: RestorePendingReason,
 }
 
+/// A value a dry-run restore would change
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingChange {
+    /// What changes: a settings key (`category.key`, prefixed with
+    /// `profiles/<name>/` when profiles are enabled), or a sub-settings entry
+    /// (`type/entry`)
+    pub key: String,
+    /// Current value, `Null` if a sub-settings entry does not exist yet
+    pub current: Value,
+    /// Value after the restore
+    pub incoming: Value,
+}
+
 #[derive(Debug, Default)]
 pub struct RestoreResult {
     /// Items that were restored
@@ -1145,6 +1437,14 @@ pub struct RestoreResult {
     /// Whether this was a dry run (no actual changes made)
     pub is_dry_run: bool,
 
+    /// Values the restore would change, filled in only for dry runs.
+    ///
+    /// Covers main settings key by key and sub-settings entry by entry
+    /// (added or changed; a restore never removes entries). Files copied as a
+    /// whole, such as per-profile sub-settings and external configs, are only
+    /// listed in `restored`.
+    pub changes: Vec<SettingChange>,
+
     /// Whether the checksum was verified successfully
     pub checksum_valid: Option<bool>,
 }
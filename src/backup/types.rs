@@ -97,6 +97,30 @@ pub struct BackupOptions {
 
     /// Policy for handling secret values (passwords, tokens)
     pub secret_policy: crate::SecretBackupPolicy,
+
+    /// Only back up main settings that differ from their defaults
+    pub customizations_only: bool,
+
+    /// Full backup to build an incremental backup on
+    pub base: Option<PathBuf>,
+
+    /// Cipher used when a password is set
+    pub cipher: BackupCipher,
+}
+
+/// Cipher of a password-protected backup
+///
+/// Recorded in the manifest, so restore picks the matching decryption. Backups
+/// without a recorded cipher use [`Aes256`](Self::Aes256).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupCipher {
+    /// AES-256 zip entry encryption (`WinZip` AE-2), readable by common zip tools
+    #[default]
+    Aes256,
+    /// ChaCha20-Poly1305 over the whole data archive, with an Argon2id key.
+    /// Requires the `backup-chacha` feature
+    ChaCha20Poly1305,
 }
 
 /// Callback function for progress reporting (`current_bytes`, `total_bytes`)
@@ -132,6 +156,9 @@ impl Default for BackupOptions {
             #[cfg(feature = "profiles")]
             include_profiles: Vec::new(),
             secret_policy: crate::SecretBackupPolicy::default(),
+            customizations_only: false,
+            base: None,
+            cipher: BackupCipher::default(),
         }
     }
 }
@@ -182,6 +209,25 @@ impl BackupOptions {
         self
     }
 
+    /// Set the cipher used to encrypt the backup (requires a password)
+    ///
+    /// [`BackupCipher::ChaCha20Poly1305`] needs the `backup-chacha` feature;
+    /// without it creating the backup fails.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rcman::{BackupCipher, BackupOptions};
+    ///
+    /// let options = BackupOptions::new()
+    ///     .password("secure-password")
+    ///     .cipher(BackupCipher::ChaCha20Poly1305);
+    /// ```
+    #[must_use]
+    pub fn cipher(mut self, cipher: BackupCipher) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
     /// Add a user note to include in the backup manifest
     #[must_use]
     pub fn note(mut self, note: impl Into<String>) -> Self {
@@ -240,6 +286,29 @@ impl BackupOptions {
         self
     }
 
+    /// Only back up settings that differ from their defaults.
+    ///
+    /// Switches to `ExportType::SettingsOnly`, so sub-settings and external
+    /// configs are only included when selected explicitly. Secrets still follow
+    /// the secret policy. Restoring such a backup layers the customizations on
+    /// top of the target's defaults.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rcman::BackupOptions;
+    ///
+    /// let options = BackupOptions::new()
+    ///     .customizations_only()
+    ///     .include_sub_settings_items("remotes", &["gdrive"]);
+    /// ```
+    #[must_use]
+    pub fn customizations_only(mut self) -> Self {
+        self.export_type = ExportType::SettingsOnly;
+        self.include_settings = true;
+        self.customizations_only = true;
+        self
+    }
+
     /// Set a custom filename suffix
     #[must_use]
     pub fn filename_suffix(mut self, suffix: impl Into<String>) -> Self {
@@ -257,6 +326,19 @@ impl BackupOptions {
         self
     }
 
+    /// Create an incremental backup on top of the full backup at `path`.
+    ///
+    /// Files that are identical in the base backup are left out, so a backup
+    /// after changing one remote only stores that remote. The manifest records
+    /// the base's checksum, and restoring needs both files (see
+    /// [`RestoreOptions::base`]). The base must be a full backup, not another
+    /// incremental one; if it is encrypted, it must use the same password.
+    #[must_use]
+    pub fn base(mut self, path: impl Into<PathBuf>) -> Self {
+        self.base = Some(path.into());
+        self
+    }
+
     /// Include specific profile (if profiles enabled)
     #[cfg(feature = "profiles")]
     #[must_use]
@@ -264,18 +346,63 @@ impl BackupOptions {
         self.include_profiles.push(profile.into());
         self
     }
+
+    /// Whether secret values go into the backup under the secret policy
+    pub(crate) fn includes_secrets(&self) -> bool {
+        match self.secret_policy {
+            crate::SecretBackupPolicy::Exclude => false,
+            crate::SecretBackupPolicy::Include => true,
+            crate::SecretBackupPolicy::EncryptedOnly => self.password.is_some(),
+        }
+    }
+}
+
+/// How a restore treats settings and entries that already exist
+///
+/// Set with [`RestoreOptions::mode`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RestoreMode {
+    /// Keep existing settings files and entries; restore only missing ones
+    #[default]
+    SkipExisting,
+    /// Replace existing settings files and entries with the backup's
+    Overwrite,
+    /// Deep-merge the backup into existing settings files and sub-settings
+    /// entries
+    ///
+    /// Objects are merged key by key. On a conflict at a leaf (or where only
+    /// one side is an object) the backup's value wins, while keys that exist
+    /// only in the current data are kept. Profiled sub-settings entries and
+    /// external configs are copied as whole files, so they are overwritten.
+    Merge,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
 pub struct RestoreControl {
     /// Whether to overwrite existing entries
     pub overwrite_existing: bool,
+    /// Whether existing settings and entries are merged with the backup
+    /// instead of replaced (requires `overwrite_existing`). Set through
+    /// [`RestoreOptions::mode`] and read through [`mode`](Self::mode).
+    pub(crate) merge: bool,
     /// Dry run mode - preview what would be restored without making changes
     pub dry_run: bool,
     /// Whether to verify the data archive checksum
     pub verify_checksum: bool,
 }
 
+impl RestoreControl {
+    /// The [`RestoreMode`] these flags describe
+    #[must_use]
+    pub fn mode(&self) -> RestoreMode {
+        match (self.overwrite_existing, self.merge) {
+            (false, _) => RestoreMode::SkipExisting,
+            (true, false) => RestoreMode::Overwrite,
+            (true, true) => RestoreMode::Merge,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct RestoreScope {
     /// Whether to restore main settings
@@ -324,6 +451,12 @@ pub struct RestoreOptions {
     /// Rename restored profile to this name (requires `restore_profile`)
     #[cfg(feature = "profiles")]
     pub restore_profile_as: Option<String>,
+
+    /// Path of a progress journal to maintain while restoring (see [`RestoreJournal`])
+    pub journal_path: Option<PathBuf>,
+
+    /// Base backup of an incremental backup
+    pub base_path: Option<PathBuf>,
 }
 
 impl RestoreOptions {
@@ -360,9 +493,21 @@ impl RestoreOptions {
     }
 
     /// Set whether to overwrite existing entries
+    ///
+    /// Shorthand for [`mode`](Self::mode) with [`RestoreMode::Overwrite`] or
+    /// [`RestoreMode::SkipExisting`].
     #[must_use]
     pub fn overwrite(mut self, overwrite: bool) -> Self {
         self.flags.control.overwrite_existing = overwrite;
+        self.flags.control.merge = false;
+        self
+    }
+
+    /// Set how existing settings and entries are treated
+    #[must_use]
+    pub fn mode(mut self, mode: RestoreMode) -> Self {
+        self.flags.control.overwrite_existing = mode != RestoreMode::SkipExisting;
+        self.flags.control.merge = mode == RestoreMode::Merge;
         self
     }
 
@@ -422,6 +567,72 @@ impl RestoreOptions {
         self.restore_profile_as = Some(name.into());
         self
     }
+
+    /// Record restore progress in a journal file
+    ///
+    /// The journal is updated as items are restored and removed once the
+    /// restore completes, so a leftover journal means the restore was
+    /// interrupted and can be continued with `BackupManager::resume_restore`.
+    #[must_use]
+    pub fn journal(mut self, path: impl Into<PathBuf>) -> Self {
+        self.journal_path = Some(path.into());
+        self
+    }
+
+    /// Set the base backup an incremental backup was created on
+    ///
+    /// Required to restore a backup created with [`BackupOptions::base`]; the
+    /// base is checked against the checksum recorded in the incremental
+    /// backup. The password, if any, is used for both files.
+    #[must_use]
+    pub fn base(mut self, path: impl Into<PathBuf>) -> Self {
+        self.base_path = Some(path.into());
+        self
+    }
+}
+
+/// Progress journal of a restore operation
+///
+/// Lists the items (using the same identifiers as `RestoreResult::restored`)
+/// that were already written, so an interrupted restore can be resumed
+/// without redoing or re-skipping them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestoreJournal {
+    /// Backup file the journal belongs to
+    pub backup_path: PathBuf,
+
+    /// Items that were restored successfully
+    pub completed: Vec<String>,
+}
+
+impl RestoreJournal {
+    /// Load a journal from disk
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid journal.
+    pub fn load(path: impl AsRef<std::path::Path>) -> crate::error::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| crate::error::Error::FileRead {
+            path: path.to_path_buf(),
+            source: e,
+        })?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Write the journal to disk
+    ///
+    /// The file is replaced atomically (temp file + rename), so a crash while
+    /// saving leaves the previous journal intact.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the journal cannot be serialized or written.
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> crate::error::Result<()> {
+        use crate::storage::StorageBackend;
+
+        crate::storage::JsonStorage::new().write(path.as_ref(), self)
+    }
 }
 
 // =============================================================================
@@ -741,6 +952,10 @@ pub struct BackupManifest {
 
     /// Integrity info (checksums, sizes)
     pub integrity: BackupIntegrity,
+
+    /// Base backup, if this is an incremental backup
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<BaseBackupRef>,
 }
 
 impl Default for BackupManifest {
@@ -750,10 +965,25 @@ impl Default for BackupManifest {
             backup: BackupInfo::default(),
             contents: BackupContents::default(),
             integrity: BackupIntegrity::default(),
+            base: None,
         }
     }
 }
 
+/// Reference from an incremental backup to the full backup it builds on
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaseBackupRef {
+    /// SHA-256 checksum of the base backup's data archive
+    pub sha256: String,
+
+    /// File name of the base backup when the incremental backup was created
+    pub file_name: String,
+
+    /// Files of the base backup that no longer exist (paths in the data archive)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed_files: Vec<String>,
+}
+
 /// Backup metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackupInfo {
@@ -782,6 +1012,21 @@ pub struct BackupInfo {
     /// was introduced.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub secret_policy: Option<crate::SecretBackupPolicy>,
+
+    /// Cipher of an encrypted backup.
+    ///
+    /// `None` for unencrypted backups and for encrypted backups created before
+    /// this field was introduced, which use [`BackupCipher::Aes256`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cipher: Option<BackupCipher>,
+}
+
+impl BackupInfo {
+    /// Cipher the data archive is encrypted with, if it is encrypted
+    #[must_use]
+    pub fn effective_cipher(&self) -> Option<BackupCipher> {
+        self.encrypted.then(|| self.cipher.unwrap_or_default())
+    }
 }
 
 impl Default for BackupInfo {
@@ -794,6 +1039,7 @@ impl Default for BackupInfo {
             encrypted: false,
             user_note: None,
             secret_policy: None,
+            cipher: None,
         }
     }
 }
@@ -885,6 +1131,28 @@ impl BackupContents {
     }
 }
 
+/// A backup found by [`BackupManager::list`](crate::BackupManager::list)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupEntry {
+    /// Path of the `.rcman` file
+    pub path: PathBuf,
+
+    /// When the backup was created
+    pub created_at: OffsetDateTime,
+
+    /// Application version that created the backup
+    pub app_version: String,
+
+    /// Whether the data is encrypted
+    pub encrypted: bool,
+
+    /// User note
+    pub note: Option<String>,
+
+    /// Size of the `.rcman` file in bytes
+    pub size_bytes: u64,
+}
+
 /// Result of analyzing a backup file
 #[derive(Debug, Clone)]
 pub struct BackupAnalysis {
@@ -8,6 +8,10 @@ pub enum CacheStrategy {
     Full,
     /// LRU cache with maximum entries
     Lru(usize),
+    /// LRU cache bounded by the total serialized size of its entries, in bytes
+    ///
+    /// Fits better than [`Lru`](Self::Lru) when entry sizes are uneven.
+    MemoryBudget(usize),
     /// No caching - always read from disk (high I/O, minimal memory)
     None,
 }
@@ -17,13 +21,33 @@ impl CacheStrategy {
     ///
     /// # Errors
     ///
-    /// Returns error if LRU size is 0 (would panic on `NonZeroUsize`)
+    /// Returns error if LRU size or memory budget is 0
     pub fn validate(&self) -> crate::Result<()> {
         match self {
             CacheStrategy::Lru(size) if *size == 0 => Err(crate::Error::Config(
                 "LRU cache size must be greater than 0".into(),
             )),
+            CacheStrategy::MemoryBudget(bytes) if *bytes == 0 => Err(crate::Error::Config(
+                "Cache memory budget must be greater than 0".into(),
+            )),
             _ => Ok(()),
         }
     }
 }
+
+/// Cache counters of a sub-settings store, for tuning the [`CacheStrategy`]
+///
+/// Hits, misses and evictions accumulate for the lifetime of the store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    /// Entries currently cached
+    pub entries: usize,
+    /// Total serialized size of the cached entries
+    pub bytes: usize,
+    /// Reads served from the cache
+    pub hits: u64,
+    /// Reads that went to disk
+    pub misses: u64,
+    /// Entries dropped to stay within the cache bounds
+    pub evictions: u64,
+}
@@ -1,10 +1,24 @@
 //! Documentation generator for settings schema
 //!
-//! Generates markdown documentation from `SettingsSchema` metadata.
+//! Generates markdown documentation, environment variable docs and a JSON
+//! Schema for the settings file from `SettingsSchema` metadata.
 
-use crate::config::{SettingMetadata, SettingType, SettingsSchema};
+use crate::config::{SettingMetadata, SettingType, SettingsSchema, Widget, meta};
+use crate::manager::REDACTED_SECRET;
+use crate::manager::env::env_var_name;
 use std::collections::HashMap;
 
+/// Layout of the generated documentation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DocsFormat {
+    /// One section per setting with a property table and its options
+    #[default]
+    Sections,
+    /// One table per category with a row per setting, for reference pages.
+    /// Secret defaults are redacted.
+    Markdown,
+}
+
 /// Configuration for docs generation
 #[derive(Debug, Clone)]
 pub struct DocsConfig {
@@ -16,6 +30,8 @@ pub struct DocsConfig {
     pub show_advanced: bool,
     /// Whether to group by category
     pub group_by_category: bool,
+    /// Output layout
+    pub format: DocsFormat,
 }
 
 impl Default for DocsConfig {
@@ -25,6 +41,7 @@ impl Default for DocsConfig {
             description: None,
             show_advanced: true,
             group_by_category: true,
+            format: DocsFormat::Sections,
         }
     }
 }
@@ -52,6 +69,12 @@ impl DocsConfig {
         self.show_advanced = false;
         self
     }
+
+    #[must_use]
+    pub fn with_format(mut self, format: DocsFormat) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 /// Generate markdown documentation from a settings schema
@@ -108,6 +131,12 @@ pub fn generate_docs_from_metadata<S: std::hash::BuildHasher>(
         }
     });
 
+    let format_setting = match config.format {
+        DocsFormat::Sections => format_setting,
+        DocsFormat::Markdown => format_table_row,
+    };
+    let table_header = config.format == DocsFormat::Markdown;
+
     if config.group_by_category {
         // Group by category
         let mut current_category: Option<&str> = None;
@@ -118,6 +147,9 @@ pub fn generate_docs_from_metadata<S: std::hash::BuildHasher>(
             // New category header
             if current_category != Some(category) {
                 let _ = writeln!(output, "\n## {}\n", capitalize(category));
+                if table_header {
+                    output.push_str(TABLE_HEADER);
+                }
                 current_category = Some(category);
             }
 
@@ -126,6 +158,9 @@ pub fn generate_docs_from_metadata<S: std::hash::BuildHasher>(
     } else {
         // Flat list
         output.push_str("## Settings\n\n");
+        if table_header {
+            output.push_str(TABLE_HEADER);
+        }
         for (key, meta) in &settings {
             format_setting(&mut output, key, meta);
         }
@@ -134,6 +169,289 @@ pub fn generate_docs_from_metadata<S: std::hash::BuildHasher>(
     output
 }
 
+/// Generate a markdown table of the environment variables that override settings
+///
+/// Lists one row per setting with its variable name (as built from `prefix`
+/// by the manager, see [`SettingsConfigBuilder::with_env_prefix`]), the
+/// setting key, its description and the default as an example value.
+/// Secret settings are only listed when `env_overrides_secrets` is set,
+/// matching [`SettingsConfigBuilder::env_overrides_secrets`]; their example
+/// value is redacted.
+///
+/// [`SettingsConfigBuilder::with_env_prefix`]: crate::SettingsConfigBuilder::with_env_prefix
+/// [`SettingsConfigBuilder::env_overrides_secrets`]: crate::SettingsConfigBuilder::env_overrides_secrets
+#[must_use]
+pub fn generate_env_docs<S: std::hash::BuildHasher>(
+    prefix: &str,
+    metadata: &HashMap<String, SettingMetadata, S>,
+    env_overrides_secrets: bool,
+) -> String {
+    use std::fmt::Write;
+
+    let mut settings: Vec<_> = metadata
+        .iter()
+        .filter(|(_, meta)| env_overrides_secrets || !meta.is_secret())
+        .map(|(key, meta)| (env_var_name(prefix, key), key, meta))
+        .collect();
+    settings.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut output = String::from(
+        "| Variable | Setting | Description | Example |\n\
+         |----------|---------|-------------|---------|\n",
+    );
+    for (name, key, meta) in settings {
+        let example = if meta.is_secret() {
+            REDACTED_SECRET.to_string()
+        } else {
+            match &meta.default {
+                // Strings are taken as-is, anything else is parsed as JSON
+                serde_json::Value::String(s) => format!("`{s}`"),
+                value => format!("`{value}`"),
+            }
+        };
+        let _ = writeln!(
+            output,
+            "| `{name}` | `{key}` | {} | {} |",
+            table_cell(meta.get_meta_str("description").unwrap_or("")),
+            table_cell(&example)
+        );
+    }
+    output
+}
+
+/// Generate a JSON Schema (draft 2020-12) for the settings file
+///
+/// Keys are nested the way they are stored: `category.key` becomes property
+/// `key` of object property `category`. Each property carries its type,
+/// constraints (`minimum`/`maximum`, `enum`, `pattern`, `minLength`/
+/// `maxLength`), label, description
+/// and default. Defaults of secret settings are left out.
+///
+/// # Example
+///
+/// ```
+/// use rcman::{SettingMetadata, generate_json_schema};
+/// use std::collections::HashMap;
+///
+/// let mut metadata = HashMap::new();
+/// metadata.insert("network.port".to_string(), SettingMetadata::number(8080.0).min(1.0));
+///
+/// let schema = generate_json_schema(&metadata);
+/// assert_eq!(schema["properties"]["network"]["properties"]["port"]["minimum"], 1.0);
+/// ```
+#[must_use]
+pub fn generate_json_schema<S: std::hash::BuildHasher>(
+    metadata: &HashMap<String, SettingMetadata, S>,
+) -> serde_json::Value {
+    use serde_json::{Map, Value, json};
+
+    let mut properties = Map::new();
+    let mut keys: Vec<&String> = metadata.keys().collect();
+    keys.sort();
+
+    for key in keys {
+        let property = json_schema_property(&metadata[key]);
+        match key.split_once('.') {
+            Some((category, name)) => {
+                let category = properties
+                    .entry(category)
+                    .or_insert_with(|| json!({"type": "object", "properties": {}}));
+                if let Some(fields) = category
+                    .get_mut("properties")
+                    .and_then(Value::as_object_mut)
+                {
+                    fields.insert(name.to_string(), property);
+                }
+            }
+            None => {
+                properties.insert(key.clone(), property);
+            }
+        }
+    }
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": properties,
+    })
+}
+
+fn json_schema_property(meta: &SettingMetadata) -> serde_json::Value {
+    use serde_json::{Map, Value, json};
+
+    let mut property = Map::new();
+    match meta.setting_type {
+        SettingType::Toggle => {
+            property.insert("type".into(), json!("boolean"));
+        }
+        SettingType::Text => {
+            property.insert("type".into(), json!("string"));
+            if let Some(ref pattern) = meta.constraints.text.pattern {
+                property.insert("pattern".into(), json!(pattern));
+            }
+            if let Some(min) = meta.constraints.text.min_length {
+                property.insert("minLength".into(), json!(min));
+            }
+            if let Some(max) = meta.constraints.text.max_length {
+                property.insert("maxLength".into(), json!(max));
+            }
+        }
+        SettingType::Number => {
+            property.insert("type".into(), json!("number"));
+            if let Some(min) = meta.constraints.number.min {
+                property.insert("minimum".into(), json!(min));
+            }
+            if let Some(max) = meta.constraints.number.max {
+                property.insert("maximum".into(), json!(max));
+            }
+        }
+        SettingType::Select => {
+            if let Some(ref options) = meta.constraints.options {
+                let values: Vec<Value> = options.iter().map(|opt| opt.value.clone()).collect();
+                property.insert("enum".into(), Value::Array(values));
+            }
+        }
+        SettingType::MultiSelect => {
+            property.insert("type".into(), json!("array"));
+            property.insert("uniqueItems".into(), json!(true));
+            if let Some(ref options) = meta.constraints.options {
+                let values: Vec<Value> = options.iter().map(|opt| opt.value.clone()).collect();
+                property.insert("items".into(), json!({"enum": values}));
+            }
+        }
+        SettingType::Duration => {
+            property.insert("type".into(), json!("integer"));
+            property.insert(
+                "minimum".into(),
+                json!(meta.constraints.number.min.unwrap_or(0.0)),
+            );
+            if let Some(max) = meta.constraints.number.max {
+                property.insert("maximum".into(), json!(max));
+            }
+        }
+        SettingType::Color => {
+            property.insert("type".into(), json!("string"));
+            property.insert(
+                "pattern".into(),
+                json!("^#([0-9a-fA-F]{6}|[0-9a-fA-F]{8})$"),
+            );
+        }
+        SettingType::Info => {
+            property.insert("readOnly".into(), json!(true));
+        }
+        SettingType::List => {
+            property.insert("type".into(), json!("array"));
+            property.insert("items".into(), json!({"type": "string"}));
+        }
+        // Any JSON value is accepted
+        SettingType::Object => {}
+    }
+
+    if meta.nullable {
+        if let Some(ty) = property.remove("type") {
+            property.insert("type".into(), json!([ty, "null"]));
+        } else if let Some(values) = property.remove("enum") {
+            property.insert("anyOf".into(), json!([{"enum": values}, {"type": "null"}]));
+        }
+    }
+
+    if let Some(label) = meta.get_meta_str("label") {
+        property.insert("title".into(), json!(label));
+    }
+    if let Some(description) = meta.get_meta_str("description") {
+        property.insert("description".into(), json!(description));
+    }
+    // Annotation only, standard validators ignore unknown keywords
+    if let Some(ref condition) = meta.depends_on {
+        property.insert(
+            "x-depends-on".into(),
+            json!({"key": condition.key, "value": condition.value}),
+        );
+    }
+    if !meta.is_secret() {
+        property.insert("default".into(), meta.default.clone());
+    }
+
+    Value::Object(property)
+}
+
+const TABLE_HEADER: &str = "| Setting | Label | Type | Default | Constraints | Description | Notes |\n\
+                            |---------|-------|------|---------|-------------|-------------|-------|\n";
+
+fn format_table_row(out: &mut String, key: &str, meta: &SettingMetadata) {
+    use std::fmt::Write;
+
+    let default = if meta.is_secret() {
+        REDACTED_SECRET.to_string()
+    } else {
+        format!("`{}`", format_value(&meta.default))
+    };
+
+    let mut constraints = Vec::new();
+    let number = &meta.constraints.number;
+    match (number.min, number.max) {
+        (Some(min), Some(max)) => constraints.push(format!("{min} - {max}")),
+        (Some(min), None) => constraints.push(format!("min {min}")),
+        (None, Some(max)) => constraints.push(format!("max {max}")),
+        (None, None) => {}
+    }
+    if let Some(step) = number.step {
+        constraints.push(format!("step {step}"));
+    }
+    if let Some(length) = format_length(meta) {
+        constraints.push(length);
+    }
+    if let Some(ref pattern) = meta.constraints.text.pattern {
+        constraints.push(format!("pattern `{pattern}`"));
+    }
+    if let Some(ref options) = meta.constraints.options {
+        let values: Vec<String> = options
+            .iter()
+            .map(|opt| format!("`{}`", format_value(&opt.value)))
+            .collect();
+        let quantifier = if meta.setting_type == SettingType::MultiSelect {
+            "any of"
+        } else {
+            "one of"
+        };
+        constraints.push(format!("{quantifier} {}", values.join(", ")));
+    }
+
+    let mut notes = Vec::new();
+    if meta.is_restart_required() {
+        notes.push("requires restart".to_string());
+    }
+    if meta.get_meta_bool("advanced").unwrap_or(false) {
+        notes.push("advanced".to_string());
+    }
+    if meta.is_secret() {
+        notes.push(meta::SECRET.to_string());
+    }
+    if let Some(ref condition) = meta.depends_on {
+        notes.push(format!(
+            "when `{}` is `{}`",
+            condition.key,
+            format_value(&condition.value)
+        ));
+    }
+
+    let _ = writeln!(
+        out,
+        "| `{key}` | {} | {} | {} | {} | {} | {} |",
+        table_cell(meta.get_meta_str("label").unwrap_or("")),
+        format_type(&meta.setting_type),
+        table_cell(&default),
+        table_cell(&constraints.join(", ")),
+        table_cell(meta.get_meta_str("description").unwrap_or("")),
+        table_cell(&notes.join(", "))
+    );
+}
+
+/// Keep a value on one table row: escape pipes and fold line breaks
+fn table_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
 fn format_setting(out: &mut String, key: &str, meta: &SettingMetadata) {
     use std::fmt::Write;
 
@@ -168,20 +486,61 @@ fn format_setting(out: &mut String, key: &str, meta: &SettingMetadata) {
     out.push_str("|----------|-------|\n");
     let _ = writeln!(out, "| **Type** | {} |", format_type(&meta.setting_type));
     let _ = writeln!(out, "| **Default** | `{}` |", format_value(&meta.default));
+    if let Some(ref condition) = meta.depends_on {
+        let _ = writeln!(
+            out,
+            "| **Depends on** | `{}` = `{}` |",
+            condition.key,
+            table_cell(&format_value(&condition.value))
+        );
+    }
 
     // Range for numbers
     if meta.setting_type == SettingType::Number {
         if let (Some(min), Some(max)) = (meta.constraints.number.min, meta.constraints.number.max) {
-            let _ = writeln!(out, "| **Range** | {min} - {max} |");
+            if meta.constraints.number.clamp {
+                let _ = writeln!(
+                    out,
+                    "| **Range** | {min} - {max} (stored values outside are clamped) |"
+                );
+            } else {
+                let _ = writeln!(out, "| **Range** | {min} - {max} |");
+            }
         }
         if let Some(step) = meta.constraints.number.step {
-            let _ = writeln!(out, "| **Step** | {step} |");
+            if meta.constraints.number.enforce_step {
+                let base = meta.constraints.number.min.unwrap_or(0.0);
+                let _ = writeln!(
+                    out,
+                    "| **Step** | {step} (enforced: value must be {base} plus a multiple of {step}) |"
+                );
+            } else {
+                let _ = writeln!(out, "| **Step** | {step} |");
+            }
+        }
+        if let Some(widget) = meta.constraints.number.widget {
+            let widget = match widget {
+                Widget::Slider => "Slider",
+                Widget::SpinBox => "Spin box",
+                Widget::Stepper => "Stepper",
+            };
+            let _ = writeln!(out, "| **Widget** | {widget} |");
         }
     }
 
-    // Pattern for text
+    // Length and pattern for text
+    if let Some(length) = format_length(meta) {
+        let _ = writeln!(out, "| **Length** | {length} |");
+    }
     if let Some(ref pattern) = meta.constraints.text.pattern {
-        let _ = writeln!(out, "| **Pattern** | `{pattern}` |");
+        match meta.constraints.text.pattern_flags.as_deref() {
+            Some(flags) if !flags.is_empty() => {
+                let _ = writeln!(out, "| **Pattern** | `{pattern}` (flags: `{flags}`) |");
+            }
+            _ => {
+                let _ = writeln!(out, "| **Pattern** | `{pattern}` |");
+            }
+        }
     }
 
     out.push('\n');
@@ -208,12 +567,27 @@ fn format_setting(out: &mut String, key: &str, meta: &SettingMetadata) {
     out.push_str("---\n\n");
 }
 
+fn format_length(meta: &SettingMetadata) -> Option<String> {
+    match (
+        meta.constraints.text.min_length,
+        meta.constraints.text.max_length,
+    ) {
+        (Some(min), Some(max)) => Some(format!("{min} - {max} characters")),
+        (Some(min), None) => Some(format!("at least {min} characters")),
+        (None, Some(max)) => Some(format!("at most {max} characters")),
+        (None, None) => None,
+    }
+}
+
 fn format_type(t: &SettingType) -> &'static str {
     match t {
         SettingType::Toggle => "Boolean",
         SettingType::Text => "String",
         SettingType::Number => "Number",
         SettingType::Select => "Select",
+        SettingType::MultiSelect => "Multi-select",
+        SettingType::Duration => "Duration (ms)",
+        SettingType::Color => "Color (`#RRGGBB` or `#RRGGBBAA`)",
         SettingType::Info => "Info (Read-only)",
         SettingType::List => "List (Strings)",
         SettingType::Object => "Object (JSON)",
@@ -276,7 +650,8 @@ mod tests {
                     .meta_str("category", "network")
                     .min(1.0)
                     .max(65535.0)
-                    .meta_str("description", "Server port number"),
+                    .meta_str("description", "Server port number")
+                    .depends_on("network.enabled", true),
             );
             m.insert("security.api_key".into(), {
                 let s = SettingMetadata::text("")
@@ -309,6 +684,109 @@ mod tests {
         assert!(docs.contains("Advanced"));
     }
 
+    #[test]
+    fn test_markdown_table_format() {
+        let docs =
+            generate_docs::<TestSettings>(DocsConfig::new().with_format(DocsFormat::Markdown));
+
+        assert!(docs.contains("## Network\n\n| Setting | Label | Type |"));
+        assert!(docs.contains(
+            "| `network.port` | Port | Number | `8080.0` | 1 - 65535 | Server port number | when `network.enabled` is `true` |"
+        ));
+        assert!(docs.contains(
+            "| `appearance.theme` | Theme | Select | `\"system\"` | one of `\"light\"`, `\"dark\"`, `\"system\"` |"
+        ));
+        #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+        assert!(docs.contains(
+            "| `security.api_key` | API Key | String | <redacted> |  |  | advanced, secret |"
+        ));
+        assert!(!docs.contains("### `"));
+    }
+
+    #[test]
+    fn test_generate_json_schema() {
+        let schema = generate_json_schema(&TestSettings::get_metadata());
+
+        assert_eq!(
+            schema["$schema"],
+            "https://json-schema.org/draft/2020-12/schema"
+        );
+        let network = &schema["properties"]["network"];
+        assert_eq!(network["type"], "object");
+        assert_eq!(
+            network["properties"]["port"],
+            serde_json::json!({
+                "type": "number",
+                "minimum": 1.0,
+                "maximum": 65535.0,
+                "title": "Port",
+                "description": "Server port number",
+                "x-depends-on": {"key": "network.enabled", "value": true},
+                "default": 8080.0,
+            })
+        );
+        assert_eq!(
+            schema["properties"]["appearance"]["properties"]["theme"]["enum"],
+            serde_json::json!(["light", "dark", "system"])
+        );
+        #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+        assert!(
+            schema["properties"]["security"]["properties"]["api_key"]
+                .get("default")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_json_schema_nullable() {
+        let mut metadata = HashMap::new();
+        metadata.insert(
+            "limits.timeout".to_string(),
+            SettingMetadata::number(30.0).nullable(true),
+        );
+        metadata.insert(
+            "limits.mode".to_string(),
+            SettingMetadata::select("fast", vec![SettingOption::new("fast", "Fast")])
+                .nullable(true),
+        );
+        let schema = generate_json_schema(&metadata);
+
+        let limits = &schema["properties"]["limits"]["properties"];
+        assert_eq!(
+            limits["timeout"],
+            serde_json::json!({"type": ["number", "null"], "default": 30.0})
+        );
+        assert_eq!(
+            limits["mode"],
+            serde_json::json!({
+                "anyOf": [{"enum": ["fast"]}, {"type": "null"}],
+                "default": "fast",
+            })
+        );
+    }
+
+    #[test]
+    fn test_generate_env_docs() {
+        let metadata = TestSettings::get_metadata();
+        let docs = generate_env_docs("myapp", &metadata, false);
+
+        assert!(
+            docs.contains(
+                "| `MYAPP_NETWORK_PORT` | `network.port` | Server port number | `8080.0` |"
+            )
+        );
+        assert!(docs.contains("| `MYAPP_APPEARANCE_THEME` | `appearance.theme` |"));
+
+        #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+        {
+            assert!(!docs.contains("MYAPP_SECURITY_API_KEY"));
+            let docs = generate_env_docs("myapp", &metadata, true);
+            assert!(
+                docs.contains("| `MYAPP_SECURITY_API_KEY` | `security.api_key` |  | <redacted> |")
+            );
+        }
+    }
+
     #[test]
     fn test_hide_advanced() {
         let docs = generate_docs::<TestSettings>(DocsConfig::new().hide_advanced());
@@ -11,15 +11,20 @@ mod schema;
 mod types;
 
 pub use schema::{
-    NumberConstraints, SettingConstraints, SettingMetadata, SettingOption, SettingType,
-    SettingsSchema, TextConstraints, meta, opt,
+    CategoryMeta, DependsOn, NumberConstraints, RequiredWhen, SettingConstraints, SettingMetadata,
+    SettingOption, SettingType, SettingValidator, SettingsEnum, SettingsSchema, TextConstraints,
+    Widget, meta, opt, parse_duration,
 };
 
-pub use cache::CacheStrategy;
-pub use docs::{DocsConfig, generate_docs, generate_docs_from_metadata};
+pub use cache::{CacheStats, CacheStrategy};
+pub use docs::{
+    DocsConfig, DocsFormat, generate_docs, generate_docs_from_metadata, generate_env_docs,
+    generate_json_schema,
+};
 
 pub use types::{
-    CredentialConfig, DefaultEnvSource, EnvSource, SettingsConfig, SettingsConfigBuilder,
+    ConfigSource, CorruptPolicy, CredentialConfig, DefaultEnvSource, EnvSource, SettingsConfig,
+    SettingsConfigBuilder,
 };
 
 #[cfg(feature = "hot-reload")]
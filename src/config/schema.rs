@@ -17,7 +17,7 @@
 //!
 //! 1. **Type-Specific Constraints** (static fields):
 //!    - `constraints.number` - min, max, step for Number type
-//!    - `constraints.text` - pattern for Text type
+//!    - `constraints.text` - pattern and length for Text type
 //!    - `constraints.options` - Select options (REQUIRED for Select type)
 //!
 //! 2. **Custom Metadata** (`HashMap<String, Value>`):
@@ -158,6 +158,7 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 // =============================================================================
 // Well-known Metadata Keys
@@ -185,6 +186,10 @@ pub mod meta {
     pub const SECRET: &str = "secret";
     /// Environment variable override indicator - populated at runtime by manager
     pub const ENV_OVERRIDE: &str = "env_override";
+    /// Changing the setting takes effect only after a restart
+    pub const REQUIRES_RESTART: &str = "requires_restart";
+    /// Stored value was clamped into range on load - populated at runtime by manager
+    pub const CLAMPED: &str = "clamped";
 }
 
 // =============================================================================
@@ -204,6 +209,12 @@ pub enum SettingType {
     Number,
     /// Dropdown/select with predefined options
     Select,
+    /// Any subset of predefined options, stored as an array of option values
+    MultiSelect,
+    /// Length of time, stored as whole milliseconds
+    Duration,
+    /// Hex color string, `#RRGGBB` or `#RRGGBBAA`
+    Color,
     /// Read-only display
     Info,
     /// List of strings
@@ -225,6 +236,29 @@ pub struct NumberConstraints {
     pub max: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub step: Option<f64>,
+    /// Reject values that are not on the `min + k * step` grid (`0` when no
+    /// `min` is set). Off by default; `step` is then only a UI hint.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub enforce_step: bool,
+    /// Pull stored values outside `min`..`max` into the range when settings
+    /// are loaded, instead of returning them as-is
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub clamp: bool,
+    /// Preferred input control (presentational only, not validated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub widget: Option<Widget>,
+}
+
+/// Input control hint for Number type settings
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Widget {
+    /// Slider across the `min`..`max` range
+    Slider,
+    /// Free-entry numeric field with increment/decrement arrows
+    SpinBox,
+    /// Plus/minus buttons moving by `step`
+    Stepper,
 }
 
 /// Constraints for Text type settings
@@ -233,6 +267,119 @@ pub struct TextConstraints {
     /// Regex pattern for validation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pattern: Option<String>,
+    /// Regex flags applied to `pattern`: any of `i` (case-insensitive),
+    /// `m` (multi-line), `s` (`.` matches newline) and `x` (ignore whitespace)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern_flags: Option<String>,
+    /// Minimum length in characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+    /// Maximum length in characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+}
+
+/// Parse a human-friendly duration into milliseconds
+///
+/// Accepts one or more `<number><unit>` parts with units `ms`, `s`, `m`, `h`
+/// and `d` (`"30s"`, `"5m"`, `"1h30m"`). A bare number is milliseconds.
+///
+/// # Errors
+/// Returns an error message if the text is not a valid duration.
+///
+/// # Example
+///
+/// ```
+/// use rcman::parse_duration;
+///
+/// assert_eq!(parse_duration("1m30s"), Ok(90_000));
+/// assert_eq!(parse_duration("250"), Ok(250));
+/// assert!(parse_duration("5 minutes").is_err());
+/// ```
+pub fn parse_duration(text: &str) -> Result<u64, String> {
+    let invalid = || format!("Invalid duration '{text}' (expected e.g. 500ms, 30s, 5m, 1h)");
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return Err(invalid());
+    }
+    if let Ok(millis) = trimmed.parse::<u64>() {
+        return Ok(millis);
+    }
+
+    let mut total: u64 = 0;
+    let mut rest = trimmed;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let units = rest[digits..]
+            .find(|c: char| c.is_ascii_digit())
+            .map_or(rest.len(), |end| digits + end);
+        let amount: u64 = rest[..digits].parse().map_err(|_| invalid())?;
+        let factor = match &rest[digits..units] {
+            "ms" => 1,
+            "s" => 1_000,
+            "m" => 60_000,
+            "h" => 3_600_000,
+            "d" => 86_400_000,
+            _ => return Err(invalid()),
+        };
+        total = amount
+            .checked_mul(factor)
+            .and_then(|millis| total.checked_add(millis))
+            .ok_or_else(invalid)?;
+        rest = &rest[units..];
+    }
+    Ok(total)
+}
+
+/// Option value as shown in messages, without JSON quotes for strings
+fn option_text(value: &Value) -> String {
+    value
+        .as_str()
+        .map_or_else(|| value.to_string(), ToString::to_string)
+}
+
+/// Compile `pattern` with `flags`, reusing earlier compilations
+///
+/// Validation runs on every save, so compiled patterns are kept in a small
+/// process-wide LRU cache keyed by pattern and flags.
+fn compiled_pattern(pattern: &str, flags: Option<&str>) -> Result<regex::Regex, String> {
+    use std::sync::{Mutex, OnceLock};
+
+    static CACHE: OnceLock<Mutex<lru::LruCache<(String, String), regex::Regex>>> = OnceLock::new();
+
+    let flags = flags.unwrap_or_default();
+    let key = (pattern.to_string(), flags.to_string());
+    let cache = CACHE.get_or_init(|| {
+        Mutex::new(lru::LruCache::new(
+            std::num::NonZeroUsize::new(256).unwrap_or(std::num::NonZeroUsize::MIN),
+        ))
+    });
+    if let Ok(mut cache) = cache.lock()
+        && let Some(re) = cache.get(&key)
+    {
+        return Ok(re.clone());
+    }
+
+    let mut builder = regex::RegexBuilder::new(pattern);
+    for flag in flags.chars() {
+        match flag {
+            'i' => builder.case_insensitive(true),
+            'm' => builder.multi_line(true),
+            's' => builder.dot_matches_new_line(true),
+            'x' => builder.ignore_whitespace(true),
+            other => return Err(format!("Invalid regex flag '{other}'")),
+        };
+    }
+    let re = builder
+        .build()
+        .map_err(|e| format!("Invalid regex pattern: {e}"))?;
+
+    if let Ok(mut cache) = cache.lock() {
+        cache.put(key, re.clone());
+    }
+    Ok(re)
 }
 
 /// Match mode for list reservations
@@ -259,6 +406,9 @@ pub struct ListConstraints {
     /// How to match reserved values
     #[serde(skip_serializing_if = "Option::is_none")]
     pub match_mode: Option<ReservedMatchMode>,
+    /// Pairs of value groups that cannot be selected together
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusive_groups: Option<Vec<(Vec<String>, Vec<String>)>>,
 }
 
 /// Type-specific constraints
@@ -281,6 +431,55 @@ pub struct SettingConstraints {
     pub list: ListConstraints,
 }
 
+/// Condition under which a setting is required, see [`SettingMetadata::required_when`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RequiredWhen {
+    /// Full key (`category.key`) of the controlling setting
+    pub key: String,
+    /// Value of the controlling setting that makes this setting required
+    pub value: Value,
+}
+
+/// Condition under which a setting applies, see [`SettingMetadata::depends_on`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DependsOn {
+    /// Full key (`category.key`) of the controlling setting
+    pub key: String,
+    /// Value of the controlling setting that makes this setting active
+    pub value: Value,
+}
+
+/// Custom validator attached with [`SettingMetadata::with_validator`]
+///
+/// Clones share the closure; two validators are equal only if they are the
+/// same closure.
+#[derive(Clone)]
+pub struct SettingValidator(Arc<ValidatorFn>);
+
+type ValidatorFn = dyn Fn(&Value) -> Result<(), String> + Send + Sync;
+
+impl SettingValidator {
+    /// Run the validator on `value`
+    ///
+    /// # Errors
+    /// Returns the validator's error message.
+    pub fn call(&self, value: &Value) -> Result<(), String> {
+        (self.0)(value)
+    }
+}
+
+impl std::fmt::Debug for SettingValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SettingValidator(..)")
+    }
+}
+
+impl PartialEq for SettingValidator {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
 // =============================================================================
 // Setting Metadata
 // =============================================================================
@@ -327,10 +526,30 @@ pub struct SettingMetadata {
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub nullable: bool,
 
+    /// Whether this setting must have a non-blank value
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+
+    /// Required only while another setting has a given value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_when: Option<RequiredWhen>,
+
+    /// Only meaningful while another setting has a given value (advisory, for UIs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<DependsOn>,
+
     /// Type-specific constraints
     #[serde(flatten)]
     pub constraints: SettingConstraints,
 
+    /// Custom check run after the built-in ones (not serialized)
+    #[serde(skip)]
+    pub validator: Option<SettingValidator>,
+
+    /// Free-form tags for cross-cutting grouping and filtering
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
     /// Developer-defined custom metadata (fully dynamic)
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub metadata: HashMap<String, Value>,
@@ -343,7 +562,12 @@ impl Default for SettingMetadata {
             default: Value::Null,
             value: None,
             nullable: false,
+            required: false,
+            required_when: None,
+            depends_on: None,
             constraints: SettingConstraints::default(),
+            validator: None,
+            tags: Vec::new(),
             metadata: HashMap::new(),
         }
     }
@@ -397,6 +621,69 @@ impl SettingMetadata {
         }
     }
 
+    /// Create a multi-select setting: any subset of `options`
+    ///
+    /// The value is a JSON array of option values without duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rcman::{SettingMetadata, SettingOption};
+    /// use serde_json::json;
+    ///
+    /// let columns = SettingMetadata::multi_select(
+    ///     &["name", "size"],
+    ///     vec![
+    ///         SettingOption::new("name", "Name"),
+    ///         SettingOption::new("size", "Size"),
+    ///         SettingOption::new("modified", "Modified"),
+    ///     ],
+    /// );
+    /// assert!(columns.validate(&json!(["modified", "name"])).is_ok());
+    /// assert!(columns.validate(&json!(["name", "name"])).is_err());
+    /// ```
+    #[must_use]
+    pub fn multi_select(default: &[impl AsRef<str>], options: Vec<SettingOption>) -> Self {
+        Self {
+            setting_type: SettingType::MultiSelect,
+            default: Value::Array(
+                default
+                    .iter()
+                    .map(|value| Value::String(value.as_ref().to_string()))
+                    .collect(),
+            ),
+            constraints: SettingConstraints {
+                options: Some(options),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Create a duration setting, stored as whole milliseconds
+    ///
+    /// `min`/`max` are in milliseconds too. Environment overrides and
+    /// [`parse_input`](Self::parse_input) also accept text like `"30s"`,
+    /// see [`parse_duration`].
+    #[must_use]
+    pub fn duration(default: std::time::Duration) -> Self {
+        Self {
+            setting_type: SettingType::Duration,
+            default: Value::from(u64::try_from(default.as_millis()).unwrap_or(u64::MAX)),
+            ..Default::default()
+        }
+    }
+
+    /// Create a color setting, a `#RRGGBB` or `#RRGGBBAA` hex string
+    #[must_use]
+    pub fn color(default: impl Into<String>) -> Self {
+        Self {
+            setting_type: SettingType::Color,
+            default: Value::String(default.into()),
+            ..Default::default()
+        }
+    }
+
     /// Create an info/read-only setting
     #[must_use]
     pub fn info(default: Value) -> Self {
@@ -509,6 +796,53 @@ impl SettingMetadata {
         self
     }
 
+    /// Make validation reject Number values that are not aligned to `step`
+    ///
+    /// Accepted values are `min + k * step` for integer `k` (counting from `0`
+    /// when no `min` is set), with a small tolerance for float rounding.
+    #[must_use]
+    pub fn enforce_step(mut self) -> Self {
+        self.constraints.number.enforce_step = true;
+        self
+    }
+
+    /// Clamp stored values into `min`..`max` when settings are loaded
+    ///
+    /// Useful after tightening a range in a new release: an old value outside
+    /// the new range is replaced by the nearest bound instead of reaching the
+    /// UI out of bounds. Metadata of a clamped setting carries
+    /// `metadata["clamped"] = true` until a new value is saved.
+    #[must_use]
+    pub fn clamp(mut self) -> Self {
+        self.constraints.number.clamp = true;
+        self
+    }
+
+    /// Set the input control a UI should render for a Number setting
+    #[must_use]
+    pub fn widget(mut self, widget: Widget) -> Self {
+        self.constraints.number.widget = Some(widget);
+        self
+    }
+
+    /// Render this Number setting as a slider
+    #[must_use]
+    pub fn as_slider(self) -> Self {
+        self.widget(Widget::Slider)
+    }
+
+    /// Render this Number setting as a spin box
+    #[must_use]
+    pub fn as_spinbox(self) -> Self {
+        self.widget(Widget::SpinBox)
+    }
+
+    /// Render this Number setting as a stepper
+    #[must_use]
+    pub fn as_stepper(self) -> Self {
+        self.widget(Widget::Stepper)
+    }
+
     // =========================================================================
     // Text constraint setters (builder pattern)
     // =========================================================================
@@ -520,6 +854,58 @@ impl SettingMetadata {
         self
     }
 
+    /// Set regex flags for the pattern (`i`, `m`, `s`, `x`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rcman::SettingMetadata;
+    /// use serde_json::json;
+    ///
+    /// let name = SettingMetadata::text("alice")
+    ///     .pattern("^[a-z]+$")
+    ///     .pattern_flags("i");
+    /// assert!(name.validate(&json!("Alice")).is_ok());
+    /// ```
+    #[must_use]
+    pub fn pattern_flags(mut self, flags: impl Into<String>) -> Self {
+        self.constraints.text.pattern_flags = Some(flags.into());
+        self
+    }
+
+    /// Set a case-insensitive regex pattern for validation
+    #[must_use]
+    pub fn pattern_ci(self, pattern: impl Into<String>) -> Self {
+        self.pattern(pattern).pattern_flags("i")
+    }
+
+    /// Set minimum text length, in characters
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rcman::SettingMetadata;
+    /// use serde_json::json;
+    ///
+    /// let username = SettingMetadata::text("alice").min_length(3).max_length(20);
+    /// assert_eq!(
+    ///     username.validate(&json!("al")).unwrap_err(),
+    ///     "Value must be at least 3 characters"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn min_length(mut self, min: usize) -> Self {
+        self.constraints.text.min_length = Some(min);
+        self
+    }
+
+    /// Set maximum text length, in characters
+    #[must_use]
+    pub fn max_length(mut self, max: usize) -> Self {
+        self.constraints.text.max_length = Some(max);
+        self
+    }
+
     // =========================================================================
     // List constraint setters (builder pattern)
     // =========================================================================
@@ -538,6 +924,32 @@ impl SettingMetadata {
         self
     }
 
+    /// Declare two groups of values that cannot be selected together
+    ///
+    /// A value containing any item of `first` and any item of `second` is
+    /// rejected. Can be called multiple times to add more groups.
+    ///
+    /// ```
+    /// use rcman::SettingMetadata;
+    /// use serde_json::json;
+    ///
+    /// let codecs = SettingMetadata::list(&["auto".to_string()])
+    ///     .exclusive_group(&["auto"], &["h264", "vp9"]);
+    ///
+    /// assert!(codecs.validate(&json!(["h264", "vp9"])).is_ok());
+    /// assert!(codecs.validate(&json!(["auto", "vp9"])).is_err());
+    /// ```
+    #[must_use]
+    pub fn exclusive_group(mut self, first: &[&str], second: &[&str]) -> Self {
+        let to_owned = |group: &[&str]| group.iter().map(|s| (*s).to_string()).collect();
+        self.constraints
+            .list
+            .exclusive_groups
+            .get_or_insert_with(Vec::new)
+            .push((to_owned(first), to_owned(second)));
+        self
+    }
+
     // Secret storage (special handling)
 
     /// Mark setting as secret (stored in credential manager)
@@ -559,12 +971,207 @@ impl SettingMetadata {
         self
     }
 
+    /// Mark setting as required (rejects null, blank strings and empty lists)
+    ///
+    /// The default value is exempt from this check, so a required setting can
+    /// start out empty and force the user to fill it in before saving.
+    #[must_use]
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Mark setting as required while the setting `key` (`category.key`) has `value`
+    ///
+    /// E.g. a proxy host that must be filled in when `network.use_proxy` is
+    /// `true`, and may stay empty otherwise. The condition depends on other
+    /// settings, so [`validate`](Self::validate) does not check it;
+    /// `SettingsManager` enforces it when the setting is saved, when
+    /// `save_settings_batch` changes either setting, and in `validate_all`.
+    #[must_use]
+    pub fn required_when(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.required_when = Some(RequiredWhen {
+            key: key.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Mark setting as only meaningful while the setting `key` (`category.key`) has `value`
+    ///
+    /// E.g. a proxy port that only matters when `proxy.enabled` is `true`, so
+    /// a UI can grey it out otherwise. This is advisory: saving is never
+    /// blocked. Check it with `SettingsManager::is_active`.
+    #[must_use]
+    pub fn depends_on(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.depends_on = Some(DependsOn {
+            key: key.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Attach a custom validator, run by [`validate`](Self::validate) after
+    /// the built-in checks pass
+    ///
+    /// For rules a pattern or range cannot express. Null values of nullable
+    /// settings are accepted without calling it. The closure is not
+    /// serialized, so metadata sent to a UI does not carry it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rcman::SettingMetadata;
+    /// use serde_json::json;
+    ///
+    /// let subnet = SettingMetadata::text("10.0.0.0/8").with_validator(|value| {
+    ///     let text = value.as_str().unwrap_or_default();
+    ///     let (_, prefix) = text.split_once('/').ok_or("Missing prefix length")?;
+    ///     match prefix.parse::<u8>() {
+    ///         Ok(0..=32) => Ok(()),
+    ///         _ => Err(format!("Invalid prefix length '{prefix}'")),
+    ///     }
+    /// });
+    /// assert!(subnet.validate(&json!("192.168.0.0/16")).is_ok());
+    /// assert!(subnet.validate(&json!("192.168.0.0/40")).is_err());
+    /// ```
+    #[must_use]
+    pub fn with_validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&Value) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validator = Some(SettingValidator(Arc::new(validator)));
+        self
+    }
+
+    /// Add a free-form tag (e.g. `"experimental"`); duplicates are ignored
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        if !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+        self
+    }
+
+    /// Check if this setting carries the given tag
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// Mark setting as taking effect only after an application restart
+    ///
+    /// `SettingsManager::save_setting` reports changes to such settings in its
+    /// `SaveOutcome`.
+    #[must_use]
+    pub fn requires_restart(self) -> Self {
+        self.meta_bool(meta::REQUIRES_RESTART, true)
+    }
+
+    /// Check if this setting requires a restart when changed
+    #[must_use]
+    pub fn is_restart_required(&self) -> bool {
+        self.get_meta_bool(meta::REQUIRES_RESTART).unwrap_or(false)
+    }
+
     /// Check if this setting is marked as secret
     #[must_use]
     pub fn is_secret(&self) -> bool {
         self.get_meta_bool(meta::SECRET).unwrap_or(false)
     }
 
+    /// Pull a Number value outside `min`..`max` to the nearest bound
+    ///
+    /// Returns `None` when [`clamp`](Self::clamp) is not enabled or `value`
+    /// is already in range (or not a number). Integers stay integers when the
+    /// bound is a whole number.
+    #[must_use]
+    pub fn clamp_value(&self, value: &Value) -> Option<Value> {
+        let number = &self.constraints.number;
+        if !number.clamp {
+            return None;
+        }
+        let num = value.as_f64()?;
+        let bound = match (number.min, number.max) {
+            (Some(min), _) if num < min => min,
+            (_, Some(max)) if num > max => max,
+            _ => return None,
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        let clamped = if value.is_f64() || bound.fract() != 0.0 {
+            Value::from(bound)
+        } else {
+            Value::from(bound as i64)
+        };
+        Some(clamped)
+    }
+
+    /// Parse user-supplied text (e.g. a CLI argument) into a value of this
+    /// setting's type
+    ///
+    /// - Toggle: `true`/`false`, `yes`/`no`, `on`/`off` or `1`/`0`
+    /// - Number: an integer or a finite decimal
+    /// - Text, Select and Color: the text as-is
+    /// - Duration: milliseconds or text like `30s`, see [`parse_duration`]
+    /// - List and `MultiSelect`: a JSON array, or comma-separated items (empty
+    ///   text is an empty list)
+    /// - Object and Info: JSON
+    ///
+    /// `null` parses to JSON `null` for nullable settings. The result is not
+    /// validated; pass it to [`validate`](Self::validate) or save it.
+    ///
+    /// # Errors
+    /// Returns an error message if `raw` cannot be read as the setting's type.
+    pub fn parse_input(&self, raw: &str) -> Result<Value, String> {
+        if self.nullable && raw.trim() == "null" {
+            return Ok(Value::Null);
+        }
+
+        match self.setting_type {
+            SettingType::Toggle => match raw.trim().to_ascii_lowercase().as_str() {
+                "true" | "yes" | "on" | "1" => Ok(Value::Bool(true)),
+                "false" | "no" | "off" | "0" => Ok(Value::Bool(false)),
+                _ => Err(format!("Expected a boolean (true/false), got '{raw}'")),
+            },
+            SettingType::Number => {
+                let trimmed = raw.trim();
+                if let Ok(n) = trimmed.parse::<i64>() {
+                    return Ok(Value::from(n));
+                }
+                trimmed
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number)
+                    .ok_or_else(|| format!("Expected a number, got '{raw}'"))
+            }
+            SettingType::Text | SettingType::Select | SettingType::Color => {
+                Ok(Value::String(raw.to_string()))
+            }
+            SettingType::Duration => parse_duration(raw).map(Value::from),
+            SettingType::List | SettingType::MultiSelect => {
+                let trimmed = raw.trim();
+                if trimmed.starts_with('[') {
+                    return serde_json::from_str(trimmed)
+                        .map_err(|e| format!("Expected a JSON array: {e}"));
+                }
+                Ok(Value::Array(
+                    trimmed
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|item| !item.is_empty())
+                        .map(|item| Value::String(item.to_string()))
+                        .collect(),
+                ))
+            }
+            SettingType::Object | SettingType::Info => {
+                serde_json::from_str(raw).map_err(|e| format!("Expected JSON: {e}"))
+            }
+        }
+    }
+
     // =========================================================================
     // Validation
     // =========================================================================
@@ -572,14 +1179,28 @@ impl SettingMetadata {
     /// Validate a value against this setting's constraints
     ///
     /// Checks:
-    /// - Number range (min/max)
+    /// - Number range (min/max), also for durations in milliseconds
     /// - Regex pattern for text
-    /// - Valid option for select type
-    /// - Type compatibility
+    /// - `#RRGGBB` or `#RRGGBBAA` hex for color
+    /// - Valid option for select type, valid and distinct options for
+    ///   multi-select
+    /// - Non-blank value for required settings
+    /// - Type compatibility: a boolean for Toggle, a number for Number, a
+    ///   string for Text and Select, an array for List and `MultiSelect`
+    ///   (Info and Object accept any JSON)
+    /// - The custom [`with_validator`](Self::with_validator) closure, last
     ///
     /// # Errors
     /// Returns an error message if validation fails (type mismatch, out of range, invalid pattern, etc.)
     pub fn validate(&self, value: &Value) -> Result<(), String> {
+        if self.required && Self::is_blank(value) {
+            return Err("Value is required and cannot be empty".to_string());
+        }
+        self.validate_constraints(value)
+    }
+
+    /// Validate a value against type constraints only, ignoring `required`.
+    pub(crate) fn validate_constraints(&self, value: &Value) -> Result<(), String> {
         if value.is_null() {
             if self.nullable {
                 return Ok(());
@@ -589,10 +1210,31 @@ impl SettingMetadata {
         match self.setting_type {
             SettingType::Toggle => Self::validate_toggle(value),
             SettingType::Number => self.validate_number(value),
+            SettingType::Duration => {
+                if value.as_u64().is_none() {
+                    return Err("Value must be a whole number of milliseconds".to_string());
+                }
+                self.validate_number(value)
+            }
             SettingType::Text => self.validate_text(value),
             SettingType::Select => self.validate_select(value),
             SettingType::List => self.validate_list(value),
+            SettingType::MultiSelect => self.validate_multi_select(value),
+            SettingType::Color => Self::validate_color(value),
             SettingType::Info | SettingType::Object => Ok(()), // Read-only / untyped JSON, no validation needed
+        }?;
+        match &self.validator {
+            Some(validator) => validator.call(value),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn is_blank(value: &Value) -> bool {
+        match value {
+            Value::Null => true,
+            Value::String(s) => s.trim().is_empty(),
+            Value::Array(arr) => arr.is_empty(),
+            _ => false,
         }
     }
 
@@ -618,6 +1260,15 @@ impl SettingMetadata {
         {
             return Err(format!("Value must be at most {max}"));
         }
+        if self.constraints.number.enforce_step
+            && let Some(step) = self.constraints.number.step
+        {
+            let base = self.constraints.number.min.unwrap_or(0.0);
+            let steps = (num - base) / step;
+            if (steps - steps.round()).abs() > 1e-9 {
+                return Err(format!("Value must be {base} plus a multiple of {step}"));
+            }
+        }
         Ok(())
     }
 
@@ -626,9 +1277,20 @@ impl SettingMetadata {
             .as_str()
             .ok_or_else(|| "Value must be a string".to_string())?;
 
+        let length = text.chars().count();
+        if let Some(min) = self.constraints.text.min_length
+            && length < min
+        {
+            return Err(format!("Value must be at least {min} characters"));
+        }
+        if let Some(max) = self.constraints.text.max_length
+            && length > max
+        {
+            return Err(format!("Value must be at most {max} characters"));
+        }
+
         if let Some(ref pattern) = self.constraints.text.pattern {
-            let re =
-                regex::Regex::new(pattern).map_err(|e| format!("Invalid regex pattern: {e}"))?;
+            let re = compiled_pattern(pattern, self.constraints.text.pattern_flags.as_deref())?;
             if !re.is_match(text) {
                 return Err(format!("Value does not match pattern: {pattern}"));
             }
@@ -636,16 +1298,72 @@ impl SettingMetadata {
         Ok(())
     }
 
+    fn validate_color(value: &Value) -> Result<(), String> {
+        let color = value
+            .as_str()
+            .ok_or_else(|| "Value must be a string".to_string())?;
+        let valid = color.strip_prefix('#').is_some_and(|hex| {
+            matches!(hex.len(), 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        });
+        if !valid {
+            return Err(format!(
+                "'{color}' is not a valid color (expected #RRGGBB or #RRGGBBAA)"
+            ));
+        }
+        Ok(())
+    }
+
     fn validate_select(&self, value: &Value) -> Result<(), String> {
-        if let Some(ref options) = self.constraints.options {
-            let is_valid = options.iter().any(|opt| opt.value == *value);
-            if !is_valid {
-                return Err("Value must be one of the available options".to_string());
-            }
+        if !value.is_string() {
+            return Err("Value must be a string".to_string());
+        }
+        // The default is always accepted, `validate_schema` warns about it
+        if let Some(ref options) = self.constraints.options
+            && *value != self.default
+            && !options.iter().any(|opt| opt.value == *value)
+        {
+            let expected: Vec<String> = options.iter().map(|opt| option_text(&opt.value)).collect();
+            return Err(format!(
+                "'{}' is not a valid option (expected one of: {})",
+                option_text(value),
+                expected.join(", ")
+            ));
         }
         Ok(())
     }
 
+    fn validate_multi_select(&self, value: &Value) -> Result<(), String> {
+        let items = value
+            .as_array()
+            .ok_or_else(|| "Value must be an array".to_string())?;
+
+        for (i, item) in items.iter().enumerate() {
+            if !item.is_string() {
+                return Err("Value must be an array of strings".to_string());
+            }
+            if items[..i].contains(item) {
+                return Err(format!(
+                    "'{}' is selected more than once",
+                    option_text(item)
+                ));
+            }
+            if let Some(ref options) = self.constraints.options
+                && !options.iter().any(|opt| opt.value == *item)
+            {
+                let expected: Vec<String> =
+                    options.iter().map(|opt| option_text(&opt.value)).collect();
+                return Err(format!(
+                    "'{}' is not a valid option (expected any of: {})",
+                    option_text(item),
+                    expected.join(", ")
+                ));
+            }
+        }
+
+        // Reserved values and exclusive groups apply as for lists
+        self.validate_list(value)
+    }
+
     fn validate_list(&self, value: &Value) -> Result<(), String> {
         if !value.is_array() {
             return Err("Value must be an array".to_string());
@@ -668,6 +1386,24 @@ impl SettingMetadata {
                 }
             }
         }
+
+        // Check exclusive groups
+        if let Some(groups) = &self.constraints.list.exclusive_groups
+            && let Some(arr) = value.as_array()
+        {
+            fn selected<'a>(group: &'a [String], arr: &[Value]) -> Option<&'a String> {
+                group
+                    .iter()
+                    .find(|g| arr.iter().any(|item| item.as_str() == Some(g.as_str())))
+            }
+            for (first, second) in groups {
+                if let (Some(a), Some(b)) = (selected(first, arr), selected(second, arr)) {
+                    return Err(format!(
+                        "Values '{a}' and '{b}' cannot be selected together"
+                    ));
+                }
+            }
+        }
         Ok(())
     }
 
@@ -729,6 +1465,22 @@ impl SettingMetadata {
         if self.setting_type == SettingType::Select && self.constraints.options.is_none() {
             return Err("Select type must have options defined".to_string());
         }
+        if self.setting_type == SettingType::MultiSelect {
+            if self.constraints.options.is_none() {
+                return Err("MultiSelect type must have options defined".to_string());
+            }
+            self.validate_multi_select(&self.default)
+                .map_err(|e| format!("Invalid default: {e}"))?;
+        }
+        if self.setting_type == SettingType::Select
+            && let Some(ref options) = self.constraints.options
+            && !options.iter().any(|opt| opt.value == self.default)
+        {
+            log::warn!(
+                "Select default '{}' is not one of its options",
+                option_text(&self.default)
+            );
+        }
 
         // Check number range validity
         if let (Some(min), Some(max)) = (self.constraints.number.min, self.constraints.number.max)
@@ -743,10 +1495,30 @@ impl SettingMetadata {
         {
             return Err(format!("step must be positive, got {step}"));
         }
+        if self.constraints.number.enforce_step && self.constraints.number.step.is_none() {
+            return Err("enforce_step requires a step".to_string());
+        }
+        if self.constraints.number.clamp
+            && self.constraints.number.min.is_none()
+            && self.constraints.number.max.is_none()
+        {
+            return Err("clamp requires min or max".to_string());
+        }
+
+        // Check text length range validity
+        if let (Some(min), Some(max)) = (
+            self.constraints.text.min_length,
+            self.constraints.text.max_length,
+        ) && min > max
+        {
+            return Err(format!(
+                "min_length ({min}) cannot be greater than max_length ({max})"
+            ));
+        }
 
         // Check pattern is valid regex
         if let Some(ref pattern) = self.constraints.text.pattern {
-            regex::Regex::new(pattern).map_err(|e| format!("Invalid regex pattern: {e}"))?;
+            compiled_pattern(pattern, self.constraints.text.pattern_flags.as_deref())?;
 
             // Pattern should not be empty
             if pattern.is_empty() {
@@ -754,8 +1526,22 @@ impl SettingMetadata {
             }
         }
 
-        // Validate default value against constraints
-        self.validate(&self.default)
+        // Check exclusive groups are non-empty and disjoint
+        if let Some(groups) = &self.constraints.list.exclusive_groups {
+            for (first, second) in groups {
+                if first.is_empty() || second.is_empty() {
+                    return Err("Exclusive group cannot be empty".to_string());
+                }
+                if let Some(shared) = first.iter().find(|v| second.contains(v)) {
+                    return Err(format!(
+                        "Value '{shared}' cannot be on both sides of an exclusive group"
+                    ));
+                }
+            }
+        }
+
+        // Validate default value against constraints (a required setting may start empty)
+        self.validate_constraints(&self.default)
             .map_err(|e| format!("Default value is invalid: {e}"))?;
 
         Ok(())
@@ -804,6 +1590,56 @@ impl SettingOption {
     }
 }
 
+// =============================================================================
+// Category Metadata
+// =============================================================================
+
+/// Display metadata for a settings category (e.g. a tab in a settings UI)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CategoryMeta {
+    /// Display label
+    pub label: String,
+    /// Optional description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional icon identifier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// Sort order (lower comes first)
+    pub order: i32,
+}
+
+impl CategoryMeta {
+    /// Create category metadata with a display label
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the description
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the icon identifier
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the sort order
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+}
+
 // =============================================================================
 // Settings Schema Trait
 // =============================================================================
@@ -832,6 +1668,15 @@ pub trait SettingsSchema: Default + Serialize + for<'de> Deserialize<'de> {
     }
 }
 
+/// Trait for unit-only enums used as select settings
+///
+/// Usually derived with `DeriveSettingsEnum`; a schema field of the enum type
+/// marked `#[setting(enum_options)]` then becomes a select setting.
+pub trait SettingsEnum {
+    /// One option per variant, valued by the name serde writes for it
+    fn select_options() -> Vec<SettingOption>;
+}
+
 // Default implementation for () to allow DynamicManager (no schema)
 impl SettingsSchema for () {
     fn get_metadata() -> HashMap<String, SettingMetadata> {
@@ -943,6 +1788,65 @@ mod tests {
         assert_eq!(setting.constraints.number.step, Some(5.0));
     }
 
+    #[test]
+    fn test_number_step_enforcement() {
+        let loose = SettingMetadata::number(50.0).min(0.0).max(100.0).step(5.0);
+        assert!(loose.validate(&Value::from(7)).is_ok());
+
+        let strict = loose.enforce_step();
+        assert!(strict.validate(&Value::from(45)).is_ok());
+        assert!(strict.validate(&Value::from(7)).is_err());
+
+        let offset = SettingMetadata::number(0.3)
+            .min(0.1)
+            .step(0.1)
+            .enforce_step();
+        assert!(offset.validate(&Value::from(0.7)).is_ok());
+        assert!(offset.validate(&Value::from(0.75)).is_err());
+
+        let no_step = SettingMetadata::number(1.0).enforce_step();
+        assert!(no_step.validate_schema().is_err());
+    }
+
+    #[test]
+    fn test_number_clamp_value() {
+        let unclamped = SettingMetadata::number(50).min(10.0).max(100.0);
+        assert_eq!(unclamped.clamp_value(&json!(5)), None);
+
+        let setting = unclamped.clamp();
+        assert_eq!(setting.clamp_value(&json!(5)), Some(json!(10)));
+        assert_eq!(setting.clamp_value(&json!(250.5)), Some(json!(100.0)));
+        assert_eq!(setting.clamp_value(&json!(50)), None);
+        assert_eq!(setting.clamp_value(&json!("5")), None);
+
+        let no_range = SettingMetadata::number(1.0).clamp();
+        assert!(no_range.validate_schema().is_err());
+    }
+
+    #[test]
+    fn test_value_type_must_match_setting_type() {
+        let toggle = SettingMetadata::toggle(false);
+        assert!(toggle.validate(&json!(true)).is_ok());
+        assert!(toggle.validate(&json!("true")).is_err());
+        assert!(toggle.validate(&json!(1)).is_err());
+
+        let number = SettingMetadata::number(1.0);
+        assert!(number.validate(&json!("1")).is_err());
+
+        let text = SettingMetadata::text("");
+        assert!(text.validate(&json!(1)).is_err());
+
+        let select = SettingMetadata::select("a", vec![opt("a", "A")]);
+        assert!(select.validate(&json!(["a"])).is_err());
+        let mut open_select = SettingMetadata::select("a", vec![]);
+        open_select.constraints.options = None;
+        assert!(open_select.validate(&json!("anything")).is_ok());
+        assert!(open_select.validate(&json!(false)).is_err());
+
+        let list = SettingMetadata::list(&[]);
+        assert!(list.validate(&json!("a")).is_err());
+    }
+
     #[test]
     fn test_number_validation() {
         let setting = SettingMetadata::number(8080.0).min(1.0).max(65535.0);
@@ -958,6 +1862,22 @@ mod tests {
         assert!(setting.validate(&Value::from("not a number")).is_err());
     }
 
+    #[test]
+    fn test_text_pattern_flags() {
+        let strict = SettingMetadata::text("abc").pattern("^[a-z]+$");
+        assert!(strict.validate(&json!("ABC")).is_err());
+
+        let ci = SettingMetadata::text("abc").pattern_ci("^[a-z]+$");
+        assert!(ci.validate(&json!("ABC")).is_ok());
+        assert!(ci.validate(&json!("AB1")).is_err());
+
+        let multi_line = SettingMetadata::text("a").pattern("^b$").pattern_flags("m");
+        assert!(multi_line.validate(&json!("a\nb")).is_ok());
+
+        let bad_flag = SettingMetadata::text("a").pattern("^a$").pattern_flags("q");
+        assert!(bad_flag.validate_schema().is_err());
+    }
+
     #[test]
     fn test_text_pattern_validation() {
         let setting = SettingMetadata::text("").pattern(r"^[\w.-]+@[\w.-]+\.\w+$");
@@ -979,6 +1899,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_text_length_validation() {
+        let setting = SettingMetadata::text("alice").min_length(3).max_length(5);
+
+        assert!(setting.validate(&Value::from("bob")).is_ok());
+        assert!(setting.validate(&Value::from("ééééé")).is_ok());
+        assert_eq!(
+            setting.validate(&Value::from("al")).unwrap_err(),
+            "Value must be at least 3 characters"
+        );
+        assert_eq!(
+            setting.validate(&Value::from("alice1")).unwrap_err(),
+            "Value must be at most 5 characters"
+        );
+
+        let inverted = SettingMetadata::text("alice").min_length(5).max_length(3);
+        assert_eq!(
+            inverted.validate_schema().unwrap_err(),
+            "min_length (5) cannot be greater than max_length (3)"
+        );
+    }
+
     #[test]
     fn test_nullable_validation() {
         let text_setting = SettingMetadata::text("default");
@@ -988,6 +1930,31 @@ mod tests {
         assert!(nullable_text_setting.validate(&Value::Null).is_ok());
     }
 
+    #[test]
+    fn test_required_validation() {
+        let setting = SettingMetadata::text("").required();
+        assert!(setting.required);
+        assert!(
+            setting
+                .validate(&Value::from("https://api.example.com"))
+                .is_ok()
+        );
+        assert!(setting.validate(&Value::from("")).is_err());
+        assert!(setting.validate(&Value::from("   ")).is_err());
+        assert!(setting.validate(&Value::Null).is_err());
+
+        // Required overrides nullable
+        let nullable = SettingMetadata::text("x").nullable(true).required();
+        assert!(nullable.validate(&Value::Null).is_err());
+
+        // An empty default is still a valid schema
+        assert!(setting.validate_schema().is_ok());
+
+        let list = SettingMetadata::list(&[]).required();
+        assert!(list.validate(&json!([])).is_err());
+        assert!(list.validate(&json!(["a"])).is_ok());
+    }
+
     #[test]
     fn test_select_validation() {
         let options = vec![
@@ -1000,8 +1967,148 @@ mod tests {
         assert!(setting.validate(&Value::from("en")).is_ok());
         assert!(setting.validate(&Value::from("tr")).is_ok());
 
-        // Invalid option
-        assert!(setting.validate(&Value::from("invalid")).is_err());
+        // Invalid option, matched against values rather than labels
+        assert_eq!(
+            setting.validate(&Value::from("invalid")).unwrap_err(),
+            "'invalid' is not a valid option (expected one of: en, tr)"
+        );
+        assert!(setting.validate(&Value::from("English")).is_err());
+    }
+
+    #[test]
+    fn test_select_default_outside_options() {
+        let setting = SettingMetadata::select(
+            "system",
+            vec![
+                SettingOption::new("light", "Light"),
+                SettingOption::new("dark", "Dark"),
+            ],
+        );
+
+        // Only logs a warning
+        assert!(setting.validate_schema().is_ok());
+        assert!(setting.validate(&Value::from("system")).is_ok());
+        assert!(setting.validate(&Value::from("teal")).is_err());
+    }
+
+    #[test]
+    fn test_multi_select_validation() {
+        let setting = SettingMetadata::multi_select(
+            &["name"],
+            vec![
+                SettingOption::new("name", "Name"),
+                SettingOption::new("size", "Size"),
+                SettingOption::new("modified", "Modified"),
+            ],
+        );
+        assert_eq!(setting.setting_type, SettingType::MultiSelect);
+        assert_eq!(setting.default, Value::from(vec!["name"]));
+        assert!(setting.validate_schema().is_ok());
+
+        // Any subset, in any order
+        assert!(setting.validate(&Value::from(Vec::<String>::new())).is_ok());
+        assert!(setting.validate(&Value::from(vec!["size", "name"])).is_ok());
+
+        assert_eq!(
+            setting
+                .validate(&Value::from(vec!["name", "Size"]))
+                .unwrap_err(),
+            "'Size' is not a valid option (expected any of: name, size, modified)"
+        );
+        assert_eq!(
+            setting
+                .validate(&Value::from(vec!["size", "size"]))
+                .unwrap_err(),
+            "'size' is selected more than once"
+        );
+        assert!(setting.validate(&Value::from("name")).is_err());
+        assert!(setting.validate(&serde_json::json!([1])).is_err());
+
+        let bad_default =
+            SettingMetadata::multi_select(&["owner"], vec![SettingOption::new("name", "Name")]);
+        assert!(bad_default.validate_schema().is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("30s"), Ok(30_000));
+        assert_eq!(parse_duration("5m"), Ok(300_000));
+        assert_eq!(parse_duration(" 1h "), Ok(3_600_000));
+        assert_eq!(parse_duration("1d2h3m4s5ms"), Ok(93_784_005));
+        assert_eq!(parse_duration("1500"), Ok(1500));
+        assert_eq!(parse_duration("250ms"), Ok(250));
+
+        for invalid in ["", "s", "1.5s", "-1s", "5 m", "3w", "m5"] {
+            assert!(parse_duration(invalid).is_err(), "{invalid} should fail");
+        }
+    }
+
+    #[test]
+    fn test_duration_validation() {
+        let setting = SettingMetadata::duration(std::time::Duration::from_secs(30))
+            .min(1000.0)
+            .max(60_000.0);
+        assert_eq!(setting.setting_type, SettingType::Duration);
+        assert_eq!(setting.default, json!(30_000));
+
+        assert!(setting.validate(&json!(1000)).is_ok());
+        assert_eq!(
+            setting.validate(&json!(500)).unwrap_err(),
+            "Value must be at least 1000"
+        );
+        assert!(setting.validate(&json!(120_000)).is_err());
+        assert!(setting.validate(&json!(1500.5)).is_err());
+        assert!(setting.validate(&json!("30s")).is_err());
+
+        assert_eq!(setting.parse_input("45s"), Ok(json!(45_000)));
+        assert!(setting.parse_input("soon").is_err());
+    }
+
+    #[test]
+    fn test_custom_validator() {
+        let setting = SettingMetadata::number(8.0)
+            .min(0.0)
+            .with_validator(|value| match value.as_f64() {
+                Some(n) if n % 2.0 == 0.0 => Ok(()),
+                _ => Err("Value must be even".to_string()),
+            });
+
+        assert!(setting.validate(&json!(4)).is_ok());
+        assert_eq!(
+            setting.validate(&json!(3)).unwrap_err(),
+            "Value must be even"
+        );
+        // Built-in checks run first
+        assert_eq!(
+            setting.validate(&json!(-2)).unwrap_err(),
+            "Value must be at least 0"
+        );
+
+        // Clones share the closure, serialization leaves it out
+        let copy = setting.clone();
+        assert_eq!(copy, setting);
+        assert!(copy.validate(&json!(3)).is_err());
+        let roundtrip: SettingMetadata =
+            serde_json::from_value(serde_json::to_value(&setting).unwrap()).unwrap();
+        assert!(roundtrip.validator.is_none());
+        assert_ne!(roundtrip, setting);
+    }
+
+    #[test]
+    fn test_color_validation() {
+        let setting = SettingMetadata::color("#1E90FF");
+        assert_eq!(setting.setting_type, SettingType::Color);
+
+        assert!(setting.validate(&json!("#1e90ff")).is_ok());
+        assert!(setting.validate(&json!("#1E90FF80")).is_ok());
+        assert_eq!(
+            setting.validate(&json!("#1E90F")).unwrap_err(),
+            "'#1E90F' is not a valid color (expected #RRGGBB or #RRGGBBAA)"
+        );
+        for invalid in ["1E90FF", "#GGGGGG", "#1E90FF8", "#fff", "red"] {
+            assert!(setting.validate(&json!(invalid)).is_err(), "{invalid}");
+        }
+        assert!(setting.validate(&json!(0x1E90FF)).is_err());
     }
 
     #[test]
@@ -1146,4 +2253,58 @@ mod tests {
         let err = meta.validate(&json!(["--rc-serve=true"])).unwrap_err();
         assert!(err.contains("Value '--rc-serve=true' matches reserved flag '--rc-serve'"));
     }
+
+    #[test]
+    fn test_exclusive_group_validation() {
+        let meta = SettingMetadata::list(&["auto".to_string()])
+            .exclusive_group(&["auto"], &["manual_a", "manual_b"])
+            .exclusive_group(&["lossless"], &["low_bitrate"]);
+
+        assert!(meta.validate_schema().is_ok());
+        assert!(meta.validate(&json!(["auto"])).is_ok());
+        assert!(meta.validate(&json!(["manual_a", "manual_b"])).is_ok());
+        assert!(meta.validate(&json!(["auto", "lossless"])).is_ok());
+
+        let err = meta.validate(&json!(["manual_b", "auto"])).unwrap_err();
+        assert_eq!(
+            err,
+            "Values 'auto' and 'manual_b' cannot be selected together"
+        );
+        assert!(meta.validate(&json!(["low_bitrate", "lossless"])).is_err());
+
+        // Schema checks
+        let overlapping = SettingMetadata::list(&[]).exclusive_group(&["a"], &["a", "b"]);
+        assert!(overlapping.validate_schema().is_err());
+        let empty = SettingMetadata::list(&[]).exclusive_group(&[], &["b"]);
+        assert!(empty.validate_schema().is_err());
+        let bad_default = SettingMetadata::list(&["a".to_string(), "b".to_string()])
+            .exclusive_group(&["a"], &["b"]);
+        assert!(bad_default.validate_schema().is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let toggle = SettingMetadata::toggle(false);
+        assert_eq!(toggle.parse_input("On").unwrap(), json!(true));
+        assert_eq!(toggle.parse_input("0").unwrap(), json!(false));
+        assert!(toggle.parse_input("maybe").is_err());
+
+        let number = SettingMetadata::number(1.0);
+        assert_eq!(number.parse_input(" 42 ").unwrap(), json!(42));
+        assert_eq!(number.parse_input("1.5").unwrap(), json!(1.5));
+        assert!(number.parse_input("NaN").is_err());
+
+        let list = SettingMetadata::list(&[]);
+        assert_eq!(list.parse_input("a, b,,c").unwrap(), json!(["a", "b", "c"]));
+        assert_eq!(list.parse_input(r#"["a,b"]"#).unwrap(), json!(["a,b"]));
+        assert_eq!(list.parse_input("").unwrap(), json!([]));
+
+        let text = SettingMetadata::text("").nullable(true);
+        assert_eq!(text.parse_input(" padded ").unwrap(), json!(" padded "));
+        assert_eq!(text.parse_input("null").unwrap(), Value::Null);
+        assert_eq!(
+            SettingMetadata::text("").parse_input("null").unwrap(),
+            json!("null")
+        );
+    }
 }
@@ -1,9 +1,10 @@
 //! Core types for rcman library
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::config::SettingsSchema;
+use crate::config::{CategoryMeta, SettingsSchema};
 use crate::storage::{JsonStorage, StorageBackend};
 
 #[cfg(feature = "backup")]
@@ -77,6 +78,34 @@ impl EnvSource for DefaultEnvSource {
     }
 }
 
+/// Source of centrally managed setting values
+///
+/// A fallback source is consulted for settings that have no locally stored
+/// value, before the schema default. Use it to serve organisation-wide
+/// defaults (from an HTTP endpoint, a shared file, ...) that users can still
+/// override locally.
+///
+/// Values are read when settings are loaded, so a slow source is not hit on
+/// every read; call `SettingsManager::invalidate_cache` to pick up changes.
+pub trait ConfigSource: Send + Sync {
+    /// Get the value for a setting key (e.g. `"ui.theme"`), or `None` to use
+    /// the schema default
+    fn get(&self, key: &str) -> Option<serde_json::Value>;
+}
+
+/// What to do when the main settings file cannot be parsed.
+///
+/// See [`SettingsConfigBuilder::on_corrupt`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum CorruptPolicy {
+    /// Fail loading with the parse error.
+    #[default]
+    FailFast,
+    /// Rename the file to `<settings file>.corrupt-<timestamp>`, log a warning
+    /// and start on defaults.
+    QuarantineAndReset,
+}
+
 /// Backend strategy for file watching in hot-reload mode.
 #[cfg(feature = "hot-reload")]
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
@@ -139,6 +168,17 @@ pub struct SettingsConfig<S: StorageBackend = JsonStorage, Schema: SettingsSchem
     /// Default: false (secrets are never overridden by env vars)
     pub env_overrides_secrets: bool,
 
+    /// Fail loading when a stored value does not pass schema validation
+    /// Default: false (invalid or unreadable stored data is tolerated)
+    pub strict_validation: bool,
+
+    /// How an unparseable settings file is handled
+    /// Default: `CorruptPolicy::FailFast`
+    pub on_corrupt: CorruptPolicy,
+
+    /// Display metadata for categories (label, icon, order), keyed by category name
+    pub categories: HashMap<String, CategoryMeta>,
+
     /// External configuration files registered for backup
     #[cfg(feature = "backup")]
     pub external_configs: Vec<ExternalConfig>,
@@ -153,6 +193,11 @@ pub struct SettingsConfig<S: StorageBackend = JsonStorage, Schema: SettingsSchem
     #[cfg(feature = "profiles")]
     pub profiles_enabled: bool,
 
+    /// Open the manifest's default profile on startup instead of the profile
+    /// that was active last
+    #[cfg(feature = "profiles")]
+    pub start_in_default_profile: bool,
+
     /// Profile migration strategy (defaults to Auto)
     #[cfg(feature = "profiles")]
     pub profile_migrator: crate::profiles::ProfileMigrator,
@@ -164,6 +209,16 @@ pub struct SettingsConfig<S: StorageBackend = JsonStorage, Schema: SettingsSchem
     /// Source for environment variables (defaults to `std::env`)
     pub env_source: std::sync::Arc<dyn EnvSource>,
 
+    /// Source consulted after stored values and before schema defaults
+    pub fallback_source: Option<std::sync::Arc<dyn ConfigSource>>,
+
+    /// Values replacing schema defaults, keyed by setting key
+    pub default_overrides: HashMap<String, serde_json::Value>,
+
+    /// Interval at which deferred settings writes are persisted (`None`
+    /// writes every change immediately)
+    pub deferred_writes: Option<std::time::Duration>,
+
     /// Hot-reload configuration (when enabled).
     #[cfg(feature = "hot-reload")]
     pub hot_reload: Option<HotReloadConfig>,
@@ -182,15 +237,23 @@ impl Default for SettingsConfig {
             credential_config: CredentialConfig::Disabled,
             env_prefix: None,
             env_overrides_secrets: false,
+            strict_validation: false,
+            on_corrupt: CorruptPolicy::FailFast,
+            categories: HashMap::new(),
             #[cfg(feature = "backup")]
             external_configs: Vec::new(),
             migrator: None,
             #[cfg(feature = "profiles")]
             profiles_enabled: false,
             #[cfg(feature = "profiles")]
+            start_in_default_profile: false,
+            #[cfg(feature = "profiles")]
             profile_migrator: crate::profiles::ProfileMigrator::default(),
             _schema: PhantomData,
             env_source: std::sync::Arc::new(DefaultEnvSource),
+            fallback_source: None,
+            default_overrides: HashMap::new(),
+            deferred_writes: None,
             #[cfg(feature = "hot-reload")]
             hot_reload: None,
         }
@@ -276,13 +339,21 @@ pub struct SettingsConfigBuilder<S: StorageBackend = JsonStorage, Schema: Settin
     profile_migrator: Option<crate::profiles::ProfileMigrator>,
 
     env_source: Option<std::sync::Arc<dyn EnvSource>>,
+    fallback_source: Option<std::sync::Arc<dyn ConfigSource>>,
+    default_overrides: HashMap<String, serde_json::Value>,
+    deferred_writes: Option<std::time::Duration>,
 
     #[cfg(feature = "profiles")]
     profiles_enabled: bool,
+    #[cfg(feature = "profiles")]
+    start_in_default_profile: bool,
     #[cfg(feature = "hot-reload")]
     hot_reload: Option<HotReloadConfig>,
     credential_config: CredentialConfig,
     env_overrides_secrets: bool,
+    strict_validation: bool,
+    on_corrupt: CorruptPolicy,
+    categories: HashMap<String, CategoryMeta>,
     resolve_env_credentials: bool,
 
     storage: S,
@@ -302,6 +373,9 @@ impl<S: StorageBackend, Schema: SettingsSchema> std::fmt::Debug
             .field("credential_config", &self.credential_config)
             .field("env_prefix", &self.env_prefix)
             .field("env_overrides_secrets", &self.env_overrides_secrets)
+            .field("strict_validation", &self.strict_validation)
+            .field("on_corrupt", &self.on_corrupt)
+            .field("categories", &self.categories)
             .field("resolve_env_credentials", &self.resolve_env_credentials);
 
         #[cfg(feature = "backup")]
@@ -310,9 +384,17 @@ impl<S: StorageBackend, Schema: SettingsSchema> std::fmt::Debug
         #[cfg(feature = "profiles")]
         debug.field("profiles_enabled", &self.profiles_enabled);
         #[cfg(feature = "profiles")]
+        debug.field("start_in_default_profile", &self.start_in_default_profile);
+        #[cfg(feature = "profiles")]
         debug.field("profile_migrator", &self.profile_migrator);
 
         debug.field("migrator", &self.migrator.as_ref().map(|_| "Some(Fn)"));
+        debug.field(
+            "fallback_source",
+            &self.fallback_source.as_ref().map(|_| "Some(ConfigSource)"),
+        );
+        debug.field("default_overrides", &self.default_overrides);
+        debug.field("deferred_writes", &self.deferred_writes);
         debug.finish_non_exhaustive()
     }
 }
@@ -332,12 +414,20 @@ impl SettingsConfigBuilder {
             #[cfg(feature = "profiles")]
             profile_migrator: None,
             env_source: None,
+            fallback_source: None,
+            default_overrides: HashMap::new(),
+            deferred_writes: None,
             #[cfg(feature = "profiles")]
             profiles_enabled: false,
+            #[cfg(feature = "profiles")]
+            start_in_default_profile: false,
             #[cfg(feature = "hot-reload")]
             hot_reload: None,
             credential_config: CredentialConfig::Disabled,
             env_overrides_secrets: false,
+            strict_validation: false,
+            on_corrupt: CorruptPolicy::FailFast,
+            categories: HashMap::new(),
             resolve_env_credentials: false,
             storage: JsonStorage::new(),
             _schema: PhantomData,
@@ -368,21 +458,13 @@ impl<Schema: SettingsSchema> SettingsConfigBuilder<JsonStorage, Schema> {
 impl<S: StorageBackend, Schema: SettingsSchema> SettingsConfigBuilder<S, Schema> {
     /// Set the configuration directory
     ///
-    /// Supports `~` expansion for home directory.
+    /// A leading `~` expands to the home directory, and `$VAR` / `${VAR}`
+    /// references are replaced with environment variables (read through the
+    /// configured [`EnvSource`]) when the config is built. References to unset
+    /// variables are left as written.
     #[must_use]
     pub fn with_config_dir(mut self, path: impl Into<PathBuf>) -> Self {
-        let path: PathBuf = path.into();
-        // Expand ~ to home directory
-        let expanded = if path.starts_with("~") {
-            if let Some(home) = dirs::home_dir() {
-                home.join(path.strip_prefix("~").unwrap_or(&path))
-            } else {
-                path
-            }
-        } else {
-            path
-        };
-        self.config_dir = Some(expanded);
+        self.config_dir = Some(path.into());
         self
     }
 
@@ -553,6 +635,75 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsConfigBuilder<S, Schema>
         self
     }
 
+    /// Refuse to load settings that fail schema validation
+    ///
+    /// By default an unreadable settings file is treated as empty and stored
+    /// values are used as-is. With strict validation, loading fails with
+    /// [`Error::InvalidStoredValue`](crate::Error::InvalidStoredValue) for the
+    /// first stored value that does not pass its schema, or with the parse
+    /// error for an unreadable file. `SettingsManager::new` loads eagerly in
+    /// this mode, so a bad config blocks startup.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rcman::SettingsConfig;
+    ///
+    /// let config = SettingsConfig::builder("my-app", "1.0.0")
+    ///     .strict_validation()
+    ///     .build();
+    /// assert!(config.strict_validation);
+    /// ```
+    #[must_use]
+    pub fn strict_validation(mut self) -> Self {
+        self.strict_validation = true;
+        self
+    }
+
+    /// Choose how an unparseable settings file is handled
+    ///
+    /// By default a hand-edited or partially written file makes loading fail
+    /// with the parse error, leaving the app no way to start.
+    /// [`CorruptPolicy::QuarantineAndReset`] moves the broken file aside for
+    /// inspection and starts on defaults instead, also in strict mode.
+    /// Unreadable files (e.g. missing permissions) are not affected.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rcman::{CorruptPolicy, SettingsConfig};
+    ///
+    /// let config = SettingsConfig::builder("my-app", "1.0.0")
+    ///     .on_corrupt(CorruptPolicy::QuarantineAndReset)
+    ///     .build();
+    /// assert_eq!(config.on_corrupt, CorruptPolicy::QuarantineAndReset);
+    /// ```
+    #[must_use]
+    pub fn on_corrupt(mut self, policy: CorruptPolicy) -> Self {
+        self.on_corrupt = policy;
+        self
+    }
+
+    /// Register display metadata for a category
+    ///
+    /// Categories are the first segment of a setting key (`"network"` in
+    /// `"network.proxy"`). The metadata is exposed through
+    /// `SettingsManager::category_metadata` so a UI can render tabs without
+    /// keeping its own table of labels and icons.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rcman::{CategoryMeta, SettingsConfig};
+    ///
+    /// let config = SettingsConfig::builder("my-app", "1.0.0")
+    ///     .with_category("network", CategoryMeta::new("Network").icon("wifi").order(2))
+    ///     .build();
+    /// assert_eq!(config.categories["network"].label, "Network");
+    /// ```
+    #[must_use]
+    pub fn with_category(mut self, name: impl Into<String>, meta: CategoryMeta) -> Self {
+        self.categories.insert(name.into(), meta);
+        self
+    }
+
     /// Set a custom environment variable source
     ///
     /// Useful for testing or injecting env vars procedurally.
@@ -562,6 +713,93 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsConfigBuilder<S, Schema>
         self
     }
 
+    /// Set a source consulted for settings without a locally stored value
+    ///
+    /// Values resolve as: environment override, stored value, fallback
+    /// source, schema default. Secret settings never read from the source,
+    /// and source values failing validation are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rcman::{ConfigSource, SettingsConfig};
+    /// use serde_json::{Value, json};
+    /// use std::sync::Arc;
+    ///
+    /// struct OrgDefaults;
+    ///
+    /// impl ConfigSource for OrgDefaults {
+    ///     fn get(&self, key: &str) -> Option<Value> {
+    ///         (key == "ui.theme").then(|| json!("dark"))
+    ///     }
+    /// }
+    ///
+    /// let config = SettingsConfig::builder("my-app", "1.0.0")
+    ///     .with_fallback_source(Arc::new(OrgDefaults))
+    ///     .build();
+    /// assert!(config.fallback_source.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_fallback_source(mut self, source: std::sync::Arc<dyn ConfigSource>) -> Self {
+        self.fallback_source = Some(source);
+        self
+    }
+
+    /// Replace schema defaults for this deployment
+    ///
+    /// The given values become the effective defaults: they are reported by
+    /// [`SettingsManager::metadata`](crate::SettingsManager::metadata), used
+    /// by resets, and a saved value equal to one of them is removed from the
+    /// settings file. Values resolve as: environment override, stored value,
+    /// fallback source, default override, schema default. Calling this more
+    /// than once merges the maps.
+    ///
+    /// `SettingsManager::new` fails if a key is not in the schema or a value
+    /// does not validate.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rcman::SettingsConfig;
+    /// use serde_json::json;
+    /// use std::collections::HashMap;
+    ///
+    /// let overrides = HashMap::from([("general.telemetry".to_string(), json!(false))]);
+    /// let config = SettingsConfig::builder("my-app", "1.0.0")
+    ///     .with_default_overrides(overrides)
+    ///     .build();
+    /// assert_eq!(config.default_overrides["general.telemetry"], json!(false));
+    /// ```
+    #[must_use]
+    pub fn with_default_overrides(mut self, overrides: HashMap<String, serde_json::Value>) -> Self {
+        self.default_overrides.extend(overrides);
+        self
+    }
+
+    /// Defer writing non-secret settings to disk
+    ///
+    /// `save_setting` then updates the in-memory cache (so reads and change
+    /// listeners see the new value at once) and marks it dirty; the settings
+    /// file is written at most once per `interval`, on
+    /// [`SettingsManager::flush`](crate::SettingsManager::flush), and when the
+    /// manager is dropped. Use this for interactive controls such as sliders
+    /// that save many times per second. Secret settings are still written to
+    /// the credential store immediately.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rcman::SettingsConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = SettingsConfig::builder("my-app", "1.0.0")
+    ///     .deferred_writes(Duration::from_secs(2))
+    ///     .build();
+    /// assert_eq!(config.deferred_writes, Some(Duration::from_secs(2)));
+    /// ```
+    #[must_use]
+    pub fn deferred_writes(mut self, interval: std::time::Duration) -> Self {
+        self.deferred_writes = Some(interval);
+        self
+    }
+
     /// Set a migration function for schema changes (lazy migration)
     ///
     /// The migrator function is called automatically when loading settings.
@@ -638,6 +876,23 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsConfigBuilder<S, Schema>
         self
     }
 
+    /// Start in the default profile instead of the last active one.
+    ///
+    /// Without this, the profile active when the app last ran is opened again.
+    /// With it, the profile chosen with
+    /// [`ProfileManager::set_default`](crate::profiles::ProfileManager::set_default)
+    /// (initially `"default"`) is activated on every startup, e.g. to always
+    /// start in "personal" even after switching to "work" last session.
+    /// Sub-settings types with profiles start in their own default profile.
+    /// Implies [`with_profiles`](Self::with_profiles).
+    #[cfg(feature = "profiles")]
+    #[must_use]
+    pub fn start_in_default_profile(mut self) -> Self {
+        self.profiles_enabled = true;
+        self.start_in_default_profile = true;
+        self
+    }
+
     /// Specify the schema type for compile-time type safety.
     ///
     /// This binds your settings struct to the manager, enabling:
@@ -684,12 +939,20 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsConfigBuilder<S, Schema>
             #[cfg(feature = "profiles")]
             profile_migrator,
             env_source,
+            fallback_source,
+            default_overrides,
+            deferred_writes,
             #[cfg(feature = "profiles")]
             profiles_enabled,
+            #[cfg(feature = "profiles")]
+            start_in_default_profile,
             #[cfg(feature = "hot-reload")]
             hot_reload,
             credential_config,
             env_overrides_secrets,
+            strict_validation,
+            on_corrupt,
+            categories,
             resolve_env_credentials,
             storage,
             ..
@@ -707,12 +970,20 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsConfigBuilder<S, Schema>
             #[cfg(feature = "profiles")]
             profile_migrator,
             env_source,
+            fallback_source,
+            default_overrides,
+            deferred_writes,
             #[cfg(feature = "profiles")]
             profiles_enabled,
+            #[cfg(feature = "profiles")]
+            start_in_default_profile,
             #[cfg(feature = "hot-reload")]
             hot_reload,
             credential_config,
             env_overrides_secrets,
+            strict_validation,
+            on_corrupt,
+            categories,
             resolve_env_credentials,
             storage,
             _schema: PhantomData,
@@ -735,6 +1006,18 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsConfigBuilder<S, Schema>
     #[must_use]
     pub fn with_storage<NewS: StorageBackend + Default>(
         self,
+    ) -> SettingsConfigBuilder<NewS, Schema> {
+        self.with_storage_backend(NewS::default())
+    }
+
+    /// Use a configured storage backend instance.
+    ///
+    /// Like [`with_storage`](Self::with_storage), but for backends that need
+    /// configuration beyond their `Default` (e.g. a shared SQLite database).
+    #[must_use]
+    pub fn with_storage_backend<NewS: StorageBackend>(
+        self,
+        storage: NewS,
     ) -> SettingsConfigBuilder<NewS, Schema> {
         let Self {
             config_dir,
@@ -748,12 +1031,20 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsConfigBuilder<S, Schema>
             #[cfg(feature = "profiles")]
             profile_migrator,
             env_source,
+            fallback_source,
+            default_overrides,
+            deferred_writes,
             #[cfg(feature = "profiles")]
             profiles_enabled,
+            #[cfg(feature = "profiles")]
+            start_in_default_profile,
             #[cfg(feature = "hot-reload")]
             hot_reload,
             credential_config,
             env_overrides_secrets,
+            strict_validation,
+            on_corrupt,
+            categories,
             resolve_env_credentials,
             ..
         } = self;
@@ -770,14 +1061,22 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsConfigBuilder<S, Schema>
             #[cfg(feature = "profiles")]
             profile_migrator,
             env_source,
+            fallback_source,
+            default_overrides,
+            deferred_writes,
             #[cfg(feature = "profiles")]
             profiles_enabled,
+            #[cfg(feature = "profiles")]
+            start_in_default_profile,
             #[cfg(feature = "hot-reload")]
             hot_reload,
             credential_config,
             env_overrides_secrets,
+            strict_validation,
+            on_corrupt,
+            categories,
             resolve_env_credentials,
-            storage: NewS::default(),
+            storage,
             _schema: PhantomData,
         }
     }
@@ -787,10 +1086,17 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsConfigBuilder<S, Schema>
     /// If `config_dir` is not set, uses the system config directory for the app.
     #[must_use]
     pub fn build(self) -> SettingsConfig<S, Schema> {
-        let config_dir = self.config_dir.unwrap_or_else(|| {
-            // Use system config dir if available, otherwise current dir
-            dirs::config_dir().map_or_else(|| PathBuf::from("."), |d| d.join(&self.app_name))
-        });
+        let env_source = self
+            .env_source
+            .unwrap_or_else(|| std::sync::Arc::new(DefaultEnvSource));
+
+        let config_dir = self.config_dir.map_or_else(
+            || {
+                // Use system config dir if available, otherwise current dir
+                dirs::config_dir().map_or_else(|| PathBuf::from("."), |d| d.join(&self.app_name))
+            },
+            |path| expand_path(&path, env_source.as_ref()),
+        );
 
         let storage = self.storage;
 
@@ -803,10 +1109,6 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsConfigBuilder<S, Schema>
         #[cfg(not(all(feature = "keychain", feature = "encrypted-file")))]
         let credential_config = self.credential_config;
 
-        let env_source = self
-            .env_source
-            .unwrap_or_else(|| std::sync::Arc::new(DefaultEnvSource));
-
         #[cfg(all(feature = "keychain", feature = "encrypted-file"))]
         {
             if self.resolve_env_credentials {
@@ -883,28 +1185,72 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsConfigBuilder<S, Schema>
             credential_config,
             env_prefix: self.env_prefix,
             env_overrides_secrets: self.env_overrides_secrets,
+            strict_validation: self.strict_validation,
+            on_corrupt: self.on_corrupt,
+            categories: self.categories,
             #[cfg(feature = "backup")]
             external_configs: self.external_configs,
             migrator: self.migrator,
             #[cfg(feature = "profiles")]
             profiles_enabled: self.profiles_enabled,
             #[cfg(feature = "profiles")]
+            start_in_default_profile: self.start_in_default_profile,
+            #[cfg(feature = "profiles")]
             profile_migrator: self.profile_migrator.unwrap_or_default(),
             _schema: PhantomData,
             env_source,
+            fallback_source: self.fallback_source,
+            default_overrides: self.default_overrides,
+            deferred_writes: self.deferred_writes,
             #[cfg(feature = "hot-reload")]
             hot_reload: self.hot_reload,
         }
     }
 }
 
+/// Expand a leading `~` and `$VAR` / `${VAR}` references in `path`
+fn expand_path(path: &Path, env: &dyn EnvSource) -> PathBuf {
+    let Some(raw) = path.to_str() else {
+        return path.to_path_buf();
+    };
+
+    let mut expanded = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(start) = rest.find('$') {
+        expanded.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let (name, reference_len) = if let Some(braced) = after.strip_prefix('{') {
+            match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
+            }
+        } else {
+            let end = after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(after.len());
+            (&after[..end], end)
+        };
+
+        match env.var(name) {
+            Ok(value) if !name.is_empty() => expanded.push_str(&value),
+            _ => expanded.push_str(&rest[start..=start + reference_len]),
+        }
+        rest = &after[reference_len..];
+    }
+    expanded.push_str(rest);
+
+    let expanded = PathBuf::from(expanded);
+    match (expanded.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(relative), Some(home)) => home.join(relative),
+        _ => expanded,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[cfg(all(feature = "keychain", feature = "encrypted-file"))]
     struct MockEnvSource(std::collections::HashMap<String, String>);
-    #[cfg(all(feature = "keychain", feature = "encrypted-file"))]
     impl EnvSource for MockEnvSource {
         fn var(&self, key: &str) -> std::result::Result<String, std::env::VarError> {
             self.0
@@ -934,6 +1280,39 @@ mod tests {
         assert_eq!(config.settings_file, "config.json");
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn test_builder_config_dir_expands_tilde() {
+        let Some(home) = dirs::home_dir() else {
+            return;
+        };
+        let config = SettingsConfig::builder("my-app", "1.0.0")
+            .with_config_dir("~/.config/my-app")
+            .build();
+
+        assert_eq!(config.config_dir, home.join(".config/my-app"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_builder_config_dir_expands_env_vars() {
+        let env = MockEnvSource(
+            [("XDG_CONFIG_HOME", "/data/config"), ("CHANNEL", "beta")]
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        );
+        let config = SettingsConfig::builder("my-app", "1.0.0")
+            .with_env_source(std::sync::Arc::new(env))
+            .with_config_dir("$XDG_CONFIG_HOME/my-app-${CHANNEL}/$UNSET_VAR")
+            .build();
+
+        assert_eq!(
+            config.config_dir,
+            PathBuf::from("/data/config/my-app-beta/$UNSET_VAR")
+        );
+    }
+
     #[test]
     #[cfg(all(feature = "keychain", feature = "encrypted-file"))]
     fn test_builder_credentials_auto_path() {
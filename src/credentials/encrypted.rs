@@ -3,8 +3,8 @@
 //! Uses AES-256-GCM for encryption, suitable for CI/Docker environments
 //! where OS keychain is not available.
 
-use super::CredentialBackend;
 use super::types::SecretPasswordSource;
+use super::{CredentialBackend, CredentialMeta};
 use crate::error::{Error, Result};
 use aes_gcm::{
     Aes256Gcm, Nonce,
@@ -27,6 +27,9 @@ struct EncryptedEntry {
     nonce: String,
     /// Base64-encoded ciphertext
     ciphertext: String,
+    /// When the entry was stored and last updated (absent in older files)
+    #[serde(default, flatten)]
+    meta: CredentialMeta,
 }
 
 /// Encrypted file storage format
@@ -39,12 +42,18 @@ struct EncryptedStore {
     entries: HashMap<String, EncryptedEntry>,
 }
 
-/// Encrypted file backend using AES-256-GCM
-pub struct EncryptedFileBackend {
-    path: PathBuf,
+/// Cipher and the salt its key was derived with, replaced together by
+/// [`EncryptedFileBackend::rotate_key`]
+struct KeyState {
     cipher: Aes256Gcm,
     /// Salt used for key derivation (stored in file for decryption on restart)
     salt: [u8; 16],
+}
+
+/// Encrypted file backend using AES-256-GCM
+pub struct EncryptedFileBackend {
+    path: PathBuf,
+    key: RwLock<KeyState>,
     /// Plaintext read-cache.
     ///
     /// Populated lazily on `get()` and kept in sync on `store()`/`remove()`.
@@ -73,9 +82,10 @@ impl EncryptedFileBackend {
     pub(crate) fn new(path: PathBuf, key: &[u8; 32], salt: [u8; 16]) -> Result<Self> {
         let backend = Self {
             path,
-            cipher: Aes256Gcm::new_from_slice(key)
-                .map_err(|_| Error::Credential("Invalid encryption key length".into()))?,
-            salt,
+            key: RwLock::new(KeyState {
+                cipher: new_cipher(key)?,
+                salt,
+            }),
             cache: RwLock::new(HashMap::new()),
             write_lock: Mutex::new(()),
         };
@@ -144,6 +154,65 @@ impl EncryptedFileBackend {
         Self::new(path, &key, salt)
     }
 
+    /// Re-encrypt every stored credential under `new_key`
+    ///
+    /// Decrypts all entries with the current key, generates a fresh salt and
+    /// atomically replaces the file with entries encrypted under `new_key`.
+    /// The file must be opened with `new_key` afterwards. For a store opened
+    /// with [`with_password`](Self::with_password), use
+    /// [`rotate_password`](Self::rotate_password) instead so the new key is
+    /// derived from the stored salt.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an entry cannot be decrypted with the current key
+    /// or the file cannot be written. The file is left unchanged on error.
+    pub fn rotate_key(&self, new_key: &[u8; 32]) -> Result<()> {
+        self.rotate(new_key, Self::generate_salt())
+    }
+
+    /// Re-encrypt every stored credential under a key derived from
+    /// `new_password` and a fresh salt
+    ///
+    /// The file can then be opened with
+    /// [`with_password`](Self::with_password) and `new_password`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if key derivation fails, an entry cannot be decrypted
+    /// with the current key or the file cannot be written.
+    pub fn rotate_password(&self, new_password: &str) -> Result<()> {
+        let salt = Self::generate_salt();
+        let key = Self::derive_key(new_password, &salt)?;
+        self.rotate(&key, salt)
+    }
+
+    fn rotate(&self, new_key: &[u8; 32], new_salt: [u8; 16]) -> Result<()> {
+        let _guard = self
+            .write_lock
+            .lock()
+            .map_err(|_| Error::Credential("Encrypted file write lock poisoned".into()))?;
+
+        let cipher = new_cipher(new_key)?;
+        let store = self.load_store()?;
+        let mut entries = HashMap::with_capacity(store.entries.len());
+        for (name, entry) in store.entries {
+            let plaintext = self.decrypt(&entry)?;
+            let mut reencrypted = encrypt_with(&cipher, &plaintext)?;
+            reencrypted.meta = entry.meta;
+            entries.insert(name, reencrypted);
+        }
+        self.write_store(new_salt, entries)?;
+
+        *self.key.write().map_err(|_| Error::LockPoisoned)? = KeyState {
+            cipher,
+            salt: new_salt,
+        };
+
+        debug!("Encrypted credential file re-encrypted under a new key");
+        Ok(())
+    }
+
     /// Read the salt from an existing encrypted file (without needing the key)
     ///
     /// Returns `None` if the file doesn't exist or has no salt (v1 format).
@@ -252,14 +321,20 @@ impl EncryptedFileBackend {
             .map_err(|e| Error::Credential(format!("Failed to parse encrypted store: {e}")))
     }
 
+    /// Serialize and atomically write the store to disk with the backend's own salt.
+    fn save_store(&self, entries: HashMap<String, EncryptedEntry>) -> Result<()> {
+        let salt = self.key.read().map_err(|_| Error::LockPoisoned)?.salt;
+        self.write_store(salt, entries)
+    }
+
     /// Serialize and atomically write the store to disk.
     ///
-    /// Always writes version=1 and the backend's own salt. Entries are taken by value
-    /// to avoid an extra clone when the caller is done with them.
-    fn save_store(&self, entries: HashMap<String, EncryptedEntry>) -> Result<()> {
+    /// Always writes version=1. Entries are taken by value to avoid an extra
+    /// clone when the caller is done with them.
+    fn write_store(&self, salt: [u8; 16], entries: HashMap<String, EncryptedEntry>) -> Result<()> {
         let store = EncryptedStore {
             version: 1,
-            salt: Some(BASE64.encode(self.salt)),
+            salt: Some(BASE64.encode(salt)),
             entries,
         };
 
@@ -325,18 +400,8 @@ impl EncryptedFileBackend {
     }
 
     fn encrypt(&self, plaintext: &str) -> Result<EncryptedEntry> {
-        let nonce_bytes: [u8; 12] = rand::rng().random();
-        let nonce = Nonce::from_slice(&nonce_bytes);
-
-        let ciphertext = self
-            .cipher
-            .encrypt(nonce, plaintext.as_bytes())
-            .map_err(|e| Error::Credential(format!("Encryption failed: {e}")))?;
-
-        Ok(EncryptedEntry {
-            nonce: BASE64.encode(nonce_bytes),
-            ciphertext: BASE64.encode(&ciphertext),
-        })
+        let key = self.key.read().map_err(|_| Error::LockPoisoned)?;
+        encrypt_with(&key.cipher, plaintext)
     }
 
     fn decrypt(&self, entry: &EncryptedEntry) -> Result<String> {
@@ -351,6 +416,9 @@ impl EncryptedFileBackend {
         let nonce = Nonce::from_slice(&nonce_bytes);
 
         let plaintext = self
+            .key
+            .read()
+            .map_err(|_| Error::LockPoisoned)?
             .cipher
             .decrypt(nonce, ciphertext.as_ref())
             .map_err(|_| Error::Credential("Decryption failed (wrong key?)".into()))?;
@@ -360,6 +428,26 @@ impl EncryptedFileBackend {
     }
 }
 
+fn new_cipher(key: &[u8; 32]) -> Result<Aes256Gcm> {
+    Aes256Gcm::new_from_slice(key)
+        .map_err(|_| Error::Credential("Invalid encryption key length".into()))
+}
+
+fn encrypt_with(cipher: &Aes256Gcm, plaintext: &str) -> Result<EncryptedEntry> {
+    let nonce_bytes: [u8; 12] = rand::rng().random();
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext.as_bytes())
+        .map_err(|e| Error::Credential(format!("Encryption failed: {e}")))?;
+
+    Ok(EncryptedEntry {
+        nonce: BASE64.encode(nonce_bytes),
+        ciphertext: BASE64.encode(&ciphertext),
+        meta: CredentialMeta::default(),
+    })
+}
+
 impl CredentialBackend for EncryptedFileBackend {
     fn store(&self, key: &str, value: &str) -> Result<()> {
         let _guard = self
@@ -368,7 +456,8 @@ impl CredentialBackend for EncryptedFileBackend {
             .map_err(|_| Error::Credential("Encrypted file write lock poisoned".into()))?;
 
         let mut store = self.load_store()?;
-        let encrypted = self.encrypt(value)?;
+        let mut encrypted = self.encrypt(value)?;
+        encrypted.meta = CredentialMeta::written_now(store.entries.get(key).map(|e| &e.meta));
         store.entries.insert(key.to_string(), encrypted);
         self.save_store(store.entries)?;
 
@@ -386,6 +475,52 @@ impl CredentialBackend for EncryptedFileBackend {
         Ok(cache.get(key).cloned())
     }
 
+    fn get_many(&self, keys: &[&str]) -> Result<HashMap<String, Option<String>>> {
+        let cache = self.cache.read().map_err(|_| Error::LockPoisoned)?;
+        Ok(keys
+            .iter()
+            .map(|key| ((*key).to_string(), cache.get(*key).cloned()))
+            .collect())
+    }
+
+    fn store_many(&self, entries: &[(&str, &str)]) -> Result<()> {
+        let _guard = self
+            .write_lock
+            .lock()
+            .map_err(|_| Error::Credential("Encrypted file write lock poisoned".into()))?;
+
+        // One read-modify-write of the file for the whole batch
+        let mut store = self.load_store()?;
+        for (key, value) in entries {
+            let mut encrypted = self.encrypt(value)?;
+            encrypted.meta = CredentialMeta::written_now(store.entries.get(*key).map(|e| &e.meta));
+            store.entries.insert((*key).to_string(), encrypted);
+        }
+        self.save_store(store.entries)?;
+
+        let mut cache = self.cache.write().map_err(|_| Error::LockPoisoned)?;
+        for (key, value) in entries {
+            cache.insert((*key).to_string(), (*value).to_string());
+        }
+
+        debug!("{} credentials stored in encrypted file", entries.len());
+        Ok(())
+    }
+
+    fn get_with_metadata(&self, key: &str) -> Result<Option<(String, CredentialMeta)>> {
+        let Some(value) = self.get(key)? else {
+            return Ok(None);
+        };
+        // Timestamps are not cached; read them from the file
+        let meta = self
+            .load_store()?
+            .entries
+            .get(key)
+            .map(|entry| entry.meta)
+            .unwrap_or_default();
+        Ok(Some((value, meta)))
+    }
+
     fn remove(&self, key: &str) -> Result<()> {
         let _guard = self
             .write_lock
@@ -413,6 +548,10 @@ impl CredentialBackend for EncryptedFileBackend {
     fn backend_name(&self) -> &'static str {
         "encrypted_file"
     }
+
+    fn rotate_key(&self, new_key: &[u8; 32]) -> Result<()> {
+        EncryptedFileBackend::rotate_key(self, new_key)
+    }
 }
 
 // =============================================================================
@@ -449,6 +588,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encrypted_metadata_persists() {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join("credentials.enc.json");
+        let salt = EncryptedFileBackend::generate_salt();
+        let key = EncryptedFileBackend::generate_key();
+
+        let backend = EncryptedFileBackend::new(path.clone(), &key, salt).unwrap();
+        backend.store("api_key", "v1").unwrap();
+        let (_, first) = backend.get_with_metadata("api_key").unwrap().unwrap();
+        assert!(first.created_at.is_some());
+
+        backend.store("api_key", "v2").unwrap();
+
+        let backend2 = EncryptedFileBackend::new(path, &key, salt).unwrap();
+        let (value, meta) = backend2.get_with_metadata("api_key").unwrap().unwrap();
+        assert_eq!(value, "v2");
+        assert_eq!(meta.created_at, first.created_at);
+        assert!(meta.updated_at >= first.updated_at);
+    }
+
     #[test]
     fn test_encrypted_wrong_key() {
         let temp = tempdir().unwrap();
@@ -486,6 +646,82 @@ mod tests {
         assert!(backend3.is_err());
     }
 
+    #[test]
+    fn test_store_many_and_get_many() {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join("credentials.enc.json");
+        let salt = EncryptedFileBackend::generate_salt();
+        let key = EncryptedFileBackend::generate_key();
+
+        let backend = EncryptedFileBackend::new(path.clone(), &key, salt).unwrap();
+        backend.store("existing", "old").unwrap();
+        backend
+            .store_many(&[("existing", "new"), ("api_key", "secret123")])
+            .unwrap();
+
+        let reopened = EncryptedFileBackend::new(path, &key, salt).unwrap();
+        let values = reopened
+            .get_many(&["existing", "api_key", "missing"])
+            .unwrap();
+        assert_eq!(values["existing"], Some("new".to_string()));
+        assert_eq!(values["api_key"], Some("secret123".to_string()));
+        assert_eq!(values["missing"], None);
+    }
+
+    #[test]
+    fn test_rotate_key() {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join("credentials.enc.json");
+        let old_salt = EncryptedFileBackend::generate_salt();
+        let old_key = EncryptedFileBackend::generate_key();
+        let new_key = EncryptedFileBackend::generate_key();
+
+        let backend = EncryptedFileBackend::new(path.clone(), &old_key, old_salt).unwrap();
+        backend.store("api_key", "secret123").unwrap();
+        backend.store("password", "hunter2").unwrap();
+        let (_, meta) = backend.get_with_metadata("api_key").unwrap().unwrap();
+
+        backend.rotate_key(&new_key).unwrap();
+        let new_salt = EncryptedFileBackend::read_salt(&path).unwrap().unwrap();
+        assert_ne!(new_salt, old_salt);
+
+        // The rotated backend keeps working and writes under the new key
+        backend.store("token", "abc").unwrap();
+
+        let reopened = EncryptedFileBackend::new(path.clone(), &new_key, new_salt).unwrap();
+        assert_eq!(
+            reopened.get("api_key").unwrap(),
+            Some("secret123".to_string())
+        );
+        assert_eq!(
+            reopened.get("password").unwrap(),
+            Some("hunter2".to_string())
+        );
+        assert_eq!(reopened.get("token").unwrap(), Some("abc".to_string()));
+        let (_, rotated_meta) = reopened.get_with_metadata("api_key").unwrap().unwrap();
+        assert_eq!(rotated_meta.created_at, meta.created_at);
+
+        assert!(EncryptedFileBackend::new(path.clone(), &old_key, old_salt).is_err());
+        assert!(EncryptedFileBackend::new(path, &old_key, new_salt).is_err());
+    }
+
+    #[test]
+    fn test_rotate_password() {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join("credentials.enc.json");
+
+        let backend = EncryptedFileBackend::with_password(path.clone(), "old_password").unwrap();
+        backend.store("api_key", "secret123").unwrap();
+        backend.rotate_password("new_password").unwrap();
+
+        let reopened = EncryptedFileBackend::with_password(path.clone(), "new_password").unwrap();
+        assert_eq!(
+            reopened.get("api_key").unwrap(),
+            Some("secret123".to_string())
+        );
+        assert!(EncryptedFileBackend::with_password(path, "old_password").is_err());
+    }
+
     #[test]
     fn test_derive_key() {
         let salt = EncryptedFileBackend::generate_salt();
@@ -0,0 +1,161 @@
+//! Read-only credential backend backed by environment variables
+//!
+//! Meant for ephemeral CI environments that inject secrets as environment
+//! variables and have neither an OS keychain nor an encrypted file.
+
+use super::CredentialBackend;
+use crate::config::{DefaultEnvSource, EnvSource};
+use crate::error::Result;
+use std::sync::Arc;
+
+/// Credential backend that reads secrets from environment variables
+///
+/// A credential key maps to a variable by uppercasing it and replacing every
+/// non-alphanumeric character with `_`, after an optional prefix. The
+/// `CredentialManager` key `myapp:api_key` is read from `MYAPP_API_KEY`.
+///
+/// The environment is read-only: `store` and `remove` log a warning and do
+/// nothing, and `list_keys` returns no keys.
+///
+/// # Example
+///
+/// ```
+/// use rcman::EnvBackend;
+///
+/// let backend = EnvBackend::new().with_prefix("CI_");
+/// assert_eq!(backend.var_name("myapp:api_key"), "CI_MYAPP_API_KEY");
+/// ```
+#[derive(Clone)]
+pub struct EnvBackend {
+    prefix: String,
+    source: Arc<dyn EnvSource>,
+}
+
+impl EnvBackend {
+    /// Create a backend reading from the process environment
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            prefix: String::new(),
+            source: Arc::new(DefaultEnvSource),
+        }
+    }
+
+    /// Prepend `prefix` to every variable name (used as-is)
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Read variables from `source` instead of the process environment
+    #[must_use]
+    pub fn with_env_source(mut self, source: Arc<dyn EnvSource>) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Name of the environment variable holding `key`
+    #[must_use]
+    pub fn var_name(&self, key: &str) -> String {
+        let name: String = key
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        format!("{}{name}", self.prefix)
+    }
+}
+
+impl Default for EnvBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CredentialBackend for EnvBackend {
+    fn store(&self, key: &str, _value: &str) -> Result<()> {
+        log::warn!(
+            "Ignoring store of credential '{key}': environment variables are read-only (set {})",
+            self.var_name(key)
+        );
+        Ok(())
+    }
+
+    fn get(&self, key: &str) -> Result<Option<String>> {
+        Ok(self.source.var(&self.var_name(key)).ok())
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        log::warn!(
+            "Ignoring removal of credential '{key}': environment variables are read-only (unset {})",
+            self.var_name(key)
+        );
+        Ok(())
+    }
+
+    fn list_keys(&self) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    fn backend_name(&self) -> &'static str {
+        "env"
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    struct MockEnv(HashMap<String, String>);
+
+    impl EnvSource for MockEnv {
+        fn var(&self, key: &str) -> std::result::Result<String, std::env::VarError> {
+            self.0
+                .get(key)
+                .cloned()
+                .ok_or(std::env::VarError::NotPresent)
+        }
+    }
+
+    #[test]
+    fn test_env_backend_reads_normalized_variables() {
+        let env = MockEnv(HashMap::from([
+            ("MYAPP_API_KEY".to_string(), "from-env".to_string()),
+            ("CI_MYAPP_UI_TOKEN".to_string(), "prefixed".to_string()),
+        ]));
+        let source: Arc<dyn EnvSource> = Arc::new(env);
+        let backend = EnvBackend::new().with_env_source(Arc::clone(&source));
+
+        assert_eq!(
+            backend.get("myapp:api_key").unwrap(),
+            Some("from-env".to_string())
+        );
+        assert_eq!(backend.get("myapp:missing").unwrap(), None);
+
+        let prefixed = EnvBackend::new().with_prefix("CI_").with_env_source(source);
+        assert_eq!(
+            prefixed.get("myapp:ui.token").unwrap(),
+            Some("prefixed".to_string())
+        );
+
+        // The environment is read-only
+        backend.store("myapp:api_key", "other").unwrap();
+        backend.remove("myapp:api_key").unwrap();
+        assert_eq!(
+            backend.get("myapp:api_key").unwrap(),
+            Some("from-env".to_string())
+        );
+        assert!(backend.list_keys().unwrap().is_empty());
+    }
+}
@@ -1,13 +1,19 @@
 //! In-memory credential backend for testing
 
-use super::CredentialBackend;
+use super::{CredentialBackend, CredentialMeta};
 use crate::error::{Error, Result};
 use std::collections::HashMap;
 use std::sync::RwLock;
 
+/// A stored value and its timestamps
+struct MemoryEntry {
+    value: String,
+    meta: CredentialMeta,
+}
+
 /// In-memory credential storage (not persisted)
 pub struct MemoryBackend {
-    store: RwLock<HashMap<String, String>>,
+    store: RwLock<HashMap<String, MemoryEntry>>,
 }
 
 impl MemoryBackend {
@@ -29,13 +35,27 @@ impl Default for MemoryBackend {
 impl CredentialBackend for MemoryBackend {
     fn store(&self, key: &str, value: &str) -> Result<()> {
         let mut store = self.store.write().map_err(|_| Error::LockPoisoned)?;
-        store.insert(key.to_string(), value.to_string());
+        let meta = CredentialMeta::written_now(store.get(key).map(|entry| &entry.meta));
+        store.insert(
+            key.to_string(),
+            MemoryEntry {
+                value: value.to_string(),
+                meta,
+            },
+        );
         Ok(())
     }
 
     fn get(&self, key: &str) -> Result<Option<String>> {
         let store = self.store.read().map_err(|_| Error::LockPoisoned)?;
-        Ok(store.get(key).cloned())
+        Ok(store.get(key).map(|entry| entry.value.clone()))
+    }
+
+    fn get_with_metadata(&self, key: &str) -> Result<Option<(String, CredentialMeta)>> {
+        let store = self.store.read().map_err(|_| Error::LockPoisoned)?;
+        Ok(store
+            .get(key)
+            .map(|entry| (entry.value.clone(), entry.meta)))
     }
 
     fn remove(&self, key: &str) -> Result<()> {
@@ -75,6 +95,24 @@ mod tests {
         assert_eq!(backend.get("key3").unwrap(), None);
     }
 
+    #[test]
+    fn test_memory_metadata_keeps_creation_time() {
+        let backend = MemoryBackend::new();
+
+        backend.store("key", "v1").unwrap();
+        let (_, first) = backend.get_with_metadata("key").unwrap().unwrap();
+        assert!(first.created_at.is_some());
+        assert_eq!(first.created_at, first.updated_at);
+
+        backend.store("key", "v2").unwrap();
+        let (value, second) = backend.get_with_metadata("key").unwrap().unwrap();
+        assert_eq!(value, "v2");
+        assert_eq!(second.created_at, first.created_at);
+        assert!(second.updated_at >= first.updated_at);
+
+        assert!(backend.get_with_metadata("missing").unwrap().is_none());
+    }
+
     #[test]
     fn test_memory_remove() {
         let backend = MemoryBackend::new();
@@ -109,6 +147,10 @@ mod tests {
 
         assert!(matches!(backend.store("k", "v"), Err(Error::LockPoisoned)));
         assert!(matches!(backend.get("k"), Err(Error::LockPoisoned)));
+        assert!(matches!(
+            backend.get_with_metadata("k"),
+            Err(Error::LockPoisoned)
+        ));
         assert!(matches!(backend.remove("k"), Err(Error::LockPoisoned)));
         assert!(matches!(backend.list_keys(), Err(Error::LockPoisoned)));
     }
@@ -8,9 +8,11 @@
 //!   - Android: native credential store via `keyring` v4 / `keyring-core` (android-native-keyring-store)
 //! - **Encrypted File**: Encrypted JSON fallback for CI/Docker and unsupported platforms - requires `encrypted-file` feature
 //! - **Memory**: In-memory only for testing
+//! - **Env**: Read-only environment variables for CI
 
 #[cfg(feature = "encrypted-file")]
 mod encrypted;
+mod env;
 #[cfg(feature = "keychain")]
 mod keychain;
 mod memory;
@@ -18,13 +20,15 @@ mod types;
 
 #[cfg(feature = "encrypted-file")]
 pub use encrypted::EncryptedFileBackend;
+pub use env::EnvBackend;
 #[cfg(feature = "keychain")]
 pub use keychain::KeychainBackend;
 pub use memory::MemoryBackend;
 
-pub use types::{SecretBackupPolicy, SecretPasswordSource, SecretStorage};
+pub use types::{CredentialMeta, SecretBackupPolicy, SecretPasswordSource, SecretStorage};
 
 use crate::error::Result;
+use std::collections::HashMap;
 #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
 use std::sync::{
     Arc,
@@ -47,6 +51,47 @@ pub trait CredentialBackend: Send + Sync {
     /// Returns an error if the backend fails to retrieve the key.
     fn get(&self, key: &str) -> Result<Option<String>>;
 
+    /// Retrieve a credential together with its timestamps
+    ///
+    /// The default implementation returns empty metadata for backends that
+    /// do not track timestamps.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to retrieve the key.
+    fn get_with_metadata(&self, key: &str) -> Result<Option<(String, CredentialMeta)>> {
+        Ok(self
+            .get(key)?
+            .map(|value| (value, CredentialMeta::default())))
+    }
+
+    /// Retrieve several credentials at once, keyed by the requested key
+    ///
+    /// The default implementation calls [`get`](Self::get) for each key.
+    /// Backends that can answer all lookups from one read should override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to retrieve any of the keys.
+    fn get_many(&self, keys: &[&str]) -> Result<HashMap<String, Option<String>>> {
+        keys.iter()
+            .map(|key| Ok(((*key).to_string(), self.get(key)?)))
+            .collect()
+    }
+
+    /// Store several credentials at once
+    ///
+    /// The default implementation calls [`store`](Self::store) for each entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to store any of the entries.
+    fn store_many(&self, entries: &[(&str, &str)]) -> Result<()> {
+        entries
+            .iter()
+            .try_for_each(|(key, value)| self.store(key, value))
+    }
+
     /// Remove a credential
     ///
     /// # Errors
@@ -72,6 +117,22 @@ pub trait CredentialBackend: Send + Sync {
 
     /// Backend name for logging/debugging
     fn backend_name(&self) -> &'static str;
+
+    /// Re-encrypt all stored credentials under a new 32-byte key
+    ///
+    /// Only backends that encrypt at rest support this; the default
+    /// implementation returns an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend does not support key rotation or fails
+    /// to re-encrypt its credentials.
+    fn rotate_key(&self, _new_key: &[u8; 32]) -> Result<()> {
+        Err(crate::error::Error::Credential(format!(
+            "The {} backend does not support key rotation",
+            self.backend_name()
+        )))
+    }
 }
 
 /// Credential manager with configurable backend and fallback
@@ -333,6 +394,169 @@ impl CredentialManager {
         self.volatile.get(&full_key)
     }
 
+    /// Retrieve several credentials at once, keyed by the requested key
+    ///
+    /// Missing credentials map to `None`. Backends that can batch (such as the
+    /// encrypted file) answer all lookups from one read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the primary backend fails to retrieve the keys or if the fallback backend fails to retrieve the keys.
+    pub fn get_many(&self, keys: &[&str]) -> Result<HashMap<String, Option<String>>> {
+        self.get_many_with_profile(keys, None)
+    }
+
+    /// Retrieve several credentials at once with optional profile context
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the primary backend fails to retrieve the keys or if the fallback backend fails to retrieve the keys.
+    pub fn get_many_with_profile(
+        &self,
+        keys: &[&str],
+        profile: Option<&str>,
+    ) -> Result<HashMap<String, Option<String>>> {
+        let full_keys: Vec<String> = keys
+            .iter()
+            .map(|key| self.make_key_with_profile(key, profile))
+            .collect();
+        let full_refs: Vec<&str> = full_keys.iter().map(String::as_str).collect();
+        let by_requested_key = |mut values: HashMap<String, Option<String>>| {
+            keys.iter()
+                .zip(&full_keys)
+                .map(|(key, full_key)| ((*key).to_string(), values.remove(full_key).flatten()))
+                .collect()
+        };
+
+        if !self.is_primary_failed.load(Ordering::Relaxed) {
+            match self.primary.get_many(&full_refs) {
+                Ok(values) => return Ok(by_requested_key(values)),
+                Err(e) => {
+                    log::error!("=== PRIMARY BACKEND FAILED FOR BATCH READ: {e:?}");
+                    self.is_primary_failed.store(true, Ordering::Relaxed);
+                }
+            }
+        }
+
+        if let Some(ref fallback) = self.fallback {
+            match fallback.get_many(&full_refs) {
+                Ok(values) => return Ok(by_requested_key(values)),
+                Err(e) => {
+                    log::error!(
+                        "Persistent fallback failed for batch read: {e}. Trying VOLATILE memory."
+                    );
+                }
+            }
+        }
+
+        self.volatile.get_many(&full_refs).map(by_requested_key)
+    }
+
+    /// Store several credentials at once
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the primary backend fails to store the entries or if the fallback backend fails to store the entries.
+    pub fn store_many(&self, entries: &[(&str, &str)]) -> Result<()> {
+        self.store_many_with_profile(entries, None)
+    }
+
+    /// Store several credentials at once with optional profile context
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the primary backend fails to store the entries or if the fallback backend fails to store the entries.
+    pub fn store_many_with_profile(
+        &self,
+        entries: &[(&str, &str)],
+        profile: Option<&str>,
+    ) -> Result<()> {
+        let full_keys: Vec<String> = entries
+            .iter()
+            .map(|(key, _)| self.make_key_with_profile(key, profile))
+            .collect();
+        let full_entries: Vec<(&str, &str)> = full_keys
+            .iter()
+            .zip(entries)
+            .map(|(full_key, (_, value))| (full_key.as_str(), *value))
+            .collect();
+
+        if !self.is_primary_failed.load(Ordering::Relaxed) {
+            match self.primary.store_many(&full_entries) {
+                Ok(()) => {
+                    log::debug!(
+                        "Stored {} credentials in {}",
+                        entries.len(),
+                        self.primary.backend_name()
+                    );
+                    return Ok(());
+                }
+                Err(e) => {
+                    log::error!("=== PRIMARY BACKEND FAILED FOR BATCH STORE: {e:?}");
+                    self.is_primary_failed.store(true, Ordering::Relaxed);
+                }
+            }
+        }
+
+        if let Some(ref fallback) = self.fallback {
+            match fallback.store_many(&full_entries) {
+                Ok(()) => {
+                    log::debug!(
+                        "Stored {} credentials in persistent fallback",
+                        entries.len()
+                    );
+                    return Ok(());
+                }
+                Err(e) => {
+                    log::error!(
+                        "Persistent fallback failed for batch store: {e}. Falling back to VOLATILE memory."
+                    );
+                }
+            }
+        }
+
+        log::warn!(
+            "Using volatile fallback for {} credentials - secrets will NOT persist across restarts",
+            entries.len()
+        );
+        self.volatile.store_many(&full_entries)
+    }
+
+    /// Retrieve a credential together with when it was stored and last updated
+    ///
+    /// Timestamps are `None` when the backend holding the credential does not
+    /// track them (for example the OS keychain).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the primary backend fails to retrieve the key or if the fallback backend fails to retrieve the key.
+    pub fn get_with_metadata(&self, key: &str) -> Result<Option<(String, CredentialMeta)>> {
+        let full_key = self.make_key_with_profile(key, None);
+
+        if !self.is_primary_failed.load(Ordering::Relaxed) {
+            match self.primary.get_with_metadata(&full_key) {
+                Ok(entry) => return Ok(entry),
+                Err(e) => {
+                    log::error!("=== PRIMARY BACKEND FAILED FOR {key}: {e:?}");
+                    self.is_primary_failed.store(true, Ordering::Relaxed);
+                }
+            }
+        }
+
+        if let Some(ref fallback) = self.fallback {
+            match fallback.get_with_metadata(&full_key) {
+                Ok(entry) => return Ok(entry),
+                Err(e) => {
+                    log::error!(
+                        "Persistent fallback failed for '{key}': {e}. Trying VOLATILE memory."
+                    );
+                }
+            }
+        }
+
+        self.volatile.get_with_metadata(&full_key)
+    }
+
     /// Remove a credential
     ///
     /// # Errors
@@ -378,49 +602,98 @@ impl CredentialManager {
         self.volatile.exists(&full_key).unwrap_or(false)
     }
 
-    /// Clear all credentials for this service.
+    /// Re-encrypt the fallback credential file under `new_key`.
+    ///
+    /// Only the persistent fallback is rotated; the OS keychain manages its
+    /// own encryption. See `EncryptedFileBackend::rotate_key`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the cache invalidation fails.
-    pub fn clear(&self) -> Result<()> {
-        #[cfg(feature = "profiles")]
-        let prefix = if let Some(profile_ctx) = &self.profile_context {
-            format!("{}:profiles:{}:", self.service_name, profile_ctx)
-        } else {
-            format!("{}:", self.service_name)
-        };
+    /// Returns an error if no fallback backend is configured, the fallback
+    /// does not support key rotation, or re-encryption fails.
+    pub fn rotate_fallback_key(&self, new_key: &[u8; 32]) -> Result<()> {
+        self.fallback
+            .as_ref()
+            .ok_or_else(|| {
+                crate::error::Error::Credential("No fallback backend is configured".into())
+            })?
+            .rotate_key(new_key)
+    }
 
-        #[cfg(not(feature = "profiles"))]
-        let prefix = format!("{}:", self.service_name);
+    /// Copy every credential of this service from the primary backend into
+    /// `target`, returning how many were copied.
+    ///
+    /// Covers all profiles and keeps the full keys, so a manager built with
+    /// [`with_backend`](Self::with_backend) on `target` and the same service
+    /// name finds the credentials. Each copy is read back and compared before
+    /// the next one. The primary backend is left untouched; use
+    /// [`migrate_and_remove`](Self::migrate_and_remove) to move the
+    /// credentials instead. The OS keychain only lists keys written during the
+    /// current session, so migrate away from it before restarting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the primary backend cannot list or read its keys,
+    /// or a credential cannot be stored in or read back from `target`.
+    /// Credentials copied before the error stay in `target`.
+    pub fn migrate_to(&self, target: Arc<dyn CredentialBackend>) -> Result<usize> {
+        self.migrate(target.as_ref(), false)
+    }
 
-        let mut keys_to_remove = std::collections::HashSet::new();
+    /// Like [`migrate_to`](Self::migrate_to), but removes each credential from
+    /// the primary backend once its copy in `target` is verified.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a credential cannot be copied, verified or removed
+    /// from the primary backend.
+    pub fn migrate_and_remove(&self, target: Arc<dyn CredentialBackend>) -> Result<usize> {
+        self.migrate(target.as_ref(), true)
+    }
 
-        if let Ok(keys) = self.primary.list_keys() {
-            for key in keys {
-                if key.starts_with(&prefix) {
-                    keys_to_remove.insert(key);
-                }
+    fn migrate(&self, target: &dyn CredentialBackend, remove_source: bool) -> Result<usize> {
+        let prefix = format!("{}:", self.service_name);
+        let mut keys: Vec<String> = self
+            .primary
+            .list_keys()?
+            .into_iter()
+            .filter(|key| key.starts_with(&prefix))
+            .collect();
+        keys.sort();
+
+        let mut migrated = 0;
+        for key in keys {
+            let Some(value) = self.primary.get(&key)? else {
+                continue;
+            };
+            target.store(&key, &value)?;
+            if target.get(&key)?.as_deref() != Some(value.as_str()) {
+                return Err(crate::error::Error::Credential(format!(
+                    "Credential '{key}' did not read back from the {} backend after migration",
+                    target.backend_name()
+                )));
             }
-        }
-
-        if let Some(ref fallback) = self.fallback
-            && let Ok(keys) = fallback.list_keys()
-        {
-            for key in keys {
-                if key.starts_with(&prefix) {
-                    keys_to_remove.insert(key);
-                }
+            if remove_source {
+                self.primary.remove(&key)?;
             }
+            migrated += 1;
         }
 
-        if let Ok(keys) = self.volatile.list_keys() {
-            for key in keys {
-                if key.starts_with(&prefix) {
-                    keys_to_remove.insert(key);
-                }
-            }
-        }
+        log::info!(
+            "Migrated {migrated} credentials from {} to {}",
+            self.primary.backend_name(),
+            target.backend_name()
+        );
+        Ok(migrated)
+    }
+
+    /// Clear all credentials for this service.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache invalidation fails.
+    pub fn clear(&self) -> Result<()> {
+        let keys_to_remove = self.service_keys();
 
         // Track per-key failures so we can log a useful aggregate without
         // aborting the loop on the first error.
@@ -464,6 +737,33 @@ impl CredentialManager {
         Ok(())
     }
 
+    /// Full keys of every credential [`clear`](Self::clear) would remove,
+    /// across all backends.
+    pub(crate) fn service_keys(&self) -> std::collections::HashSet<String> {
+        #[cfg(feature = "profiles")]
+        let prefix = if let Some(profile_ctx) = &self.profile_context {
+            format!("{}:profiles:{}:", self.service_name, profile_ctx)
+        } else {
+            format!("{}:", self.service_name)
+        };
+
+        #[cfg(not(feature = "profiles"))]
+        let prefix = format!("{}:", self.service_name);
+
+        let mut service_keys = std::collections::HashSet::new();
+        let mut backends: Vec<&dyn CredentialBackend> =
+            vec![self.primary.as_ref(), self.volatile.as_ref()];
+        if let Some(fallback) = &self.fallback {
+            backends.push(fallback.as_ref());
+        }
+        for backend in backends {
+            if let Ok(keys) = backend.list_keys() {
+                service_keys.extend(keys.into_iter().filter(|key| key.starts_with(&prefix)));
+            }
+        }
+        service_keys
+    }
+
     /// Get service name
     #[must_use]
     pub fn service_name(&self) -> &str {
@@ -869,4 +1169,108 @@ mod tests {
         manager.remove("api_key").unwrap();
         assert_eq!(manager.get("api_key").unwrap(), None);
     }
+
+    #[test]
+    fn test_batch_operations_use_fallback() {
+        let manager = CredentialManager {
+            primary: Arc::new(FailingBackend),
+            fallback: Some(Arc::new(MemoryBackend::new())),
+            is_primary_failed: Arc::new(AtomicBool::new(false)),
+            service_name: "test-app".to_string(),
+            #[cfg(feature = "profiles")]
+            profile_context: None,
+            volatile: Arc::new(MemoryBackend::new()),
+            tracked_secrets_cache: Arc::new(std::sync::RwLock::new(
+                std::collections::HashMap::new(),
+            )),
+        };
+
+        manager
+            .store_many(&[("api_key", "one"), ("token", "two")])
+            .unwrap();
+        assert!(manager.is_primary_failed());
+        assert!(!manager.is_volatile_active());
+
+        let values = manager.get_many(&["api_key", "token", "missing"]).unwrap();
+        assert_eq!(values.len(), 3);
+        assert_eq!(values["api_key"], Some("one".to_string()));
+        assert_eq!(values["token"], Some("two".to_string()));
+        assert_eq!(values["missing"], None);
+        assert_eq!(manager.get("token").unwrap(), Some("two".to_string()));
+    }
+
+    #[test]
+    fn test_migrate_to_copies_and_verifies() {
+        let source = Arc::new(MemoryBackend::new());
+        source.store("other-app:token", "not ours").unwrap();
+        let manager = CredentialManager::with_backend("test-app", source.clone());
+        manager.store("api_key", "one").unwrap();
+        manager.store("token", "two").unwrap();
+
+        let target = Arc::new(MemoryBackend::new());
+        assert_eq!(manager.migrate_to(target.clone()).unwrap(), 2);
+        assert_eq!(source.list_keys().unwrap().len(), 3);
+
+        let migrated = CredentialManager::with_backend("test-app", target.clone());
+        assert_eq!(migrated.get("api_key").unwrap(), Some("one".to_string()));
+        assert_eq!(migrated.get("token").unwrap(), Some("two".to_string()));
+        assert_eq!(target.get("other-app:token").unwrap(), None);
+
+        // Moving removes the verified copies from the source
+        let moved = Arc::new(MemoryBackend::new());
+        assert_eq!(manager.migrate_and_remove(moved).unwrap(), 2);
+        assert_eq!(source.list_keys().unwrap(), vec!["other-app:token"]);
+
+        // A target that does not keep the value fails verification
+        manager.store("api_key", "three").unwrap();
+        let result = manager.migrate_to(Arc::new(EnvBackend::new().with_prefix("RCMAN_TEST_")));
+        assert!(result.is_err());
+        assert_eq!(manager.get("api_key").unwrap(), Some("three".to_string()));
+    }
+
+    #[cfg(feature = "encrypted-file")]
+    #[test]
+    fn test_rotate_fallback_key() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("secrets.enc");
+        let old_key = EncryptedFileBackend::generate_key();
+        let new_key = EncryptedFileBackend::generate_key();
+        let salt = EncryptedFileBackend::generate_salt();
+
+        let manager = CredentialManager {
+            primary: Arc::new(FailingBackend),
+            fallback: Some(Arc::new(
+                EncryptedFileBackend::new(path.clone(), &old_key, salt).unwrap(),
+            )),
+            is_primary_failed: Arc::new(AtomicBool::new(false)),
+            service_name: "test-app".to_string(),
+            #[cfg(feature = "profiles")]
+            profile_context: None,
+            volatile: Arc::new(MemoryBackend::new()),
+            tracked_secrets_cache: Arc::new(std::sync::RwLock::new(
+                std::collections::HashMap::new(),
+            )),
+        };
+        manager.store("api_key", "top-secret").unwrap();
+        manager.rotate_fallback_key(&new_key).unwrap();
+        assert_eq!(
+            manager.get("api_key").unwrap(),
+            Some("top-secret".to_string())
+        );
+
+        let new_salt = EncryptedFileBackend::read_salt(&path).unwrap().unwrap();
+        let reopened = EncryptedFileBackend::new(path.clone(), &new_key, new_salt).unwrap();
+        assert_eq!(
+            reopened.get("test-app:api_key").unwrap(),
+            Some("top-secret".to_string())
+        );
+        assert!(EncryptedFileBackend::new(path, &old_key, salt).is_err());
+
+        // Without an encrypting fallback there is nothing to rotate
+        assert!(
+            CredentialManager::memory_only("test-app")
+                .rotate_fallback_key(&new_key)
+                .is_err()
+        );
+    }
 }
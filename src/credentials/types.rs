@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use time::OffsetDateTime;
 
 /// Where to store secret values
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -71,3 +72,31 @@ impl SecretPasswordSource {
         }
     }
 }
+
+/// Timestamps recorded for a stored credential
+///
+/// Backends that cannot track timestamps (such as the OS keychain) leave both
+/// fields as `None`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CredentialMeta {
+    /// When the credential was first stored
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub created_at: Option<OffsetDateTime>,
+
+    /// When the credential value was last written
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub updated_at: Option<OffsetDateTime>,
+}
+
+impl CredentialMeta {
+    /// Metadata for a credential written now, keeping the creation time of
+    /// the previous write if there was one
+    #[must_use]
+    pub fn written_now(previous: Option<&Self>) -> Self {
+        let now = OffsetDateTime::now_utc();
+        Self {
+            created_at: previous.and_then(|meta| meta.created_at).or(Some(now)),
+            updated_at: Some(now),
+        }
+    }
+}
@@ -71,6 +71,12 @@ pub enum Error {
     #[error("Invalid setting metadata for {key}: {reason}")]
     InvalidSettingMetadata { key: String, reason: String },
 
+    #[error("Invalid stored value for {key}: {reason}")]
+    InvalidStoredValue { key: String, reason: String },
+
+    #[error("Setting {0} has async validators and must be saved with save_setting_async")]
+    AsyncValidationRequired(String),
+
     #[error("Settings schema not registered")]
     SchemaNotRegistered,
 
@@ -90,6 +96,13 @@ pub enum Error {
     #[error("Sub-settings entry '{0}' not found")]
     SubSettingsEntryNotFound(String),
 
+    #[error("Sub-settings entry '{0}' already exists")]
+    SubSettingsEntryExists(String),
+
+    /// `name` is the sub-settings type whose entry limit was hit
+    #[error("Sub-settings '{name}' is limited to {limit} entries")]
+    SubSettingsLimitReached { name: String, limit: usize },
+
     // -------------------------------------------------------------------------
     // Backup Errors
     // -------------------------------------------------------------------------
@@ -105,6 +118,10 @@ pub enum Error {
     #[error("Backup password required")]
     PasswordRequired,
 
+    /// An incremental backup was restored without its base backup
+    #[error("Missing base backup: {0}")]
+    MissingBaseBackup(String),
+
     #[error("Invalid backup password")]
     InvalidPassword,
 
@@ -131,6 +148,9 @@ pub enum Error {
     #[error("Manager not initialized")]
     NotInitialized,
 
+    #[error("Settings changed since the reset was prepared")]
+    StaleResetToken,
+
     // -------------------------------------------------------------------------
     // Credential Errors
     // -------------------------------------------------------------------------
@@ -187,6 +207,74 @@ pub enum Error {
 }
 
 impl Error {
+    /// Stable machine-readable code for this error
+    ///
+    /// Codes never change between releases, unlike the English `Display`
+    /// text, so they can be used as keys for localized messages.
+    ///
+    /// ```
+    /// use rcman::Error;
+    ///
+    /// let err = Error::SettingNotFound("ui.theme".into());
+    /// assert_eq!(err.code(), "RCMAN_SETTING_NOT_FOUND");
+    /// ```
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::FileRead { .. } => "RCMAN_FILE_READ",
+            Error::FileWrite { .. } => "RCMAN_FILE_WRITE",
+            Error::DirectoryCreate { .. } => "RCMAN_DIRECTORY_CREATE",
+            Error::DirectoryRead { .. } => "RCMAN_DIRECTORY_READ",
+            Error::FileDelete { .. } => "RCMAN_FILE_DELETE",
+            Error::PathNotFound(_) => "RCMAN_PATH_NOT_FOUND",
+            Error::Serialize(_) => "RCMAN_SERIALIZE",
+            Error::Parse(_) => "RCMAN_PARSE",
+            Error::SettingNotFound(_) => "RCMAN_SETTING_NOT_FOUND",
+            Error::InvalidSettingValue { .. } => "RCMAN_INVALID_SETTING_VALUE",
+            Error::InvalidSettingMetadata { .. } => "RCMAN_INVALID_SETTING_METADATA",
+            Error::InvalidStoredValue { .. } => "RCMAN_INVALID_STORED_VALUE",
+            Error::SchemaNotRegistered => "RCMAN_SCHEMA_NOT_REGISTERED",
+            Error::TypeMismatch { .. } => "RCMAN_TYPE_MISMATCH",
+            Error::SubSettingsNotRegistered(_) => "RCMAN_SUB_SETTINGS_NOT_REGISTERED",
+            Error::SubSettingsEntryNotFound(_) => "RCMAN_SUB_SETTINGS_ENTRY_NOT_FOUND",
+            Error::SubSettingsEntryExists(_) => "RCMAN_SUB_SETTINGS_ENTRY_EXISTS",
+            Error::SubSettingsLimitReached { .. } => "RCMAN_SUB_SETTINGS_LIMIT_REACHED",
+            Error::BackupFailed(_) => "RCMAN_BACKUP_FAILED",
+            Error::RestoreFailed(_) => "RCMAN_RESTORE_FAILED",
+            Error::InvalidBackup(_) => "RCMAN_INVALID_BACKUP",
+            Error::PasswordRequired => "RCMAN_PASSWORD_REQUIRED",
+            Error::MissingBaseBackup(_) => "RCMAN_MISSING_BASE_BACKUP",
+            Error::InvalidPassword => "RCMAN_INVALID_PASSWORD",
+            Error::VersionMismatch { .. } => "RCMAN_VERSION_MISMATCH",
+            #[cfg(feature = "backup")]
+            Error::Archive(_) => "RCMAN_ARCHIVE",
+            #[cfg(feature = "backup")]
+            Error::Zip(_) => "RCMAN_ZIP",
+            Error::Config(_) => "RCMAN_CONFIG",
+            Error::NotInitialized => "RCMAN_NOT_INITIALIZED",
+            Error::Credential(_) => "RCMAN_CREDENTIAL",
+            #[cfg(feature = "profiles")]
+            Error::ProfileNotFound(_) => "RCMAN_PROFILE_NOT_FOUND",
+            #[cfg(feature = "profiles")]
+            Error::ProfileAlreadyExists(_) => "RCMAN_PROFILE_ALREADY_EXISTS",
+            #[cfg(feature = "profiles")]
+            Error::CannotDeleteActiveProfile(_) => "RCMAN_CANNOT_DELETE_ACTIVE_PROFILE",
+            #[cfg(feature = "profiles")]
+            Error::CannotDeleteLastProfile => "RCMAN_CANNOT_DELETE_LAST_PROFILE",
+            #[cfg(feature = "profiles")]
+            Error::InvalidProfileName(_) => "RCMAN_INVALID_PROFILE_NAME",
+            #[cfg(feature = "profiles")]
+            Error::ProfilesNotEnabled => "RCMAN_PROFILES_NOT_ENABLED",
+            #[cfg(feature = "profiles")]
+            Error::ProfileMigrationFailed(_) => "RCMAN_PROFILE_MIGRATION_FAILED",
+            Error::InvalidCacheStrategy(_) => "RCMAN_INVALID_CACHE_STRATEGY",
+            Error::LockPoisoned => "RCMAN_LOCK_POISONED",
+            Error::LockError(_) => "RCMAN_LOCK_ERROR",
+            Error::StaleResetToken => "RCMAN_STALE_RESET_TOKEN",
+            Error::AsyncValidationRequired(_) => "RCMAN_ASYNC_VALIDATION_REQUIRED",
+        }
+    }
+
     /// Check if this is a "not found" type error
     #[must_use]
     pub fn is_not_found(&self) -> bool {
@@ -205,6 +293,7 @@ impl Error {
                 | Error::RestoreFailed(_)
                 | Error::InvalidBackup(_)
                 | Error::PasswordRequired
+                | Error::MissingBaseBackup(_)
                 | Error::InvalidPassword
                 | Error::VersionMismatch { .. }
         )
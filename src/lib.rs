@@ -13,6 +13,7 @@
 //! - **Schema Validation**: Regex patterns, numeric ranges, and option constraints
 //! - **Performance**: In-memory caching for fast access
 //! - **Hot Reload**: Optional file watcher runtime for external settings updates (requires `hot-reload` feature)
+//! - **Tokio**: Load and save on `spawn_blocking` from async tasks (requires `tokio` feature)
 //!
 //! ## Quick Start
 //!
@@ -285,16 +286,20 @@ mod credentials;
 
 /// Core configuration types and traits for settings management.
 pub use config::{
-    CredentialConfig, DefaultEnvSource, EnvSource, NumberConstraints, SettingConstraints,
-    SettingMetadata, SettingOption, SettingType, SettingsConfig, SettingsConfigBuilder,
-    SettingsSchema, TextConstraints, meta, opt,
+    CategoryMeta, ConfigSource, CorruptPolicy, CredentialConfig, DefaultEnvSource, DependsOn,
+    EnvSource, NumberConstraints, RequiredWhen, SettingConstraints, SettingMetadata, SettingOption,
+    SettingType, SettingValidator, SettingsConfig, SettingsConfigBuilder, SettingsEnum,
+    SettingsSchema, TextConstraints, Widget, meta, opt, parse_duration,
 };
 
 #[cfg(feature = "hot-reload")]
 pub use config::{HotReloadBackend, HotReloadConfig};
 
 /// Documentation generation utilities.
-pub use config::{DocsConfig, generate_docs, generate_docs_from_metadata};
+pub use config::{
+    DocsConfig, DocsFormat, generate_docs, generate_docs_from_metadata, generate_env_docs,
+    generate_json_schema,
+};
 
 /// Error types for the library.
 pub use error::{Error, Result};
@@ -303,16 +308,22 @@ pub use error::{Error, Result};
 pub use serde_json;
 
 /// Event system for reactive settings changes.
-pub use manager::EventManager;
+pub use manager::{EventManager, ListenerHandle};
 
 /// Main settings manager and builder.
-pub use manager::{SettingsManager, SettingsManagerBuilder};
+pub use manager::{
+    ImportOptions, ImportReport, Mutation, REDACTED_SECRET, RedactedExport, ResetToken,
+    SaveOutcome, SettingsManager, SettingsManagerBuilder, SettingsSnapshot,
+};
 
 #[cfg(feature = "hot-reload")]
 pub use manager::{HotReloadEvent, HotReloadRuntime};
 
 /// Sub-settings for per-entity configuration.
-pub use sub_settings::{SubSettings, SubSettingsAction, SubSettingsConfig, SubSettingsMode};
+pub use sub_settings::{
+    ReplaceReport, SCHEMA_VERSION_KEY, StrictRead, SubSettings, SubSettingsAction,
+    SubSettingsConfig, SubSettingsMode, VersionedMigrator,
+};
 
 // -----------------------------------------------------------------------------
 // Storage Backends
@@ -321,6 +332,12 @@ pub use sub_settings::{SubSettings, SubSettingsAction, SubSettingsConfig, SubSet
 /// JSON storage backend (default).
 pub use storage::{JsonStorage, StorageBackend};
 
+/// Storage wrapper that counts reads and writes, for diagnostics.
+pub use storage::{InstrumentedStorage, StorageStats};
+
+/// Storage backend that keeps settings in memory, for tests.
+pub use storage::MemoryStorage;
+
 /// Convenience type alias for the most common configuration:
 /// `SettingsManager` using `JsonStorage`.
 pub type JsonManager<S> = SettingsManager<JsonStorage, S>;
@@ -345,7 +362,7 @@ pub use storage::SqliteStorage;
 // -----------------------------------------------------------------------------
 
 /// Cache strategy for settings components.
-pub use config::CacheStrategy;
+pub use config::{CacheStats, CacheStrategy};
 
 // -----------------------------------------------------------------------------
 // Backup & Restore (requires backup feature)
@@ -353,9 +370,10 @@ pub use config::CacheStrategy;
 
 #[cfg(feature = "backup")]
 pub use backup::{
-    BackupInfo, BackupManager, BackupOptions, ExportType, ProfileEntry, ProgressCallback,
-    RestoreOptions, RestorePendingItem, RestorePendingReason, RestoreResult, RestoreSkipReason,
-    RestoreSkippedItem, SubSettingsManifestEntry,
+    BackupCipher, BackupEntry, BackupInfo, BackupManager, BackupOptions, BaseBackupRef, ExportType,
+    ProfileEntry, ProgressCallback, RestoreJournal, RestoreMode, RestoreOptions,
+    RestorePendingItem, RestorePendingReason, RestoreResult, RestoreSkipReason, RestoreSkippedItem,
+    SettingChange, SubSettingsManifestEntry,
 };
 
 // -----------------------------------------------------------------------------
@@ -364,8 +382,8 @@ pub use backup::{
 
 #[cfg(feature = "profiles")]
 pub use profiles::{
-    DEFAULT_PROFILE, PROFILES_DIR, ProfileEvent, ProfileManager, ProfileManifest, ProfileMigrator,
-    migrate, validate_profile_name,
+    DEFAULT_PROFILE, PROFILES_DIR, ProfileEvent, ProfileManager, ProfileManifest, ProfileMeta,
+    ProfileMigrator, migrate, validate_profile_name,
 };
 
 // -----------------------------------------------------------------------------
@@ -374,7 +392,8 @@ pub use profiles::{
 
 /// Credential storage backend trait and types.
 pub use credentials::{
-    CredentialBackend, MemoryBackend, SecretBackupPolicy, SecretPasswordSource, SecretStorage,
+    CredentialBackend, CredentialMeta, EnvBackend, MemoryBackend, SecretBackupPolicy,
+    SecretPasswordSource, SecretStorage,
 };
 
 /// Keychain backend (requires `keychain` feature).
@@ -419,6 +438,33 @@ pub use credentials::CredentialManager;
 #[cfg(feature = "derive")]
 pub use rcman_derive::SettingsSchema as DeriveSettingsSchema;
 
+/// Derive macro for [`SettingsEnum`], listing a unit-only enum's variants as
+/// select options.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rcman::{DeriveSettingsEnum, DeriveSettingsSchema};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Default, Serialize, Deserialize, DeriveSettingsEnum)]
+/// #[serde(rename_all = "lowercase")]
+/// enum Theme {
+///     #[default]
+///     Light,
+///     Dark,
+/// }
+///
+/// #[derive(Default, Serialize, Deserialize, DeriveSettingsSchema)]
+/// #[schema(category = "ui")]
+/// struct UiSettings {
+///     #[setting(enum_options)]
+///     theme: Theme,
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use rcman_derive::SettingsEnum as DeriveSettingsEnum;
+
 // =============================================================================
 // PRELUDE MODULE (convenient glob import)
 // =============================================================================
@@ -468,7 +514,7 @@ pub mod prelude {
 
     // Derive
     #[cfg(feature = "derive")]
-    pub use super::DeriveSettingsSchema;
+    pub use super::{DeriveSettingsEnum, DeriveSettingsSchema};
 
     // Hot reload
     #[cfg(feature = "hot-reload")]
@@ -0,0 +1,88 @@
+//! Tokio variants of the blocking settings I/O
+//!
+//! Loading and saving settings reads and writes files (or the credential
+//! store) on the calling thread. With the `tokio` feature, the methods here run
+//! that work on [`tokio::task::spawn_blocking`] so an async task never stalls
+//! the runtime. They take `self: &Arc<Self>` because the blocking task must
+//! own a handle to the manager.
+//!
+//! The blocking task calls the same sync methods, with the same internal
+//! locks, so the sync and async variants can be mixed freely: a save through
+//! one is never interleaved with a save through the other.
+
+use crate::config::SettingsSchema;
+use crate::error::{Error, Result};
+use crate::manager::{SaveOutcome, SettingsManager};
+use crate::storage::StorageBackend;
+
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Run `work` on the blocking thread pool, resuming its panic if it panicked.
+async fn spawn_blocking<T, F>(work: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    match tokio::task::spawn_blocking(work).await {
+        Ok(result) => result,
+        Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+        Err(err) => Err(Error::Config(format!(
+            "Blocking settings task failed: {err}"
+        ))),
+    }
+}
+
+impl<S: StorageBackend + 'static, Schema: SettingsSchema + Send + Sync + 'static>
+    SettingsManager<S, Schema>
+{
+    /// Load the settings cache from disk without blocking the runtime.
+    ///
+    /// Async counterpart of
+    /// [`ensure_cache_populated`](Self::ensure_cache_populated), meant to be
+    /// awaited once at startup so later reads are served from memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if loading from disk or parsing fails.
+    pub async fn load_startup_async(self: &Arc<Self>) -> Result<()> {
+        let manager = Arc::clone(self);
+        spawn_blocking(move || manager.ensure_cache_populated()).await
+    }
+
+    /// Load all settings without blocking the runtime.
+    ///
+    /// Async counterpart of [`get_all`](Self::get_all).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if settings cannot be loaded or deserialized.
+    pub async fn load_settings_async(self: &Arc<Self>) -> Result<Schema> {
+        let manager = Arc::clone(self);
+        spawn_blocking(move || manager.get_all()).await
+    }
+
+    /// Save a single setting without blocking the runtime.
+    ///
+    /// Validates like [`save_setting_async`](Self::save_setting_async),
+    /// including async validators awaited on the calling task, then writes on
+    /// the blocking thread pool. `save_setting_async` itself stays
+    /// runtime-agnostic and writes on the calling thread.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation (sync or async) fails, or if saving fails.
+    pub async fn save_setting_spawned(
+        self: &Arc<Self>,
+        category: &str,
+        key: &str,
+        value: Value,
+    ) -> Result<SaveOutcome> {
+        self.validate_before_async_save(category, key, &value)
+            .await?;
+
+        let manager = Arc::clone(self);
+        let (category, key) = (category.to_string(), key.to_string());
+        spawn_blocking(move || manager.save_setting_checked(&category, &key, &value, true)).await
+    }
+}
@@ -167,6 +167,71 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsManagerBuilder<S, Schema
         self
     }
 
+    /// Refuse to load settings that fail schema validation.
+    ///
+    /// `build()` then fails with `Error::InvalidStoredValue` for the first
+    /// stored value that does not pass its schema, instead of starting with it.
+    #[must_use]
+    pub fn strict_validation(mut self) -> Self {
+        self.config_builder = self.config_builder.strict_validation();
+        self
+    }
+
+    /// Choose how an unparseable settings file is handled.
+    ///
+    /// See [`SettingsConfigBuilder::on_corrupt`](crate::SettingsConfigBuilder::on_corrupt).
+    #[must_use]
+    pub fn on_corrupt(mut self, policy: crate::config::CorruptPolicy) -> Self {
+        self.config_builder = self.config_builder.on_corrupt(policy);
+        self
+    }
+
+    /// Register display metadata for a settings category.
+    ///
+    /// See [`SettingsManager::category_metadata`](crate::SettingsManager::category_metadata).
+    #[must_use]
+    pub fn with_category(
+        mut self,
+        name: impl Into<String>,
+        meta: crate::config::CategoryMeta,
+    ) -> Self {
+        self.config_builder = self.config_builder.with_category(name, meta);
+        self
+    }
+
+    /// Set a source consulted for settings without a locally stored value.
+    ///
+    /// See [`SettingsConfigBuilder::with_fallback_source`](crate::SettingsConfigBuilder::with_fallback_source).
+    #[must_use]
+    pub fn with_fallback_source(
+        mut self,
+        source: std::sync::Arc<dyn crate::config::ConfigSource>,
+    ) -> Self {
+        self.config_builder = self.config_builder.with_fallback_source(source);
+        self
+    }
+
+    /// Replace schema defaults for this deployment.
+    ///
+    /// See [`SettingsConfigBuilder::with_default_overrides`](crate::SettingsConfigBuilder::with_default_overrides).
+    #[must_use]
+    pub fn with_default_overrides(
+        mut self,
+        overrides: std::collections::HashMap<String, serde_json::Value>,
+    ) -> Self {
+        self.config_builder = self.config_builder.with_default_overrides(overrides);
+        self
+    }
+
+    /// Defer writing non-secret settings to disk.
+    ///
+    /// See [`SettingsConfigBuilder::deferred_writes`](crate::SettingsConfigBuilder::deferred_writes).
+    #[must_use]
+    pub fn deferred_writes(mut self, interval: std::time::Duration) -> Self {
+        self.config_builder = self.config_builder.deferred_writes(interval);
+        self
+    }
+
     /// Set a migration function for schema changes (lazy migration).
     ///
     /// The migrator function is called automatically when loading settings.
@@ -269,6 +334,28 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsManagerBuilder<S, Schema
         }
     }
 
+    /// Use a configured storage backend instance.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use rcman::{SettingsManager, JsonStorage};
+    ///
+    /// let manager = SettingsManager::builder("my-app", "1.0.0")
+    ///     .with_storage_backend(JsonStorage::compact())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn with_storage_backend<NewS: StorageBackend>(
+        self,
+        storage: NewS,
+    ) -> SettingsManagerBuilder<NewS, Schema> {
+        SettingsManagerBuilder {
+            config_builder: self.config_builder.with_storage_backend(storage),
+            sub_settings: self.sub_settings,
+        }
+    }
+
     /// Enable profiles for main settings.
     ///
     /// When enabled, the main settings file is stored per-profile, allowing
@@ -294,6 +381,16 @@ impl<S: StorageBackend, Schema: SettingsSchema> SettingsManagerBuilder<S, Schema
         self
     }
 
+    /// Start in the default profile instead of the last active one.
+    ///
+    /// See [`SettingsConfigBuilder::start_in_default_profile`](crate::SettingsConfigBuilder::start_in_default_profile).
+    #[cfg(feature = "profiles")]
+    #[must_use]
+    pub fn start_in_default_profile(mut self) -> Self {
+        self.config_builder = self.config_builder.start_in_default_profile();
+        self
+    }
+
     /// Register a sub-settings type for per-entity configuration.
     ///
     /// Sub-settings allow you to manage separate config files for each entity
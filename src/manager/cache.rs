@@ -13,10 +13,15 @@ pub struct CachedSettings {
     pub stored: Value,
     /// Merged settings (defaults + stored), lazily computed
     pub merged: Option<Value>,
-    /// Default values for quick lookup
+    /// Default values for quick lookup (schema defaults, overlaid with
+    /// fallback source values)
     pub defaults: Arc<HashMap<String, Value>>,
+    /// Values provided by the fallback source, keyed by setting key
+    pub fallback: HashMap<String, Value>,
     /// Generation counter — incremented on every mutation.
     pub generation: u64,
+    /// Stored values clamped into range on load, keyed by setting key
+    pub clamped: HashMap<String, Value>,
 }
 
 pub struct SettingsCache {
@@ -45,13 +50,21 @@ impl SettingsCache {
     ) -> Result<Option<Value>> {
         let guard = self.state.read_recovered()?;
         if let Some(cached) = guard.as_ref() {
-            // Check stored
-            if let Some(value) = cached
+            // Check stored, deep-merged over an object default so nested
+            // fields missing from the file keep their defaults
+            if let Some(stored) = cached
                 .stored
                 .get(category)
                 .and_then(|cat| cat.get(setting_name))
             {
-                return Ok(Some(value.clone()));
+                return Ok(Some(match cached.defaults.get(key) {
+                    Some(default) if default.is_object() && stored.is_object() => {
+                        let mut value = default.clone();
+                        crate::utils::value::deep_merge(&mut value, stored);
+                        value
+                    }
+                    _ => stored.clone(),
+                }));
             }
             // Check defaults
             if let Some(value) = cached.defaults.get(key) {
@@ -68,7 +81,7 @@ impl SettingsCache {
     /// and our write-back, the computed result is discarded and we retry.
     pub fn get_or_compute_merged<F>(&self, computer: F) -> Result<Value>
     where
-        F: Fn(&Value) -> Result<Value>,
+        F: Fn(&CachedSettings) -> Result<Value>,
     {
         // Fast path: read lock, return if merged is already cached
         {
@@ -93,11 +106,39 @@ impl SettingsCache {
             return Ok(merged.clone());
         }
 
-        let computed_value = computer(&cached.stored)?;
+        let computed_value = computer(cached)?;
         cached.merged = Some(computed_value.clone());
         Ok(computed_value)
     }
 
+    /// Effective default for a key (fallback source value or schema default)
+    pub fn get_default(&self, key: &str) -> Result<Option<Value>> {
+        let guard = self.state.read_recovered()?;
+        Ok(guard
+            .as_ref()
+            .and_then(|cached| cached.defaults.get(key).cloned()))
+    }
+
+    /// Values provided by the fallback source, keyed by setting key
+    #[cfg(feature = "backup")]
+    pub fn get_fallback(&self) -> Result<HashMap<String, Value>> {
+        let guard = self.state.read_recovered()?;
+        Ok(guard
+            .as_ref()
+            .map(|cached| cached.fallback.clone())
+            .unwrap_or_default())
+    }
+
+    /// Check whether `key` still holds the value it was clamped to on load
+    pub fn is_clamped(&self, key: &str) -> Result<bool> {
+        let guard = self.state.read_recovered()?;
+        Ok(guard.as_ref().is_some_and(|cached| {
+            cached.clamped.get(key).is_some_and(|clamped| {
+                crate::utils::value::get_path(&cached.stored, key) == Some(clamped)
+            })
+        }))
+    }
+
     pub fn get_stored(&self) -> Result<Option<Value>> {
         let guard = self.state.read_recovered()?;
         Ok(guard.as_ref().map(|c| c.stored.clone()))
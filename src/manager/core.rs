@@ -5,7 +5,7 @@ use crate::config::CredentialConfig;
 use crate::config::{SettingMetadata, SettingsConfig, SettingsSchema};
 #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
 use crate::credentials::CredentialManager;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::manager::EventManager;
 use crate::manager::cache::SettingsCache;
 use crate::manager::env::EnvironmentHandler;
@@ -70,6 +70,20 @@ pub struct SettingsManager<
     /// Serializes non-secret settings write transactions to avoid read-modify-write races.
     pub(super) settings_write_lock: Mutex<()>,
 
+    /// Restart-requiring settings changed since the last `clear_restart_required`
+    pub(super) restart_required: RwLock<std::collections::HashSet<String>>,
+
+    /// Values scheduled by `save_setting_debounced` that are not yet written
+    pub(super) pending_writes: Arc<crate::manager::pending::PendingWrites>,
+
+    /// Writes pending debounced values when the manager is dropped. Set in
+    /// `new`, where the bounds needed to save are known; `Drop` cannot
+    /// require them.
+    pub(super) flush_on_drop: fn(&Self),
+
+    /// Unwritten settings file contents when deferred writes are enabled
+    pub(super) deferred_writes: Option<Arc<crate::manager::deferred::DeferredWrites<S>>>,
+
     /// Environment variable handler
     pub(super) env_handler: EnvironmentHandler,
 
@@ -165,11 +179,12 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
 
         // Initialize profile manager if profiles are enabled
         #[cfg(feature = "profiles")]
-        let (settings_dir, profile_manager) = crate::profiles::ProfileManager::initialize(
+        let (settings_dir, profile_manager) = crate::profiles::ProfileManager::initialize_with(
             &config.config_dir,
             "settings",
             storage.clone(),
             config.profiles_enabled,
+            config.start_in_default_profile,
             &config.profile_migrator,
         )?;
 
@@ -177,7 +192,20 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         let settings_dir = config.config_dir.clone();
 
         // Pre-compute schema metadata/defaults ONCE (memory optimization)
-        let metadata = Arc::new(Schema::get_metadata());
+        let mut metadata = Schema::get_metadata();
+        for (key, value) in &config.default_overrides {
+            let setting = metadata
+                .get_mut(key)
+                .ok_or_else(|| Error::SettingNotFound(key.clone()))?;
+            setting
+                .validate_constraints(value)
+                .map_err(|reason| Error::InvalidSettingValue {
+                    key: key.clone(),
+                    reason,
+                })?;
+            setting.default = value.clone();
+        }
+        let metadata = Arc::new(metadata);
         let schema_defaults = Arc::new(
             metadata
                 .iter()
@@ -188,6 +216,14 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         let env_handler =
             EnvironmentHandler::new(config.env_prefix.clone(), config.env_source.clone());
 
+        let deferred_writes = config.deferred_writes.map(|interval| {
+            let writes = Arc::new(crate::manager::deferred::DeferredWrites::new(
+                storage.clone(),
+            ));
+            crate::manager::deferred::DeferredWrites::spawn_flusher(&writes, interval);
+            writes
+        });
+
         log::debug!(
             "Initialized rcman SettingsManager at: {:?}",
             config.config_dir.display()
@@ -201,6 +237,10 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
             events: Arc::new(EventManager::new()),
             settings_cache: SettingsCache::new(),
             settings_write_lock: Mutex::new(()),
+            restart_required: RwLock::new(std::collections::HashSet::new()),
+            pending_writes: Arc::default(),
+            flush_on_drop: Self::flush_pending_on_drop,
+            deferred_writes,
             env_handler,
             schema_defaults,
             schema_metadata: metadata,
@@ -219,6 +259,11 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
         manager.migrate_secret_keys()?;
 
+        // Load eagerly so invalid stored values block startup
+        if manager.config.strict_validation {
+            manager.ensure_cache_populated()?;
+        }
+
         Ok(manager)
     }
     /// Get the configuration
@@ -281,10 +326,7 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
     }
 
     /// Get reference to the schema metadata map
-    #[cfg(all(
-        feature = "backup",
-        any(feature = "keychain", feature = "encrypted-file")
-    ))]
+    #[cfg(feature = "backup")]
     pub(crate) fn schema_metadata(&self) -> &HashMap<String, SettingMetadata> {
         &self.schema_metadata
     }
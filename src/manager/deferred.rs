@@ -0,0 +1,312 @@
+//! Deferred persistence of the main settings file
+//!
+//! With [`SettingsConfigBuilder::deferred_writes`](crate::SettingsConfigBuilder::deferred_writes)
+//! a save only updates the settings cache and records the new file contents
+//! here. The contents are written by a background timer, by
+//! [`SettingsManager::flush`], or when the manager is dropped, so a control
+//! that saves many times per second costs one disk write per interval.
+//!
+//! When hot reload sees the file change on disk while contents are pending,
+//! the pending changes are merged into the new file instead of overwriting it.
+
+use crate::config::SettingsSchema;
+use crate::error::{Error, Result};
+use crate::manager::SettingsManager;
+use crate::storage::StorageBackend;
+
+use log::{debug, warn};
+use serde_json::{Value, json};
+use std::path::PathBuf;
+use std::sync::{Arc, Condvar, Mutex, Weak};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Unwritten contents of one settings file
+struct Dirty {
+    path: PathBuf,
+    contents: Value,
+    /// File contents the pending changes were made on top of
+    base: Value,
+}
+
+/// Settings file contents waiting to be written
+pub(crate) struct DeferredWrites<S: StorageBackend> {
+    storage: S,
+    /// Latest unwritten contents and the file they belong to. The lock is held
+    /// while writing so an older snapshot can never land after a newer one.
+    dirty: Mutex<Option<Dirty>>,
+    /// Periodic flusher thread, stopped and joined on drop
+    flusher: Mutex<Option<(Arc<FlusherStop>, JoinHandle<()>)>>,
+}
+
+/// Tells the flusher thread to exit
+#[derive(Default)]
+struct FlusherStop {
+    stopped: Mutex<bool>,
+    signal: Condvar,
+}
+
+impl<S: StorageBackend> DeferredWrites<S> {
+    pub(crate) fn new(storage: S) -> Self {
+        Self {
+            storage,
+            dirty: Mutex::new(None),
+            flusher: Mutex::new(None),
+        }
+    }
+
+    /// Record `stored` as the contents to write to `path`.
+    ///
+    /// `previous` is what the file held before this change. Unwritten
+    /// contents of a different file (the active profile changed) are
+    /// written first.
+    pub(crate) fn defer(&self, path: PathBuf, previous: Value, stored: Value) -> Result<()> {
+        let mut dirty = self.dirty.lock().map_err(|_| Error::LockPoisoned)?;
+        let base = match dirty.take() {
+            Some(pending) if pending.path == path => pending.base,
+            Some(pending) => {
+                if let Err(e) = self.storage.write(&pending.path, &pending.contents) {
+                    *dirty = Some(pending);
+                    return Err(e);
+                }
+                previous
+            }
+            None => previous,
+        };
+        *dirty = Some(Dirty {
+            path,
+            contents: stored,
+            base,
+        });
+        Ok(())
+    }
+
+    /// Write the pending contents, if any.
+    pub(crate) fn flush(&self) -> Result<()> {
+        let mut dirty = self.dirty.lock().map_err(|_| Error::LockPoisoned)?;
+        if let Some(pending) = dirty.as_ref() {
+            self.storage.write(&pending.path, &pending.contents)?;
+            debug!(
+                "Flushed deferred settings write to {}",
+                pending.path.display()
+            );
+            *dirty = None;
+        }
+        Ok(())
+    }
+
+    /// Merge the pending changes into the file as it is now on disk.
+    ///
+    /// Only the settings changed since the pending contents were started are
+    /// written over the file, so edits made to it by another process survive.
+    /// Nothing is written if the file at `path` has no pending contents.
+    #[cfg(feature = "hot-reload")]
+    pub(crate) fn reconcile(&self, path: &std::path::Path) -> Result<()> {
+        let mut dirty = self.dirty.lock().map_err(|_| Error::LockPoisoned)?;
+        let Some(pending) = dirty.as_ref().filter(|pending| pending.path == path) else {
+            return Ok(());
+        };
+
+        let mut merged = if path.exists() {
+            self.storage.read(path)?
+        } else {
+            json!({})
+        };
+        if !merged.is_object() {
+            return Err(Error::Config(format!(
+                "Settings file {} is not an object",
+                path.display()
+            )));
+        }
+        apply_changes(&mut merged, &pending.base, &pending.contents);
+
+        self.storage.write(path, &merged)?;
+        debug!(
+            "Merged deferred settings write into changed {}",
+            path.display()
+        );
+        *dirty = None;
+        Ok(())
+    }
+
+    /// Drop the pending contents without writing them.
+    pub(crate) fn discard(&self) -> Result<()> {
+        self.dirty.lock().map_err(|_| Error::LockPoisoned)?.take();
+        Ok(())
+    }
+
+    pub(crate) fn is_dirty(&self) -> bool {
+        self.dirty.lock().is_ok_and(|dirty| dirty.is_some())
+    }
+}
+
+/// Apply to `target` every `category.key` that differs between `base` and `changed`
+#[cfg(feature = "hot-reload")]
+fn apply_changes(target: &mut Value, base: &Value, changed: &Value) {
+    let empty = serde_json::Map::new();
+    let base = base.as_object().unwrap_or(&empty);
+    let changed = changed.as_object().unwrap_or(&empty);
+    let Some(target) = target.as_object_mut() else {
+        return;
+    };
+
+    for name in base.keys().chain(changed.keys()) {
+        let (before, after) = (base.get(name), changed.get(name));
+        if before == after {
+            continue;
+        }
+        // A missing category holds no settings; anything else that is not
+        // an object is replaced as a whole
+        let (Some(before), Some(after)) = (
+            before.map_or(Some(&empty), Value::as_object),
+            after.map_or(Some(&empty), Value::as_object),
+        ) else {
+            match after {
+                Some(value) => target.insert(name.clone(), value.clone()),
+                None => target.remove(name),
+            };
+            continue;
+        };
+
+        let category = target.entry(name.clone()).or_insert_with(|| json!({}));
+        if !category.is_object() {
+            *category = json!({});
+        }
+        let Some(category) = category.as_object_mut() else {
+            continue;
+        };
+        for key in before.keys().chain(after.keys()) {
+            match (before.get(key), after.get(key)) {
+                (old, new) if old == new => {}
+                (_, Some(value)) => {
+                    category.insert(key.clone(), value.clone());
+                }
+                (_, None) => {
+                    category.remove(key);
+                }
+            }
+        }
+        if category.is_empty() {
+            target.remove(name);
+        }
+    }
+}
+
+impl<S: StorageBackend + 'static> DeferredWrites<S> {
+    /// Flush every `interval` on a background thread until `writes` is dropped.
+    pub(crate) fn spawn_flusher(writes: &Arc<Self>, interval: Duration) {
+        let stop = Arc::new(FlusherStop::default());
+        let weak: Weak<Self> = Arc::downgrade(writes);
+        let thread_stop = Arc::clone(&stop);
+        let handle = thread::spawn(move || {
+            loop {
+                let Ok(stopped) = thread_stop.stopped.lock() else {
+                    return;
+                };
+                let Ok((stopped, _)) =
+                    thread_stop
+                        .signal
+                        .wait_timeout_while(stopped, interval, |stopped| !*stopped)
+                else {
+                    return;
+                };
+                if *stopped {
+                    return;
+                }
+                drop(stopped);
+                let Some(writes) = weak.upgrade() else {
+                    return;
+                };
+                if let Err(e) = writes.flush() {
+                    warn!("Deferred settings write failed: {e}");
+                }
+            }
+        });
+        if let Ok(mut flusher) = writes.flusher.lock() {
+            *flusher = Some((stop, handle));
+        }
+    }
+}
+
+impl<S: StorageBackend> Drop for DeferredWrites<S> {
+    fn drop(&mut self) {
+        let flusher = self
+            .flusher
+            .get_mut()
+            .map_or_else(|poisoned| poisoned.into_inner().take(), Option::take);
+        if let Some((stop, handle)) = flusher {
+            if let Ok(mut stopped) = stop.stopped.lock() {
+                *stopped = true;
+            }
+            stop.signal.notify_all();
+            // The flusher itself may hold the last reference while flushing
+            if handle.thread().id() != thread::current().id() {
+                let _ = handle.join();
+            }
+        }
+        if let Err(e) = self.flush() {
+            warn!("Failed to write deferred settings on drop: {e}");
+        }
+    }
+}
+
+impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Schema> {
+    /// Write deferred settings changes to disk now.
+    ///
+    /// Does nothing when [`deferred_writes`](crate::SettingsConfigBuilder::deferred_writes)
+    /// is not configured or nothing changed since the last write.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing the settings file fails; the changes stay
+    /// pending and are retried on the next flush.
+    pub fn flush(&self) -> Result<()> {
+        self.deferred_writes
+            .as_ref()
+            .map_or(Ok(()), |writes| writes.flush())
+    }
+
+    /// Check whether settings changes are waiting to be written by [`flush`](Self::flush).
+    #[must_use]
+    pub fn has_unflushed_changes(&self) -> bool {
+        self.deferred_writes
+            .as_ref()
+            .is_some_and(|writes| writes.is_dirty())
+    }
+
+    /// Persist `stored` as the settings file at `path`, honoring deferred writes.
+    ///
+    /// Must be called before the cache is updated to `stored`.
+    pub(crate) fn persist_settings(&self, path: PathBuf, stored: &Value) -> Result<()> {
+        match &self.deferred_writes {
+            Some(writes) => {
+                let previous = self
+                    .settings_cache
+                    .get_stored()?
+                    .unwrap_or_else(|| json!({}));
+                writes.defer(path, previous, stored.clone())
+            }
+            None => self.storage.write(&path, stored),
+        }
+    }
+
+    /// Reload after the settings file was changed by another process.
+    ///
+    /// Unlike [`invalidate_cache`](Self::invalidate_cache), pending deferred
+    /// writes are merged into the changed file rather than written over it.
+    /// If merging fails they stay pending and the file is left alone.
+    #[cfg(feature = "hot-reload")]
+    pub(crate) fn reload_external_change(&self) {
+        if let Some(writes) = &self.deferred_writes {
+            match self.settings_path() {
+                Ok(path) => {
+                    if let Err(e) = writes.reconcile(&path) {
+                        warn!("Failed to merge deferred settings into the changed file: {e}");
+                    }
+                }
+                Err(e) => warn!("Failed to resolve the settings path: {e}"),
+            }
+        }
+        self.clear_caches();
+    }
+}
@@ -5,6 +5,13 @@
 use crate::config::EnvSource;
 use serde_json::Value;
 
+/// Environment variable name of a setting key: `{PREFIX}_{CATEGORY}_{KEY}`,
+/// all uppercase
+pub(crate) fn env_var_name(prefix: &str, key: &str) -> String {
+    let env_key = key.replace('.', "_").to_uppercase();
+    format!("{}_{}", prefix.to_uppercase(), env_key)
+}
+
 /// Handles environment variable lookups and parsing
 pub struct EnvironmentHandler {
     prefix: Option<String>,
@@ -21,10 +28,7 @@ impl EnvironmentHandler {
     /// Returns None if env var overrides are disabled.
     /// Format: {PREFIX}_{CATEGORY}_{KEY} (all uppercase)
     pub fn get_env_var_name(&self, key: &str) -> Option<String> {
-        self.prefix.as_ref().map(|prefix| {
-            let env_key = key.replace('.', "_").to_uppercase();
-            format!("{}_{}", prefix.to_uppercase(), env_key)
-        })
+        self.prefix.as_ref().map(|prefix| env_var_name(prefix, key))
     }
 
     /// Check if a setting value is overridden by an environment variable
@@ -2,11 +2,17 @@
 //!
 //! Provides reactive callbacks for settings modifications.
 
+use crate::sub_settings::SubSettingsAction;
 use crate::utils::sync::RwLockExt;
 use serde_json::Value;
-use std::collections::HashMap;
-use std::sync::Arc;
-use std::sync::RwLock;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
 /// Type alias for a change callback
 pub type ChangeCallback = Arc<dyn Fn(&str, &Value, &Value) + Send + Sync>;
@@ -14,16 +20,239 @@ pub type ChangeCallback = Arc<dyn Fn(&str, &Value, &Value) + Send + Sync>;
 /// Type alias for a validator function
 pub type Validator = Arc<dyn Fn(&Value) -> Result<(), String> + Send + Sync>;
 
+/// Type alias for a cross-field validator receiving the values of its keys
+pub type CrossValidator = Arc<dyn Fn(&HashMap<String, Value>) -> Result<(), String> + Send + Sync>;
+
+/// Type alias for a boxed, sendable future
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// Type alias for an async validator function
+pub type AsyncValidator = Arc<dyn Fn(Value) -> BoxFuture<Result<(), String>> + Send + Sync>;
+
+/// Type alias for a sub-settings change callback receiving (`settings_type`, `entry`, `action`)
+pub type SubSettingsCallback = Arc<dyn Fn(&str, &str, SubSettingsAction) + Send + Sync>;
+
+/// Type alias for a profile switch callback receiving (`target`, `from`, `to`)
+#[cfg(feature = "profiles")]
+pub type ProfileSwitchCallback = Arc<dyn Fn(&str, &str, &str) + Send + Sync>;
+
+/// Identifies a registered listener or validator
+///
+/// Returned by the registration methods of [`EventManager`]; pass it to
+/// [`EventManager::remove_listener`] to unregister the callback, e.g. when the
+/// settings window that registered it closes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListenerHandle(u64);
+
+/// Callbacks keyed by registration id; ids only grow, so iteration follows
+/// registration order
+type Registry<T> = BTreeMap<u64, T>;
+
+/// A coalesced change waiting for its key to go quiet
+struct DebouncedChange {
+    old_value: Value,
+    new_value: Value,
+    deadline: Instant,
+}
+
+/// Pending change of a debounced listener, and whether it is being dropped
+#[derive(Default)]
+struct DebounceState {
+    pending: Option<DebouncedChange>,
+    shutdown: bool,
+}
+
+/// Part of a debouncer shared with its scheduler thread
+struct DebounceShared {
+    key: String,
+    callback: ChangeCallback,
+    state: Mutex<DebounceState>,
+    /// Signalled when a change is recorded or the debouncer is dropped
+    changed: Condvar,
+}
+
+/// State of one `watch_debounced` listener, owned by its registry entry.
+/// A single scheduler thread delivers its changes; dropping the debouncer
+/// (listener removed or event manager dropped) stops and joins it.
+struct Debouncer {
+    delay: Duration,
+    shared: Arc<DebounceShared>,
+    scheduler: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Debouncer {
+    fn record(&self, old_value: &Value, new_value: &Value) {
+        let Ok(mut state) = self.shared.state.lock() else {
+            log::warn!(
+                "Failed to debounce change of {} due to poisoned lock",
+                self.shared.key
+            );
+            return;
+        };
+        let deadline = Instant::now() + self.delay;
+        match &mut state.pending {
+            Some(pending) => {
+                pending.new_value = new_value.clone();
+                pending.deadline = deadline;
+            }
+            None => {
+                state.pending = Some(DebouncedChange {
+                    old_value: old_value.clone(),
+                    new_value: new_value.clone(),
+                    deadline,
+                });
+            }
+        }
+        drop(state);
+        self.shared.changed.notify_all();
+
+        // Started on the first change; restarted if a panicking callback
+        // took the previous one down
+        if let Ok(mut scheduler) = self.scheduler.lock()
+            && scheduler.as_ref().is_none_or(JoinHandle::is_finished)
+        {
+            let shared = Arc::clone(&self.shared);
+            *scheduler = Some(thread::spawn(move || Self::run_scheduler(&shared)));
+        }
+    }
+
+    /// Deliver each pending change once it is quiet, until shut down
+    fn run_scheduler(shared: &DebounceShared) {
+        let Ok(mut state) = shared.state.lock() else {
+            return;
+        };
+        loop {
+            if state.shutdown {
+                return;
+            }
+            let now = Instant::now();
+            let wait = match &state.pending {
+                None => None,
+                Some(pending) if pending.deadline > now => Some(pending.deadline - now),
+                Some(_) => {
+                    let change = state.pending.take();
+                    drop(state);
+                    if let Some(change) = change
+                        && change.old_value != change.new_value
+                    {
+                        (shared.callback)(&shared.key, &change.old_value, &change.new_value);
+                    }
+                    let Ok(relocked) = shared.state.lock() else {
+                        return;
+                    };
+                    state = relocked;
+                    continue;
+                }
+            };
+            let waited = match wait {
+                Some(wait) => shared
+                    .changed
+                    .wait_timeout(state, wait)
+                    .ok()
+                    .map(|(state, _)| state),
+                None => shared.changed.wait(state).ok(),
+            };
+            let Some(relocked) = waited else {
+                return;
+            };
+            state = relocked;
+        }
+    }
+}
+
+impl Drop for Debouncer {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.shared.state.lock() {
+            state.shutdown = true;
+        }
+        self.shared.changed.notify_all();
+        let scheduler = self
+            .scheduler
+            .get_mut()
+            .map_or_else(|poisoned| poisoned.into_inner().take(), Option::take);
+        // A callback removing its own listener drops the debouncer on the
+        // scheduler thread, which then exits by itself
+        if let Some(scheduler) = scheduler
+            && scheduler.thread().id() != thread::current().id()
+        {
+            let _ = scheduler.join();
+        }
+    }
+}
+
+/// Upper bound on changes made by listeners while one notification is being
+/// dispatched; beyond it further nested changes are not delivered, which stops
+/// listeners that keep changing each other's settings from looping forever
+const MAX_NESTED_NOTIFICATIONS: usize = 1024;
+
+/// A change raised by a listener, waiting for the outer dispatch to finish
+type DeferredChange = (String, Value, Value);
+
+thread_local! {
+    /// Event managers dispatching on this thread (by address), with the
+    /// changes their listeners raised in the meantime
+    static DISPATCHING: RefCell<Vec<(usize, VecDeque<DeferredChange>)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Marks an event manager as dispatching on this thread until dropped, so a
+/// panicking listener cannot leave it marked
+struct DispatchGuard(usize);
+
+impl Drop for DispatchGuard {
+    fn drop(&mut self) {
+        DISPATCHING.with(|dispatching| {
+            dispatching.borrow_mut().retain(|(id, _)| *id != self.0);
+        });
+    }
+}
+
 /// Manages event listeners for settings changes
+///
+/// # Ordering and re-entrancy
+///
+/// Listeners are called in the order global, key-specific, category, then
+/// pattern, each group in registration order. Listeners may save settings or register other
+/// listeners. A change made from inside a listener is not delivered
+/// recursively: it is queued and delivered, in the order it happened, once
+/// every listener has seen the change being dispatched. At most 1024 such
+/// nested changes are delivered per outer notification; the rest are dropped
+/// with a warning.
+///
+/// Every registration returns a [`ListenerHandle`]; pass it to
+/// [`remove_listener`](Self::remove_listener) once the callback is no longer
+/// wanted.
 pub struct EventManager {
+    /// Source of listener ids
+    next_id: AtomicU64,
+
     /// Global listeners (called for all changes)
-    global_listeners: RwLock<Vec<ChangeCallback>>,
+    global_listeners: RwLock<Registry<ChangeCallback>>,
 
     /// Per-key listeners (called only for specific setting changes)
-    key_listeners: RwLock<HashMap<String, Vec<ChangeCallback>>>,
+    key_listeners: RwLock<HashMap<String, Registry<ChangeCallback>>>,
+
+    /// Per-category listeners (called for every setting in the category)
+    category_listeners: RwLock<HashMap<String, Registry<ChangeCallback>>>,
+
+    /// Key pattern listeners (see `watch_pattern`)
+    pattern_listeners: RwLock<HashMap<String, Registry<ChangeCallback>>>,
 
     /// Validators per key
-    validators: RwLock<HashMap<String, Vec<Validator>>>,
+    validators: RwLock<HashMap<String, Registry<Validator>>>,
+
+    /// Async validators per key (run by `save_setting_async`)
+    async_validators: RwLock<HashMap<String, Registry<AsyncValidator>>>,
+
+    /// Cross-field validators with the keys they cover
+    cross_validators: RwLock<Registry<(Vec<String>, CrossValidator)>>,
+
+    /// Sub-settings change listeners (every registered type)
+    sub_settings_listeners: RwLock<Registry<SubSettingsCallback>>,
+
+    /// Profile switch listeners (main settings and every sub-settings type)
+    #[cfg(feature = "profiles")]
+    profile_switch_listeners: RwLock<Registry<ProfileSwitchCallback>>,
 }
 
 impl EventManager {
@@ -31,25 +260,39 @@ impl EventManager {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            global_listeners: RwLock::new(Vec::new()),
+            next_id: AtomicU64::new(0),
+            global_listeners: RwLock::new(BTreeMap::new()),
             key_listeners: RwLock::new(HashMap::new()),
+            category_listeners: RwLock::new(HashMap::new()),
+            pattern_listeners: RwLock::new(HashMap::new()),
             validators: RwLock::new(HashMap::new()),
+            async_validators: RwLock::new(HashMap::new()),
+            cross_validators: RwLock::new(BTreeMap::new()),
+            sub_settings_listeners: RwLock::new(BTreeMap::new()),
+            #[cfg(feature = "profiles")]
+            profile_switch_listeners: RwLock::new(BTreeMap::new()),
         }
     }
 
+    fn next_handle(&self) -> ListenerHandle {
+        ListenerHandle(self.next_id.fetch_add(1, Ordering::Relaxed))
+    }
+
     /// Register a global change listener (called for all settings changes)
     ///
     /// # Arguments
     /// * `callback` - Function receiving (`full_key`, `old_value`, `new_value`)
-    pub fn on_change<F>(&self, callback: F)
+    pub fn on_change<F>(&self, callback: F) -> ListenerHandle
     where
         F: Fn(&str, &Value, &Value) + Send + Sync + 'static,
     {
+        let handle = self.next_handle();
         if let Ok(mut guard) = self.global_listeners.write_recovered() {
-            guard.push(Arc::new(callback));
+            guard.insert(handle.0, Arc::new(callback));
         } else {
             log::warn!("Failed to register global change listener due to lock recovery error");
         }
+        handle
     }
 
     /// Register a listener for a specific setting key
@@ -57,20 +300,115 @@ impl EventManager {
     /// # Arguments
     /// * `key` - The setting key (e.g., "`general.dark_mode`")
     /// * `callback` - Function receiving (`full_key`, `old_value`, `new_value`)
-    pub fn watch<F>(&self, key: &str, callback: F)
+    pub fn watch<F>(&self, key: &str, callback: F) -> ListenerHandle
     where
         F: Fn(&str, &Value, &Value) + Send + Sync + 'static,
     {
+        let handle = self.next_handle();
         if let Ok(mut listeners) = self.key_listeners.write_recovered() {
             listeners
                 .entry(key.to_string())
                 .or_default()
-                .push(Arc::new(callback));
+                .insert(handle.0, Arc::new(callback));
         } else {
             log::warn!(
                 "Failed to register key-specific listener for {key} due to lock recovery error"
             );
         }
+        handle
+    }
+
+    /// Register a listener for a setting key that fires once changes settle
+    ///
+    /// Rapid changes are coalesced: the callback runs on a background thread
+    /// once `key` has not changed for `delay`, with the value from before the
+    /// first change and the latest value. It is skipped if the value ended up
+    /// unchanged. Use it for expensive reactions, such as relayout while a
+    /// slider is dragged.
+    ///
+    /// A change still waiting when the listener is removed or the event
+    /// manager (with its `SettingsManager`) is dropped is discarded, and the
+    /// listener's background thread is stopped.
+    ///
+    /// # Arguments
+    /// * `key` - The setting key (e.g., "`ui.font_size`")
+    /// * `delay` - How long the key must stay unchanged
+    /// * `callback` - Function receiving (`full_key`, `old_value`, `new_value`)
+    pub fn watch_debounced<F>(&self, key: &str, delay: Duration, callback: F) -> ListenerHandle
+    where
+        F: Fn(&str, &Value, &Value) + Send + Sync + 'static,
+    {
+        let debouncer = Debouncer {
+            delay,
+            shared: Arc::new(DebounceShared {
+                key: key.to_string(),
+                callback: Arc::new(callback),
+                state: Mutex::new(DebounceState::default()),
+                changed: Condvar::new(),
+            }),
+            scheduler: Mutex::new(None),
+        };
+        self.watch(key, move |_, old_value, new_value| {
+            debouncer.record(old_value, new_value);
+        })
+    }
+
+    /// Register a listener for every setting in a category
+    ///
+    /// # Arguments
+    /// * `category` - The category name (e.g., "`general`")
+    /// * `callback` - Function receiving (`full_key`, `old_value`, `new_value`)
+    pub fn watch_category<F>(&self, category: &str, callback: F) -> ListenerHandle
+    where
+        F: Fn(&str, &Value, &Value) + Send + Sync + 'static,
+    {
+        let handle = self.next_handle();
+        if let Ok(mut listeners) = self.category_listeners.write_recovered() {
+            listeners
+                .entry(category.to_string())
+                .or_default()
+                .insert(handle.0, Arc::new(callback));
+        } else {
+            log::warn!(
+                "Failed to register category listener for {category} due to lock recovery error"
+            );
+        }
+        handle
+    }
+
+    /// Register a listener for every setting key matching `pattern`
+    ///
+    /// A trailing `*` matches any suffix, so `"network.*"` covers every key
+    /// of the `network` category and `"net*"` every key starting with `net`;
+    /// `"*"` alone matches all keys. A pattern without `*` matches that exact
+    /// key.
+    ///
+    /// # Arguments
+    /// * `pattern` - The key pattern (e.g., "`network.*`")
+    /// * `callback` - Function receiving (`full_key`, `old_value`, `new_value`)
+    pub fn watch_pattern<F>(&self, pattern: &str, callback: F) -> ListenerHandle
+    where
+        F: Fn(&str, &Value, &Value) + Send + Sync + 'static,
+    {
+        let handle = self.next_handle();
+        if let Ok(mut listeners) = self.pattern_listeners.write_recovered() {
+            listeners
+                .entry(pattern.to_string())
+                .or_default()
+                .insert(handle.0, Arc::new(callback));
+        } else {
+            log::warn!(
+                "Failed to register pattern listener for {pattern} due to lock recovery error"
+            );
+        }
+        handle
+    }
+
+    fn pattern_matches(pattern: &str, key: &str) -> bool {
+        match pattern.strip_suffix('*') {
+            Some(prefix) => key.starts_with(prefix),
+            None => pattern == key,
+        }
     }
 
     /// Register a validator for a specific setting key
@@ -81,18 +419,173 @@ impl EventManager {
     /// # Arguments
     /// * `key` - The setting key (e.g., "`general.dark_mode`")
     /// * `validator` - Function receiving the candidate value
-    pub fn add_validator<F>(&self, key: &str, validator: F)
+    pub fn add_validator<F>(&self, key: &str, validator: F) -> ListenerHandle
     where
         F: Fn(&Value) -> Result<(), String> + Send + Sync + 'static,
     {
+        let handle = self.next_handle();
         if let Ok(mut validators) = self.validators.write_recovered() {
             validators
                 .entry(key.to_string())
                 .or_default()
-                .push(Arc::new(validator));
+                .insert(handle.0, Arc::new(validator));
         } else {
             log::warn!("Failed to register validator for {key} due to lock recovery error");
         }
+        handle
+    }
+
+    /// Register a validator for settings that are only valid relative to each other
+    ///
+    /// Runs whenever one of `keys` is saved, after the per-key validators
+    /// and schema checks of the saved values have passed. `validator`
+    /// receives the effective value of every key in `keys`, with the
+    /// pending change (or the whole batch of `save_settings_batch`) applied.
+    /// If it returns an error, the save is rejected with
+    /// [`Error::InvalidSettingValue`](crate::Error::InvalidSettingValue).
+    ///
+    /// # Example
+    /// ```
+    /// # use rcman::EventManager;
+    /// # let events = EventManager::new();
+    /// events.add_cross_validator(&["network.min_port", "network.max_port"], |values| {
+    ///     let min = values["network.min_port"].as_f64().unwrap_or_default();
+    ///     let max = values["network.max_port"].as_f64().unwrap_or_default();
+    ///     if min > max {
+    ///         return Err("min_port must not exceed max_port".into());
+    ///     }
+    ///     Ok(())
+    /// });
+    /// ```
+    pub fn add_cross_validator<F>(&self, keys: &[&str], validator: F) -> ListenerHandle
+    where
+        F: Fn(&HashMap<String, Value>) -> Result<(), String> + Send + Sync + 'static,
+    {
+        let handle = self.next_handle();
+        let keys = keys.iter().map(ToString::to_string).collect();
+        if let Ok(mut validators) = self.cross_validators.write_recovered() {
+            validators.insert(handle.0, (keys, Arc::new(validator)));
+        } else {
+            log::warn!("Failed to register cross-field validator due to lock recovery error");
+        }
+        handle
+    }
+
+    /// Run the cross-field validators covering any key of `pending`
+    ///
+    /// `current` supplies the effective value of keys not in `pending`. On
+    /// failure, returns the first pending key covered by the failing
+    /// validator and its message.
+    pub(crate) fn validate_cross<F>(
+        &self,
+        pending: &HashMap<&str, &Value>,
+        current: F,
+    ) -> Result<(), (String, String)>
+    where
+        F: Fn(&str) -> Option<Value>,
+    {
+        let validators: Vec<(Vec<String>, CrossValidator)> =
+            match self.cross_validators.read_recovered() {
+                Ok(guard) => guard
+                    .values()
+                    .filter(|(keys, _)| keys.iter().any(|key| pending.contains_key(key.as_str())))
+                    .cloned()
+                    .collect(),
+                Err(err) => {
+                    log::warn!(
+                        "Failed to run cross-field validators due to lock recovery error: {err}"
+                    );
+                    let key = pending
+                        .keys()
+                        .min()
+                        .map(ToString::to_string)
+                        .unwrap_or_default();
+                    return Err((key, "Internal lock error".to_string()));
+                }
+            };
+
+        for (keys, validator) in validators {
+            let values: HashMap<String, Value> = keys
+                .iter()
+                .map(|key| {
+                    let value = match pending.get(key.as_str()) {
+                        Some(value) => (*value).clone(),
+                        None => current(key).unwrap_or(Value::Null),
+                    };
+                    (key.clone(), value)
+                })
+                .collect();
+            if let Err(reason) = validator(&values) {
+                let key = keys
+                    .iter()
+                    .filter(|key| pending.contains_key(key.as_str()))
+                    .min()
+                    .cloned()
+                    .unwrap_or_default();
+                return Err((key, reason));
+            }
+        }
+        Ok(())
+    }
+
+    /// Register an async validator for a specific setting key
+    ///
+    /// Async validators are for checks that need I/O, such as testing that a
+    /// server is reachable. They only run in
+    /// [`SettingsManager::save_setting_async`](crate::SettingsManager::save_setting_async);
+    /// a synchronous `save_setting` for the key fails with
+    /// [`Error::AsyncValidationRequired`](crate::Error::AsyncValidationRequired)
+    /// instead of skipping the check.
+    ///
+    /// # Arguments
+    /// * `key` - The setting key (e.g., "`remote.endpoint`")
+    /// * `validator` - Function receiving the candidate value and returning a future
+    pub fn add_async_validator<F>(&self, key: &str, validator: F) -> ListenerHandle
+    where
+        F: Fn(Value) -> BoxFuture<Result<(), String>> + Send + Sync + 'static,
+    {
+        let handle = self.next_handle();
+        if let Ok(mut validators) = self.async_validators.write_recovered() {
+            validators
+                .entry(key.to_string())
+                .or_default()
+                .insert(handle.0, Arc::new(validator));
+        } else {
+            log::warn!("Failed to register async validator for {key} due to lock recovery error");
+        }
+        handle
+    }
+
+    /// Check whether any async validator is registered for a key
+    #[must_use]
+    pub fn has_async_validators(&self, key: &str) -> bool {
+        self.async_validators
+            .read_recovered()
+            .is_ok_and(|validators| validators.get(key).is_some_and(|list| !list.is_empty()))
+    }
+
+    /// Run the async validators of a key in registration order
+    ///
+    /// # Errors
+    ///
+    /// Returns the first validation error message if any validator fails.
+    pub async fn validate_async(&self, key: &str, value: &Value) -> Result<(), String> {
+        // Clone the validators so no lock is held across an await point
+        let validators: Vec<AsyncValidator> = self
+            .async_validators
+            .read_recovered()
+            .map_err(|err| {
+                log::warn!("Failed to validate {key} due to lock recovery error: {err}");
+                "Internal lock error".to_string()
+            })?
+            .get(key)
+            .map(|validators| validators.values().cloned().collect())
+            .unwrap_or_default();
+
+        for validator in validators {
+            validator(value.clone()).await?;
+        }
+        Ok(())
     }
 
     /// Validate a value before saving
@@ -108,7 +601,7 @@ impl EventManager {
             "Internal lock error".to_string()
         })?;
         if let Some(validators) = guard.get(key) {
-            for validator in validators {
+            for validator in validators.values() {
                 validator(value)?;
             }
         }
@@ -122,27 +615,242 @@ impl EventManager {
     /// * `old_value` - The old value
     /// * `new_value` - The new value
     pub fn notify(&self, key: &str, old_value: &Value, new_value: &Value) {
-        // Call global listeners
-        if let Ok(guard) = self.global_listeners.read_recovered() {
-            for callback in guard.iter() {
-                callback(key, old_value, new_value);
+        let id = std::ptr::from_ref(self) as usize;
+
+        // Called from one of our own listeners: queue for the outer dispatch
+        let deferred = DISPATCHING.with(|dispatching| {
+            let mut dispatching = dispatching.borrow_mut();
+            let Some((_, queue)) = dispatching.iter_mut().find(|(other, _)| *other == id) else {
+                return false;
+            };
+            queue.push_back((key.to_string(), old_value.clone(), new_value.clone()));
+            true
+        });
+        if deferred {
+            return;
+        }
+
+        DISPATCHING.with(|dispatching| dispatching.borrow_mut().push((id, VecDeque::new())));
+        let _guard = DispatchGuard(id);
+
+        self.dispatch(key, old_value, new_value);
+
+        let mut delivered = 0;
+        while let Some((key, old_value, new_value)) = Self::next_deferred(id) {
+            if delivered == MAX_NESTED_NOTIFICATIONS {
+                log::warn!(
+                    "Dropping change notifications raised by listeners after {MAX_NESTED_NOTIFICATIONS} nested changes (next: {key})"
+                );
+                break;
             }
+            delivered += 1;
+            self.dispatch(&key, &old_value, &new_value);
+        }
+    }
+
+    fn next_deferred(id: usize) -> Option<DeferredChange> {
+        DISPATCHING.with(|dispatching| {
+            dispatching
+                .borrow_mut()
+                .iter_mut()
+                .find(|(other, _)| *other == id)
+                .and_then(|(_, queue)| queue.pop_front())
+        })
+    }
+
+    /// Call every listener interested in `key`
+    ///
+    /// Callbacks are copied out first so no lock is held while they run.
+    fn dispatch(&self, key: &str, old_value: &Value, new_value: &Value) {
+        let mut callbacks: Vec<ChangeCallback> = Vec::new();
+
+        if let Ok(guard) = self.global_listeners.read_recovered() {
+            callbacks.extend(guard.values().cloned());
         } else {
             log::warn!("Failed to read global listeners for {key} due to lock recovery error");
         }
 
-        // Call key-specific listeners
         if let Ok(guard) = self.key_listeners.read_recovered() {
             if let Some(listeners) = guard.get(key) {
-                for callback in listeners {
-                    callback(key, old_value, new_value);
-                }
+                callbacks.extend(listeners.values().cloned());
             }
         } else {
             log::warn!(
                 "Failed to read key-specific listeners for {key} due to lock recovery error"
             );
         }
+
+        if let Some((category, _)) = key.split_once('.') {
+            if let Ok(guard) = self.category_listeners.read_recovered() {
+                if let Some(listeners) = guard.get(category) {
+                    callbacks.extend(listeners.values().cloned());
+                }
+            } else {
+                log::warn!(
+                    "Failed to read category listeners for {category} due to lock recovery error"
+                );
+            }
+        }
+
+        if let Ok(guard) = self.pattern_listeners.read_recovered() {
+            let mut matching: Vec<(&u64, &ChangeCallback)> = guard
+                .iter()
+                .filter(|(pattern, _)| Self::pattern_matches(pattern, key))
+                .flat_map(|(_, listeners)| listeners.iter())
+                .collect();
+            // Registration order across patterns
+            matching.sort_by_key(|(id, _)| **id);
+            callbacks.extend(matching.into_iter().map(|(_, callback)| callback.clone()));
+        } else {
+            log::warn!("Failed to read pattern listeners for {key} due to lock recovery error");
+        }
+
+        for callback in callbacks {
+            callback(key, old_value, new_value);
+        }
+    }
+
+    /// Register a listener for entry changes of every sub-settings type
+    ///
+    /// Covers types registered with the `SettingsManager` before or after
+    /// this call. Unlike [`SubSettings::set_on_change`](crate::SubSettings::set_on_change),
+    /// any number of these listeners can be registered.
+    ///
+    /// # Arguments
+    /// * `callback` - Function receiving (`settings_type`, `entry`, `action`)
+    pub fn on_sub_settings_change<F>(&self, callback: F) -> ListenerHandle
+    where
+        F: Fn(&str, &str, SubSettingsAction) + Send + Sync + 'static,
+    {
+        let handle = self.next_handle();
+        if let Ok(mut guard) = self.sub_settings_listeners.write_recovered() {
+            guard.insert(handle.0, Arc::new(callback));
+        } else {
+            log::warn!("Failed to register sub-settings listener due to lock recovery error");
+        }
+        handle
+    }
+
+    /// Notify all sub-settings change listeners
+    ///
+    /// # Arguments
+    /// * `settings_type` - The sub-settings name
+    /// * `entry` - The entry that changed
+    /// * `action` - What happened to the entry
+    pub fn notify_sub_settings_change(
+        &self,
+        settings_type: &str,
+        entry: &str,
+        action: SubSettingsAction,
+    ) {
+        let callbacks: Vec<SubSettingsCallback> = match self.sub_settings_listeners.read_recovered()
+        {
+            Ok(guard) => guard.values().cloned().collect(),
+            Err(_) => {
+                log::warn!(
+                    "Failed to read sub-settings listeners for {settings_type} due to lock recovery error"
+                );
+                return;
+            }
+        };
+        for callback in callbacks {
+            callback(settings_type, entry, action);
+        }
+    }
+
+    /// Register a profile switch listener
+    ///
+    /// # Arguments
+    /// * `callback` - Function receiving (`target`, `from_profile`, `to_profile`), where
+    ///   `target` is `"settings"` for main settings or the sub-settings name
+    #[cfg(feature = "profiles")]
+    pub fn on_profile_switch<F>(&self, callback: F) -> ListenerHandle
+    where
+        F: Fn(&str, &str, &str) + Send + Sync + 'static,
+    {
+        let handle = self.next_handle();
+        if let Ok(mut guard) = self.profile_switch_listeners.write_recovered() {
+            guard.insert(handle.0, Arc::new(callback));
+        } else {
+            log::warn!("Failed to register profile switch listener due to lock recovery error");
+        }
+        handle
+    }
+
+    /// Notify all profile switch listeners
+    ///
+    /// # Arguments
+    /// * `target` - `"settings"` or the sub-settings name
+    /// * `from` - The previously active profile
+    /// * `to` - The newly active profile
+    #[cfg(feature = "profiles")]
+    pub fn notify_profile_switch(&self, target: &str, from: &str, to: &str) {
+        // Copy the callbacks so listeners can unregister themselves
+        let callbacks: Vec<ProfileSwitchCallback> = match self
+            .profile_switch_listeners
+            .read_recovered()
+        {
+            Ok(guard) => guard.values().cloned().collect(),
+            Err(_) => {
+                log::warn!(
+                    "Failed to read profile switch listeners for {target} due to lock recovery error"
+                );
+                return;
+            }
+        };
+        for callback in callbacks {
+            callback(target, from, to);
+        }
+    }
+
+    /// Unregister the listener or validator identified by `handle`
+    ///
+    /// Returns `false` if it was already removed (including by
+    /// [`unwatch`](Self::unwatch) or [`clear`](Self::clear)).
+    pub fn remove_listener(&self, handle: ListenerHandle) -> bool {
+        let id = handle.0;
+        let removed = Self::remove_from(&self.global_listeners, id)
+            || Self::remove_keyed(&self.key_listeners, id)
+            || Self::remove_keyed(&self.category_listeners, id)
+            || Self::remove_keyed(&self.pattern_listeners, id)
+            || Self::remove_keyed(&self.validators, id)
+            || Self::remove_keyed(&self.async_validators, id)
+            || Self::remove_from(&self.cross_validators, id)
+            || Self::remove_from(&self.sub_settings_listeners, id);
+        #[cfg(feature = "profiles")]
+        let removed = removed || Self::remove_from(&self.profile_switch_listeners, id);
+        removed
+    }
+
+    fn remove_from<T>(registry: &RwLock<Registry<T>>, id: u64) -> bool {
+        match registry.write_recovered() {
+            Ok(mut guard) => guard.remove(&id).is_some(),
+            Err(err) => {
+                log::warn!("Failed to remove listener due to lock recovery error: {err}");
+                false
+            }
+        }
+    }
+
+    fn remove_keyed<T>(registries: &RwLock<HashMap<String, Registry<T>>>, id: u64) -> bool {
+        let Ok(mut guard) = registries.write_recovered() else {
+            log::warn!("Failed to remove listener due to lock recovery error");
+            return false;
+        };
+        let Some(key) = guard
+            .iter()
+            .find(|(_, registry)| registry.contains_key(&id))
+            .map(|(key, _)| key.clone())
+        else {
+            return false;
+        };
+        if let Some(registry) = guard.get_mut(&key) {
+            registry.remove(&id);
+            if registry.is_empty() {
+                guard.remove(&key);
+            }
+        }
+        true
     }
 
     /// Remove all listeners for a specific key
@@ -154,6 +862,24 @@ impl EventManager {
         }
     }
 
+    /// Remove all listeners for a category
+    pub fn unwatch_category(&self, category: &str) {
+        if let Ok(mut guard) = self.category_listeners.write_recovered() {
+            guard.remove(category);
+        } else {
+            log::warn!("Failed to remove listeners for {category} due to lock recovery error");
+        }
+    }
+
+    /// Remove all listeners registered with `pattern`
+    pub fn unwatch_pattern(&self, pattern: &str) {
+        if let Ok(mut guard) = self.pattern_listeners.write_recovered() {
+            guard.remove(pattern);
+        } else {
+            log::warn!("Failed to remove listeners for {pattern} due to lock recovery error");
+        }
+    }
+
     /// Clear all listeners
     pub fn clear(&self) {
         if let Ok(mut guard) = self.global_listeners.write_recovered() {
@@ -166,6 +892,27 @@ impl EventManager {
         } else {
             log::warn!("Failed to clear key-specific listeners due to lock recovery error");
         }
+        if let Ok(mut guard) = self.category_listeners.write_recovered() {
+            guard.clear();
+        } else {
+            log::warn!("Failed to clear category listeners due to lock recovery error");
+        }
+        if let Ok(mut guard) = self.pattern_listeners.write_recovered() {
+            guard.clear();
+        } else {
+            log::warn!("Failed to clear pattern listeners due to lock recovery error");
+        }
+        if let Ok(mut guard) = self.sub_settings_listeners.write_recovered() {
+            guard.clear();
+        } else {
+            log::warn!("Failed to clear sub-settings listeners due to lock recovery error");
+        }
+        #[cfg(feature = "profiles")]
+        if let Ok(mut guard) = self.profile_switch_listeners.write_recovered() {
+            guard.clear();
+        } else {
+            log::warn!("Failed to clear profile switch listeners due to lock recovery error");
+        }
     }
 }
 
@@ -201,6 +948,56 @@ mod tests {
         assert_eq!(counter.load(Ordering::SeqCst), 1);
     }
 
+    #[test]
+    fn test_category_listener() {
+        let events = EventManager::new();
+        let keys = Arc::new(RwLock::new(Vec::new()));
+        let keys_clone = keys.clone();
+
+        events.watch_category("ui", move |key, _old, _new| {
+            keys_clone.write().unwrap().push(key.to_string());
+        });
+
+        events.notify("ui.theme", &json!("light"), &json!("dark"));
+        events.notify("general.language", &json!("en"), &json!("de"));
+        events.notify("uix.other", &json!(1), &json!(2));
+
+        assert_eq!(*keys.read().unwrap(), vec!["ui.theme".to_string()]);
+
+        events.unwatch_category("ui");
+        events.notify("ui.theme", &json!("dark"), &json!("light"));
+        assert_eq!(keys.read().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_pattern_listener() {
+        let events = EventManager::new();
+        let keys = Arc::new(RwLock::new(Vec::new()));
+
+        for pattern in ["network.*", "*", "network.port"] {
+            let keys_clone = keys.clone();
+            events.watch_pattern(pattern, move |key, _old, _new| {
+                keys_clone.write().unwrap().push(format!("{pattern} {key}"));
+            });
+        }
+
+        events.notify("network.port", &json!(80), &json!(8080));
+        events.notify("networking.mode", &json!("a"), &json!("b"));
+        assert_eq!(
+            *keys.read().unwrap(),
+            vec![
+                "network.* network.port",
+                "* network.port",
+                "network.port network.port",
+                "* networking.mode",
+            ]
+        );
+
+        events.unwatch_pattern("*");
+        events.notify("ui.theme", &json!("dark"), &json!("light"));
+        assert_eq!(keys.read().unwrap().len(), 4);
+    }
+
     #[test]
     fn test_key_specific_listener() {
         let events = EventManager::new();
@@ -220,6 +1017,61 @@ mod tests {
         assert_eq!(counter.load(Ordering::SeqCst), 1);
     }
 
+    #[test]
+    fn test_nested_notifications_are_deferred_in_order() {
+        let events = Arc::new(EventManager::new());
+        let seen = Arc::new(RwLock::new(Vec::new()));
+
+        let seen_clone = seen.clone();
+        events.on_change(move |key, _old, _new| {
+            seen_clone.write().unwrap().push(format!("global:{key}"));
+        });
+        let events_clone = events.clone();
+        events.watch("ui.theme", move |_key, _old, _new| {
+            events_clone.notify("ui.accent", &json!("blue"), &json!("red"));
+            events_clone.notify("ui.font", &json!(12), &json!(14));
+        });
+        let seen_clone = seen.clone();
+        events.watch_category("ui", move |key, _old, _new| {
+            seen_clone.write().unwrap().push(format!("category:{key}"));
+        });
+
+        events.notify("ui.theme", &json!("light"), &json!("dark"));
+
+        assert_eq!(
+            *seen.read().unwrap(),
+            vec![
+                "global:ui.theme",
+                "category:ui.theme",
+                "global:ui.accent",
+                "category:ui.accent",
+                "global:ui.font",
+                "category:ui.font",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested_notification_loop_is_bounded() {
+        let events = Arc::new(EventManager::new());
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        let events_clone = events.clone();
+        let counter_clone = counter.clone();
+        events.on_change(move |key, old, new| {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+            events_clone.notify(key, new, old);
+        });
+
+        events.notify("ui.theme", &json!("light"), &json!("dark"));
+
+        assert_eq!(counter.load(Ordering::SeqCst), MAX_NESTED_NOTIFICATIONS + 1);
+
+        // The dispatch state is released afterwards
+        events.clear();
+        events.notify("ui.theme", &json!("dark"), &json!("light"));
+    }
+
     #[test]
     fn test_validator() {
         let events = EventManager::new();
@@ -251,6 +1103,104 @@ mod tests {
         assert!(events.validate("other", &json!("anything")).is_ok());
     }
 
+    #[test]
+    fn test_debounced_listener_coalesces_changes() {
+        let events = EventManager::new();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let calls_clone = calls.clone();
+        events.watch_debounced(
+            "ui.font_size",
+            Duration::from_millis(50),
+            move |key, old, new| {
+                calls_clone
+                    .lock()
+                    .unwrap()
+                    .push((key.to_string(), old.clone(), new.clone()));
+            },
+        );
+
+        for size in 15..20 {
+            events.notify("ui.font_size", &json!(size - 1), &json!(size));
+        }
+        assert!(calls.lock().unwrap().is_empty());
+        thread::sleep(Duration::from_millis(300));
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![("ui.font_size".to_string(), json!(14), json!(19))]
+        );
+
+        // Changed and changed back: nothing to report
+        events.notify("ui.font_size", &json!(19), &json!(20));
+        events.notify("ui.font_size", &json!(20), &json!(19));
+        thread::sleep(Duration::from_millis(300));
+        assert_eq!(calls.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_debounced_change_is_dropped_with_manager() {
+        let events = EventManager::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        events.watch_debounced("ui.theme", Duration::from_millis(50), move |_, _, _| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        events.notify("ui.theme", &json!("dark"), &json!("light"));
+        drop(events);
+        thread::sleep(Duration::from_millis(200));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_debounced_listener_can_remove_itself() {
+        let events = Arc::new(EventManager::new());
+        let handle = Arc::new(Mutex::new(None));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let (events_clone, handle_clone, calls_clone) =
+            (Arc::clone(&events), Arc::clone(&handle), Arc::clone(&calls));
+        let registered =
+            events.watch_debounced("ui.theme", Duration::from_millis(20), move |_, _, _| {
+                calls_clone.fetch_add(1, Ordering::SeqCst);
+                if let Some(handle) = handle_clone.lock().unwrap().take() {
+                    events_clone.remove_listener(handle);
+                }
+            });
+        *handle.lock().unwrap() = Some(registered);
+
+        events.notify("ui.theme", &json!("dark"), &json!("light"));
+        thread::sleep(Duration::from_millis(200));
+        events.notify("ui.theme", &json!("light"), &json!("dark"));
+        thread::sleep(Duration::from_millis(200));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_remove_listener_by_handle() {
+        let events = EventManager::new();
+        let first = Arc::new(AtomicUsize::new(0));
+        let second = Arc::new(AtomicUsize::new(0));
+
+        let first_clone = first.clone();
+        let handle = events.on_change(move |_, _, _| {
+            first_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        let second_clone = second.clone();
+        events.on_change(move |_, _, _| {
+            second_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert!(events.remove_listener(handle));
+        assert!(!events.remove_listener(handle));
+        events.notify("ui.theme", &json!("dark"), &json!("light"));
+        assert_eq!(first.load(Ordering::SeqCst), 0);
+        assert_eq!(second.load(Ordering::SeqCst), 1);
+
+        let validator = events.add_validator("network.port", |_| Err("rejected".into()));
+        assert!(events.validate("network.port", &json!(80)).is_err());
+        assert!(events.remove_listener(validator));
+        assert!(events.validate("network.port", &json!(80)).is_ok());
+    }
+
     #[test]
     fn test_handles_poisoned_locks_without_panicking() {
         let events = EventManager::new();
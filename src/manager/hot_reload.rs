@@ -11,6 +11,10 @@ use std::thread;
 use std::time::{Duration, Instant};
 
 /// Event emitted by the hot-reload runtime.
+///
+/// Before `Reloaded` is emitted, change listeners registered with
+/// [`EventManager`](crate::EventManager) are notified of every setting whose
+/// effective value differs between the old cache and the reloaded file.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HotReloadEvent {
     /// Reload completed and cache has been refreshed.
@@ -58,6 +62,11 @@ impl HotReloadRuntime {
         F: Fn(HotReloadEvent) + Send + Sync + 'static,
     {
         let watched_file = manager.settings_path()?;
+
+        // Load the current file so the first reload has values to diff against
+        if let Err(err) = manager.ensure_cache_populated() {
+            log::warn!("Hot reload started without loaded settings: {err}");
+        }
         let watch_target = watched_file
             .parent()
             .unwrap_or_else(|| Path::new("."))
@@ -179,10 +188,13 @@ fn run_reload_loop<S, Schema>(
             && Instant::now() >= suppress_until
             && Instant::now().duration_since(last_change) >= debounce_window
         {
-            manager.invalidate_cache();
+            let before_values = manager.capture_effective_values();
+            manager.reload_external_change();
 
             match manager.ensure_cache_populated() {
                 Ok(()) => {
+                    let after_values = manager.capture_effective_values();
+                    manager.notify_value_changes(&before_values, &after_values);
                     callback(HotReloadEvent::Reloaded {
                         path: watched_file.to_path_buf(),
                     });
@@ -1,14 +1,116 @@
-#[cfg(any(feature = "keychain", feature = "encrypted-file"))]
 use crate::config::SettingMetadata;
-use crate::config::SettingsSchema;
+use crate::config::{CorruptPolicy, SettingsSchema};
 use crate::error::{Error, Result};
 use crate::manager::cache::CachedSettings;
 use crate::manager::core::SettingsManager;
 use crate::storage::StorageBackend;
 use crate::utils::sync::RwLockExt;
 
-use log::debug;
+use log::{debug, warn};
 use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use time::{OffsetDateTime, macros::format_description};
+
+/// Outcome of [`SettingsManager::save_setting`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SaveOutcome {
+    /// Whether the stored value actually changed
+    pub changed: bool,
+    /// Whether the setting changed and is marked `requires_restart`
+    pub requires_restart: bool,
+}
+
+/// Options for [`SettingsManager::import_bundle`]
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    /// Overwrite sub-settings entries that already exist (default: `true`)
+    pub overwrite_existing: bool,
+    /// Skip sub-settings types that are not registered instead of failing
+    /// (default: `false`)
+    pub skip_unknown_types: bool,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            overwrite_existing: true,
+            skip_unknown_types: false,
+        }
+    }
+}
+
+impl ImportOptions {
+    /// Create options with the defaults
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether existing sub-settings entries are overwritten
+    #[must_use]
+    pub fn overwrite_existing(mut self, overwrite: bool) -> Self {
+        self.overwrite_existing = overwrite;
+        self
+    }
+
+    /// Set whether unregistered sub-settings types are skipped
+    #[must_use]
+    pub fn skip_unknown_types(mut self, skip: bool) -> Self {
+        self.skip_unknown_types = skip;
+        self
+    }
+}
+
+/// Outcome of [`SettingsManager::import_bundle`]
+///
+/// Each list is sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    /// Main settings that were applied (`category.key`)
+    pub settings: Vec<String>,
+    /// Sub-settings entries that were written (`type/name`)
+    pub sub_settings: Vec<String>,
+    /// Existing entries (`type/name`) and unknown types (`type`) that were left alone
+    pub skipped: Vec<String>,
+}
+
+/// Summary of what [`SettingsManager::reset_all_confirmed`] will destroy
+///
+/// Returned by [`SettingsManager::prepare_reset_all`]. The token also records
+/// the settings state it was prepared for, so it is rejected if anything
+/// changes before it is confirmed.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ResetToken {
+    /// Settings that differ from their defaults, not counting secrets
+    pub customized_settings: usize,
+    /// Secret settings holding a stored value
+    pub secrets: usize,
+    /// Sub-settings secrets in the credential store. They are not part of the
+    /// main settings, but the reset clears the whole credential store, so
+    /// they are lost too
+    pub sub_settings_secrets: usize,
+    /// Profile whose settings will be reset, if profiles are enabled
+    pub profile: Option<String>,
+    state: u64,
+}
+
+// The state fingerprint is left out; it only matters to `reset_all_confirmed`
+impl std::fmt::Debug for ResetToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResetToken")
+            .field("customized_settings", &self.customized_settings)
+            .field("secrets", &self.secrets)
+            .field("sub_settings_secrets", &self.sub_settings_secrets)
+            .field("profile", &self.profile)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Key for the reset token fingerprint, random per process so the
+/// fingerprint cannot be used to guess secret values offline
+static RESET_TOKEN_KEY: std::sync::LazyLock<std::hash::RandomState> =
+    std::sync::LazyLock::new(std::hash::RandomState::new);
 
 impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Schema> {
     /// Resolve the active profile name, or `None` if profiles are disabled.
@@ -39,7 +141,7 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         full_key: &str,
         value: &Value,
         metadata: &SettingMetadata,
-    ) -> Result<()> {
+    ) -> Result<bool> {
         let default_value = metadata.default.clone();
 
         let old_value = if self.credentials.is_some() {
@@ -57,7 +159,7 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
 
         if old_value == *value {
             debug!("Secret setting {full_key} unchanged, skipping save");
-            return Ok(());
+            return Ok(false);
         }
 
         if *value == default_value {
@@ -74,7 +176,7 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
                 self.events.notify(full_key, &old_value, value);
             }
 
-            return Ok(());
+            return Ok(true);
         }
 
         let value_str = match value {
@@ -96,7 +198,7 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
             self.events.notify(full_key, &old_value, value);
         }
 
-        Ok(())
+        Ok(true)
     }
 
     /// Get the current settings file path.
@@ -114,6 +216,30 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         creds.get_with_profile(key, profile.as_deref())
     }
 
+    /// Read every secret setting with one batch lookup, so loops over the
+    /// schema do not hit the credential store once per key.
+    ///
+    /// Returns `None` when credentials are disabled or the batch read fails;
+    /// callers then look secrets up one by one.
+    #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+    pub(crate) fn prefetch_secrets(&self) -> Option<HashMap<String, Option<String>>> {
+        let creds = self.credentials.as_ref()?;
+        let keys: Vec<&str> = self
+            .schema_metadata
+            .iter()
+            .filter(|(_, metadata)| metadata.is_secret())
+            .map(|(key, _)| key.as_str())
+            .collect();
+        if keys.is_empty() {
+            return None;
+        }
+        let profile = self.active_profile_name();
+        creds
+            .get_many_with_profile(&keys, profile.as_deref())
+            .map_err(|e| debug!("Batch secret lookup failed, reading secrets one by one: {e}"))
+            .ok()
+    }
+
     #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
     pub(crate) fn store_credential_with_profile(&self, key: &str, value: &str) -> Result<()> {
         let creds = self.require_credentials()?;
@@ -129,7 +255,7 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
     }
 
     #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
-    fn get_tracked_secrets(&self) -> Result<std::collections::HashSet<String>> {
+    pub(crate) fn get_tracked_secrets(&self) -> Result<std::collections::HashSet<String>> {
         let creds = self.require_credentials()?;
         let profile = self.active_profile_name();
         {
@@ -183,8 +309,17 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
 
     /// Invalidate the settings cache.
     ///
-    /// Call this if the settings file was modified externally.
+    /// Call this if the settings file was modified externally. Deferred
+    /// writes are flushed first so no saved change is lost.
     pub fn invalidate_cache(&self) {
+        if let Err(e) = self.flush() {
+            warn!("Failed to flush deferred settings before invalidating the cache: {e}");
+        }
+        self.clear_caches();
+    }
+
+    /// Drop every cached value without touching deferred writes
+    pub(crate) fn clear_caches(&self) {
         self.settings_cache.invalidate();
 
         #[cfg(feature = "profiles")]
@@ -208,15 +343,182 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
     /// Validates the value, updates the cache, and writes to disk.
     /// Secret settings (when credentials are enabled) are routed to the OS
     /// keychain instead. Values equal to the default are removed from storage.
-    /// Unchanged values produce no I/O.
+    /// Unchanged values produce no I/O. A value still pending from
+    /// [`save_setting_debounced`](Self::save_setting_debounced) is discarded.
+    ///
+    /// The returned [`SaveOutcome`] tells whether the value changed and whether
+    /// the change needs an application restart (settings marked with
+    /// [`SettingMetadata::requires_restart`](crate::SettingMetadata::requires_restart)).
+    /// Such changes are also collected in
+    /// [`restart_required_keys`](Self::restart_required_keys).
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - Validation fails
+    /// - The setting has async validators
+    ///   (use [`save_setting_async`](Self::save_setting_async))
     /// - Keyring storage or file writing fails
     /// - Serialization or parsing fails
-    pub fn save_setting(&self, category: &str, key: &str, value: &Value) -> Result<()> {
+    pub fn save_setting(&self, category: &str, key: &str, value: &Value) -> Result<SaveOutcome> {
+        let full_key = format!("{category}.{key}");
+        if self.events.has_async_validators(&full_key) {
+            return Err(Error::AsyncValidationRequired(full_key));
+        }
+        self.cancel_pending(Some(&full_key))?;
+        self.save_setting_checked(category, key, value, true)
+    }
+
+    /// Parse text input for a setting and save it.
+    ///
+    /// `key` is the full `category.setting` key. The text is converted to the
+    /// setting's type with [`SettingMetadata::parse_input`] (e.g. `"on"` for a
+    /// toggle, `"a, b"` for a list) and then saved with
+    /// [`save_setting`](Self::save_setting), so all validation applies. Meant
+    /// for front-ends such as `config set ui.theme dark`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is malformed or not in the schema, the text
+    /// cannot be parsed as the setting's type, or saving fails.
+    pub fn parse_and_save(&self, key: &str, raw: &str) -> Result<SaveOutcome> {
+        let (category, setting) = Self::parse_setting_key(key).ok_or_else(|| {
+            Error::Config(format!("Key '{key}' must be in format 'category.setting'"))
+        })?;
+        let setting_meta = self
+            .schema_metadata
+            .get(key)
+            .ok_or_else(|| Error::SettingNotFound(key.to_string()))?;
+        let value = setting_meta
+            .parse_input(raw)
+            .map_err(|reason| Error::InvalidSettingValue {
+                key: key.to_string(),
+                reason,
+            })?;
+
+        self.save_setting(category, setting, &value)
+    }
+
+    /// Save a single setting value after running its async validators.
+    ///
+    /// The value is checked against the schema and the synchronous validators
+    /// first, so an invalid value never reaches the (possibly slow) async
+    /// validators registered with
+    /// [`EventManager::add_async_validator`](crate::EventManager::add_async_validator).
+    /// The save itself is the same as [`save_setting`](Self::save_setting).
+    ///
+    /// The returned future does not depend on any particular async runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation (sync or async) fails, or if saving fails.
+    pub async fn save_setting_async(
+        &self,
+        category: &str,
+        key: &str,
+        value: &Value,
+    ) -> Result<SaveOutcome> {
+        self.validate_before_async_save(category, key, value)
+            .await?;
+        self.save_setting_checked(category, key, value, true)
+    }
+
+    /// Run the sync checks, then the async validators, of a setting about to
+    /// be saved.
+    pub(super) async fn validate_before_async_save(
+        &self,
+        category: &str,
+        key: &str,
+        value: &Value,
+    ) -> Result<()> {
+        let full_key = format!("{category}.{key}");
+        let setting_meta = self
+            .schema_metadata
+            .get(&full_key)
+            .ok_or_else(|| Error::SettingNotFound(full_key.clone()))?;
+        let invalid = |reason| Error::InvalidSettingValue {
+            key: full_key.clone(),
+            reason,
+        };
+        self.events.validate(&full_key, value).map_err(invalid)?;
+        setting_meta.validate(value).map_err(invalid)?;
+
+        self.events
+            .validate_async(&full_key, value)
+            .await
+            .map_err(invalid)
+    }
+
+    /// Keys of restart-requiring settings changed since the last
+    /// [`clear_restart_required`](Self::clear_restart_required), sorted.
+    #[must_use]
+    pub fn restart_required_keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self
+            .restart_required
+            .read_recovered()
+            .map(|keys| keys.iter().cloned().collect())
+            .unwrap_or_default();
+        keys.sort();
+        keys
+    }
+
+    /// Whether any restart-requiring setting changed this session.
+    #[must_use]
+    pub fn needs_restart(&self) -> bool {
+        self.restart_required
+            .read_recovered()
+            .is_ok_and(|keys| !keys.is_empty())
+    }
+
+    /// Forget the restart-requiring changes collected so far.
+    pub fn clear_restart_required(&self) {
+        if let Ok(mut keys) = self.restart_required.write_recovered() {
+            keys.clear();
+        }
+    }
+
+    /// Record a changed setting and report whether it requires a restart.
+    fn track_change(&self, full_key: &str, changed: bool) -> SaveOutcome {
+        let requires_restart = changed
+            && self
+                .schema_metadata
+                .get(full_key)
+                .is_some_and(SettingMetadata::is_restart_required);
+
+        if requires_restart && let Ok(mut keys) = self.restart_required.write_recovered() {
+            keys.insert(full_key.to_string());
+        }
+
+        SaveOutcome {
+            changed,
+            requires_restart,
+        }
+    }
+
+    /// Shared implementation of [`save_setting`](Self::save_setting).
+    ///
+    /// `enforce_required` is disabled when resetting, so a required setting
+    /// with an empty default can still be reset.
+    pub(super) fn save_setting_checked(
+        &self,
+        category: &str,
+        key: &str,
+        value: &Value,
+        enforce_required: bool,
+    ) -> Result<SaveOutcome> {
+        let full_key = format!("{category}.{key}");
+        let changed = self.write_setting(category, key, value, enforce_required)?;
+        Ok(self.track_change(&full_key, changed))
+    }
+
+    /// Validate and persist a setting, returning whether the value changed.
+    fn write_setting(
+        &self,
+        category: &str,
+        key: &str,
+        value: &Value,
+        enforce_required: bool,
+    ) -> Result<bool> {
         let path = self.settings_path()?;
         let full_key = format!("{category}.{key}");
 
@@ -228,18 +530,36 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
                 reason: msg,
             })?;
 
-        let metadata = &self.schema_metadata;
+        // Validate against schema and get metadata
+        let setting_meta = self
+            .schema_metadata
+            .get(&full_key)
+            .ok_or_else(|| Error::SettingNotFound(full_key.clone()))?;
+
+        let validation = if enforce_required {
+            setting_meta.validate(value)
+        } else {
+            setting_meta.validate_constraints(value)
+        };
+        validation.map_err(|reason| Error::InvalidSettingValue {
+            key: full_key.clone(),
+            reason,
+        })?;
+        let pending = HashMap::from([(full_key.as_str(), value)]);
+        self.validate_cross(&pending)?;
+        if enforce_required {
+            self.check_required_when(&pending, false)?;
+        }
 
         // Route secret settings to the credential backend
         #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
-        if let Some(setting_meta) = metadata.get(&full_key).filter(|m| m.is_secret()) {
-            self.save_secret_setting(&full_key, value, setting_meta)?;
-            return Ok(());
+        if setting_meta.is_secret() {
+            return self.save_secret_setting(&full_key, value, setting_meta);
         }
 
         self.ensure_cache_populated()?;
 
-        let _write_guard = self
+        let write_guard = self
             .settings_write_lock
             .lock()
             .map_err(|_| Error::Config("Settings write lock poisoned".into()))?;
@@ -249,18 +569,10 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
             .get_stored()?
             .unwrap_or_else(|| json!({}));
 
-        // Validate against schema and get metadata
-        let setting_meta = metadata
-            .get(&full_key)
-            .ok_or_else(|| Error::SettingNotFound(full_key.clone()))?;
-
-        if let Err(e) = setting_meta.validate(value) {
-            return Err(Error::Config(format!(
-                "Validation failed for {full_key}: {e}"
-            )));
-        }
-
-        let default_value = setting_meta.default.clone();
+        let default_value = self
+            .settings_cache
+            .get_default(&full_key)?
+            .unwrap_or_else(|| setting_meta.default.clone());
 
         let old_value = stored
             .get(category)
@@ -270,9 +582,33 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
 
         if old_value == *value {
             debug!("Setting {full_key} unchanged, skipping save");
-            return Ok(());
+            return Ok(false);
         }
 
+        Self::apply_stored_change(&mut stored, category, key, value, &default_value)?;
+
+        self.persist_settings(path, &stored)?;
+        self.settings_cache.update_stored(stored)?;
+        drop(write_guard);
+
+        debug!("Setting {full_key} saved");
+        // Listeners run without the write lock so they can save settings themselves
+        self.events.notify(&full_key, &old_value, value);
+
+        Ok(true)
+    }
+
+    /// Set `category.key` in the stored (non-default) settings object.
+    ///
+    /// A value equal to the default is removed to keep the file minimal, and
+    /// categories left empty are dropped.
+    fn apply_stored_change(
+        stored: &mut Value,
+        category: &str,
+        key: &str,
+        value: &Value,
+        default_value: &Value,
+    ) -> Result<()> {
         let stored_obj = stored
             .as_object_mut()
             .ok_or_else(|| Error::Parse("Settings root is not an object".into()))?;
@@ -284,10 +620,9 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
                 .as_object_mut()
                 .ok_or_else(|| Error::Parse(format!("Category {category} is not an object")))?;
 
-            // If value equals default, remove it to keep the file minimal
-            if *value == default_value {
+            if value == default_value {
                 category_obj.remove(key);
-                debug!("Setting {full_key} set to default, removed from store");
+                debug!("Setting {category}.{key} set to default, removed from store");
             } else {
                 category_obj.insert(key.to_string(), value.clone());
             }
@@ -302,16 +637,145 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
             stored_obj.remove(category);
         }
 
-        self.storage.write(&path, &stored)?;
-        self.settings_cache.update_stored(stored)?;
+        Ok(())
+    }
 
-        debug!("Setting {full_key} saved");
-        self.events.notify(&full_key, &old_value, value);
+    /// Save several settings with a single write.
+    ///
+    /// Every change is validated first (custom validators and schema); if any
+    /// is invalid, nothing is saved. The changes are then applied in order
+    /// (a later change to the same key wins), values equal to their default are
+    /// removed from storage as with [`save_setting`](Self::save_setting), and the
+    /// settings file is written once. Change listeners are notified for each
+    /// changed key after the write.
+    ///
+    /// Secret settings are stored in the credential backend one by one, before
+    /// the settings file is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A key is not in the schema, or a value fails validation
+    /// - A setting has async validators (see [`save_setting_async`](Self::save_setting_async))
+    /// - Keyring storage or file writing fails
+    pub fn save_settings_batch(&self, changes: &[(&str, &str, Value)]) -> Result<()> {
+        self.write_settings_batch(changes, true).map(drop)
+    }
 
-        Ok(())
+    /// Shared implementation of [`save_settings_batch`](Self::save_settings_batch),
+    /// returning the keys that changed.
+    ///
+    /// `enforce_required` is disabled when resetting: defaults are then only
+    /// checked against type constraints, and async validators do not apply.
+    fn write_settings_batch(
+        &self,
+        changes: &[(&str, &str, Value)],
+        enforce_required: bool,
+    ) -> Result<Vec<String>> {
+        let mut validated = Vec::with_capacity(changes.len());
+        for (category, key, value) in changes {
+            let full_key = format!("{category}.{key}");
+            if enforce_required && self.events.has_async_validators(&full_key) {
+                return Err(Error::AsyncValidationRequired(full_key));
+            }
+            let invalid = |reason| Error::InvalidSettingValue {
+                key: full_key.clone(),
+                reason,
+            };
+            self.events.validate(&full_key, value).map_err(invalid)?;
+            let setting_meta = self
+                .schema_metadata
+                .get(&full_key)
+                .ok_or_else(|| Error::SettingNotFound(full_key.clone()))?;
+            if enforce_required {
+                setting_meta.validate(value).map_err(invalid)?;
+            } else {
+                setting_meta.validate_constraints(value).map_err(invalid)?;
+            }
+            validated.push((*category, *key, value, full_key, setting_meta));
+        }
+        let pending: HashMap<&str, &Value> = validated
+            .iter()
+            .map(|(_, _, value, full_key, _)| (full_key.as_str(), *value))
+            .collect();
+        self.validate_cross(&pending)?;
+        if enforce_required {
+            self.check_required_when(&pending, true)?;
+        }
+        for full_key in pending.keys() {
+            self.cancel_pending(Some(full_key))?;
+        }
+
+        let mut changed_keys = Vec::new();
+        let mut file_changes = Vec::with_capacity(validated.len());
+        for (category, key, value, full_key, setting_meta) in validated {
+            #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+            if setting_meta.is_secret() {
+                let changed = self.save_secret_setting(&full_key, value, setting_meta)?;
+                self.track_change(&full_key, changed);
+                if changed {
+                    changed_keys.push(full_key);
+                }
+                continue;
+            }
+            file_changes.push((category, key, value, full_key, setting_meta));
+        }
+        if file_changes.is_empty() {
+            return Ok(changed_keys);
+        }
+
+        let path = self.settings_path()?;
+        self.ensure_cache_populated()?;
+
+        let write_guard = self
+            .settings_write_lock
+            .lock()
+            .map_err(|_| Error::Config("Settings write lock poisoned".into()))?;
+
+        let mut stored = self
+            .settings_cache
+            .get_stored()?
+            .unwrap_or_else(|| json!({}));
+
+        let mut notifications = Vec::new();
+        for (category, key, value, full_key, setting_meta) in file_changes {
+            let default_value = self
+                .settings_cache
+                .get_default(&full_key)?
+                .unwrap_or_else(|| setting_meta.default.clone());
+            let old_value = stored
+                .get(category)
+                .and_then(|cat| cat.get(key))
+                .cloned()
+                .unwrap_or_else(|| default_value.clone());
+            if old_value == *value {
+                continue;
+            }
+
+            Self::apply_stored_change(&mut stored, category, key, value, &default_value)?;
+            notifications.push((full_key, old_value, value));
+        }
+
+        if !notifications.is_empty() {
+            self.persist_settings(path, &stored)?;
+            self.settings_cache.update_stored(stored)?;
+        }
+        drop(write_guard);
+
+        debug!("Saved batch of {} settings", notifications.len());
+        for (full_key, old_value, value) in notifications {
+            self.events.notify(&full_key, &old_value, value);
+            self.track_change(&full_key, true);
+            changed_keys.push(full_key);
+        }
+
+        Ok(changed_keys)
     }
 
-    /// Reset a single setting to its schema default.
+    /// Reset a single setting to its default.
+    ///
+    /// The default is the fallback source value when one is configured,
+    /// otherwise the schema default.
     ///
     /// # Errors
     ///
@@ -319,25 +783,643 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
     /// or if saving the default value fails.
     pub fn reset_setting(&self, category: &str, key: &str) -> Result<Value> {
         let metadata_key = format!("{category}.{key}");
-        let default_value = self
+        let schema_default = self
             .schema_metadata
             .get(&metadata_key)
             .map(|m| m.default.clone())
             .ok_or_else(|| Error::SettingNotFound(format!("{category}.{key}")))?;
 
-        self.save_setting(category, key, &default_value)?;
+        self.ensure_cache_populated()?;
+        let default_value = self
+            .settings_cache
+            .get_default(&metadata_key)?
+            .unwrap_or(schema_default);
+
+        self.cancel_pending(Some(&metadata_key))?;
+        self.save_setting_checked(category, key, &default_value, false)?;
 
         debug!("Setting {category}.{key} reset to default");
         Ok(default_value)
     }
 
+    /// Reset every setting of a category to its default with a single write.
+    ///
+    /// Covers all schema keys of the form `category.*`, including secrets,
+    /// which are removed from the credential store. As with
+    /// [`reset_setting`](Self::reset_setting), the default is the fallback
+    /// source value when one is configured. Listeners are notified for each
+    /// setting that changed.
+    ///
+    /// Returns the keys that were reset (those not already at their default),
+    /// sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema has no settings in `category`, or if
+    /// writing the settings file or the credential store fails.
+    pub fn reset_category(&self, category: &str) -> Result<Vec<String>> {
+        let changes = self.category_defaults(category)?;
+        let mut reset = self.write_settings_batch(&changes, false)?;
+        reset.sort();
+        debug!("Reset {} settings in category {category}", reset.len());
+        Ok(reset)
+    }
+
+    /// Replace the contents of a category with a single write.
+    ///
+    /// `values` maps setting names within `category` (e.g. `"proxy_host"` for
+    /// `network.proxy_host`) to their new values. Settings of the category
+    /// missing from `values` are reset to their defaults, so nothing from the
+    /// previous contents lingers. Meant for categories driven by an external
+    /// source, such as a config server.
+    ///
+    /// All values are validated before anything is written; listeners are
+    /// notified only for settings whose value actually changed. Returns those
+    /// keys, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema has no settings in `category`, a name in
+    /// `values` is not a setting of the category, a value fails validation,
+    /// or writing fails.
+    pub fn replace_category(
+        &self,
+        category: &str,
+        values: &HashMap<String, Value>,
+    ) -> Result<Vec<String>> {
+        let mut changes = self.category_defaults(category)?;
+        for (key, value) in values {
+            let full_key = format!("{category}.{key}");
+            let Some(change) = changes.iter_mut().find(|(_, name, _)| name == key) else {
+                return Err(Error::SettingNotFound(full_key));
+            };
+            if self.events.has_async_validators(&full_key) {
+                return Err(Error::AsyncValidationRequired(full_key));
+            }
+            self.schema_metadata[&full_key]
+                .validate(value)
+                .map_err(|reason| Error::InvalidSettingValue {
+                    key: full_key.clone(),
+                    reason,
+                })?;
+            change.2 = value.clone();
+        }
+        let full_keys: Vec<String> = changes
+            .iter()
+            .map(|(category, key, _)| format!("{category}.{key}"))
+            .collect();
+        let pending: HashMap<&str, &Value> = full_keys
+            .iter()
+            .map(String::as_str)
+            .zip(changes.iter().map(|(_, _, value)| value))
+            .collect();
+        self.check_required_when(&pending, true)?;
+
+        let mut changed = self.write_settings_batch(&changes, false)?;
+        changed.sort();
+        debug!(
+            "Replaced category {category}, {} settings changed",
+            changed.len()
+        );
+        Ok(changed)
+    }
+
+    /// Every setting of `category` paired with its default (the fallback
+    /// source value if there is one), sorted by name.
+    fn category_defaults<'a>(
+        &'a self,
+        category: &'a str,
+    ) -> Result<Vec<(&'a str, &'a str, Value)>> {
+        let mut keys: Vec<&str> = self
+            .schema_metadata
+            .keys()
+            .filter_map(|full_key| {
+                Self::parse_setting_key(full_key)
+                    .filter(|(cat, _)| *cat == category)
+                    .map(|(_, key)| key)
+            })
+            .collect();
+        if keys.is_empty() {
+            return Err(Error::SettingNotFound(format!("{category}.*")));
+        }
+        keys.sort_unstable();
+
+        self.ensure_cache_populated()?;
+        let mut changes = Vec::with_capacity(keys.len());
+        for key in keys {
+            let full_key = format!("{category}.{key}");
+            let default_value =
+                self.effective_default(&full_key, &self.schema_metadata[&full_key])?;
+            changes.push((category, key, default_value));
+        }
+        Ok(changes)
+    }
+
+    /// Check every current setting value against the schema.
+    ///
+    /// Returns `(key, reason)` for each setting whose effective value fails
+    /// validation, sorted by key. Unlike a save, this also reports conditions
+    /// that depend on other settings, such as a
+    /// [`required_when`](crate::SettingMetadata::required_when) setting left
+    /// empty after its controlling toggle was switched on, and required
+    /// settings still at an empty default. Useful before leaving a settings
+    /// form or starting work that needs a complete configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if settings cannot be read.
+    pub fn validate_all(&self) -> Result<Vec<(String, String)>> {
+        let mut keys: Vec<&String> = self.schema_metadata.keys().collect();
+        keys.sort();
+
+        let mut problems = Vec::new();
+        for key in keys {
+            let metadata = &self.schema_metadata[key];
+            let value = self.get_value(key)?;
+            if let Err(reason) = metadata.validate(&value) {
+                problems.push((key.clone(), reason));
+            } else if let Some(reason) = self.required_when_violation(key, &|k| {
+                if k == key {
+                    Some(value.clone())
+                } else {
+                    self.get_value(k).ok()
+                }
+            }) {
+                problems.push((key.clone(), reason));
+            }
+        }
+        Ok(problems)
+    }
+
+    /// Run the cross-field validators covering `pending` against current values.
+    fn validate_cross(&self, pending: &HashMap<&str, &Value>) -> Result<()> {
+        self.events
+            .validate_cross(pending, |key| self.get_value(key).ok())
+            .map_err(|(key, reason)| Error::InvalidSettingValue { key, reason })
+    }
+
+    /// Enforce [`required_when`](crate::SettingMetadata::required_when) for
+    /// values about to be saved.
+    ///
+    /// `pending` values take precedence over current ones, so a batch is
+    /// checked against its own result. With `include_dependents`, settings
+    /// whose controlling setting is in `pending` are checked as well.
+    fn check_required_when(
+        &self,
+        pending: &HashMap<&str, &Value>,
+        include_dependents: bool,
+    ) -> Result<()> {
+        let lookup = |key: &str| match pending.get(key) {
+            Some(value) => Some((*value).clone()),
+            None => self.get_value(key).ok(),
+        };
+
+        let mut keys: Vec<&String> = self
+            .schema_metadata
+            .iter()
+            .filter_map(|(key, metadata)| {
+                let condition = metadata.required_when.as_ref()?;
+                let relevant = pending.contains_key(key.as_str())
+                    || (include_dependents && pending.contains_key(condition.key.as_str()));
+                relevant.then_some(key)
+            })
+            .collect();
+        keys.sort();
+
+        for key in keys {
+            if let Some(reason) = self.required_when_violation(key, &lookup) {
+                return Err(Error::InvalidSettingValue {
+                    key: key.clone(),
+                    reason,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Reason why `key` violates its `required_when` condition, if it does.
+    fn required_when_violation(
+        &self,
+        key: &str,
+        lookup: &dyn Fn(&str) -> Option<Value>,
+    ) -> Option<String> {
+        let condition = self.schema_metadata.get(key)?.required_when.as_ref()?;
+        if lookup(&condition.key).as_ref() != Some(&condition.value) {
+            return None;
+        }
+        lookup(key)
+            .is_none_or(|value| SettingMetadata::is_blank(&value))
+            .then(|| {
+                format!(
+                    "Value is required when {} is {}",
+                    condition.key, condition.value
+                )
+            })
+    }
+
+    /// Keys in the stored settings file that are not in the schema.
+    ///
+    /// Settings that were renamed or removed in a newer app version linger in
+    /// the file; this lists them as `category.key` (or just `category` when
+    /// the category is not an object), sorted. They are ignored when loading
+    /// and can be removed with [`prune_unknown`](Self::prune_unknown).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the settings file cannot be read.
+    pub fn unknown_stored_keys(&self) -> Result<Vec<String>> {
+        self.ensure_cache_populated()?;
+        let stored = self.settings_cache.get_stored()?.unwrap_or_default();
+        Ok(self.find_unknown_keys(&stored))
+    }
+
+    /// Remove the keys reported by [`unknown_stored_keys`](Self::unknown_stored_keys)
+    /// from the settings file, with a single write.
+    ///
+    /// Returns the removed keys, sorted. The file is not written when there is
+    /// nothing to remove.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the settings file cannot be read or written.
+    pub fn prune_unknown(&self) -> Result<Vec<String>> {
+        let path = self.settings_path()?;
+        self.ensure_cache_populated()?;
+
+        let _write_guard = self
+            .settings_write_lock
+            .lock()
+            .map_err(|_| Error::Config("Settings write lock poisoned".into()))?;
+
+        let mut stored = self
+            .settings_cache
+            .get_stored()?
+            .unwrap_or_else(|| json!({}));
+        let unknown = self.find_unknown_keys(&stored);
+        if unknown.is_empty() {
+            return Ok(unknown);
+        }
+
+        if let Some(obj) = stored.as_object_mut() {
+            for key in &unknown {
+                match key.split_once('.') {
+                    Some((category, key)) => {
+                        if let Some(settings) = obj.get_mut(category).and_then(Value::as_object_mut)
+                        {
+                            settings.remove(key);
+                        }
+                    }
+                    None => {
+                        obj.remove(key);
+                    }
+                }
+            }
+            obj.retain(|_, category| category.as_object().is_none_or(|c| !c.is_empty()));
+        }
+
+        self.persist_settings(path, &stored)?;
+        self.settings_cache.update_stored(stored)?;
+        debug!("Pruned {} unknown settings", unknown.len());
+        Ok(unknown)
+    }
+
+    fn find_unknown_keys(&self, stored: &Value) -> Vec<String> {
+        let mut unknown = Vec::new();
+        for (category, settings) in stored.as_object().into_iter().flatten() {
+            let Some(settings) = settings.as_object() else {
+                unknown.push(category.clone());
+                continue;
+            };
+            for key in settings.keys() {
+                let full_key = format!("{category}.{key}");
+                if !self.schema_metadata.contains_key(&full_key) {
+                    unknown.push(full_key);
+                }
+            }
+        }
+        unknown.sort();
+        unknown
+    }
+
+    /// Import settings from a flat map keyed by dotted path.
+    ///
+    /// This is the inverse of [`export_flat`](Self::export_flat). All keys and
+    /// values are validated before anything is written, so an invalid entry
+    /// leaves the stored settings untouched. The values are applied with
+    /// [`save_settings_batch`](Self::save_settings_batch).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A key is not in `category.setting` format or not in the schema
+    /// - A value fails validation
+    /// - Saving a setting fails
+    pub fn import_flat(&self, values: &HashMap<String, Value>) -> Result<()> {
+        let mut entries = Vec::with_capacity(values.len());
+        for (full_key, value) in values {
+            let (category, key) = Self::parse_setting_key(full_key).ok_or_else(|| {
+                Error::Config(format!(
+                    "Key '{full_key}' must be in format 'category.setting'"
+                ))
+            })?;
+            entries.push((category, key, value.clone()));
+        }
+        // Notify listeners in a stable order
+        entries.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+        self.storage.begin_batch()?;
+        let result = self.save_settings_batch(&entries);
+        let committed = self.storage.commit_batch();
+        result.and(committed)?;
+
+        debug!("Imported {} flat settings", values.len());
+        Ok(())
+    }
+
+    /// Check a JSON settings document without applying it.
+    ///
+    /// Accepts the nested layout of the settings file (`{"ui": {"theme": "dark"}}`)
+    /// as well as flat dotted keys (`{"ui.theme": "dark"}`, as produced by
+    /// [`export_flat`](Self::export_flat)). Every key is checked against the
+    /// schema and all problems are returned as `(key, reason)` pairs sorted by
+    /// key; an empty list means the document passes the schema validation
+    /// that [`import_json`](Self::import_json) applies. The import itself can
+    /// still fail, e.g. if the settings file cannot be written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not a JSON object.
+    pub fn validate_import(&self, json: &str) -> Result<Vec<(String, String)>> {
+        let values = Self::parse_import_json(json)?;
+
+        let mut problems = Vec::new();
+        for (full_key, value) in &values {
+            let reason = if Self::parse_setting_key(full_key).is_none() {
+                "Key must be in format 'category.setting'".to_string()
+            } else if let Some(setting_meta) = self.schema_metadata.get(full_key) {
+                match setting_meta.validate(value) {
+                    Ok(()) => continue,
+                    Err(reason) => reason,
+                }
+            } else {
+                "Setting is not defined in the schema".to_string()
+            };
+            problems.push((full_key.clone(), reason));
+        }
+
+        problems.sort();
+        Ok(problems)
+    }
+
+    /// Import settings from a JSON document.
+    ///
+    /// Accepts the same layouts as [`validate_import`](Self::validate_import)
+    /// and applies them with [`import_flat`](Self::import_flat), so nothing is
+    /// written unless every entry is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not a JSON object, or for the same reasons
+    /// as [`import_flat`](Self::import_flat).
+    pub fn import_json(&self, json: &str) -> Result<()> {
+        let values = Self::parse_import_json(json)?;
+        self.import_flat(&values)
+    }
+
+    /// Import main settings and sub-settings entries from one JSON document.
+    ///
+    /// The bundle has the shape
+    /// `{"settings": {...}, "sub_settings": {"<type>": {"<name>": <value>}}}`;
+    /// both sections are optional. `settings` accepts the same layouts as
+    /// [`import_json`](Self::import_json). This is a plain-JSON alternative to
+    /// restoring a backup archive, convenient for scripting.
+    ///
+    /// Every value is validated before anything is written. Settings are
+    /// applied with [`import_flat`](Self::import_flat) and the entries of each
+    /// sub-settings type with [`SubSettings::set_many`](crate::SubSettings::set_many).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The bundle is not an object or contains an unknown section
+    /// - A sub-settings type is not registered (unless skipped via options)
+    /// - A setting or entry fails validation
+    /// - Writing settings, entries or secrets fails
+    pub fn import_bundle(&self, bundle: Value, options: &ImportOptions) -> Result<ImportReport> {
+        let Value::Object(mut root) = bundle else {
+            return Err(Error::Parse(
+                "Import bundle must be a JSON object".to_string(),
+            ));
+        };
+
+        let settings = match root.remove("settings") {
+            None | Some(Value::Null) => HashMap::new(),
+            Some(Value::Object(settings)) => Self::flatten_import(settings),
+            Some(_) => {
+                return Err(Error::Parse(
+                    "Import bundle 'settings' must be a JSON object".to_string(),
+                ));
+            }
+        };
+        let sub_settings = match root.remove("sub_settings") {
+            None | Some(Value::Null) => serde_json::Map::new(),
+            Some(Value::Object(sub_settings)) => sub_settings,
+            Some(_) => {
+                return Err(Error::Parse(
+                    "Import bundle 'sub_settings' must be a JSON object".to_string(),
+                ));
+            }
+        };
+        if let Some(section) = root.keys().next() {
+            return Err(Error::Parse(format!(
+                "Unknown import bundle section '{section}'"
+            )));
+        }
+
+        let mut report = ImportReport::default();
+        let mut writes = Vec::with_capacity(sub_settings.len());
+        for (type_name, entries) in sub_settings {
+            let sub = match self.sub_settings(&type_name) {
+                Ok(sub) => sub,
+                Err(Error::SubSettingsNotRegistered(_)) if options.skip_unknown_types => {
+                    report.skipped.push(type_name);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            let Value::Object(entries) = entries else {
+                return Err(Error::Parse(format!(
+                    "Import bundle sub-settings '{type_name}' must map entry names to values"
+                )));
+            };
+
+            let mut prepared = Vec::with_capacity(entries.len());
+            for (name, value) in entries {
+                if !options.overwrite_existing && sub.exists(&name)? {
+                    report.skipped.push(format!("{type_name}/{name}"));
+                    continue;
+                }
+                sub.validate_against_schema(&name, &value)?;
+                prepared.push((name, value));
+            }
+            writes.push((type_name, sub, prepared));
+        }
+
+        if !settings.is_empty() {
+            self.import_flat(&settings)?;
+            report.settings = settings.into_keys().collect();
+        }
+
+        for (type_name, sub, prepared) in writes {
+            if prepared.is_empty() {
+                continue;
+            }
+            let entries: Vec<(&str, &Value)> = prepared
+                .iter()
+                .map(|(name, value)| (name.as_str(), value))
+                .collect();
+            sub.set_many(&entries)?;
+            report.sub_settings.extend(
+                prepared
+                    .iter()
+                    .map(|(name, _)| format!("{type_name}/{name}")),
+            );
+        }
+
+        report.settings.sort();
+        report.sub_settings.sort();
+        report.skipped.sort();
+        debug!(
+            "Imported bundle: {} settings, {} sub-settings entries",
+            report.settings.len(),
+            report.sub_settings.len()
+        );
+        Ok(report)
+    }
+
+    /// Flatten a nested or flat JSON settings document into dotted keys.
+    fn parse_import_json(json: &str) -> Result<HashMap<String, Value>> {
+        let document: Value = serde_json::from_str(json)?;
+        let Value::Object(root) = document else {
+            return Err(Error::Parse(
+                "Imported settings must be a JSON object".to_string(),
+            ));
+        };
+        Ok(Self::flatten_import(root))
+    }
+
+    fn flatten_import(root: serde_json::Map<String, Value>) -> HashMap<String, Value> {
+        let mut values = HashMap::new();
+        for (key, value) in root {
+            match value {
+                Value::Object(settings) if !key.contains('.') => {
+                    for (setting, value) in settings {
+                        values.insert(format!("{key}.{setting}"), value);
+                    }
+                }
+                value => {
+                    values.insert(key, value);
+                }
+            }
+        }
+        values
+    }
+
+    /// Prepare a two-step [`reset_all`](Self::reset_all).
+    ///
+    /// The returned token tells the user what will be lost, for an "are you
+    /// sure" prompt; pass it to [`reset_all_confirmed`](Self::reset_all_confirmed)
+    /// to go ahead. Sub-settings entries are not affected by a reset, but
+    /// their secrets are: the reset clears every credential of the app, see
+    /// [`ResetToken::sub_settings_secrets`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if settings cannot be read.
+    pub fn prepare_reset_all(&self) -> Result<ResetToken> {
+        use std::hash::{BuildHasher, Hash, Hasher};
+
+        let mut customized: Vec<(String, Value)> = self.export_flat(true)?.into_iter().collect();
+        customized.sort_by(|a, b| a.0.cmp(&b.0));
+        let secrets = customized
+            .iter()
+            .filter(|(key, _)| self.schema_metadata[key].is_secret())
+            .count();
+
+        #[cfg(feature = "profiles")]
+        let profile = self
+            .profile_manager
+            .as_ref()
+            .map(|pm| pm.active())
+            .transpose()?;
+        #[cfg(not(feature = "profiles"))]
+        let profile = None;
+
+        // Sub-settings credential keys are `sub.{type}.{entry}.{field}`
+        #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+        let sub_settings_secrets = self.credentials.as_ref().map_or(0, |creds| {
+            creds
+                .service_keys()
+                .iter()
+                .filter(|key| {
+                    key.rsplit(':')
+                        .next()
+                        .is_some_and(|k| k.starts_with("sub."))
+                })
+                .count()
+        });
+        #[cfg(not(any(feature = "keychain", feature = "encrypted-file")))]
+        let sub_settings_secrets = 0;
+
+        // Fingerprint the state with a keyed hash instead of keeping it, so
+        // secret values are neither stored in the token nor derivable from it
+        let mut hasher = RESET_TOKEN_KEY.build_hasher();
+        for (key, value) in &customized {
+            key.hash(&mut hasher);
+            value.to_string().hash(&mut hasher);
+        }
+        // Stored keys outside the schema are wiped too
+        self.settings_cache
+            .get_stored()?
+            .map(|stored| stored.to_string())
+            .hash(&mut hasher);
+        profile.hash(&mut hasher);
+
+        Ok(ResetToken {
+            customized_settings: customized.len() - secrets,
+            secrets,
+            sub_settings_secrets,
+            profile,
+            state: hasher.finish(),
+        })
+    }
+
+    /// Reset all settings to defaults if nothing changed since `token` was prepared.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::StaleResetToken`] if settings, secrets or the active
+    /// profile changed after [`prepare_reset_all`](Self::prepare_reset_all),
+    /// otherwise the same errors as [`reset_all`](Self::reset_all).
+    pub fn reset_all_confirmed(&self, token: &ResetToken) -> Result<()> {
+        if self.prepare_reset_all()? != *token {
+            return Err(Error::StaleResetToken);
+        }
+        self.reset_all()
+    }
+
     /// Reset all settings to defaults.
     ///
+    /// This cannot be undone; prefer [`prepare_reset_all`](Self::prepare_reset_all)
+    /// and [`reset_all_confirmed`](Self::reset_all_confirmed) when the reset is
+    /// triggered from the UI.
+    ///
     /// # Errors
     ///
     /// Returns an error if writing to storage fails or credential clearing fails.
     pub fn reset_all(&self) -> Result<()> {
         let path = self.settings_path()?;
+        self.cancel_pending(None)?;
 
         self.ensure_cache_populated()?;
 
@@ -356,7 +1438,10 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
                 continue;
             };
 
-            let default_value = metadata.default.clone();
+            let default_value = self
+                .settings_cache
+                .get_default(full_key)?
+                .unwrap_or_else(|| metadata.default.clone());
 
             #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
             let old_value = if metadata.is_secret() && self.credentials.is_some() {
@@ -390,7 +1475,10 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
             }
         }
 
-        // Write empty object
+        // Write empty object, replacing any deferred changes
+        if let Some(writes) = &self.deferred_writes {
+            writes.discard()?;
+        }
         self.storage.write(&path, &json!({}))?;
 
         #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
@@ -406,19 +1494,69 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         self.invalidate_cache();
 
         for (full_key, old_value, new_value) in changed_events {
+            self.track_change(&full_key, true);
             self.events.notify(&full_key, &old_value, &new_value);
         }
 
         Ok(())
     }
 
+    /// Move an unparseable settings file aside so the next save does not
+    /// overwrite it.
+    fn quarantine_corrupt_file(&self, path: &Path, reason: &Error) -> Result<()> {
+        let timestamp = OffsetDateTime::now_utc()
+            .format(format_description!(
+                "[year][month][day]_[hour][minute][second]"
+            ))
+            .map_err(|e| Error::Config(e.to_string()))?;
+        let mut quarantined = path.as_os_str().to_owned();
+        quarantined.push(format!(".corrupt-{timestamp}"));
+        let quarantined = PathBuf::from(quarantined);
+
+        // Backends that do not keep the settings in a file have nothing to move
+        if path.is_file() {
+            std::fs::rename(path, &quarantined).map_err(|e| Error::FileWrite {
+                path: quarantined.clone(),
+                source: e,
+            })?;
+            warn!(
+                "Settings file {} is corrupt ({reason}); moved it to {} and started on defaults",
+                path.display(),
+                quarantined.display()
+            );
+        } else {
+            warn!(
+                "Settings at {} are corrupt ({reason}); started on defaults",
+                path.display()
+            );
+        }
+        Ok(())
+    }
+
     /// Load settings from disk, applying migrations if needed.
     pub(crate) fn load_from_disk(&self) -> Result<CachedSettings> {
         let settings_path = self.settings_path()?;
         let mut value: Value = match self.storage.read(&settings_path) {
             Ok(v) => v,
-            Err(Error::FileRead { .. } | Error::PathNotFound(_) | Error::Parse(_)) => {
-                // Start empty if not found or corrupted/invalid JSON
+            Err(Error::FileRead { source, .. })
+                if source.kind() == std::io::ErrorKind::NotFound =>
+            {
+                json!({})
+            }
+            Err(e @ (Error::Parse(_) | Error::Serialize(_))) => match self.config.on_corrupt {
+                CorruptPolicy::FailFast => return Err(e),
+                CorruptPolicy::QuarantineAndReset => {
+                    self.quarantine_corrupt_file(&settings_path, &e)?;
+                    json!({})
+                }
+            },
+            Err(e @ (Error::FileRead { .. } | Error::PathNotFound(_)))
+                if self.config.strict_validation =>
+            {
+                return Err(e);
+            }
+            Err(Error::FileRead { .. } | Error::PathNotFound(_)) => {
+                // Start empty if the file cannot be read
                 json!({})
             }
             Err(e) => return Err(e),
@@ -441,14 +1579,92 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         // clobbering schema defaults.
         crate::utils::value::strip_nulls(&mut value);
 
+        let clamped = self.clamp_stored(&mut value);
+
+        if self.config.strict_validation {
+            self.validate_stored(&value)?;
+        }
+
+        let fallback = self.read_fallback_source();
+        let defaults = if fallback.is_empty() {
+            self.schema_defaults.clone()
+        } else {
+            let mut defaults = (*self.schema_defaults).clone();
+            defaults.extend(fallback.clone());
+            std::sync::Arc::new(defaults)
+        };
+
         Ok(CachedSettings {
             stored: value,
             merged: None,
-            defaults: self.schema_defaults.clone(),
+            defaults,
+            fallback,
             generation: 0,
+            clamped,
         })
     }
 
+    /// Clamp stored numbers into range for settings with `clamp` enabled.
+    ///
+    /// Only the in-memory copy changes; the file is rewritten on the next save.
+    fn clamp_stored(&self, stored: &mut Value) -> HashMap<String, Value> {
+        let mut clamped = HashMap::new();
+        for (key, metadata) in self.schema_metadata.iter() {
+            let Some(new_value) = crate::utils::value::get_path(stored, key)
+                .and_then(|value| metadata.clamp_value(value))
+            else {
+                continue;
+            };
+            debug!("Clamped stored value of {key} to {new_value}");
+            crate::utils::value::set_path(stored, key, new_value.clone());
+            clamped.insert(key.clone(), new_value);
+        }
+        clamped
+    }
+
+    /// Read every non-secret setting from the fallback source, dropping
+    /// values that fail validation.
+    fn read_fallback_source(&self) -> HashMap<String, Value> {
+        let mut fallback = HashMap::new();
+        let Some(source) = &self.config.fallback_source else {
+            return fallback;
+        };
+
+        for (key, metadata) in self.schema_metadata.iter() {
+            if metadata.is_secret() {
+                continue;
+            }
+            let Some(value) = source.get(key) else {
+                continue;
+            };
+            match metadata.validate(&value) {
+                Ok(()) => {
+                    fallback.insert(key.clone(), value);
+                }
+                Err(reason) => warn!("Ignoring fallback source value for {key}: {reason}"),
+            }
+        }
+
+        fallback
+    }
+
+    /// Check every stored value against its schema (strict validation mode).
+    fn validate_stored(&self, stored: &Value) -> Result<()> {
+        let mut keys: Vec<_> = self.schema_metadata.keys().collect();
+        keys.sort();
+        for key in keys {
+            if let Some(value) = crate::utils::value::get_path(stored, key) {
+                self.schema_metadata[key]
+                    .validate(value)
+                    .map_err(|reason| Error::InvalidStoredValue {
+                        key: key.clone(),
+                        reason,
+                    })?;
+            }
+        }
+        Ok(())
+    }
+
     /// Ensure the settings cache is populated.
     ///
     /// Thread-safe — `populate()` acquires a write lock internally and
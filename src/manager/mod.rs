@@ -3,12 +3,18 @@
 //! This module contains the [`SettingsManager`] struct which is the primary entry point
 //! for managing application settings.
 
+#[cfg(feature = "tokio")]
+mod blocking;
 pub mod cache;
 pub mod core;
+mod deferred;
 pub mod env;
 pub mod events;
 pub mod io;
+mod mutations;
 pub mod operations;
+mod pending;
+pub mod snapshot;
 
 #[cfg(feature = "hot-reload")]
 pub mod hot_reload;
@@ -18,9 +24,13 @@ pub mod profiles;
 
 // Re-export core types
 pub use self::core::SettingsManager;
-pub use self::events::EventManager;
+pub use self::events::{EventManager, ListenerHandle};
 #[cfg(feature = "hot-reload")]
 pub use self::hot_reload::{HotReloadEvent, HotReloadRuntime};
+pub use self::io::{ImportOptions, ImportReport, ResetToken, SaveOutcome};
+pub use self::mutations::Mutation;
+pub use self::operations::{REDACTED_SECRET, RedactedExport};
+pub use self::snapshot::SettingsSnapshot;
 
 // Builder Module
 mod builder;
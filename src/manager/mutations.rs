@@ -0,0 +1,131 @@
+//! Unified stream of settings mutations
+//!
+//! [`SettingsManager::subscribe_all_mutations`] delivers changes to main
+//! settings, sub-settings entries and (with the `profiles` feature) profile
+//! switches through one channel, so a reactive UI can drive all of its state
+//! from a single subscription instead of one callback per subsystem.
+
+use crate::config::SettingsSchema;
+use crate::manager::SettingsManager;
+use crate::manager::events::{EventManager, ListenerHandle};
+use crate::storage::StorageBackend;
+use crate::sub_settings::SubSettingsAction;
+
+use serde_json::Value;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, OnceLock, Weak};
+
+/// A change delivered by [`SettingsManager::subscribe_all_mutations`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mutation {
+    /// A main setting changed
+    Setting {
+        /// Full key (`category.key`)
+        key: String,
+        /// Value before the change
+        old_value: Value,
+        /// Value after the change
+        new_value: Value,
+    },
+    /// A sub-settings entry was created, updated or deleted
+    SubSetting {
+        /// Sub-settings type name
+        settings_type: String,
+        /// Entry name
+        name: String,
+        /// What happened to the entry
+        action: SubSettingsAction,
+    },
+    /// Main settings or a sub-settings type switched profile
+    #[cfg(feature = "profiles")]
+    ProfileSwitch {
+        /// `"settings"` for main settings, otherwise the sub-settings name
+        target: String,
+        /// The previously active profile
+        from: String,
+        /// The newly active profile
+        to: String,
+    },
+}
+
+/// Sending side of one subscription. Once the receiver is gone the
+/// subscription unregisters its listeners on the next send.
+struct Subscription {
+    sender: Sender<Mutation>,
+    events: Weak<EventManager>,
+    handles: OnceLock<Vec<ListenerHandle>>,
+}
+
+impl Subscription {
+    fn send(&self, mutation: Mutation) {
+        if self.sender.send(mutation).is_err()
+            && let (Some(events), Some(handles)) = (self.events.upgrade(), self.handles.get())
+        {
+            for handle in handles {
+                events.remove_listener(*handle);
+            }
+        }
+    }
+}
+
+impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Schema> {
+    /// Receive every settings mutation through one channel.
+    ///
+    /// Delivers main settings changes, sub-settings entry changes (including
+    /// types registered after this call) and profile switches as
+    /// [`Mutation`]s, in the order the change listeners see them. Mutations
+    /// are sent from the thread that made the change; the receiver can be
+    /// moved to any thread.
+    ///
+    /// The subscription ends by dropping the receiver: its listeners are
+    /// removed when the next mutation fails to send.
+    #[must_use]
+    pub fn subscribe_all_mutations(&self) -> Receiver<Mutation> {
+        let (sender, receiver) = mpsc::channel();
+        let subscription = Arc::new(Subscription {
+            sender,
+            events: Arc::downgrade(&self.events),
+            handles: OnceLock::new(),
+        });
+
+        let setting = Arc::clone(&subscription);
+        let sub_setting = Arc::clone(&subscription);
+        let setting_handle = self.events.on_change(move |key, old_value, new_value| {
+            setting.send(Mutation::Setting {
+                key: key.to_string(),
+                old_value: old_value.clone(),
+                new_value: new_value.clone(),
+            });
+        });
+        let sub_setting_handle =
+            self.events
+                .on_sub_settings_change(move |settings_type, name, action| {
+                    sub_setting.send(Mutation::SubSetting {
+                        settings_type: settings_type.to_string(),
+                        name: name.to_string(),
+                        action,
+                    });
+                });
+
+        #[cfg(feature = "profiles")]
+        let profile_switch_handle = {
+            let profile_switch = Arc::clone(&subscription);
+            Some(self.events.on_profile_switch(move |target, from, to| {
+                profile_switch.send(Mutation::ProfileSwitch {
+                    target: target.to_string(),
+                    from: from.to_string(),
+                    to: to.to_string(),
+                });
+            }))
+        };
+        #[cfg(not(feature = "profiles"))]
+        let profile_switch_handle = None;
+
+        let handles = [setting_handle, sub_setting_handle]
+            .into_iter()
+            .chain(profile_switch_handle)
+            .collect();
+        let _ = subscription.handles.set(handles);
+        receiver
+    }
+}
@@ -1,6 +1,9 @@
-use crate::config::{SettingMetadata, SettingsSchema};
+use crate::config::{
+    CategoryMeta, SettingMetadata, SettingType, SettingsSchema, meta, parse_duration,
+};
 use crate::error::{Error, Result};
 use crate::manager::core::SettingsManager;
+use crate::manager::events::ListenerHandle;
 use crate::storage::StorageBackend;
 use crate::sub_settings::{SubSettings, SubSettingsConfig};
 use crate::utils::sync::RwLockExt;
@@ -13,7 +16,83 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Stands in for secret values in [`SettingsManager::diff_from_defaults`] and
+/// [`SettingsManager::effective_settings_redacted`]
+pub const REDACTED_SECRET: &str = "<redacted>";
+
+/// Output of [`SettingsManager::export_flat_redacted`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RedactedExport {
+    /// Exported non-default settings keyed by `category.key`, after redaction
+    pub values: HashMap<String, Value>,
+    /// Keys whose value was rewritten or dropped by the redaction callback, sorted
+    pub redacted: Vec<String>,
+}
+
+impl RedactedExport {
+    /// Whether the redaction callback changed anything
+    #[must_use]
+    pub fn is_sanitized(&self) -> bool {
+        !self.redacted.is_empty()
+    }
+}
+
 impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Schema> {
+    /// Effective value of every schema key, for diffing around a bulk change
+    /// such as a profile switch or a reload from disk.
+    #[cfg(any(feature = "profiles", feature = "hot-reload"))]
+    pub(crate) fn capture_effective_values(&self) -> HashMap<String, Value> {
+        let mut values = HashMap::new();
+
+        for full_key in self.schema_metadata.keys() {
+            match self.get_value(full_key) {
+                Ok(value) => {
+                    values.insert(full_key.clone(), value);
+                }
+                Err(err) => {
+                    debug!(
+                        "Skipping '{full_key}' in change-event snapshot due to read error: {err}"
+                    );
+                }
+            }
+        }
+
+        values
+    }
+
+    /// Notify change listeners of every key whose effective value differs
+    /// between two [`capture_effective_values`](Self::capture_effective_values) snapshots.
+    #[cfg(any(feature = "profiles", feature = "hot-reload"))]
+    pub(crate) fn notify_value_changes(
+        &self,
+        before: &HashMap<String, Value>,
+        after: &HashMap<String, Value>,
+    ) {
+        for (full_key, metadata) in self.schema_metadata.iter() {
+            let old_value = before
+                .get(full_key)
+                .cloned()
+                .unwrap_or_else(|| metadata.default.clone());
+            let new_value = after
+                .get(full_key)
+                .cloned()
+                .unwrap_or_else(|| metadata.default.clone());
+
+            if old_value != new_value {
+                self.events.notify(full_key, &old_value, &new_value);
+            }
+        }
+    }
+
+    /// Default of `key` as the app sees it: the fallback source value if
+    /// there is one, otherwise the schema default.
+    pub(crate) fn effective_default(&self, key: &str, metadata: &SettingMetadata) -> Result<Value> {
+        Ok(self
+            .settings_cache
+            .get_default(key)?
+            .unwrap_or_else(|| metadata.default.clone()))
+    }
+
     pub(crate) fn parse_setting_key(key: &str) -> Option<(&str, &str)> {
         let mut parts = key.split('.');
         let category = parts.next()?;
@@ -30,10 +109,12 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
     ///
     /// This centralizes the logic for retrieving values that may be stored in
     /// the keyring (for secrets) or in the file cache (for normal settings).
+    /// `secrets` holds values read beforehand with one batch lookup.
     fn get_value_with_secret_support(
         &self,
         key: &str,
         metadata: &SettingMetadata,
+        secrets: Option<&HashMap<String, Option<String>>>,
     ) -> Result<Option<(Value, bool)>> {
         if cfg!(any(feature = "keychain", feature = "encrypted-file")) && metadata.is_secret() {
             // Check env var override for secrets if enabled
@@ -45,9 +126,17 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
 
             // Try retrieving from keyring
             #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
-            if let Ok(Some(secret_value)) = self.get_credential_with_profile(key) {
-                return Ok(Some((Value::String(secret_value), false)));
+            {
+                let secret = match secrets.and_then(|secrets| secrets.get(key)) {
+                    Some(prefetched) => prefetched.clone(),
+                    None => self.get_credential_with_profile(key).ok().flatten(),
+                };
+                if let Some(secret_value) = secret {
+                    return Ok(Some((Value::String(secret_value), false)));
+                }
             }
+            #[cfg(not(any(feature = "keychain", feature = "encrypted-file")))]
+            let _ = secrets;
 
             // Secret not found, use default
             return Ok(Some((metadata.default.clone(), false)));
@@ -71,8 +160,18 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
     /// Check if a setting value is overridden by an environment variable
     ///
     /// Returns the parsed value if env var is set and successfully parsed.
+    /// Duration settings also accept text like `30s`.
     pub(crate) fn get_env_override(&self, key: &str) -> Option<Value> {
-        self.env_handler.get_env_override(key)
+        let value = self.env_handler.get_env_override(key)?;
+        if let Some(text) = value.as_str()
+            && self
+                .schema_metadata
+                .get(key)
+                .is_some_and(|meta| meta.setting_type == SettingType::Duration)
+        {
+            return Some(parse_duration(text).map_or(value.clone(), Value::from));
+        }
+        Some(value)
     }
 
     /// Get all setting metadata with current values populated.
@@ -95,9 +194,14 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         // Get metadata and populate values
         let mut metadata = (*self.schema_metadata).clone();
 
+        #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+        let secrets = self.prefetch_secrets();
+        #[cfg(not(any(feature = "keychain", feature = "encrypted-file")))]
+        let secrets = None;
+
         for (key, option) in &mut metadata {
             if Self::parse_setting_key(key).is_some() {
-                match self.get_value_with_secret_support(key, option) {
+                match self.get_value_with_secret_support(key, option, secrets.as_ref()) {
                     Ok(Some((value, env_overridden))) => {
                         option.value = Some(value);
                         if env_overridden {
@@ -105,6 +209,10 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
                                 .metadata
                                 .insert("env_override".to_string(), Value::Bool(true));
                             debug!("Setting {key} overridden by env var");
+                        } else if self.settings_cache.is_clamped(key)? {
+                            option
+                                .metadata
+                                .insert(meta::CLAMPED.to_string(), Value::Bool(true));
                         }
                     }
                     Ok(None) => {
@@ -123,8 +231,176 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         Ok(metadata)
     }
 
+    /// Get display metadata for every settings category.
+    ///
+    /// Returns the metadata registered with `with_category`, plus an entry
+    /// labelled with the category name for each schema category that has none,
+    /// so a UI can build its tabs from this map alone.
+    #[must_use]
+    pub fn category_metadata(&self) -> HashMap<String, CategoryMeta> {
+        let mut categories = self.config.categories.clone();
+        for key in self.schema_metadata.keys() {
+            if let Some((category, _)) = Self::parse_setting_key(key) {
+                categories
+                    .entry(category.to_string())
+                    .or_insert_with(|| CategoryMeta::new(category));
+            }
+        }
+        categories
+    }
+
+    /// Watch a setting, optionally running the callback once right away.
+    ///
+    /// Registers `callback` like [`EventManager::watch`](crate::EventManager::watch).
+    /// When `fire_immediately` is true, the callback is also invoked
+    /// synchronously before this returns, with `old == new == current value`,
+    /// so setup code can reuse the change handler.
+    ///
+    /// The returned handle unregisters the callback through
+    /// [`EventManager::remove_listener`](crate::EventManager::remove_listener).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is not in the schema or its current value
+    /// cannot be read; the callback is not registered then.
+    pub fn watch_key<F>(
+        &self,
+        key: &str,
+        fire_immediately: bool,
+        callback: F,
+    ) -> Result<ListenerHandle>
+    where
+        F: Fn(&str, &Value, &Value) + Send + Sync + 'static,
+    {
+        if !self.schema_metadata.contains_key(key) {
+            return Err(Error::SettingNotFound(key.to_string()));
+        }
+
+        let callback = Arc::new(callback);
+        let listener = Arc::clone(&callback);
+        let handle = self
+            .events
+            .watch(key, move |key, old, new| listener(key, old, new));
+
+        if fire_immediately {
+            let current = self.get_value(key).inspect_err(|_| {
+                self.events.remove_listener(handle);
+            })?;
+            callback(key, &current, &current);
+        }
+        Ok(handle)
+    }
+
+    /// Watch every setting in a category, optionally running the callback
+    /// once per setting right away.
+    ///
+    /// When `fire_immediately` is true, the callback is invoked synchronously
+    /// for each setting in the category (sorted by key) with
+    /// `old == new == current value` before this returns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema has no settings in `category` or a
+    /// current value cannot be read; the callback is not registered then.
+    pub fn watch_category<F>(
+        &self,
+        category: &str,
+        fire_immediately: bool,
+        callback: F,
+    ) -> Result<ListenerHandle>
+    where
+        F: Fn(&str, &Value, &Value) + Send + Sync + 'static,
+    {
+        let prefix = format!("{category}.");
+        let mut keys: Vec<&String> = self
+            .schema_metadata
+            .keys()
+            .filter(|key| key.starts_with(&prefix))
+            .collect();
+        if keys.is_empty() {
+            return Err(Error::SettingNotFound(category.to_string()));
+        }
+        keys.sort();
+
+        let callback = Arc::new(callback);
+        let listener = Arc::clone(&callback);
+        let handle = self
+            .events
+            .watch_category(category, move |key, old, new| listener(key, old, new));
+
+        if fire_immediately {
+            // Read every value first so a failure leaves nothing registered
+            let current = keys
+                .iter()
+                .map(|key| self.get_value(key))
+                .collect::<Result<Vec<_>>>()
+                .inspect_err(|_| {
+                    self.events.remove_listener(handle);
+                })?;
+            for (key, value) in keys.into_iter().zip(current) {
+                callback(key, &value, &value);
+            }
+        }
+        Ok(handle)
+    }
+
+    /// Get the keys of all settings carrying `tag`, sorted.
+    ///
+    /// Tags are attached with [`SettingMetadata::tag`] or `#[setting(tags(...))]`.
+    #[must_use]
+    pub fn find_by_tag(&self, tag: &str) -> Vec<String> {
+        let mut keys: Vec<String> = self
+            .schema_metadata
+            .iter()
+            .filter(|(_, metadata)| metadata.has_tag(tag))
+            .map(|(key, _)| key.clone())
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    /// Whether the [`depends_on`](SettingMetadata::depends_on) condition of
+    /// `key` is met by the current effective values.
+    ///
+    /// Settings without a condition are always active. A setting whose
+    /// controlling setting is itself inactive is inactive too.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `key` or a controlling setting is not in the
+    /// schema, the conditions form a cycle, or values cannot be read.
+    pub fn is_active(&self, key: &str) -> Result<bool> {
+        let mut current = key;
+        let mut seen = Vec::new();
+        loop {
+            let metadata = self
+                .schema_metadata
+                .get(current)
+                .ok_or_else(|| Error::SettingNotFound(current.to_string()))?;
+            let Some(condition) = &metadata.depends_on else {
+                return Ok(true);
+            };
+            if seen.contains(&current) {
+                return Err(Error::Config(format!(
+                    "Setting '{key}' has a depends_on cycle"
+                )));
+            }
+            seen.push(current);
+            if self.get_value(&condition.key)? != condition.value {
+                return Ok(false);
+            }
+            current = &condition.key;
+        }
+    }
+
     /// Get a single setting value by key path.
     ///
+    /// Only this key is resolved: an environment override wins, then the
+    /// stored value (or the credential store for secrets), then the default.
+    /// Unlike [`metadata`](Self::metadata) or [`get_all`](Self::get_all), no
+    /// metadata map or merged settings object is built, so it is cheap to
+    /// poll.
+    ///
     /// # Type Parameters
     ///
     /// * `T` - The type to deserialize the value into
@@ -178,11 +454,49 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
             .ok_or_else(|| Error::SettingNotFound(format!("{category}.{setting_name}")))?;
 
         // Use the helper that handles both secrets and regular settings.
-        self.get_value_with_secret_support(key, setting_metadata)?
+        self.get_value_with_secret_support(key, setting_metadata, None)?
             .map(|(v, _)| v)
             .ok_or_else(|| Error::SettingNotFound(format!("{category}.{setting_name}")))
     }
 
+    /// Get a display-safe string for a setting.
+    ///
+    /// Secret settings are masked (e.g. `sk-...1234`, see
+    /// [`mask_secret`](crate::utils::security::mask_secret)), so a UI can show
+    /// whether a secret is set without exposing it. Non-secret settings are
+    /// returned unmasked; strings as-is and other values as JSON text.
+    ///
+    /// Returns `None` when the value is null, or when a secret is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Setting key in "category.name" format
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the setting doesn't exist or cannot be read.
+    pub fn masked_value(&self, key: &str) -> Result<Option<String>> {
+        let is_secret = self
+            .schema_metadata
+            .get(key)
+            .ok_or_else(|| Error::SettingNotFound(key.to_string()))?
+            .is_secret();
+
+        let text = match self.get_value(key)? {
+            Value::Null => return Ok(None),
+            Value::String(s) => s,
+            other => other.to_string(),
+        };
+
+        if !is_secret {
+            return Ok(Some(text));
+        }
+        if text.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(crate::utils::security::mask_secret(&text)))
+    }
+
     /// Get merged settings as raw JSON.
     ///
     /// # Errors
@@ -190,8 +504,13 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
     /// Returns an error if settings cannot be read.
     pub fn get_all_data(&self) -> Result<Value> {
         self.ensure_cache_populated()?;
-        self.settings_cache
-            .get_or_compute_merged(|stored| Self::merge_with_defaults(stored))
+        self.settings_cache.get_or_compute_merged(|cached| {
+            Self::merge_with_defaults(
+                &cached.stored,
+                &self.config.default_overrides,
+                &cached.fallback,
+            )
+        })
     }
 
     /// Get merged settings struct with caching.
@@ -206,11 +525,250 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         serde_json::from_value(merged).map_err(|e| Error::Parse(e.to_string()))
     }
 
-    /// Internal helper to merge stored settings with schema defaults.
-    pub(crate) fn merge_with_defaults(stored: &Value) -> Result<Value> {
+    /// Export non-default settings as a flat map keyed by dotted path.
+    ///
+    /// Returns entries such as `{"ui.theme": "dark"}` for every setting whose
+    /// value differs from its default, which is the fallback source value if
+    /// one is configured and the schema default otherwise. Values coming from
+    /// environment variable overrides are not exported.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_secrets` - Whether to include secret settings in the output
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if settings cannot be read.
+    pub fn export_flat(&self, include_secrets: bool) -> Result<HashMap<String, Value>> {
+        self.ensure_cache_populated()?;
+
+        let mut flat = HashMap::new();
+        for (key, metadata) in self.schema_metadata.iter() {
+            if metadata.is_secret() && !include_secrets {
+                continue;
+            }
+
+            if let Some((value, false)) = self.get_value_with_secret_support(key, metadata, None)?
+                && value != self.effective_default(key, metadata)?
+            {
+                flat.insert(key.clone(), value);
+            }
+        }
+
+        Ok(flat)
+    }
+
+    /// Export non-default settings for sharing, passing each value through `redact`.
+    ///
+    /// Works like [`export_flat`](Self::export_flat) without secrets. For each
+    /// exported key, `redact` receives the key and value and returns the value
+    /// to export, or `None` to drop the key. Use it for app-specific data that
+    /// is sensitive without being a secret, such as paths containing the user
+    /// name. The keys that were rewritten or dropped are listed in
+    /// [`RedactedExport::redacted`], so a shared config can be marked as
+    /// sanitized.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rcman::SettingsManager;
+    /// # use serde_json::json;
+    /// # let manager = SettingsManager::builder("my-app", "1.0.0").build().unwrap();
+    /// let export = manager.export_flat_redacted(|key, value| {
+    ///     if key.starts_with("paths.") {
+    ///         Some(json!("<redacted>"))
+    ///     } else {
+    ///         Some(value.clone())
+    ///     }
+    /// })?;
+    /// # Ok::<(), rcman::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if settings cannot be read.
+    pub fn export_flat_redacted<F>(&self, redact: F) -> Result<RedactedExport>
+    where
+        F: Fn(&str, &Value) -> Option<Value>,
+    {
+        let mut export = RedactedExport::default();
+        for (key, value) in self.export_flat(false)? {
+            match redact(&key, &value) {
+                Some(redacted) if redacted == value => {
+                    export.values.insert(key, value);
+                }
+                Some(redacted) => {
+                    export.values.insert(key.clone(), redacted);
+                    export.redacted.push(key);
+                }
+                None => export.redacted.push(key),
+            }
+        }
+        export.redacted.sort();
+        Ok(export)
+    }
+
+    /// Settings whose effective value differs from the default.
+    ///
+    /// The default is the fallback source value if there is one, otherwise
+    /// the schema default.
+    /// Maps each such `category.key` to `(default, current)`. Unlike
+    /// [`export_flat`](Self::export_flat), the current value includes
+    /// environment variable overrides, so the result describes what the app
+    /// actually runs with, e.g. for a "customized" indicator or a bug report.
+    ///
+    /// Secrets are reported without reading the credential store: a secret is
+    /// listed when it has a stored value (in the credential store, or in the
+    /// settings file without a credential backend) or an env override, if
+    /// secrets may be overridden, with [`REDACTED_SECRET`] as its current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if settings cannot be read.
+    pub fn diff_from_defaults(&self) -> Result<HashMap<String, (Value, Value)>> {
+        self.ensure_cache_populated()?;
+
+        #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+        let tracked_secrets = if self.credentials.is_some() {
+            self.get_tracked_secrets()?
+        } else {
+            std::collections::HashSet::new()
+        };
+
+        let mut diff = HashMap::new();
+        for (key, metadata) in self.schema_metadata.iter() {
+            let default = self.effective_default(key, metadata)?;
+            if metadata.is_secret() {
+                #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+                let stored = tracked_secrets.contains(key);
+                // Without a credential backend, secrets live in the settings file
+                #[cfg(not(any(feature = "keychain", feature = "encrypted-file")))]
+                let stored = self
+                    .get_value_with_secret_support(key, metadata, None)?
+                    .is_some_and(|(value, _)| value != default);
+                let env_overridden = self.config.env_overrides_secrets
+                    && self
+                        .get_env_override(key)
+                        .is_some_and(|value| value != default);
+                if stored || env_overridden {
+                    let redacted = Value::String(REDACTED_SECRET.to_string());
+                    diff.insert(key.clone(), (default, redacted));
+                }
+                continue;
+            }
+
+            if let Some((value, _)) = self.get_value_with_secret_support(key, metadata, None)?
+                && value != default
+            {
+                diff.insert(key.clone(), (default, value));
+            }
+        }
+
+        Ok(diff)
+    }
+
+    /// Effective settings as a nested `{category: {key: value}}` JSON value
+    /// that is safe to log.
+    ///
+    /// Holds every schema setting with environment variable overrides
+    /// applied. Secret settings are replaced with [`REDACTED_SECRET`] without
+    /// reading the credential store, whether or not a credential backend is
+    /// enabled (without one, secrets live in the settings file).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if settings cannot be read.
+    pub fn effective_settings_redacted(&self) -> Result<Value> {
+        self.ensure_cache_populated()?;
+
+        let mut settings = serde_json::Map::new();
+        for (key, metadata) in self.schema_metadata.iter() {
+            let Some((category, name)) = Self::parse_setting_key(key) else {
+                continue;
+            };
+            let value = if metadata.is_secret() {
+                Value::String(REDACTED_SECRET.to_string())
+            } else {
+                self.get_value_with_secret_support(key, metadata, None)?
+                    .map_or_else(|| metadata.default.clone(), |(value, _)| value)
+            };
+            if let Value::Object(fields) = settings
+                .entry(category)
+                .or_insert_with(|| Value::Object(serde_json::Map::new()))
+            {
+                fields.insert(name.to_string(), value);
+            }
+        }
+        Ok(Value::Object(settings))
+    }
+
+    /// Fingerprint of which settings are customized, safe to send as telemetry.
+    ///
+    /// The result is a 16-digit hex hash of the sorted keys whose value differs
+    /// from the default (as in [`export_flat`](Self::export_flat)). Values never
+    /// enter the hash and secret settings are left out entirely, so the
+    /// fingerprint reveals nothing about what the user configured, only that
+    /// two installs customized the same set of keys. It is stable across runs
+    /// and platforms for the same set of customized keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if settings cannot be read.
+    pub fn config_fingerprint(&self) -> Result<String> {
+        let mut keys: Vec<String> = self.export_flat(false)?.into_keys().collect();
+        keys.sort();
+
+        // FNV-1a: small, dependency-free and unlike `DefaultHasher` guaranteed
+        // not to change between Rust releases
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in keys.iter().flat_map(|key| key.bytes().chain([b'\n'])) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        Ok(format!("{hash:016x}"))
+    }
+
+    /// Resolve `stored` settings the way [`get_value`](Self::get_value) does:
+    /// environment overrides win over stored values, which win over fallback
+    /// source values, default overrides and schema defaults.
+    ///
+    /// Used to compare a settings file other than the loaded one (e.g. one
+    /// about to be restored) against the current effective values.
+    #[cfg(feature = "backup")]
+    pub(crate) fn resolve_effective(&self, stored: &Value) -> Result<Value> {
+        self.ensure_cache_populated()?;
+        let fallback = self.settings_cache.get_fallback()?;
+        let mut resolved =
+            Self::merge_with_defaults(stored, &self.config.default_overrides, &fallback)?;
+
+        for (key, metadata) in self.schema_metadata.iter() {
+            if cfg!(any(feature = "keychain", feature = "encrypted-file"))
+                && metadata.is_secret()
+                && !self.config.env_overrides_secrets
+            {
+                continue;
+            }
+            if let Some(env_value) = self.get_env_override(key) {
+                crate::utils::value::set_path(&mut resolved, key, env_value);
+            }
+        }
+        Ok(resolved)
+    }
+
+    /// Internal helper to merge stored settings over fallback source values,
+    /// default overrides and schema defaults.
+    pub(crate) fn merge_with_defaults(
+        stored: &Value,
+        default_overrides: &HashMap<String, Value>,
+        fallback: &HashMap<String, Value>,
+    ) -> Result<Value> {
         let default = Schema::default();
         let mut merged = serde_json::to_value(&default)?;
 
+        for (key, value) in default_overrides.iter().chain(fallback) {
+            crate::utils::value::set_path(&mut merged, key, value.clone());
+        }
+
         // Merge stored on top of defaults only if stored is an object
         if stored.is_object() {
             crate::utils::value::deep_merge(&mut merged, stored);
@@ -234,6 +792,16 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
     pub fn register_sub_settings(&self, config: SubSettingsConfig) -> Result<()> {
         let name = config.name.clone();
 
+        #[cfg(feature = "profiles")]
+        let config = if config.profiles_enabled && self.config.start_in_default_profile {
+            SubSettingsConfig {
+                start_in_default_profile: true,
+                ..config
+            }
+        } else {
+            config
+        };
+
         #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
         let credentials = self.credentials.clone();
 
@@ -245,6 +813,21 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
             credentials,
         )?);
 
+        #[cfg(feature = "profiles")]
+        {
+            let events = Arc::clone(&self.events);
+            handler.set_on_profile_switch(Arc::new(move |target, from, to| {
+                events.notify_profile_switch(target, from, to);
+            }))?;
+        }
+        {
+            let events = Arc::clone(&self.events);
+            let settings_type = name.clone();
+            handler.set_on_change_forward(Arc::new(move |entry, action| {
+                events.notify_sub_settings_change(&settings_type, entry, action);
+            }))?;
+        }
+
         let mut guard = self.sub_settings.write_recovered()?;
         guard.insert(name.clone(), handler.clone());
 
@@ -255,6 +838,28 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         Ok(())
     }
 
+    /// Unregister a sub-settings type, e.g. when the plugin that owns it unloads.
+    ///
+    /// The handler is removed along with its cache. Files and stored secrets
+    /// are left untouched, so registering the type again picks up the existing
+    /// entries. Handlers previously returned by
+    /// [`sub_settings`](Self::sub_settings) keep working until dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::SubSettingsNotRegistered` if no type with this name is registered.
+    pub fn unregister_sub_settings(&self, name: &str) -> Result<()> {
+        let handler = self
+            .sub_settings
+            .write_recovered()?
+            .remove(name)
+            .ok_or_else(|| Error::SubSettingsNotRegistered(name.to_string()))?;
+        handler.invalidate_cache();
+
+        info!("Unregistered sub-settings type: {name}");
+        Ok(())
+    }
+
     /// Get a registered sub-settings handler.
     ///
     /// Returns the handler for the specified sub-settings type, which can be used
@@ -337,6 +942,30 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         BackupManager::new(self)
     }
 
+    /// Create a backup holding only settings that differ from their defaults.
+    ///
+    /// Shorthand for creating a backup with [`BackupOptions::customizations_only`]:
+    /// no sub-settings or external configs are included and secrets are
+    /// excluded under the default secret policy. Use `backup().create(...)`
+    /// to add selected sub-settings.
+    ///
+    /// [`BackupOptions::customizations_only`]: crate::BackupOptions::customizations_only
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if settings cannot be read or the backup cannot be written.
+    #[cfg(feature = "backup")]
+    pub fn export_diff_as_backup(
+        &self,
+        output_dir: impl Into<std::path::PathBuf>,
+    ) -> Result<std::path::PathBuf> {
+        self.backup().create(
+            &crate::BackupOptions::new()
+                .output_dir(output_dir)
+                .customizations_only(),
+        )
+    }
+
     /// Get all registered external configs
     ///
     /// Returns the external config files that were registered via
@@ -0,0 +1,299 @@
+//! Coalesced (debounced) setting writes
+//!
+//! [`SettingsManager::save_setting_debounced`] keeps only the latest value per
+//! key and writes it once the key has been quiet for the requested delay, so
+//! bursts of saves (e.g. typing into a secret field) reach the storage backend
+//! and keychain once instead of once per keystroke.
+//!
+//! A single worker thread writes values as they fall due. It runs only while
+//! values are pending and holds no strong reference to the manager while it
+//! waits, so dropping the manager flushes what is left.
+
+use crate::config::SettingsSchema;
+use crate::error::{Error, Result};
+use crate::manager::SettingsManager;
+use crate::storage::StorageBackend;
+
+use log::{debug, warn};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, Weak};
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
+
+/// A value waiting to be written
+struct PendingWrite {
+    value: Value,
+    generation: u64,
+    due: Instant,
+}
+
+#[derive(Default)]
+struct PendingState {
+    writes: HashMap<String, PendingWrite>,
+    next_generation: u64,
+    /// Key whose value is being written, and the thread writing it
+    in_flight: Option<(String, ThreadId)>,
+    worker_running: bool,
+}
+
+/// Writes scheduled by `save_setting_debounced`, keyed by full setting key
+#[derive(Default)]
+pub(crate) struct PendingWrites {
+    state: Mutex<PendingState>,
+    /// Signalled when a write is scheduled or an in-flight write finishes
+    changed: Condvar,
+    /// Held while taking and writing values, so an older value can never be
+    /// written after a newer one for the same key
+    write_lock: Mutex<()>,
+}
+
+impl PendingWrites {
+    fn state(&self) -> Result<MutexGuard<'_, PendingState>> {
+        self.state.lock().map_err(|_| Error::LockPoisoned)
+    }
+
+    /// Drop the pending value for `full_key` (every key if `None`) ahead of a
+    /// direct write, waiting for an in-flight write of it to finish first.
+    ///
+    /// A write made from the writing thread itself (a change listener) does
+    /// not wait, as that would never finish.
+    fn cancel(&self, full_key: Option<&str>) -> Result<()> {
+        let current = thread::current().id();
+        let mut state = self.state()?;
+        match full_key {
+            Some(full_key) => {
+                state.writes.remove(full_key);
+            }
+            None => state.writes.clear(),
+        }
+        while state.in_flight.as_ref().is_some_and(|(key, thread)| {
+            *thread != current && full_key.is_none_or(|full_key| full_key == key)
+        }) {
+            state = self.changed.wait(state).map_err(|_| Error::LockPoisoned)?;
+        }
+        Ok(())
+    }
+
+    /// Remove the value for `full_key` and mark it in flight.
+    ///
+    /// With `generation`, only that exact value is taken.
+    fn take(&self, full_key: &str, generation: Option<u64>) -> Result<Option<Value>> {
+        let mut state = self.state()?;
+        if generation.is_some_and(|generation| {
+            state
+                .writes
+                .get(full_key)
+                .is_none_or(|write| write.generation != generation)
+        }) {
+            return Ok(None);
+        }
+        let Some(write) = state.writes.remove(full_key) else {
+            return Ok(None);
+        };
+        state.in_flight = Some((full_key.to_string(), thread::current().id()));
+        Ok(Some(write.value))
+    }
+
+    fn finish(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.in_flight = None;
+        }
+        self.changed.notify_all();
+    }
+}
+
+impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Schema> {
+    /// Write every pending debounced value now.
+    ///
+    /// All pending writes are attempted even if one fails; the first error is
+    /// returned. Pending values are also written when the manager is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error raised while saving a pending value.
+    pub fn flush_pending(&self) -> Result<()> {
+        let _write_guard = self
+            .pending_writes
+            .write_lock
+            .lock()
+            .map_err(|_| Error::LockPoisoned)?;
+        let mut keys: Vec<String> = self
+            .pending_writes
+            .state()?
+            .writes
+            .keys()
+            .cloned()
+            .collect();
+        keys.sort();
+
+        let mut first_error = None;
+        for full_key in keys {
+            if let Err(e) = self.write_pending(&full_key, None) {
+                warn!("Failed to flush pending write for {full_key}: {e}");
+                first_error.get_or_insert(e);
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Check whether a debounced write is waiting for `category.key`.
+    #[must_use]
+    pub fn has_pending(&self, category: &str, key: &str) -> bool {
+        let full_key = format!("{category}.{key}");
+        self.pending_writes.state.lock().is_ok_and(|pending| {
+            pending.writes.contains_key(&full_key)
+                || pending
+                    .in_flight
+                    .as_ref()
+                    .is_some_and(|(key, _)| *key == full_key)
+        })
+    }
+
+    pub(super) fn flush_pending_on_drop(&self) {
+        if let Err(e) = self.flush_pending() {
+            warn!("Failed to write pending debounced settings on drop: {e}");
+        }
+    }
+
+    /// Drop the debounced value pending for `full_key` (or for every key),
+    /// so it cannot land after a direct write.
+    pub(super) fn cancel_pending(&self, full_key: Option<&str>) -> Result<()> {
+        self.pending_writes.cancel(full_key)
+    }
+
+    /// Write the pending value of `full_key`, if it is still there.
+    ///
+    /// With `generation`, only that exact value is written.
+    fn write_pending(&self, full_key: &str, generation: Option<u64>) -> Result<()> {
+        let Some(value) = self.pending_writes.take(full_key, generation)? else {
+            return Ok(());
+        };
+
+        debug!("Writing debounced value for {full_key}");
+        let result = Self::parse_setting_key(full_key)
+            .ok_or_else(|| Error::SettingNotFound(full_key.to_string()))
+            .and_then(|(category, key)| {
+                if self.events.has_async_validators(full_key) {
+                    return Err(Error::AsyncValidationRequired(full_key.to_string()));
+                }
+                self.save_setting_checked(category, key, &value, true)
+            });
+        self.pending_writes.finish();
+        result.map(drop)
+    }
+}
+
+impl<S: StorageBackend, Schema: SettingsSchema> Drop for SettingsManager<S, Schema> {
+    fn drop(&mut self) {
+        (self.flush_on_drop)(self);
+    }
+}
+
+impl<S: StorageBackend + 'static, Schema: SettingsSchema + Send + Sync + 'static>
+    SettingsManager<S, Schema>
+{
+    /// Save a setting after `delay`, coalescing rapid successive saves.
+    ///
+    /// The value is validated immediately, but the write is deferred: if
+    /// another value for the same key arrives within `delay`, it replaces the
+    /// pending one and the timer restarts. Only the last value is written,
+    /// which keeps slow backends such as the OS keychain from seeing a write
+    /// per keystroke. Reads return the previously saved value until the write
+    /// happens; use [`flush_pending`](Self::flush_pending) to write immediately.
+    ///
+    /// A direct save or reset of the same key replaces the pending value.
+    /// Values still pending when the manager is dropped are written then.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is not in the schema or the value fails
+    /// validation. Errors from the deferred write itself are logged.
+    pub fn save_setting_debounced(
+        self: &Arc<Self>,
+        category: &str,
+        key: &str,
+        value: &Value,
+        delay: Duration,
+    ) -> Result<()> {
+        let full_key = format!("{category}.{key}");
+        let setting_meta = self
+            .schema_metadata
+            .get(&full_key)
+            .ok_or_else(|| Error::SettingNotFound(full_key.clone()))?;
+        setting_meta
+            .validate(value)
+            .map_err(|reason| Error::InvalidSettingValue {
+                key: full_key.clone(),
+                reason,
+            })?;
+
+        let mut pending = self.pending_writes.state()?;
+        pending.next_generation += 1;
+        let generation = pending.next_generation;
+        pending.writes.insert(
+            full_key,
+            PendingWrite {
+                value: value.clone(),
+                generation,
+                due: Instant::now() + delay,
+            },
+        );
+        if !pending.worker_running {
+            pending.worker_running = true;
+            let manager = Arc::downgrade(self);
+            let writes = Arc::clone(&self.pending_writes);
+            thread::spawn(move || Self::run_pending_worker(&manager, &writes));
+        }
+        drop(pending);
+        self.pending_writes.changed.notify_all();
+
+        Ok(())
+    }
+
+    /// Write pending values as they fall due, until none are left or the
+    /// manager is dropped.
+    fn run_pending_worker(manager: &Weak<Self>, writes: &PendingWrites) {
+        loop {
+            let due = {
+                let Ok(mut state) = writes.state.lock() else {
+                    return;
+                };
+                loop {
+                    let now = Instant::now();
+                    let Some(next) = state.writes.values().map(|write| write.due).min() else {
+                        state.worker_running = false;
+                        return;
+                    };
+                    if next <= now {
+                        let mut due: Vec<(String, u64)> = state
+                            .writes
+                            .iter()
+                            .filter(|(_, write)| write.due <= now)
+                            .map(|(key, write)| (key.clone(), write.generation))
+                            .collect();
+                        due.sort_by_key(|(_, generation)| *generation);
+                        break due;
+                    }
+                    let Ok((next_state, _)) = writes.changed.wait_timeout(state, next - now) else {
+                        return;
+                    };
+                    state = next_state;
+                }
+            };
+
+            let Some(manager) = manager.upgrade() else {
+                return;
+            };
+            let Ok(_write_guard) = writes.write_lock.lock() else {
+                return;
+            };
+            for (full_key, generation) in due {
+                if let Err(e) = manager.write_pending(&full_key, Some(generation)) {
+                    warn!("Debounced write for {full_key} failed: {e}");
+                }
+            }
+        }
+    }
+}
@@ -1,55 +1,14 @@
 use crate::config::SettingsSchema;
 use crate::error::{Error, Result};
 use crate::manager::core::SettingsManager;
+use crate::manager::events::ListenerHandle;
 use crate::storage::StorageBackend;
 use crate::utils::sync::RwLockExt; // Import the trait for read_recovered/write_recovered
 use log::{debug, warn};
-use serde_json::Value;
-use std::collections::HashMap;
 use std::sync::Arc;
 
 #[cfg(feature = "profiles")]
 impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Schema> {
-    fn capture_effective_values_for_profile_events(&self) -> HashMap<String, Value> {
-        let mut values = HashMap::new();
-
-        for full_key in self.schema_metadata.keys() {
-            match self.get_value(full_key) {
-                Ok(value) => {
-                    values.insert(full_key.clone(), value);
-                }
-                Err(err) => {
-                    debug!(
-                        "Skipping profile-switch event snapshot for '{full_key}' due to read error: {err}"
-                    );
-                }
-            }
-        }
-
-        values
-    }
-
-    fn emit_profile_switch_setting_events(
-        &self,
-        before: &HashMap<String, Value>,
-        after: &HashMap<String, Value>,
-    ) {
-        for (full_key, metadata) in self.schema_metadata.iter() {
-            let old_value = before
-                .get(full_key)
-                .cloned()
-                .unwrap_or_else(|| metadata.default.clone());
-            let new_value = after
-                .get(full_key)
-                .cloned()
-                .unwrap_or_else(|| metadata.default.clone());
-
-            if old_value != new_value {
-                self.events.notify(full_key, &old_value, &new_value);
-            }
-        }
-    }
-
     /// Check if profiles are enabled for main settings
     pub fn is_profiles_enabled(&self) -> bool {
         self.profile_manager.is_some()
@@ -87,7 +46,8 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
             .as_ref()
             .ok_or(Error::ProfilesNotEnabled)?;
 
-        let before_values = self.capture_effective_values_for_profile_events();
+        let before_values = self.capture_effective_values();
+        let from = pm.active()?;
 
         // Step 1: Switch the profile in ProfileManager (this handles manifest updates)
         // This must be done first to ensure the profile exists and is valid
@@ -144,12 +104,53 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
             }
         }
 
-        let after_values = self.capture_effective_values_for_profile_events();
-        self.emit_profile_switch_setting_events(&before_values, &after_values);
+        let after_values = self.capture_effective_values();
+        self.notify_value_changes(&before_values, &after_values);
+
+        if from != name {
+            self.events.notify_profile_switch("settings", &from, name);
+        }
 
         Ok(())
     }
 
+    /// Register a listener for profile switches of main settings and every
+    /// sub-settings type
+    ///
+    /// The callback receives (`target`, `from`, `to`), where `target` is
+    /// `"settings"` for main settings or the sub-settings name. It covers
+    /// sub-settings registered after this call and switches made directly
+    /// through [`SubSettings::switch_profile`](crate::SubSettings::switch_profile).
+    ///
+    /// During [`switch_profile`](Self::switch_profile) each sub-settings type is
+    /// reported first and `"settings"` last, so the `"settings"` event marks the
+    /// whole switch as complete.
+    ///
+    /// Returns a handle for [`EventManager::remove_listener`](crate::EventManager::remove_listener).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rcman::*;
+    /// # let temp = tempfile::tempdir().unwrap();
+    /// # let manager = SettingsManager::builder("test", "1.0")
+    /// #     .with_config_dir(temp.path())
+    /// #     .with_profiles()
+    /// #     .build()
+    /// #     .unwrap();
+    /// manager.on_any_profile_switch(|target, from, to| {
+    ///     if target == "settings" {
+    ///         println!("Switched profile {from} -> {to}, re-syncing app state");
+    ///     }
+    /// });
+    /// ```
+    pub fn on_any_profile_switch<F>(&self, callback: F) -> ListenerHandle
+    where
+        F: Fn(&str, &str, &str) + Send + Sync + 'static,
+    {
+        self.events.on_profile_switch(callback)
+    }
+
     /// Create a new profile for main settings
     ///
     /// # Arguments
@@ -204,7 +205,11 @@ impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Sch
         pm.list()
     }
 
-    /// Get the active profile name
+    /// Get the active profile name of main settings
+    ///
+    /// Main settings and every profiled sub-settings type share the active
+    /// profile once it is changed through [`switch_profile`](Self::switch_profile).
+    ///
     /// # Errors
     ///
     /// Returns an error if profiles are not enabled or determining the active profile fails.
@@ -0,0 +1,201 @@
+//! Point-in-time copies of the settings state
+//!
+//! [`SettingsManager::snapshot`] captures the stored settings, every
+//! sub-settings entry and the secrets held in the credential store in memory.
+//! The snapshot does not change when settings are saved afterwards, so it can
+//! be backed up later with
+//! [`BackupManager::create_from_snapshot`](crate::backup::BackupManager::create_from_snapshot),
+//! e.g. to keep the state from before a risky operation.
+
+#[cfg(feature = "backup")]
+use crate::config::SettingMetadata;
+use crate::config::SettingsSchema;
+use crate::error::Result;
+use crate::manager::SettingsManager;
+use crate::storage::StorageBackend;
+
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, HashMap};
+#[cfg(feature = "backup")]
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+/// Settings state captured by [`SettingsManager::snapshot`]
+///
+/// Secrets held in the credential store are captured too, so a backup
+/// created from the snapshot holds the secrets of that moment if its secret
+/// policy includes them. They are not exposed by the accessors and are
+/// redacted from the `Debug` output.
+#[derive(Clone)]
+pub struct SettingsSnapshot {
+    pub(crate) taken_at: OffsetDateTime,
+    /// Settings as stored, without defaults
+    pub(crate) settings: Value,
+    /// Settings that differ from their defaults, keyed by `category.key`
+    pub(crate) customized: HashMap<String, Value>,
+    /// Active profile of the main settings, if profiles are enabled
+    pub(crate) profile: Option<String>,
+    pub(crate) sub_settings: BTreeMap<String, SubSettingsSnapshot>,
+    /// Secrets from the credential store, keyed by credential key
+    pub(crate) secrets: HashMap<String, String>,
+}
+
+impl std::fmt::Debug for SettingsSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SettingsSnapshot")
+            .field("taken_at", &self.taken_at)
+            .field("settings", &self.settings)
+            .field("customized", &self.customized)
+            .field("profile", &self.profile)
+            .field("sub_settings", &self.sub_settings)
+            .field(
+                "secrets",
+                &format_args!("<{} redacted>", self.secrets.len()),
+            )
+            .finish()
+    }
+}
+
+/// Entries of one sub-settings type at snapshot time
+#[derive(Debug, Clone)]
+pub(crate) struct SubSettingsSnapshot {
+    pub(crate) entries: BTreeMap<String, Value>,
+    #[cfg(feature = "backup")]
+    pub(crate) extension: String,
+    #[cfg(feature = "backup")]
+    pub(crate) schema: Option<Arc<HashMap<String, SettingMetadata>>>,
+    /// File name of the store in single-file mode
+    #[cfg(feature = "backup")]
+    pub(crate) single_file: Option<String>,
+    /// Active profile, if the type has profiles enabled
+    #[cfg(feature = "backup")]
+    pub(crate) profile: Option<String>,
+}
+
+impl SettingsSnapshot {
+    /// When the snapshot was taken.
+    #[must_use]
+    pub fn taken_at(&self) -> OffsetDateTime {
+        self.taken_at
+    }
+
+    /// The captured settings as stored, without defaults or secrets from the
+    /// credential store.
+    #[must_use]
+    pub fn settings(&self) -> &Value {
+        &self.settings
+    }
+
+    /// The captured entries of a sub-settings type as stored, or `None` if
+    /// the type was not registered.
+    #[must_use]
+    pub fn sub_settings(&self, settings_type: &str) -> Option<&BTreeMap<String, Value>> {
+        self.sub_settings.get(settings_type).map(|sub| &sub.entries)
+    }
+}
+
+impl<S: StorageBackend + 'static, Schema: SettingsSchema> SettingsManager<S, Schema> {
+    /// Capture the current settings, sub-settings entries and secrets.
+    ///
+    /// Pending deferred writes are included, since the snapshot reads through
+    /// the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if settings, sub-settings or the credential store
+    /// cannot be read.
+    pub fn snapshot(&self) -> Result<SettingsSnapshot> {
+        self.ensure_cache_populated()?;
+        let settings = self
+            .settings_cache
+            .get_stored()?
+            .unwrap_or_else(|| json!({}));
+
+        #[cfg(feature = "profiles")]
+        let profile = if self.config.profiles_enabled {
+            Some(self.active_profile()?)
+        } else {
+            None
+        };
+        #[cfg(not(feature = "profiles"))]
+        let profile = None;
+
+        #[cfg_attr(
+            not(any(feature = "keychain", feature = "encrypted-file")),
+            allow(unused_mut)
+        )]
+        let mut secrets = HashMap::new();
+        #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+        if let Some(creds) = self.credentials() {
+            for (key, metadata) in self.schema_metadata.iter() {
+                if metadata.is_secret()
+                    && let Some(secret) = creds.get_with_profile(key, profile.as_deref())?
+                {
+                    secrets.insert(key.clone(), secret);
+                }
+            }
+        }
+
+        let mut sub_settings = BTreeMap::new();
+        for settings_type in self.sub_settings_types() {
+            let sub = self.sub_settings(&settings_type)?;
+            #[cfg(any(feature = "backup", feature = "keychain", feature = "encrypted-file"))]
+            let schema = sub.schema_metadata();
+            let entries: BTreeMap<String, Value> = sub.get_all_stored()?.into_iter().collect();
+
+            #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+            if let (Some(creds), Some(schema)) = (self.credentials(), &schema) {
+                let secret_profile = sub.active_secret_profile();
+                for name in entries.keys() {
+                    for (path, metadata) in schema.iter() {
+                        let key = sub.secret_credential_key(name, path);
+                        if metadata.is_secret()
+                            && let Some(secret) =
+                                creds.get_with_profile(&key, secret_profile.as_deref())?
+                        {
+                            secrets.insert(key, secret);
+                        }
+                    }
+                }
+            }
+
+            #[cfg(all(feature = "backup", feature = "profiles"))]
+            let profile = if sub.profiles_enabled() {
+                Some(sub.profiles()?.active()?)
+            } else {
+                None
+            };
+            #[cfg(all(feature = "backup", not(feature = "profiles")))]
+            let profile = None;
+
+            #[cfg(feature = "backup")]
+            let single_file = sub
+                .file_path()
+                .and_then(|path| path.file_name().map(|n| n.to_string_lossy().to_string()));
+
+            sub_settings.insert(
+                settings_type,
+                SubSettingsSnapshot {
+                    entries,
+                    #[cfg(feature = "backup")]
+                    extension: sub.extension().to_string(),
+                    #[cfg(feature = "backup")]
+                    schema,
+                    #[cfg(feature = "backup")]
+                    single_file,
+                    #[cfg(feature = "backup")]
+                    profile,
+                },
+            );
+        }
+
+        Ok(SettingsSnapshot {
+            taken_at: OffsetDateTime::now_utc(),
+            settings,
+            customized: self.export_flat(false)?,
+            profile,
+            sub_settings,
+            secrets,
+        })
+    }
+}
@@ -8,6 +8,7 @@ use crate::utils::sync::RwLockExt;
 
 use log::{debug, info};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 
@@ -20,23 +21,91 @@ pub type InvalidateCallback = Arc<dyn Fn() + Send + Sync>;
 
 /// Profile manifest stored in `.profiles.json`
 ///
-/// Tracks which profiles exist and which is currently active.
+/// Tracks which profiles exist, which is currently active, and which one to
+/// open on startup when configured to start in the default profile.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProfileManifest {
     /// Currently active profile name
     pub active: String,
 
+    /// Profile opened on startup with `start_in_default_profile`
+    #[serde(default = "default_profile_name")]
+    default: String,
+
     /// List of all profile names
     pub profiles: Vec<String>,
+
+    /// Descriptive metadata, keyed by profile name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    meta: HashMap<String, ProfileMeta>,
+}
+
+fn default_profile_name() -> String {
+    DEFAULT_PROFILE.to_string()
 }
 
 impl Default for ProfileManifest {
     fn default() -> Self {
         Self {
             active: DEFAULT_PROFILE.to_string(),
+            default: DEFAULT_PROFILE.to_string(),
             profiles: vec![DEFAULT_PROFILE.to_string()],
+            meta: HashMap::new(),
+        }
+    }
+}
+
+/// Descriptive metadata of a profile (e.g. for a profile switcher UI)
+///
+/// Stored in the profile manifest; rcman itself does not interpret it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileMeta {
+    /// Optional description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Optional accent color (e.g. `"#3b82f6"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Optional icon identifier
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// When the metadata was created (RFC 3339)
+    #[serde(default)]
+    pub created_at: String,
+}
+
+impl ProfileMeta {
+    /// Create empty metadata stamped with the current time
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            created_at: time::OffsetDateTime::now_utc()
+                .format(&time::format_description::well_known::Rfc3339)
+                .unwrap_or_default(),
+            ..Default::default()
         }
     }
+
+    /// Set the description
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the accent color
+    #[must_use]
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the icon identifier
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
 }
 
 impl ProfileManifest {
@@ -60,9 +129,15 @@ impl ProfileManifest {
     }
 
     /// Remove a profile from the manifest
+    ///
+    /// If it was the default profile, the active profile becomes the default.
     pub fn remove_profile(&mut self, name: &str) -> bool {
         if let Some(pos) = self.profiles.iter().position(|p| p == name) {
             self.profiles.remove(pos);
+            self.meta.remove(name);
+            if self.default == name {
+                self.default.clone_from(&self.active);
+            }
             true
         } else {
             false
@@ -73,6 +148,12 @@ impl ProfileManifest {
     pub fn rename_profile(&mut self, from: &str, to: String) -> bool {
         if let Some(pos) = self.profiles.iter().position(|p| p == from) {
             self.profiles[pos].clone_from(&to);
+            if let Some(meta) = self.meta.remove(from) {
+                self.meta.insert(to.clone(), meta);
+            }
+            if self.default == from {
+                self.default.clone_from(&to);
+            }
             if self.active == from {
                 self.active = to;
             }
@@ -91,6 +172,28 @@ impl ProfileManifest {
             false
         }
     }
+
+    /// Descriptive metadata of a profile, if any was set
+    #[must_use]
+    pub fn meta(&self, name: &str) -> Option<&ProfileMeta> {
+        self.meta.get(name)
+    }
+
+    /// The profile opened on startup
+    #[must_use]
+    pub fn default_profile(&self) -> &str {
+        &self.default
+    }
+
+    /// Set the profile opened on startup
+    pub fn set_default(&mut self, name: &str) -> bool {
+        if self.has_profile(name) {
+            self.default = name.to_string();
+            true
+        } else {
+            false
+        }
+    }
 }
 
 // =============================================================================
@@ -131,6 +234,13 @@ pub enum ProfileEvent {
         /// New profile name
         target: String,
     },
+    /// Profile contents were copied into another existing profile
+    Copied {
+        /// Source profile
+        source: String,
+        /// Profile that received the contents
+        target: String,
+    },
 }
 
 // =============================================================================
@@ -224,6 +334,20 @@ impl<S: StorageBackend> ProfileManager<S> {
         storage: S,
         enabled: bool,
         migrator: &crate::profiles::ProfileMigrator,
+    ) -> Result<(PathBuf, Option<Self>)> {
+        Self::initialize_with(config_dir, target_name, storage, enabled, false, migrator)
+    }
+
+    /// Like [`initialize`](Self::initialize), but activates the manifest's
+    /// default profile instead of the last active one if `start_in_default`
+    /// is set.
+    pub(crate) fn initialize_with(
+        config_dir: &Path,
+        target_name: &str,
+        storage: S,
+        enabled: bool,
+        start_in_default: bool,
+        migrator: &crate::profiles::ProfileMigrator,
     ) -> Result<(PathBuf, Option<Self>)> {
         if enabled {
             // Run migration if needed
@@ -232,6 +356,9 @@ impl<S: StorageBackend> ProfileManager<S> {
             crate::profiles::migrate(config_dir, target_name, false, &storage, migrator)?;
 
             let pm = Self::new(config_dir, target_name, storage);
+            if start_in_default {
+                pm.activate_default()?;
+            }
             // Use active path from manifest (defaults to "default")
             let active = pm.active_path()?;
 
@@ -359,7 +486,7 @@ impl<S: StorageBackend> ProfileManager<S> {
         }
 
         // Try to load existing manifest
-        let load_result = if self.manifest_path.exists() {
+        let load_result = if self.storage.exists(&self.manifest_path) {
             // Normal case: load from current manifest path
             self.storage.read(&self.manifest_path).map(Some)
         } else {
@@ -563,6 +690,119 @@ impl<S: StorageBackend> ProfileManager<S> {
         Ok(())
     }
 
+    /// Get the profile opened on startup with `start_in_default_profile`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be read.
+    pub fn default_profile(&self) -> Result<String> {
+        self.ensure_manifest()?;
+        let guard = self.manifest.read_recovered()?;
+        Ok(guard.as_ref().ok_or(Error::NotInitialized)?.default.clone())
+    }
+
+    /// Set the profile opened on startup with `start_in_default_profile`
+    ///
+    /// Does not change the active profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the profile to open on startup
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile does not exist or the manifest cannot
+    /// be saved.
+    pub fn set_default(&self, name: &str) -> Result<()> {
+        self.ensure_manifest()?;
+        {
+            let mut guard = self.manifest.write_recovered()?;
+            let manifest = guard.as_mut().ok_or(Error::NotInitialized)?;
+            if !manifest.set_default(name) {
+                return Err(Error::ProfileNotFound(name.to_string()));
+            }
+        }
+        self.save_manifest()?;
+
+        info!(
+            "Default profile for '{}' set to '{}'",
+            self.target_name, name
+        );
+        Ok(())
+    }
+
+    /// Get the descriptive metadata of a profile
+    ///
+    /// Returns `None` if no metadata was set for the profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile does not exist or the manifest cannot
+    /// be read.
+    pub fn get_meta(&self, name: &str) -> Result<Option<ProfileMeta>> {
+        self.ensure_manifest()?;
+        let guard = self.manifest.read_recovered()?;
+        let manifest = guard.as_ref().ok_or(Error::NotInitialized)?;
+        if !manifest.has_profile(name) {
+            return Err(Error::ProfileNotFound(name.to_string()));
+        }
+        Ok(manifest.meta.get(name).cloned())
+    }
+
+    /// Set the descriptive metadata of a profile
+    ///
+    /// Replaces any metadata set before. The metadata follows the profile
+    /// when it is renamed and is dropped when it is deleted. No event is
+    /// emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile does not exist or the manifest cannot
+    /// be saved.
+    pub fn set_meta(&self, name: &str, meta: ProfileMeta) -> Result<()> {
+        self.ensure_manifest()?;
+        {
+            let mut guard = self.manifest.write_recovered()?;
+            let manifest = guard.as_mut().ok_or(Error::NotInitialized)?;
+            if !manifest.has_profile(name) {
+                return Err(Error::ProfileNotFound(name.to_string()));
+            }
+            manifest.meta.insert(name.to_string(), meta);
+        }
+        self.save_manifest()?;
+
+        debug!(
+            "Updated metadata of profile '{}' in '{}'",
+            name, self.target_name
+        );
+        Ok(())
+    }
+
+    /// Make the default profile active before anything is loaded.
+    ///
+    /// No events are emitted and no caches are invalidated, since this runs
+    /// during initialization.
+    pub(crate) fn activate_default(&self) -> Result<()> {
+        self.ensure_manifest()?;
+        {
+            let mut guard = self.manifest.write_recovered()?;
+            let manifest = guard.as_mut().ok_or(Error::NotInitialized)?;
+            if manifest.active == manifest.default {
+                return Ok(());
+            }
+            let default = manifest.default.clone();
+            if !manifest.set_active(&default) {
+                log::warn!(
+                    "Default profile '{default}' for '{}' does not exist, keeping '{}'",
+                    self.target_name,
+                    manifest.active
+                );
+                return Ok(());
+            }
+        }
+        self.save_manifest()
+    }
+
     /// Delete a profile
     ///
     /// Removes the profile directory and updates the manifest.
@@ -596,13 +836,7 @@ impl<S: StorageBackend> ProfileManager<S> {
         }
 
         // Delete profile directory
-        let profile_dir = self.profile_path(name);
-        if profile_dir.exists() {
-            std::fs::remove_dir_all(&profile_dir).map_err(|e| Error::FileDelete {
-                path: profile_dir.clone(),
-                source: e,
-            })?;
-        }
+        self.storage.remove_dir(&self.profile_path(name))?;
 
         // Update manifest
         {
@@ -650,25 +884,8 @@ impl<S: StorageBackend> ProfileManager<S> {
         }
 
         // Rename directory
-        let from_dir = self.profile_path(from);
-        let to_dir = self.profile_path(to);
-
-        if from_dir.exists() {
-            std::fs::rename(&from_dir, &to_dir).map_err(|e| Error::FileWrite {
-                path: std::path::PathBuf::from(format!(
-                    "{} -> {}",
-                    from_dir.display(),
-                    to_dir.display()
-                )),
-                source: e,
-            })?;
-        } else {
-            // Create the new directory if old didn't exist
-            std::fs::create_dir_all(&to_dir).map_err(|e| Error::DirectoryCreate {
-                path: to_dir.clone(),
-                source: e,
-            })?;
-        }
+        self.storage
+            .rename_dir(&self.profile_path(from), &self.profile_path(to))?;
 
         // Update manifest
         {
@@ -722,18 +939,12 @@ impl<S: StorageBackend> ProfileManager<S> {
             }
         }
 
-        let source_dir = self.profile_path(source);
-        let target_dir = self.profile_path(target);
-
         // Copy directory contents
-        if source_dir.exists() {
-            copy_dir_recursive(&source_dir, &target_dir)?;
-        } else {
-            std::fs::create_dir_all(&target_dir).map_err(|e| Error::DirectoryCreate {
-                path: target_dir.clone(),
-                source: e,
-            })?;
-        }
+        self.storage.copy_dir(
+            &self.profile_path(source),
+            &self.profile_path(target),
+            false,
+        )?;
 
         // Update manifest
         {
@@ -758,6 +969,64 @@ impl<S: StorageBackend> ProfileManager<S> {
         Ok(())
     }
 
+    /// Copy the contents of a profile into another existing profile
+    ///
+    /// Unlike [`duplicate`](Self::duplicate), the target must already exist.
+    /// Files are merged one by one: a file present in both profiles is
+    /// replaced when `overwrite` is true and kept otherwise, and files only
+    /// present in the target are left alone. Entries of single-file
+    /// sub-settings share one file, so they are copied or kept as a whole.
+    ///
+    /// Managers with `target` active keep serving cached values; switch to
+    /// the profile again to reload them.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The name of the profile to copy from
+    /// * `target` - The name of the existing profile to copy into
+    /// * `overwrite` - Whether files already in `target` are replaced
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::ProfileNotFound` if either profile does not exist, or
+    /// an error if the contents cannot be copied.
+    pub fn copy_into(&self, source: &str, target: &str, overwrite: bool) -> Result<()> {
+        self.ensure_manifest()?;
+
+        {
+            let guard = self.manifest.read_recovered()?;
+            let manifest = guard.as_ref().ok_or(Error::NotInitialized)?;
+
+            for name in [source, target] {
+                if !manifest.has_profile(name) {
+                    return Err(Error::ProfileNotFound(name.to_string()));
+                }
+            }
+        }
+
+        if source == target {
+            return Ok(());
+        }
+
+        self.storage.copy_dir(
+            &self.profile_path(source),
+            &self.profile_path(target),
+            overwrite,
+        )?;
+
+        info!(
+            "Copied profile '{}' into '{}' in '{}'",
+            source, target, self.target_name
+        );
+
+        self.emit_event(ProfileEvent::Copied {
+            source: source.to_string(),
+            target: target.to_string(),
+        });
+
+        Ok(())
+    }
+
     /// Rollback to flat structure (removes all profiles except active)
     ///
     /// # ⚠️ Warning
@@ -828,7 +1097,7 @@ impl<S: StorageBackend> ProfileManager<S> {
         F: FnOnce() -> bool,
     {
         // If manifest exists, nothing to migrate
-        if self.manifest_path.exists() {
+        if self.storage.exists(&self.manifest_path) {
             self.ensure_manifest()?;
             return Ok(false);
         }
@@ -901,43 +1170,6 @@ impl<S: StorageBackend> ProfileManager<S> {
     }
 }
 
-// =============================================================================
-// Helper Functions
-// =============================================================================
-
-/// Recursively copy a directory
-fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
-    if !dst.exists() {
-        std::fs::create_dir_all(dst).map_err(|e| Error::DirectoryCreate {
-            path: dst.to_path_buf(),
-            source: e,
-        })?;
-    }
-
-    for entry in std::fs::read_dir(src).map_err(|e| Error::FileRead {
-        path: src.to_path_buf(),
-        source: e,
-    })? {
-        let entry = entry.map_err(|e| Error::FileRead {
-            path: src.to_path_buf(),
-            source: e,
-        })?;
-        let src_path = entry.path();
-        let dst_path = dst.join(entry.file_name());
-
-        if src_path.is_dir() {
-            copy_dir_recursive(&src_path, &dst_path)?;
-        } else {
-            std::fs::copy(&src_path, &dst_path).map_err(|e| Error::FileWrite {
-                path: dst_path.clone(),
-                source: e,
-            })?;
-        }
-    }
-
-    Ok(())
-}
-
 // =============================================================================
 // Tests
 // =============================================================================
@@ -1018,6 +1250,50 @@ mod tests {
         assert!(matches!(result, Err(Error::CannotDeleteActiveProfile(_))));
     }
 
+    #[test]
+    fn test_profile_meta_persists_and_follows_renames() {
+        let (dir, manager) = create_test_manager();
+        manager.create("work").unwrap();
+        assert_eq!(manager.get_meta("work").unwrap(), None);
+
+        let meta = ProfileMeta::new()
+            .description("Office machines")
+            .color("#3b82f6");
+        assert!(!meta.created_at.is_empty());
+        manager.set_meta("work", meta.clone()).unwrap();
+        assert!(matches!(
+            manager.set_meta("missing", ProfileMeta::new()),
+            Err(Error::ProfileNotFound(_))
+        ));
+
+        // Reload from disk
+        let reloaded =
+            ProfileManager::new(dir.path(), "test", crate::storage::JsonStorage::compact());
+        assert_eq!(reloaded.get_meta("work").unwrap(), Some(meta.clone()));
+
+        reloaded.rename("work", "office").unwrap();
+        assert_eq!(reloaded.get_meta("office").unwrap(), Some(meta));
+        reloaded.delete("office").unwrap();
+        assert!(matches!(
+            reloaded.get_meta("office"),
+            Err(Error::ProfileNotFound(_))
+        ));
+        reloaded.create("office").unwrap();
+        assert_eq!(reloaded.get_meta("office").unwrap(), None);
+    }
+
+    #[test]
+    fn test_manifest_without_meta_deserializes() {
+        let manifest: ProfileManifest =
+            serde_json::from_str(r#"{"active": "work", "profiles": ["default", "work"]}"#).unwrap();
+        assert!(manifest.meta.is_empty());
+        assert_eq!(manifest.default, DEFAULT_PROFILE);
+
+        // Empty metadata is not written, keeping manifests readable by older versions
+        let json = serde_json::to_value(&manifest).unwrap();
+        assert!(json.get("meta").is_none());
+    }
+
     #[test]
     fn test_rename_profile() {
         let (_dir, manager) = create_test_manager();
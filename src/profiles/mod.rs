@@ -71,7 +71,7 @@
 mod manager;
 mod migrator;
 
-pub use manager::{ProfileEvent, ProfileManager, ProfileManifest};
+pub use manager::{ProfileEvent, ProfileManager, ProfileManifest, ProfileMeta};
 pub use migrator::{ProfileMigrator, migrate, rollback_migration};
 
 /// Default profile name used when migrating or initializing
@@ -0,0 +1,224 @@
+//! In-memory storage backend
+//!
+//! [`MemoryStorage`] keeps every value in a map keyed by path instead of
+//! writing files, which makes it a fast backend for tests. Paths keep their
+//! filesystem meaning: an entry of a multi-file sub-settings type lives at
+//! `dir/name.json` and is listed by [`StorageBackend::list_entries`] for `dir`,
+//! and profile directories are moved, copied and removed by path prefix.
+//!
+//! Clones share the same map, so sub-settings and profiles see the data
+//! written through the manager. The manager still creates its (empty) config,
+//! sub-settings and profile directories; only the contents stay in memory.
+//! Backups read files from disk and therefore see none of the data.
+
+use super::StorageBackend;
+use crate::error::{Error, Result};
+
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Storage backend that keeps settings in memory
+///
+/// Values are stored as JSON, so anything that works with
+/// [`JsonStorage`](super::JsonStorage) works here too.
+///
+/// # Example
+///
+/// ```
+/// use rcman::{MemoryStorage, SettingsManager};
+///
+/// let dir = tempfile::tempdir().unwrap();
+/// let storage = MemoryStorage::new();
+/// let manager = SettingsManager::builder("my-app", "1.0.0")
+///     .with_config_dir(dir.path())
+///     .with_storage_backend(storage.clone())
+///     .build()
+///     .unwrap();
+///
+/// assert!(storage.paths().is_empty());
+/// ```
+#[derive(Clone, Default)]
+pub struct MemoryStorage {
+    entries: Arc<Mutex<HashMap<PathBuf, Value>>>,
+}
+
+impl MemoryStorage {
+    /// Create an empty in-memory storage
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Paths of all stored entries, sorted
+    #[must_use]
+    pub fn paths(&self) -> Vec<PathBuf> {
+        let mut paths: Vec<PathBuf> = self
+            .entries
+            .lock()
+            .map(|entries| entries.keys().cloned().collect())
+            .unwrap_or_default();
+        paths.sort();
+        paths
+    }
+
+    /// Remove all stored entries
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, HashMap<PathBuf, Value>>> {
+        self.entries.lock().map_err(|_| Error::LockPoisoned)
+    }
+}
+
+impl StorageBackend for MemoryStorage {
+    fn extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn serialize<T: Serialize>(&self, data: &T) -> Result<String> {
+        serde_json::to_string_pretty(data).map_err(Error::from)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, content: &str) -> Result<T> {
+        serde_json::from_str(content).map_err(Error::from)
+    }
+
+    fn read<T: DeserializeOwned>(&self, path: &Path) -> Result<T> {
+        let value = self
+            .lock()?
+            .get(path)
+            .cloned()
+            .ok_or_else(|| Error::FileRead {
+                path: path.to_path_buf(),
+                source: std::io::ErrorKind::NotFound.into(),
+            })?;
+        serde_json::from_value(value).map_err(Error::from)
+    }
+
+    fn write<T: Serialize>(&self, path: &Path, data: &T) -> Result<()> {
+        let value = serde_json::to_value(data)?;
+        self.lock()?.insert(path.to_path_buf(), value);
+        Ok(())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.entries
+            .lock()
+            .is_ok_and(|entries| entries.contains_key(path))
+    }
+
+    fn remove(&self, path: &Path) -> Result<()> {
+        self.lock()?.remove(path);
+        Ok(())
+    }
+
+    fn list_entries(&self, dir: &Path, extension: &str) -> Result<Vec<String>> {
+        Ok(self
+            .lock()?
+            .keys()
+            .filter(|path| path.parent() == Some(dir))
+            .filter(|path| path.extension().is_some_and(|ext| ext == extension))
+            .filter_map(|path| path.file_stem())
+            .map(|stem| stem.to_string_lossy().to_string())
+            .collect())
+    }
+
+    fn remove_dir(&self, dir: &Path) -> Result<()> {
+        self.lock()?.retain(|path, _| !path.starts_with(dir));
+        Ok(())
+    }
+
+    fn rename_dir(&self, from: &Path, to: &Path) -> Result<()> {
+        let mut entries = self.lock()?;
+        let moved: Vec<PathBuf> = entries
+            .keys()
+            .filter(|path| path.starts_with(from))
+            .cloned()
+            .collect();
+        for path in moved {
+            if let (Some(value), Ok(relative)) = (entries.remove(&path), path.strip_prefix(from)) {
+                entries.insert(to.join(relative), value);
+            }
+        }
+        Ok(())
+    }
+
+    fn copy_dir(&self, from: &Path, to: &Path, overwrite: bool) -> Result<()> {
+        let mut entries = self.lock()?;
+        let copies: Vec<(PathBuf, Value)> = entries
+            .iter()
+            .filter_map(|(path, value)| {
+                let relative = path.strip_prefix(from).ok()?;
+                Some((to.join(relative), value.clone()))
+            })
+            .filter(|(path, _)| overwrite || !entries.contains_key(path))
+            .collect();
+        entries.extend(copies);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_entries_follow_path_semantics() {
+        let storage = MemoryStorage::new();
+        let root = Path::new("/config/remotes");
+
+        storage
+            .write(&root.join("a.json"), &json!({"x": 1}))
+            .unwrap();
+        storage
+            .write(&root.join("b.json"), &json!({"x": 2}))
+            .unwrap();
+        storage
+            .write(&root.join("nested/c.json"), &json!({"x": 3}))
+            .unwrap();
+        storage
+            .write(&root.join("d.toml"), &json!({"x": 4}))
+            .unwrap();
+
+        let mut names = storage.list_entries(root, "json").unwrap();
+        names.sort();
+        assert_eq!(names, vec!["a", "b"]);
+        assert_eq!(
+            storage.read::<Value>(&root.join("a.json")).unwrap(),
+            json!({"x": 1})
+        );
+        assert!(matches!(
+            storage.read::<Value>(&root.join("missing.json")),
+            Err(Error::FileRead { source, .. }) if source.kind() == std::io::ErrorKind::NotFound
+        ));
+
+        storage
+            .copy_dir(root, Path::new("/config/copy"), false)
+            .unwrap();
+        assert!(storage.exists(Path::new("/config/copy/nested/c.json")));
+
+        storage
+            .rename_dir(Path::new("/config/copy"), Path::new("/config/moved"))
+            .unwrap();
+        assert!(!storage.exists(Path::new("/config/copy/a.json")));
+        assert!(storage.exists(Path::new("/config/moved/a.json")));
+
+        storage.remove_dir(root).unwrap();
+        storage.remove(Path::new("/config/moved/b.json")).unwrap();
+        assert_eq!(
+            storage.paths(),
+            vec![
+                PathBuf::from("/config/moved/a.json"),
+                PathBuf::from("/config/moved/d.toml"),
+                PathBuf::from("/config/moved/nested/c.json"),
+            ]
+        );
+    }
+}
@@ -9,11 +9,33 @@
 //! Adding a new backend only requires implementing `extension`, `serialize`,
 //! and `deserialize`; `read` and `write` have sensible defaults that work for
 //! any single-file format. Backends that need richer storage (e.g. a database)
-//! override `read` and `write` instead.
+//! override `read` and `write` instead, along with `exists`, `remove` and
+//! `list_entries` so that multi-file sub-settings never touch the filesystem
+//! directly, and `remove_dir`, `rename_dir` and `copy_dir` for profiles.
+//!
+//! # Batching
+//!
+//! Callers that perform several writes in a row (e.g. [`SubSettings::set_many`](crate::SubSettings::set_many)
+//! or [`SettingsManager::import_flat`](crate::SettingsManager::import_flat)) bracket them with
+//! [`StorageBackend::begin_batch`] and [`StorageBackend::commit_batch`]. The contract is:
+//!
+//! - Every `begin_batch` is followed by exactly one `commit_batch`, even if a
+//!   write in between failed. Batches are never nested.
+//! - Writes inside a batch may be deferred, but must be durable once
+//!   `commit_batch` returns `Ok`.
+//! - Reads inside a batch must observe earlier writes from the same batch.
+//!
+//! Both methods default to no-ops, which is correct for the file-based backends
+//! since each `write` is already atomic on its own.
 
+mod instrumented;
+mod memory;
 #[cfg(feature = "sqlite")]
 mod sqlite;
 
+pub use instrumented::{InstrumentedStorage, StorageStats};
+pub use memory::MemoryStorage;
+
 use crate::error::{Error, Result};
 use crate::utils::security::{ensure_secure_dir, set_secure_file_permissions};
 use serde::{Serialize, de::DeserializeOwned};
@@ -81,6 +103,113 @@ pub trait StorageBackend: Clone + Send + Sync {
         self.deserialize(&content)
     }
 
+    /// Check whether an entry exists at `path`
+    ///
+    /// The default implementation checks the filesystem.
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    /// Remove the entry at `path`
+    ///
+    /// Removing a missing entry is not an error. The default implementation
+    /// deletes the file.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::FileDelete` - If the file exists but cannot be deleted
+    fn remove(&self, path: &Path) -> Result<()> {
+        remove_file_entry(path)
+    }
+
+    /// List the names of entries stored directly under `dir` with the given extension
+    ///
+    /// Names are returned without the extension and in no particular order.
+    /// A missing directory yields an empty list. The default implementation
+    /// scans the directory.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::DirectoryRead` - If the directory cannot be read
+    fn list_entries(&self, dir: &Path, extension: &str) -> Result<Vec<String>> {
+        list_file_entries(dir, extension)
+    }
+
+    /// Remove the directory `dir` and every entry below it
+    ///
+    /// Used when a profile is deleted. A missing directory is not an error.
+    /// The default implementation deletes the directory tree.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::FileDelete` - If the directory cannot be deleted
+    fn remove_dir(&self, dir: &Path) -> Result<()> {
+        remove_dir_tree(dir)
+    }
+
+    /// Move every entry below `from` to the same place below `to`
+    ///
+    /// Used when a profile is renamed. The default implementation renames the
+    /// directory, or creates an empty `to` if `from` does not exist.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::FileWrite` - If the directory cannot be renamed
+    /// * `Error::DirectoryCreate` - If `to` cannot be created
+    fn rename_dir(&self, from: &Path, to: &Path) -> Result<()> {
+        rename_dir_tree(from, to)
+    }
+
+    /// Copy every entry below `from` to the same place below `to`
+    ///
+    /// Used when a profile is duplicated or copied into another profile.
+    /// Entries already present below `to` are replaced when `overwrite` is
+    /// true and kept otherwise. The default implementation copies the
+    /// directory tree file by file, or creates an empty `to` if `from` does
+    /// not exist.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::FileRead` / `Error::FileWrite` - If a file cannot be copied
+    /// * `Error::DirectoryCreate` - If a directory cannot be created
+    fn copy_dir(&self, from: &Path, to: &Path, overwrite: bool) -> Result<()> {
+        if from.exists() {
+            copy_dir_tree(from, to, overwrite)
+        } else {
+            std::fs::create_dir_all(to).map_err(|e| Error::DirectoryCreate {
+                path: to.to_path_buf(),
+                source: e,
+            })
+        }
+    }
+
+    /// Begin a batch of writes
+    ///
+    /// Every `begin_batch` is followed by exactly one
+    /// [`commit_batch`](Self::commit_batch), even if a write in between
+    /// failed, and batches are never nested. Writes inside a batch may be
+    /// deferred until the commit, but reads must observe them.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot start a batch.
+    fn begin_batch(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Commit a batch of writes started with [`begin_batch`](Self::begin_batch)
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batched writes cannot be made durable.
+    fn commit_batch(&self) -> Result<()> {
+        Ok(())
+    }
+
     /// Serialize and write to file
     ///
     /// Uses atomic write: writes to temp file then renames to prevent corruption.
@@ -207,12 +336,111 @@ pub trait StorageBackend: Clone + Send + Sync {
     }
 }
 
+/// Delete a file-backed entry, treating a missing file as success.
+pub(crate) fn remove_file_entry(path: &Path) -> Result<()> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(Error::FileDelete {
+            path: path.to_path_buf(),
+            source: e,
+        }),
+    }
+}
+
+/// Delete a directory tree, treating a missing directory as success.
+pub(crate) fn remove_dir_tree(dir: &Path) -> Result<()> {
+    match std::fs::remove_dir_all(dir) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(Error::FileDelete {
+            path: dir.to_path_buf(),
+            source: e,
+        }),
+    }
+}
+
+/// Rename a directory, creating `to` instead if `from` does not exist.
+pub(crate) fn rename_dir_tree(from: &Path, to: &Path) -> Result<()> {
+    if from.exists() {
+        std::fs::rename(from, to).map_err(|e| Error::FileWrite {
+            path: std::path::PathBuf::from(format!("{} -> {}", from.display(), to.display())),
+            source: e,
+        })
+    } else {
+        std::fs::create_dir_all(to).map_err(|e| Error::DirectoryCreate {
+            path: to.to_path_buf(),
+            source: e,
+        })
+    }
+}
+
+/// Recursively copy a directory, keeping existing files unless `overwrite`
+pub(crate) fn copy_dir_tree(src: &Path, dst: &Path, overwrite: bool) -> Result<()> {
+    if !dst.exists() {
+        std::fs::create_dir_all(dst).map_err(|e| Error::DirectoryCreate {
+            path: dst.to_path_buf(),
+            source: e,
+        })?;
+    }
+
+    for entry in std::fs::read_dir(src).map_err(|e| Error::FileRead {
+        path: src.to_path_buf(),
+        source: e,
+    })? {
+        let entry = entry.map_err(|e| Error::FileRead {
+            path: src.to_path_buf(),
+            source: e,
+        })?;
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+
+        if src_path.is_dir() {
+            copy_dir_tree(&src_path, &dst_path, overwrite)?;
+        } else if overwrite || !dst_path.exists() {
+            std::fs::copy(&src_path, &dst_path).map_err(|e| Error::FileWrite {
+                path: dst_path.clone(),
+                source: e,
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// List file stems in `dir` that carry the given extension.
+pub(crate) fn list_file_entries(dir: &Path, extension: &str) -> Result<Vec<String>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let suffix = format!(".{extension}");
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(dir).map_err(|e| Error::DirectoryRead {
+        path: dir.to_path_buf(),
+        source: e,
+    })? {
+        let entry = entry.map_err(|e| Error::DirectoryRead {
+            path: dir.to_path_buf(),
+            source: e,
+        })?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if let Some(stem) = name.strip_suffix(&suffix) {
+            names.push(stem.to_string());
+        }
+    }
+    Ok(names)
+}
+
 // =============================================================================
 // JSON Storage Implementation
 // =============================================================================
 
 /// JSON storage backend (default)
 ///
+/// Object keys are written in sorted order, so saving the same content twice
+/// produces byte-identical files and diffs only show real changes.
+///
 /// # Example
 ///
 /// ```
@@ -265,10 +493,14 @@ impl StorageBackend for JsonStorage {
     }
 
     fn serialize<T: Serialize>(&self, data: &T) -> Result<String> {
+        // Go through `Value` so `HashMap` contents and struct fields come out
+        // sorted, also when serde_json's `preserve_order` is enabled
+        let mut value = serde_json::to_value(data)?;
+        value.sort_all_objects();
         if self.pretty {
-            serde_json::to_string_pretty(data).map_err(Error::from)
+            serde_json::to_string_pretty(&value).map_err(Error::from)
         } else {
-            serde_json::to_string(data).map_err(Error::from)
+            serde_json::to_string(&value).map_err(Error::from)
         }
     }
 
@@ -283,6 +515,11 @@ impl StorageBackend for JsonStorage {
 
 /// TOML storage backend
 ///
+/// TOML has no `null`, so values containing `null` cannot be written. Settings
+/// whose default is `null` (e.g. derived `Option<T>` fields) still work: saving
+/// the default removes the key from the file, and a missing key reads back as
+/// the `null` default.
+///
 /// Requires the `toml` feature.
 #[cfg(feature = "toml")]
 #[derive(Clone, Default)]
@@ -398,6 +635,37 @@ mod tests {
         assert!(!json.contains('\n')); // Compact
     }
 
+    #[test]
+    fn test_json_output_is_stable() {
+        let storage = JsonStorage::new();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("stable.json");
+
+        let entries = |order: &[&str]| -> std::collections::HashMap<String, serde_json::Value> {
+            order
+                .iter()
+                .map(|key| (key.to_string(), serde_json::json!({"z": 1, "a": key})))
+                .collect()
+        };
+
+        storage
+            .write(&path, &entries(&["ui", "network", "backup", "advanced"]))
+            .unwrap();
+        let first = std::fs::read(&path).unwrap();
+        storage
+            .write(&path, &entries(&["advanced", "backup", "network", "ui"]))
+            .unwrap();
+        let second = std::fs::read(&path).unwrap();
+
+        assert_eq!(first, second);
+        let text = String::from_utf8(first).unwrap();
+        let positions: Vec<usize> = ["advanced", "backup", "network", "ui"]
+            .iter()
+            .map(|key| text.find(&format!("\"{key}\": {{")).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
     #[test]
     fn test_json_roundtrip_sync() {
         let storage = JsonStorage::new();
@@ -441,6 +709,47 @@ mod tests {
         assert!(matches!(result.unwrap_err(), Error::FileRead { .. }));
     }
 
+    #[test]
+    fn test_failed_write_keeps_original_and_cleans_temp_file() {
+        struct Unserializable;
+
+        impl Serialize for Unserializable {
+            fn serialize<Ser: serde::Serializer>(
+                &self,
+                _serializer: Ser,
+            ) -> std::result::Result<Ser::Ok, Ser::Error> {
+                Err(serde::ser::Error::custom("simulated failure"))
+            }
+        }
+
+        let storage = JsonStorage::new();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("settings.json");
+        let original = TestData {
+            name: "original".into(),
+            value: 1,
+        };
+        storage.write(&path, &original).unwrap();
+
+        assert!(storage.write(&path, &Unserializable).is_err());
+        let loaded: TestData = storage.read(&path).unwrap();
+        assert_eq!(loaded, original);
+
+        // Fail after the temp file is written: the rename target is a directory
+        let blocked = dir.path().join("blocked.json");
+        std::fs::create_dir(&blocked).unwrap();
+        std::fs::write(blocked.join("keep"), "x").unwrap();
+        assert!(storage.write(&blocked, &original).is_err());
+        assert!(blocked.join("keep").exists());
+
+        let leftovers: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .filter(|name| name.ends_with(".tmp"))
+            .collect();
+        assert!(leftovers.is_empty(), "temp files left: {leftovers:?}");
+    }
+
     #[test]
     #[cfg(feature = "toml")]
     fn test_toml_roundtrip() {
@@ -30,13 +30,31 @@
 //! [`SqliteStorage::with_table`] and [`SqliteStorage::with_key`] for callers
 //! that want to share a single database file across multiple settings
 //! namespaces.
+//!
+//! # Shared database mode
+//!
+//! With [`SqliteStorage::with_database`], every path is stored as a row of one
+//! database file instead, keyed by the path relative to the database's
+//! directory (e.g. `remotes/gdrive.json`). This is intended for multi-file
+//! sub-settings with a very large number of entries: `get`, `set`, `delete`
+//! and `exists` become primary-key lookups, `list` becomes an index range scan,
+//! and no per-entry files are created. One connection is kept open and shared
+//! by all clones of the storage; [`StorageBackend::begin_batch`] starts a
+//! transaction on it that lasts until the matching `commit_batch`.
+//!
+//! Profile delete, rename and copy act on every row below the profile's
+//! directory. Shared database mode stores entries as rows rather than files,
+//! so features that copy files around on disk (backups, profile directory
+//! migration) do not see them.
 
 use crate::error::{Error, Result};
 use crate::storage::StorageBackend;
 use crate::utils::security::{ensure_secure_dir, set_secure_file_permissions};
 use rusqlite::Connection;
 use serde::{Serialize, de::DeserializeOwned};
-use std::path::Path;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 /// Default table name used by [`SqliteStorage`].
 pub const DEFAULT_TABLE: &str = "rcman_settings";
@@ -70,6 +88,16 @@ pub const DEFAULT_KEY: &str = "main";
 pub struct SqliteStorage {
     table_name: String,
     key: String,
+    database: Option<PathBuf>,
+    shared: Arc<Mutex<SharedConnection>>,
+}
+
+/// Connection to the shared database, opened lazily and reused by all clones.
+#[derive(Default)]
+struct SharedConnection {
+    conn: Option<Connection>,
+    /// Nesting depth of `begin_batch` calls; a transaction is open while > 0.
+    depth: usize,
 }
 
 impl Default for SqliteStorage {
@@ -77,6 +105,8 @@ impl Default for SqliteStorage {
         Self {
             table_name: DEFAULT_TABLE.to_string(),
             key: DEFAULT_KEY.to_string(),
+            database: None,
+            shared: Arc::new(Mutex::new(SharedConnection::default())),
         }
     }
 }
@@ -114,6 +144,66 @@ impl SqliteStorage {
         self
     }
 
+    /// Store every path as a row of a single shared database file.
+    ///
+    /// Rows are keyed by the path relative to the database's directory, so
+    /// the database should live in the application's config directory. All
+    /// clones share one connection, and batches run as a single transaction.
+    #[must_use]
+    pub fn with_database(mut self, database: impl Into<PathBuf>) -> Self {
+        self.database = Some(database.into());
+        self
+    }
+
+    /// Resolve the database file and row key for a storage path.
+    fn locate<'a>(&'a self, path: &'a Path) -> (&'a Path, Cow<'a, str>) {
+        match &self.database {
+            Some(database) => {
+                let relative = database
+                    .parent()
+                    .and_then(|root| path.strip_prefix(root).ok())
+                    .unwrap_or(path);
+                let key = relative.to_string_lossy().replace('\\', "/");
+                (database.as_path(), Cow::Owned(key))
+            }
+            None => (path, Cow::Borrowed(self.key.as_str())),
+        }
+    }
+
+    /// Run `f` against the shared connection in shared database mode, or a
+    /// fresh connection to `database` otherwise.
+    fn with_connection<T>(
+        &self,
+        database: &Path,
+        f: impl FnOnce(&Connection) -> Result<T>,
+    ) -> Result<T> {
+        if self.database.is_some() {
+            let mut shared = self.shared.lock().map_err(|_| Error::LockPoisoned)?;
+            return f(self.shared_connection(&mut shared, database)?);
+        }
+
+        let conn = self.connect(database)?;
+        self.ensure_schema(&conn)?;
+        f(&conn)
+    }
+
+    /// Return the shared connection, opening it on first use.
+    fn shared_connection<'a>(
+        &self,
+        shared: &'a mut SharedConnection,
+        database: &Path,
+    ) -> Result<&'a Connection> {
+        let conn = match shared.conn.take() {
+            Some(conn) => conn,
+            None => {
+                let conn = self.connect(database)?;
+                self.ensure_schema(&conn)?;
+                conn
+            }
+        };
+        Ok(shared.conn.insert(conn))
+    }
+
     /// Open a connection to the database at `path`, creating parent
     /// directories with secure permissions first if needed.
     fn connect(&self, path: &Path) -> Result<Connection> {
@@ -133,6 +223,42 @@ impl SqliteStorage {
             .map_err(|e| Error::Config(format!("sqlite open {}: {e}", path.display())))
     }
 
+    /// Re-key every row below `from` to the same place below `to`.
+    ///
+    /// `copy` is `None` to move the rows, or `Some(overwrite)` to copy them,
+    /// replacing or keeping rows already below `to`.
+    fn move_rows(&self, from: &Path, to: &Path, copy: Option<bool>) -> Result<()> {
+        let (database, from_key) = self.locate(from);
+        if !database.exists() {
+            return Ok(());
+        }
+        let (_, to_key) = self.locate(to);
+        let (lower, upper) = key_range(&from_key);
+        let (to_prefix, _) = key_range(&to_key);
+        // SQLite's `substr` is 1-based and counts characters, not bytes
+        let tail_start = lower.chars().count() + 1;
+
+        let sql = match copy {
+            None => format!(
+                "UPDATE {table} SET key = ?3 || substr(key, ?4)
+                 WHERE key >= ?1 AND key < ?2",
+                table = self.table_name
+            ),
+            Some(overwrite) => format!(
+                "INSERT OR {conflict} INTO {table} (key, data)
+                 SELECT ?3 || substr(key, ?4), data FROM {table}
+                 WHERE key >= ?1 AND key < ?2",
+                conflict = if overwrite { "REPLACE" } else { "IGNORE" },
+                table = self.table_name
+            ),
+        };
+        self.with_connection(database, |conn| {
+            conn.execute(&sql, rusqlite::params![lower, upper, to_prefix, tail_start])
+                .map_err(|e| Error::Config(format!("sqlite rekey: {e}")))
+        })?;
+        Ok(())
+    }
+
     /// Idempotently create the settings table if it does not yet exist.
     fn ensure_schema(&self, conn: &Connection) -> Result<()> {
         // `table_name` is validated by `connect` before we get here, so we can
@@ -151,6 +277,19 @@ impl SqliteStorage {
     }
 }
 
+/// Bounds of the row keys below the directory `dir_key`.
+///
+/// Keys under `dir/` sort between "dir/" and "dir0" ('0' follows '/'), which
+/// lets SQLite answer prefix queries with a primary-key range scan. The
+/// database's own directory maps to the empty key and matches everything.
+fn key_range(dir_key: &str) -> (String, String) {
+    if dir_key.is_empty() {
+        (String::new(), String::from("\u{10FFFF}"))
+    } else {
+        (format!("{dir_key}/"), format!("{dir_key}0"))
+    }
+}
+
 impl StorageBackend for SqliteStorage {
     fn extension(&self) -> &'static str {
         // Matches the convention used by the file-based backends: callers that
@@ -169,26 +308,27 @@ impl StorageBackend for SqliteStorage {
     }
 
     fn read<T: DeserializeOwned>(&self, path: &Path) -> Result<T> {
-        let conn = self.connect(path)?;
-        self.ensure_schema(&conn)?;
+        let (database, key) = self.locate(path);
         let sql = format!(
             "SELECT data FROM {table} WHERE key = ?1",
             table = self.table_name
         );
-        let row_data: Option<String> = conn
-            .query_row(&sql, rusqlite::params![self.key], |row| row.get(0))
-            .or_else(|e| match e {
-                rusqlite::Error::QueryReturnedNoRows => Ok(None),
-                _ => Err(e),
-            })
-            .map_err(|e| Error::Config(format!("sqlite query: {e}")))?;
+        let row_data: Option<String> = self.with_connection(database, |conn| {
+            conn.prepare_cached(&sql)
+                .and_then(|mut stmt| stmt.query_row(rusqlite::params![key], |row| row.get(0)))
+                .or_else(|e| match e {
+                    rusqlite::Error::QueryReturnedNoRows => Ok(None),
+                    _ => Err(e),
+                })
+                .map_err(|e| Error::Config(format!("sqlite query: {e}")))
+        })?;
         match row_data {
             Some(content) => self.deserialize(&content),
             None => Err(Error::FileRead {
                 path: path.to_path_buf(),
                 source: std::io::Error::new(
                     std::io::ErrorKind::NotFound,
-                    format!("no settings row for key {:?}", self.key),
+                    format!("no settings row for key {key:?}"),
                 ),
             }),
         }
@@ -196,18 +336,190 @@ impl StorageBackend for SqliteStorage {
 
     fn write<T: Serialize>(&self, path: &Path, data: &T) -> Result<()> {
         let content = self.serialize(data)?;
-        let conn = self.connect(path)?;
-        self.ensure_schema(&conn)?;
+        let (database, key) = self.locate(path);
         let sql = format!(
             "INSERT INTO {table} (key, data) VALUES (?1, ?2)
              ON CONFLICT(key) DO UPDATE SET data = excluded.data",
             table = self.table_name
         );
-        conn.execute(&sql, rusqlite::params![self.key, content])
-            .map_err(|e| Error::Config(format!("sqlite upsert: {e}")))?;
+        self.with_connection(database, |conn| {
+            conn.prepare_cached(&sql)
+                .and_then(|mut stmt| stmt.execute(rusqlite::params![key, content]))
+                .map_err(|e| Error::Config(format!("sqlite upsert: {e}")))
+        })?;
         // Best-effort: tighten permissions on the database file to match the
         // file-based backends. Errors here are not fatal.
-        let _ = set_secure_file_permissions(path);
+        let _ = set_secure_file_permissions(database);
+        Ok(())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        let (database, key) = self.locate(path);
+        if !database.exists() {
+            return false;
+        }
+        let sql = format!(
+            "SELECT 1 FROM {table} WHERE key = ?1",
+            table = self.table_name
+        );
+        self.with_connection(database, |conn| {
+            conn.prepare_cached(&sql)
+                .and_then(|mut stmt| stmt.query_row(rusqlite::params![key], |_| Ok(())))
+                .map(|()| true)
+                .or_else(|e| match e {
+                    rusqlite::Error::QueryReturnedNoRows => Ok(false),
+                    _ => Err(e),
+                })
+                .map_err(|e| Error::Config(format!("sqlite query: {e}")))
+        })
+        .unwrap_or_else(|e| {
+            // The trait cannot report errors here; don't let a broken
+            // database masquerade silently as a missing entry.
+            log::warn!("sqlite exists check for {key:?} failed: {e}");
+            false
+        })
+    }
+
+    fn remove(&self, path: &Path) -> Result<()> {
+        if self.database.is_none() {
+            // One database file per entry: removing the entry removes the file
+            return crate::storage::remove_file_entry(path);
+        }
+
+        let (database, key) = self.locate(path);
+        if !database.exists() {
+            return Ok(());
+        }
+        let sql = format!(
+            "DELETE FROM {table} WHERE key = ?1",
+            table = self.table_name
+        );
+        self.with_connection(database, |conn| {
+            conn.execute(&sql, rusqlite::params![key])
+                .map_err(|e| Error::Config(format!("sqlite delete: {e}")))
+        })?;
+        Ok(())
+    }
+
+    fn list_entries(&self, dir: &Path, extension: &str) -> Result<Vec<String>> {
+        if self.database.is_none() {
+            return crate::storage::list_file_entries(dir, extension);
+        }
+
+        let (database, dir_key) = self.locate(dir);
+        if !database.exists() {
+            return Ok(Vec::new());
+        }
+
+        let (lower, upper) = key_range(&dir_key);
+        let suffix = format!(".{extension}");
+        let sql = format!(
+            "SELECT key FROM {table} WHERE key >= ?1 AND key < ?2",
+            table = self.table_name
+        );
+        let keys: Vec<String> = self.with_connection(database, |conn| {
+            let mut stmt = conn
+                .prepare(&sql)
+                .map_err(|e| Error::Config(format!("sqlite query: {e}")))?;
+            let rows = stmt
+                .query_map(rusqlite::params![lower, upper], |row| row.get(0))
+                .map_err(|e| Error::Config(format!("sqlite query: {e}")))?;
+            rows.collect::<std::result::Result<_, _>>()
+                .map_err(|e| Error::Config(format!("sqlite query: {e}")))
+        })?;
+
+        Ok(keys
+            .iter()
+            .filter_map(|key| key.strip_prefix(&lower))
+            .filter(|rest| !rest.contains('/'))
+            .filter_map(|rest| rest.strip_suffix(&suffix))
+            .map(str::to_string)
+            .collect())
+    }
+
+    fn remove_dir(&self, dir: &Path) -> Result<()> {
+        if self.database.is_none() {
+            return crate::storage::remove_dir_tree(dir);
+        }
+
+        let (database, dir_key) = self.locate(dir);
+        if database.exists() {
+            let (lower, upper) = key_range(&dir_key);
+            let sql = format!(
+                "DELETE FROM {table} WHERE key >= ?1 AND key < ?2",
+                table = self.table_name
+            );
+            self.with_connection(database, |conn| {
+                conn.execute(&sql, rusqlite::params![lower, upper])
+                    .map_err(|e| Error::Config(format!("sqlite delete: {e}")))
+            })?;
+        }
+        // Files other components put below `dir` go with it
+        crate::storage::remove_dir_tree(dir)
+    }
+
+    fn rename_dir(&self, from: &Path, to: &Path) -> Result<()> {
+        if self.database.is_none() {
+            return crate::storage::rename_dir_tree(from, to);
+        }
+
+        self.move_rows(from, to, None)?;
+        if from.exists() {
+            crate::storage::rename_dir_tree(from, to)?;
+        }
+        Ok(())
+    }
+
+    fn copy_dir(&self, from: &Path, to: &Path, overwrite: bool) -> Result<()> {
+        if self.database.is_none() {
+            return if from.exists() {
+                crate::storage::copy_dir_tree(from, to, overwrite)
+            } else {
+                std::fs::create_dir_all(to).map_err(|e| Error::DirectoryCreate {
+                    path: to.to_path_buf(),
+                    source: e,
+                })
+            };
+        }
+
+        self.move_rows(from, to, Some(overwrite))?;
+        if from.exists() {
+            crate::storage::copy_dir_tree(from, to, overwrite)?;
+        }
+        Ok(())
+    }
+
+    fn begin_batch(&self) -> Result<()> {
+        let Some(database) = &self.database else {
+            return Ok(());
+        };
+
+        let mut shared = self.shared.lock().map_err(|_| Error::LockPoisoned)?;
+        if shared.depth == 0 {
+            self.shared_connection(&mut shared, database)?
+                .execute_batch("BEGIN")
+                .map_err(|e| Error::Config(format!("sqlite begin: {e}")))?;
+        }
+        shared.depth += 1;
+        Ok(())
+    }
+
+    fn commit_batch(&self) -> Result<()> {
+        if self.database.is_none() {
+            return Ok(());
+        }
+
+        let mut shared = self.shared.lock().map_err(|_| Error::LockPoisoned)?;
+        if shared.depth == 0 {
+            return Ok(());
+        }
+        shared.depth -= 1;
+        if shared.depth == 0
+            && let Some(conn) = &shared.conn
+        {
+            conn.execute_batch("COMMIT")
+                .map_err(|e| Error::Config(format!("sqlite commit: {e}")))?;
+        }
         Ok(())
     }
 }
@@ -367,6 +679,53 @@ mod tests {
         assert_eq!(loaded, sample());
     }
 
+    #[test]
+    fn shared_database_stores_entries_as_rows() {
+        let dir = tempdir().unwrap();
+        let storage = SqliteStorage::new().with_database(dir.path().join("rcman.db"));
+        let remotes = dir.path().join("remotes");
+
+        storage.write(&remotes.join("a.json"), &sample()).unwrap();
+        storage.write(&remotes.join("b.json"), &sample()).unwrap();
+        storage
+            .write(&remotes.join("nested").join("c.json"), &sample())
+            .unwrap();
+        storage
+            .write(&dir.path().join("remotes0.json"), &sample())
+            .unwrap();
+
+        // No per-entry files are created
+        assert!(!remotes.exists());
+        assert!(storage.exists(&remotes.join("a.json")));
+        assert!(!storage.exists(&remotes.join("missing.json")));
+
+        let mut names = storage.list_entries(&remotes, "json").unwrap();
+        names.sort();
+        assert_eq!(names, vec!["a", "b"]);
+
+        storage.remove(&remotes.join("a.json")).unwrap();
+        assert!(!storage.exists(&remotes.join("a.json")));
+        assert_eq!(storage.list_entries(&remotes, "json").unwrap(), vec!["b"]);
+    }
+
+    #[test]
+    fn shared_database_batch_commits_all_writes() {
+        let dir = tempdir().unwrap();
+        let storage = SqliteStorage::new().with_database(dir.path().join("rcman.db"));
+        let clone = storage.clone();
+
+        storage.begin_batch().unwrap();
+        for i in 0..10 {
+            clone
+                .write(&dir.path().join(format!("e{i}.json")), &sample())
+                .unwrap();
+        }
+        storage.commit_batch().unwrap();
+
+        let fresh = SqliteStorage::new().with_database(dir.path().join("rcman.db"));
+        assert_eq!(fresh.list_entries(dir.path(), "json").unwrap().len(), 10);
+    }
+
     #[test]
     fn identifier_validation() {
         assert!(is_valid_identifier("rcman_settings"));
@@ -7,6 +7,8 @@
 //! - `SingleFile`: All entities in one file (e.g., `config/backends.json`)
 
 mod multi_file;
+#[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+mod secret_cache;
 mod single_file;
 mod store;
 
@@ -18,13 +20,21 @@ use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 
 use self::multi_file::MultiFileStore;
 use self::single_file::SingleFileStore;
 pub(crate) use self::store::SubSettingsStore;
 
+/// Field stamped on each entry by [`SubSettingsConfig::with_versioned_migrator`]
+///
+/// It is kept in storage only: values returned by [`SubSettings`] never carry it.
+pub const SCHEMA_VERSION_KEY: &str = "_schema_version";
+
+/// Versioned migration function: `(entry, stored_version) -> migrated entry`
+pub type VersionedMigrator = Arc<dyn Fn(Value, u32) -> Value + Send + Sync>;
+
 /// Mode of storage for sub-settings
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SubSettingsMode {
@@ -35,6 +45,22 @@ pub enum SubSettingsMode {
     SingleFile,
 }
 
+/// How reads treat entry fields that are not in the schema
+///
+/// Set with [`SubSettingsConfig::strict_read`] or
+/// [`SubSettingsConfig::with_strict_read`]. Only applies when a schema is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StrictRead {
+    /// Return unknown fields as stored (default)
+    #[default]
+    Off,
+    /// Log a warning and drop unknown fields from the returned value; the
+    /// stored entry is not rewritten
+    Strip,
+    /// Fail the read with [`Error::InvalidSettingValue`]
+    Error,
+}
+
 /// Configuration for a sub-settings type.
 #[derive(Clone)]
 pub struct SubSettingsConfig {
@@ -47,6 +73,9 @@ pub struct SubSettingsConfig {
     /// Optional migration function for schema changes
     pub migrator: Option<Arc<dyn Fn(Value) -> Value + Send + Sync>>,
 
+    /// Optional per-entry migration with the current entry schema version
+    pub versioned_migrator: Option<(u32, VersionedMigrator)>,
+
     /// Optional schema metadata for validating sub-settings entries
     pub schema: Option<Arc<HashMap<String, SettingMetadata>>>,
 
@@ -64,8 +93,23 @@ pub struct SubSettingsConfig {
     #[cfg(feature = "profiles")]
     pub profile_migrator: crate::ProfileMigrator,
 
+    /// Whether the default profile is activated on startup instead of the
+    /// last active one (default: false)
+    #[cfg(feature = "profiles")]
+    pub start_in_default_profile: bool,
+
     /// Whether to deny unknown fields not defined in the schema (default: true)
     pub deny_unknown_fields: bool,
+
+    /// Whether reads fill fields missing from an entry with their schema
+    /// defaults (default: false)
+    pub with_defaults: bool,
+
+    /// How reads treat fields not defined in the schema (default: `Off`)
+    pub strict_read: StrictRead,
+
+    /// Maximum number of entries (default: unlimited)
+    pub max_entries: Option<usize>,
 }
 
 impl Default for SubSettingsConfig {
@@ -74,6 +118,7 @@ impl Default for SubSettingsConfig {
             name: "items".into(),
             extension: None,
             migrator: None,
+            versioned_migrator: None,
             schema: None,
             mode: SubSettingsMode::MultiFile,
             cache_strategy: crate::CacheStrategy::default(),
@@ -81,7 +126,12 @@ impl Default for SubSettingsConfig {
             profiles_enabled: false,
             #[cfg(feature = "profiles")]
             profile_migrator: crate::ProfileMigrator::default(),
+            #[cfg(feature = "profiles")]
+            start_in_default_profile: false,
             deny_unknown_fields: false,
+            with_defaults: false,
+            strict_read: StrictRead::Off,
+            max_entries: None,
         }
     }
 }
@@ -112,6 +162,12 @@ impl SubSettingsConfig {
         self.with_cache(crate::CacheStrategy::Lru(max_entries))
     }
 
+    /// Cache entries up to `bytes` of serialized data, evicting least recently used ones.
+    #[must_use]
+    pub fn with_memory_budget(self, bytes: usize) -> Self {
+        self.with_cache(crate::CacheStrategy::MemoryBudget(bytes))
+    }
+
     #[must_use]
     pub fn with_no_cache(self) -> Self {
         self.with_cache(crate::CacheStrategy::None)
@@ -132,6 +188,68 @@ impl SubSettingsConfig {
         self
     }
 
+    /// Migrate entries only when their stored schema version is behind
+    ///
+    /// Each entry is stamped with [`SCHEMA_VERSION_KEY`]. When an entry is
+    /// loaded with a version below `current_version` (entries without a stamp
+    /// count as version 0), `migrator` is called once with the entry and its
+    /// stored version, and the result is saved back stamped with
+    /// `current_version`. Up-to-date entries are returned as-is, and entries
+    /// written through [`SubSettings`] are stamped automatically.
+    ///
+    /// Only object entries are versioned. Runs after [`with_migrator`](Self::with_migrator)
+    /// when both are set.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rcman::SubSettingsConfig;
+    ///
+    /// let config = SubSettingsConfig::new("remotes").with_versioned_migrator(2, |mut entry, from| {
+    ///     if from < 1 {
+    ///         entry["type"] = entry["kind"].take();
+    ///     }
+    ///     if from < 2 {
+    ///         entry["enabled"] = true.into();
+    ///     }
+    ///     entry
+    /// });
+    /// ```
+    #[must_use]
+    pub fn with_versioned_migrator<F>(mut self, current_version: u32, migrator: F) -> Self
+    where
+        F: Fn(Value, u32) -> Value + Send + Sync + 'static,
+    {
+        self.versioned_migrator = Some((current_version, Arc::new(migrator)));
+        self
+    }
+
+    /// Build the migrator handed to the store, folding in versioned migration
+    fn store_migrator(&self) -> Option<Arc<dyn Fn(Value) -> Value + Send + Sync>> {
+        let Some((current_version, versioned)) = self.versioned_migrator.clone() else {
+            return self.migrator.clone();
+        };
+
+        let migrator = self.migrator.clone();
+        let single_file = self.mode == SubSettingsMode::SingleFile;
+        Some(Arc::new(move |value: Value| {
+            let mut value = match &migrator {
+                Some(migrator) => migrator(value),
+                None => value,
+            };
+
+            if single_file {
+                if let Value::Object(entries) = &mut value {
+                    for entry in entries.values_mut() {
+                        migrate_versioned_entry(entry, current_version, versioned.as_ref());
+                    }
+                }
+            } else {
+                migrate_versioned_entry(&mut value, current_version, versioned.as_ref());
+            }
+            value
+        }))
+    }
+
     #[must_use]
     pub fn with_metadata(mut self, metadata: HashMap<String, SettingMetadata>) -> Self {
         self.schema = Some(Arc::new(metadata));
@@ -150,6 +268,19 @@ impl SubSettingsConfig {
         self
     }
 
+    /// Activate the default profile on startup instead of the last active one.
+    ///
+    /// Set for every profiled type registered with a manager built with
+    /// [`start_in_default_profile`](crate::SettingsConfigBuilder::start_in_default_profile).
+    /// Implies [`with_profiles`](Self::with_profiles).
+    #[cfg(feature = "profiles")]
+    #[must_use]
+    pub fn start_in_default_profile(mut self) -> Self {
+        self.profiles_enabled = true;
+        self.start_in_default_profile = true;
+        self
+    }
+
     #[cfg(feature = "profiles")]
     #[must_use]
     pub fn with_profile_migrator(mut self, migrator: crate::profiles::ProfileMigrator) -> Self {
@@ -162,6 +293,72 @@ impl SubSettingsConfig {
         self.deny_unknown_fields = deny;
         self
     }
+
+    /// Fill fields missing from an entry with their schema defaults on read
+    ///
+    /// Entries written before a field was added to the schema then still
+    /// deserialize with every field present, like main settings do. Only
+    /// affects values returned by reads; stored entries are not rewritten.
+    #[must_use]
+    pub fn with_defaults(mut self, enabled: bool) -> Self {
+        self.with_defaults = enabled;
+        self
+    }
+
+    /// Reject entries with fields not defined in the schema on read
+    ///
+    /// Catches hand-edited entries or leftovers from an older app version,
+    /// which [`deny_unknown_fields`](Self::deny_unknown_fields) only rejects
+    /// on write. Shorthand for `with_strict_read(StrictRead::Error)`.
+    #[must_use]
+    pub fn strict_read(self) -> Self {
+        self.with_strict_read(StrictRead::Error)
+    }
+
+    /// Set how reads treat fields not defined in the schema
+    #[must_use]
+    pub fn with_strict_read(mut self, mode: StrictRead) -> Self {
+        self.strict_read = mode;
+        self
+    }
+
+    /// Limit the number of entries
+    ///
+    /// Creating an entry beyond the limit fails with
+    /// [`Error::SubSettingsLimitReached`]; existing entries can always be
+    /// updated.
+    #[must_use]
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+}
+
+/// Run a versioned migration on a single entry if its stamp is behind
+fn migrate_versioned_entry(
+    entry: &mut Value,
+    current_version: u32,
+    migrator: &(dyn Fn(Value, u32) -> Value + Send + Sync),
+) {
+    let Value::Object(map) = entry else {
+        return;
+    };
+
+    let from = map
+        .remove(SCHEMA_VERSION_KEY)
+        .and_then(|v| v.as_u64())
+        .map_or(0, |v| u32::try_from(v).unwrap_or(u32::MAX));
+
+    if from < current_version {
+        *entry = migrator(std::mem::take(entry), from);
+    }
+
+    if let Value::Object(map) = entry {
+        map.insert(
+            SCHEMA_VERSION_KEY.to_string(),
+            from.max(current_version).into(),
+        );
+    }
 }
 
 /// Callback for change notifications
@@ -174,25 +371,31 @@ pub struct SubSettings<S: StorageBackend = crate::storage::JsonStorage> {
     #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
     credential_manager: Option<crate::credentials::CredentialManager>,
 
+    /// Secret values injected by `get_value`, following `cache_strategy`
+    #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+    secret_cache: secret_cache::SecretCache,
+
     /// The active store implementation
     pub(crate) store: RwLock<Box<dyn SubSettingsStore>>,
 
-    /// We keep storage around mostly for profiles logic if needed,
-    /// or simple ref storage.
-    /// The storage backend instance (kept for recreating stores)
-    #[cfg(feature = "profiles")]
+    /// The storage backend instance (kept for recreating stores on profile
+    /// switch and for parsing imported files)
     storage: S,
 
-    #[cfg(not(feature = "profiles"))]
-    _marker: std::marker::PhantomData<S>,
-
     /// Callback for change notifications
     on_change: RwLock<Option<ChangeCallback>>,
 
+    /// Forwards changes to the owning manager's listeners
+    on_change_forward: RwLock<Option<ChangeCallback>>,
+
     /// Profile manager (when profiles are enabled)
     #[cfg(feature = "profiles")]
     profile_manager: Option<crate::profiles::ProfileManager<S>>,
 
+    /// Forwards completed profile switches to the owning manager's listeners
+    #[cfg(feature = "profiles")]
+    on_profile_switch: RwLock<Option<crate::manager::events::ProfileSwitchCallback>>,
+
     #[cfg(feature = "profiles")]
     root_dir: PathBuf,
 }
@@ -205,6 +408,19 @@ pub enum SubSettingsAction {
     Deleted,
 }
 
+/// Outcome of [`SubSettings::replace_all`]
+///
+/// Each list is sorted by entry name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplaceReport {
+    /// Entries that did not exist before
+    pub added: Vec<String>,
+    /// Entries that existed and were overwritten
+    pub updated: Vec<String>,
+    /// Entries that existed but were not in the incoming set
+    pub removed: Vec<String>,
+}
+
 impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
     fn make_store(
         config: &SubSettingsConfig,
@@ -219,7 +435,7 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
                 base_dir,
                 extension,
                 storage,
-                config.migrator.clone(),
+                config.store_migrator(),
                 config.cache_strategy,
             )),
             SubSettingsMode::SingleFile => Box::new(SingleFileStore::new(
@@ -227,7 +443,7 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
                 base_dir,
                 extension,
                 storage,
-                config.migrator.clone(),
+                config.store_migrator(),
             )),
         }
     }
@@ -303,7 +519,10 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
             .map_err(|e| Error::ProfileMigrationFailed(e.to_string()))?;
 
             let pm = crate::profiles::ProfileManager::new(&root_dir, &config.name, storage.clone());
-            let active_path = pm.profile_path(crate::profiles::DEFAULT_PROFILE);
+            if config.start_in_default_profile {
+                pm.activate_default()?;
+            }
+            let active_path = pm.active_path()?;
             (active_path, Some(pm))
         } else {
             (root_dir.clone(), None)
@@ -315,18 +534,20 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
         let store = Self::make_store(&config, base_dir, storage.clone());
 
         Ok(Self {
+            #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+            secret_cache: secret_cache::SecretCache::new(config.cache_strategy),
             config,
             #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
             credential_manager,
             store: RwLock::new(store),
-            #[cfg(feature = "profiles")]
             storage,
-            #[cfg(not(feature = "profiles"))]
-            _marker: std::marker::PhantomData,
             on_change: RwLock::new(None),
+            on_change_forward: RwLock::new(None),
             #[cfg(feature = "profiles")]
             profile_manager,
             #[cfg(feature = "profiles")]
+            on_profile_switch: RwLock::new(None),
+            #[cfg(feature = "profiles")]
             root_dir,
         })
     }
@@ -353,7 +574,6 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
         self.config.schema.clone()
     }
 
-    #[cfg(feature = "profiles")]
     pub fn storage(&self) -> &S {
         &self.storage
     }
@@ -362,6 +582,28 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
         if let Ok(store) = self.store.read_recovered() {
             store.invalidate_cache();
         }
+
+        #[cfg(feature = "profiles")]
+        if let Some(pm) = &self.profile_manager {
+            pm.invalidate_manifest();
+        }
+
+        #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+        if let Err(e) = self.secret_cache.clear() {
+            log::debug!("Failed to clear sub-settings secret cache: {e}");
+        }
+    }
+
+    /// Get cache counters for tuning the [`CacheStrategy`](crate::CacheStrategy).
+    ///
+    /// Single-file stores always keep the whole file in memory and report
+    /// zeroed stats.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store lock cannot be acquired.
+    pub fn cache_stats(&self) -> Result<crate::CacheStats> {
+        self.store.read_recovered()?.cache_stats()
     }
 
     /// Get the profile manager if enabled
@@ -391,15 +633,36 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
     #[cfg(feature = "profiles")]
     pub fn switch_profile(&self, name: &str) -> Result<()> {
         let pm = self.profiles()?;
+        let from = pm.active()?;
         pm.switch(name)?;
 
         // Re-create store pointing to new path
         let new_path = pm.profile_path(name);
         let new_store = Self::make_store(&self.config, new_path, self.storage.clone());
 
-        let mut store_guard = self.store.write_recovered()?;
-        *store_guard = new_store;
+        {
+            let mut store_guard = self.store.write_recovered()?;
+            *store_guard = new_store;
+        }
 
+        if from != name
+            && let Ok(guard) = self.on_profile_switch.read_recovered()
+            && let Some(callback) = guard.as_ref()
+        {
+            callback(&self.config.name, &from, name);
+        }
+
+        Ok(())
+    }
+
+    /// Set the callback run after this sub-settings type switches profile
+    #[cfg(feature = "profiles")]
+    pub(crate) fn set_on_profile_switch(
+        &self,
+        callback: crate::manager::events::ProfileSwitchCallback,
+    ) -> Result<()> {
+        let mut guard = self.on_profile_switch.write_recovered()?;
+        *guard = Some(callback);
         Ok(())
     }
 
@@ -421,12 +684,27 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
         Ok(())
     }
 
+    /// Set the callback that forwards changes to the owning manager
+    pub(crate) fn set_on_change_forward(&self, callback: ChangeCallback) -> Result<()> {
+        let mut guard = self.on_change_forward.write_recovered()?;
+        *guard = Some(callback);
+        Ok(())
+    }
+
     fn notify_change(&self, name: &str, action: SubSettingsAction) {
         if let Ok(guard) = self.on_change.read_recovered()
             && let Some(callback) = guard.as_ref()
         {
             callback(name, action);
         }
+        let forward = self
+            .on_change_forward
+            .read_recovered()
+            .ok()
+            .and_then(|guard| guard.clone());
+        if let Some(forward) = forward {
+            forward(name, action);
+        }
     }
 
     /// Update a single field in a sub-settings entry.
@@ -462,33 +740,146 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
         self.set(name, &entry)
     }
 
-    fn validate_against_schema(&self, entry_name: &str, value: &Value) -> Result<()> {
+    fn is_version_stamp(&self, key: &str) -> bool {
+        self.config.versioned_migrator.is_some() && key == SCHEMA_VERSION_KEY
+    }
+
+    /// Stamp an entry with the current schema version before it is stored
+    fn stamp_schema_version(&self, value: &mut Value) {
+        if let Some((current_version, _)) = &self.config.versioned_migrator
+            && let Value::Object(map) = value
+        {
+            map.insert(SCHEMA_VERSION_KEY.to_string(), (*current_version).into());
+        }
+    }
+
+    /// Fill schema fields missing from an object entry with their defaults
+    ///
+    /// Secret fields are skipped; their defaults come from secret injection.
+    fn fill_schema_defaults(&self, value: &mut Value) {
+        if !self.config.with_defaults || !value.is_object() {
+            return;
+        }
         let Some(schema) = self.config.schema.as_ref() else {
+            return;
+        };
+
+        for (path, metadata) in schema.iter() {
+            if !metadata.is_secret() && crate::utils::value::get_path(value, path).is_none() {
+                crate::utils::value::set_path(value, path, metadata.default.clone());
+            }
+        }
+    }
+
+    /// Reject creating `new_entries` more entries beyond `max_entries`
+    ///
+    /// Counts every entry, including those that only have secrets stored.
+    /// Callers hold the store's write lock so the count cannot go stale
+    /// before their write.
+    fn check_entry_limit(&self, store: &dyn SubSettingsStore, new_entries: usize) -> Result<()> {
+        let Some(limit) = self.config.max_entries else {
             return Ok(());
         };
+        if new_entries == 0 {
+            return Ok(());
+        }
+        let mut names: std::collections::HashSet<String> = store.list()?.into_iter().collect();
+        names.extend(self.secret_entry_names()?);
+        if names.len() + new_entries > limit {
+            return Err(Error::SubSettingsLimitReached {
+                name: self.config.name.clone(),
+                limit,
+            });
+        }
+        Ok(())
+    }
 
-        if self.config.deny_unknown_fields
-            && let Some(obj) = value.as_object()
+    /// Hide the schema version stamp from values handed to callers
+    fn strip_schema_version(&self, value: &mut Value) {
+        if self.config.versioned_migrator.is_some()
+            && let Value::Object(map) = value
         {
-            let allowed_roots: std::collections::HashSet<&str> = schema
-                .keys()
-                .map(|key| key.split('.').next().unwrap_or(key.as_str()))
-                .collect();
+            map.remove(SCHEMA_VERSION_KEY);
+        }
+    }
 
-            for key in obj.keys() {
-                if !allowed_roots.contains(key.as_str()) {
-                    return Err(Error::InvalidSettingValue {
-                        key: format!("{}.{}.{}", self.config.name, entry_name, key),
-                        reason: "Field is not defined in sub-settings schema".to_string(),
-                    });
-                }
+    /// Top-level fields of `value` not defined in `schema`
+    fn unknown_fields(
+        &self,
+        schema: &HashMap<String, SettingMetadata>,
+        value: &Value,
+    ) -> Vec<String> {
+        let Some(obj) = value.as_object() else {
+            return Vec::new();
+        };
+        let allowed_roots: std::collections::HashSet<&str> = schema
+            .keys()
+            .map(|key| key.split('.').next().unwrap_or(key.as_str()))
+            .collect();
+
+        obj.keys()
+            .filter(|key| !allowed_roots.contains(key.as_str()) && !self.is_version_stamp(key))
+            .cloned()
+            .collect()
+    }
+
+    fn unknown_field_error(&self, entry_name: &str, field: &str) -> Error {
+        Error::InvalidSettingValue {
+            key: format!("{}.{}.{}", self.config.name, entry_name, field),
+            reason: "Field is not defined in sub-settings schema".to_string(),
+        }
+    }
+
+    /// Apply [`StrictRead`] to an entry being returned by a read
+    fn check_strict_read(&self, entry_name: &str, value: &mut Value) -> Result<()> {
+        let Some(schema) = self.config.schema.as_ref() else {
+            return Ok(());
+        };
+        if self.config.strict_read == StrictRead::Off {
+            return Ok(());
+        }
+
+        let mut unknown = self.unknown_fields(schema, value);
+        if unknown.is_empty() {
+            return Ok(());
+        }
+        unknown.sort();
+        if self.config.strict_read == StrictRead::Error {
+            return Err(self.unknown_field_error(entry_name, &unknown[0]));
+        }
+
+        log::warn!(
+            "Ignoring fields not in the {} schema in entry '{entry_name}': {}",
+            self.config.name,
+            unknown.join(", ")
+        );
+        if let Value::Object(map) = value {
+            for field in &unknown {
+                map.remove(field);
             }
         }
+        Ok(())
+    }
+
+    pub(crate) fn validate_against_schema(&self, entry_name: &str, value: &Value) -> Result<()> {
+        let Some(schema) = self.config.schema.as_ref() else {
+            return Ok(());
+        };
+
+        if self.config.deny_unknown_fields
+            && let Some(key) = self.unknown_fields(schema, value).first()
+        {
+            return Err(self.unknown_field_error(entry_name, key));
+        }
 
         for (path, metadata) in schema.iter() {
-            if let Some(field_value) = crate::utils::value::get_path(value, path)
-                && let Err(reason) = metadata.validate(field_value)
-            {
+            let result = match crate::utils::value::get_path(value, path) {
+                Some(field_value) => metadata.validate(field_value),
+                None if metadata.required => Err("Value is required".to_string()),
+                None => Ok(()),
+            };
+
+            if let Err(reason) = result {
                 return Err(Error::InvalidSettingValue {
                     key: format!("{}.{}.{}", self.config.name, entry_name, path),
                     reason,
@@ -539,6 +930,7 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
             .as_ref()
             .ok_or_else(|| Error::Credential("Credentials not enabled".to_string()))?;
 
+        self.secret_cache.invalidate(entry_name)?;
         let profile = self.active_secret_profile();
 
         for (path, metadata) in secret_fields {
@@ -598,11 +990,33 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
 
         let profile = self.active_secret_profile();
 
-        for (path, metadata) in schema.iter().filter(|(_, metadata)| metadata.is_secret()) {
-            let credential_key = self.secret_credential_key(entry_name, path);
-            let secret = creds.get_with_profile(&credential_key, profile.as_deref())?;
-            let resolved = secret.map_or_else(|| metadata.default.clone(), Value::String);
-            crate::utils::value::set_path(value, path, resolved);
+        let secrets = if let Some(cached) = self.secret_cache.get(profile.as_deref(), entry_name)? {
+            cached
+        } else {
+            let fields: Vec<(&String, &SettingMetadata, String)> = schema
+                .iter()
+                .filter(|(_, metadata)| metadata.is_secret())
+                .map(|(path, metadata)| {
+                    let credential_key = self.secret_credential_key(entry_name, path);
+                    (path, metadata, credential_key)
+                })
+                .collect();
+            let keys: Vec<&str> = fields.iter().map(|(_, _, key)| key.as_str()).collect();
+            let mut stored = creds.get_many_with_profile(&keys, profile.as_deref())?;
+
+            let mut secrets = Vec::new();
+            for (path, metadata, credential_key) in &fields {
+                let secret = stored.remove(credential_key).flatten();
+                let resolved = secret.map_or_else(|| metadata.default.clone(), Value::String);
+                secrets.push(((*path).clone(), resolved));
+            }
+            self.secret_cache
+                .insert(profile.as_deref(), entry_name, secrets.clone())?;
+            secrets
+        };
+
+        for (path, resolved) in secrets {
+            crate::utils::value::set_path(value, &path, resolved);
         }
 
         Ok(())
@@ -638,6 +1052,51 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
         Ok(false)
     }
 
+    /// Names of the entries with at least one tracked secret
+    #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+    fn secret_entry_names(&self) -> Result<std::collections::HashSet<String>> {
+        let mut names = std::collections::HashSet::new();
+        let (Some(schema), Some(creds)) = (
+            self.config.schema.as_ref(),
+            self.credential_manager.as_ref(),
+        ) else {
+            return Ok(names);
+        };
+
+        let secret_paths: Vec<&String> = schema
+            .iter()
+            .filter(|(_, metadata)| metadata.is_secret())
+            .map(|(path, _)| path)
+            .collect();
+        if secret_paths.is_empty() {
+            return Ok(names);
+        }
+
+        // Credential keys are `sub.{type}.{entry}.{field}`; entry names may
+        // contain dots, so match the known field paths from the end
+        let prefix = format!("sub.{}.", self.config.name);
+        let profile = self.active_secret_profile();
+        for key in creds.get_tracked_secrets(profile.as_deref())? {
+            let Some(rest) = key.strip_prefix(&prefix) else {
+                continue;
+            };
+            for path in &secret_paths {
+                if let Some(entry) = rest
+                    .strip_suffix(path.as_str())
+                    .and_then(|entry| entry.strip_suffix('.'))
+                {
+                    names.insert(entry.to_string());
+                }
+            }
+        }
+        Ok(names)
+    }
+
+    #[cfg(not(any(feature = "keychain", feature = "encrypted-file")))]
+    fn secret_entry_names(&self) -> Result<std::collections::HashSet<String>> {
+        Ok(std::collections::HashSet::new())
+    }
+
     #[cfg(not(any(feature = "keychain", feature = "encrypted-file")))]
     fn inject_secrets_from_store(&self, _entry_name: &str, _value: &mut Value) -> Result<()> {
         Ok(())
@@ -653,6 +1112,7 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
             return Ok(());
         };
 
+        self.secret_cache.invalidate(entry_name)?;
         let profile = self.active_secret_profile();
 
         for (path, _) in schema.iter().filter(|(_, metadata)| metadata.is_secret()) {
@@ -669,6 +1129,40 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
         Ok(())
     }
 
+    /// Move the stored secrets of `from` to the credential keys of `to`
+    #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+    fn move_secret_fields(&self, from: &str, to: &str) -> Result<()> {
+        let Some(schema) = self.config.schema.as_ref() else {
+            return Ok(());
+        };
+
+        let Some(creds) = self.credential_manager.as_ref() else {
+            return Ok(());
+        };
+
+        self.secret_cache.invalidate(from)?;
+        self.secret_cache.invalidate(to)?;
+        let profile = self.active_secret_profile();
+
+        for (path, _) in schema.iter().filter(|(_, metadata)| metadata.is_secret()) {
+            let old_key = self.secret_credential_key(from, path);
+            if let Some(secret) = creds.get_with_profile(&old_key, profile.as_deref())? {
+                let new_key = self.secret_credential_key(to, path);
+                creds.store_with_profile(&new_key, &secret, profile.as_deref())?;
+                creds.add_tracked_secret(&new_key, profile.as_deref())?;
+            }
+            creds.remove_with_profile(&old_key, profile.as_deref())?;
+            creds.remove_tracked_secret(&old_key, profile.as_deref())?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(any(feature = "keychain", feature = "encrypted-file")))]
+    fn move_secret_fields(&self, _from: &str, _to: &str) -> Result<()> {
+        Ok(())
+    }
+
     // Delegation methods
 
     /// Get a raw Value from the store
@@ -691,6 +1185,7 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
                 // If at least one secret exists in keyring, reconstruct from keyring + defaults
                 if self.has_stored_secret_for_entry(name)? {
                     let mut empty_value = serde_json::json!({});
+                    self.fill_schema_defaults(&mut empty_value);
                     self.inject_secrets_from_store(name, &mut empty_value)?;
                     return Ok(empty_value);
                 }
@@ -702,11 +1197,20 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
             Err(e) => return Err(e),
         };
 
-        // Inject secrets into the existing value
-        self.inject_secrets_from_store(name, &mut value)?;
+        self.prepare_read(name, &mut value)?;
         Ok(value)
     }
 
+    /// Turn a stored entry into the value handed to callers
+    fn prepare_read(&self, name: &str, value: &mut Value) -> Result<()> {
+        self.strip_schema_version(value);
+        self.check_strict_read(name, value)?;
+        self.fill_schema_defaults(value);
+
+        // Inject secrets into the existing value
+        self.inject_secrets_from_store(name, value)
+    }
+
     /// Get and deserialize a value from the store
     ///
     /// # Arguments
@@ -737,16 +1241,45 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
     /// - Serialization fails
     /// - Store write fails
     pub fn set<T: Serialize + Sync>(&self, name: &str, value: &T) -> Result<()> {
+        self.set_and_get(name, value).map(drop)
+    }
+
+    /// Serialize and set a value, returning the entry as it was persisted
+    ///
+    /// The returned value is what [`get_value`](Self::get_value) would return,
+    /// except for secrets: secret fields are moved to the credential store on
+    /// write, so they are absent from the returned value (their stored
+    /// representation in the entry). Schema defaults are filled in as on read.
+    /// This saves a follow-up `get_value` and its credential-store lookups.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Serialization or schema validation fails
+    /// - Creating the entry would exceed [`max_entries`](SubSettingsConfig::with_max_entries)
+    /// - Store write fails
+    pub fn set_and_get<T: Serialize + Sync>(&self, name: &str, value: &T) -> Result<Value> {
         let mut json_value =
             serde_json::to_value(value).map_err(|e| Error::Parse(e.to_string()))?;
 
         self.validate_against_schema(name, &json_value)?;
-        self.extract_and_store_secrets(name, &mut json_value)?;
 
-        let existed = self.exists(name)?;
+        let existed;
+        let mut persisted;
+        {
+            // Held for the whole check-and-write, so concurrent creates
+            // cannot both pass the entry limit
+            let store = self.store.write_recovered()?;
+            existed = self.entry_exists(&**store, name)?;
+            if !existed {
+                self.check_entry_limit(&**store, 1)?;
+            }
 
-        let store = self.store.read_recovered()?;
-        store.set(name, json_value)?;
+            self.extract_and_store_secrets(name, &mut json_value)?;
+            persisted = json_value.clone();
+            self.stamp_schema_version(&mut json_value);
+            store.set(name, json_value)?;
+        }
 
         let action = if existed {
             SubSettingsAction::Updated
@@ -755,9 +1288,202 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
         };
 
         self.notify_change(name, action);
+
+        self.fill_schema_defaults(&mut persisted);
+        Ok(persisted)
+    }
+
+    /// Serialize and set several entries in one batch
+    ///
+    /// Every entry is validated against the schema before anything is written,
+    /// so an invalid entry leaves the store untouched. The writes are wrapped in
+    /// a storage batch (see [`StorageBackend::begin_batch`]); in single-file mode
+    /// this means the file is rewritten once instead of once per entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Serialization or schema validation fails for any entry
+    /// - The new entries would exceed [`max_entries`](SubSettingsConfig::with_max_entries)
+    /// - Store write fails
+    pub fn set_many<T: Serialize + Sync>(&self, entries: &[(&str, T)]) -> Result<()> {
+        let mut prepared = Vec::with_capacity(entries.len());
+        for (name, value) in entries {
+            let json_value =
+                serde_json::to_value(value).map_err(|e| Error::Parse(e.to_string()))?;
+            self.validate_against_schema(name, &json_value)?;
+            prepared.push(((*name).to_string(), json_value));
+        }
+
+        let mut actions = Vec::with_capacity(prepared.len());
+        {
+            // Held for the whole check-and-write, as in `set_and_get`
+            let store = self.store.write_recovered()?;
+            let mut new_names = std::collections::HashSet::new();
+            for (name, _) in &prepared {
+                if !self.entry_exists(&**store, name)? {
+                    new_names.insert(name.clone());
+                }
+            }
+            self.check_entry_limit(&**store, new_names.len())?;
+
+            for (name, json_value) in &mut prepared {
+                self.extract_and_store_secrets(name, json_value)?;
+                self.stamp_schema_version(json_value);
+                let action = if new_names.contains(name) {
+                    SubSettingsAction::Created
+                } else {
+                    SubSettingsAction::Updated
+                };
+                actions.push((name.clone(), action));
+            }
+
+            store.set_many(prepared)?;
+        }
+
+        for (name, action) in actions {
+            self.notify_change(&name, action);
+        }
         Ok(())
     }
 
+    /// Replace the whole set of entries
+    ///
+    /// Entries missing from `entries` are deleted (including their secrets),
+    /// new ones are created and existing ones are overwritten. Every incoming
+    /// entry is validated before anything is written, and the store is locked
+    /// for the whole diff-and-write so no other call through this handler can
+    /// interleave. Entries with a `null` value are treated as absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Schema validation fails for any entry
+    /// - `entries` exceeds [`max_entries`](SubSettingsConfig::with_max_entries)
+    /// - Store read or write fails
+    /// - Storing or clearing secrets fails
+    pub fn replace_all(&self, mut entries: HashMap<String, Value>) -> Result<ReplaceReport> {
+        entries.retain(|_, value| !value.is_null());
+        for (name, value) in &entries {
+            self.validate_against_schema(name, value)?;
+        }
+        if let Some(limit) = self.config.max_entries
+            && entries.len() > limit
+        {
+            return Err(Error::SubSettingsLimitReached {
+                name: self.config.name.clone(),
+                limit,
+            });
+        }
+
+        let mut report = ReplaceReport::default();
+        {
+            let store = self.store.write_recovered()?;
+            let current: std::collections::HashSet<String> = store.list()?.into_iter().collect();
+
+            let entries_set: std::collections::HashSet<String> = entries.keys().cloned().collect();
+            let mut prepared = Vec::with_capacity(entries.len());
+            for (name, mut value) in entries {
+                self.extract_and_store_secrets(&name, &mut value)?;
+                self.stamp_schema_version(&mut value);
+                if current.contains(&name) {
+                    report.updated.push(name.clone());
+                } else {
+                    report.added.push(name.clone());
+                }
+                prepared.push((name, value));
+            }
+
+            // A null value removes the entry from the store
+            for name in current {
+                if !entries_set.contains(&name) {
+                    prepared.push((name.clone(), Value::Null));
+                    report.removed.push(name);
+                }
+            }
+
+            store.set_many(prepared)?;
+        }
+
+        for name in &report.removed {
+            self.clear_secret_fields(name)?;
+        }
+
+        report.added.sort();
+        report.updated.sort();
+        report.removed.sort();
+
+        for name in &report.added {
+            self.notify_change(name, SubSettingsAction::Created);
+        }
+        for name in &report.updated {
+            self.notify_change(name, SubSettingsAction::Updated);
+        }
+        for name in &report.removed {
+            self.notify_change(name, SubSettingsAction::Deleted);
+        }
+        Ok(report)
+    }
+
+    /// Import every `*.{extension}` file in `dir` as an entry
+    ///
+    /// The file stem becomes the entry name. Files are parsed with the storage
+    /// backend and written through [`set_many`](Self::set_many), so every entry
+    /// is validated before anything is written and secret fields are moved to
+    /// the credential store. Existing entries are skipped unless `overwrite`
+    /// is set. Returns the imported names, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `dir` or a file in it cannot be read or parsed
+    /// - Schema validation fails for any entry
+    /// - The new entries would exceed [`max_entries`](SubSettingsConfig::with_max_entries)
+    /// - Store write or storing secrets fails
+    pub fn import_dir(&self, dir: &Path, overwrite: bool) -> Result<Vec<String>> {
+        let read_dir = std::fs::read_dir(dir).map_err(|e| Error::DirectoryRead {
+            path: dir.to_path_buf(),
+            source: e,
+        })?;
+
+        let mut entries = Vec::new();
+        for file in read_dir {
+            let path = file
+                .map_err(|e| Error::DirectoryRead {
+                    path: dir.to_path_buf(),
+                    source: e,
+                })?
+                .path();
+            if !path.is_file()
+                || path.extension().and_then(|ext| ext.to_str()) != Some(self.extension())
+            {
+                continue;
+            }
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            if !overwrite && self.exists(name)? {
+                continue;
+            }
+
+            let content = std::fs::read_to_string(&path).map_err(|e| Error::FileRead {
+                path: path.clone(),
+                source: e,
+            })?;
+            let value: Value = self.storage.deserialize(&content)?;
+            entries.push((name.to_string(), value));
+        }
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let batch: Vec<(&str, &Value)> = entries
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+            .collect();
+        self.set_many(&batch)?;
+
+        Ok(entries.into_iter().map(|(name, _)| name).collect())
+    }
+
     /// Delete a value from the store
     ///
     /// # Arguments
@@ -781,6 +1507,48 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
         Ok(())
     }
 
+    /// Rename an entry
+    ///
+    /// Moves the stored entry (its file, or its key in single-file mode) and
+    /// the credential-store keys of its secret fields, so secrets are never
+    /// read back into a plain value along the way. Listeners see a
+    /// [`Deleted`](SubSettingsAction::Deleted) for `from` followed by a
+    /// [`Created`](SubSettingsAction::Created) for `to`. Renaming an entry to
+    /// its own name does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `from` does not exist
+    /// - `to` already exists
+    /// - Store access or moving the secrets fails
+    pub fn rename(&self, from: &str, to: &str) -> Result<()> {
+        if !self.exists(from)? {
+            return Err(Error::SubSettingsEntryNotFound(format!(
+                "Sub-setting entry '{from}' not found"
+            )));
+        }
+        if from == to {
+            return Ok(());
+        }
+        if self.exists(to)? {
+            return Err(Error::SubSettingsEntryExists(to.to_string()));
+        }
+
+        {
+            let store = self.store.read_recovered()?;
+            // An entry can exist only through its secrets
+            if store.exists(from)? {
+                store.rename(from, to)?;
+            }
+        }
+        self.move_secret_fields(from, to)?;
+
+        self.notify_change(from, SubSettingsAction::Deleted);
+        self.notify_change(to, SubSettingsAction::Created);
+        Ok(())
+    }
+
     /// List all sub-setting keys
     ///
     /// # Errors
@@ -794,11 +1562,12 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
     /// Get all sub-setting entries as a map
     ///
     /// Returns a `HashMap<String, Value>` with all entry names as keys
-    /// and their deserialized values. Entries that fail to load are silently skipped.
+    /// and their deserialized values.
     ///
     /// # Errors
     ///
-    /// Returns an error if the store cannot be read.
+    /// Returns an error if the store cannot be read, or if an entry is
+    /// rejected by [`StrictRead::Error`].
     pub fn get_all_values(&self) -> Result<HashMap<String, Value>> {
         let mut result = {
             let store = self.store.read_recovered()?;
@@ -806,12 +1575,66 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
         };
 
         for (name, value) in &mut result {
+            self.strip_schema_version(value);
+            self.check_strict_read(name, value)?;
+            self.fill_schema_defaults(value);
             let _ = self.inject_secrets_from_store(name, value);
         }
 
         Ok(result)
     }
 
+    /// Get every entry as it is stored, without defaults or secrets filled in
+    pub(crate) fn get_all_stored(&self) -> Result<HashMap<String, Value>> {
+        self.store.read_recovered()?.get_all()
+    }
+
+    /// Get every entry with its value, sorted by name
+    ///
+    /// Reads the store once (one directory scan in multi-file mode, one file
+    /// read in single-file mode) instead of a [`get_value`](Self::get_value)
+    /// call per [`list`](Self::list) name. Each value is returned as
+    /// `get_value` would return it, with secrets injected. The entries are
+    /// cached as on a `get_value` call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be read, or if an entry is
+    /// rejected by [`StrictRead::Error`] or its secrets cannot be read.
+    pub fn entries(&self) -> Result<Vec<(String, Value)>> {
+        let stored = {
+            let store = self.store.read_recovered()?;
+            store.get_all()?
+        };
+
+        let mut entries = Vec::with_capacity(stored.len());
+        for (name, mut value) in stored {
+            self.prepare_read(&name, &mut value)?;
+            entries.push((name, value));
+        }
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Ok(entries)
+    }
+
+    /// Find the entries whose value at `field_path` equals `value`
+    ///
+    /// `field_path` uses dot notation for nested fields. Entries are loaded as
+    /// by [`entries`](Self::entries), so migrated values, schema defaults and
+    /// injected secrets are compared, and the cache is used where available.
+    /// Entries without the field don't match. Names are returned sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entries cannot be read (see [`entries`](Self::entries)).
+    pub fn find_by(&self, field_path: &str, value: &Value) -> Result<Vec<String>> {
+        Ok(self
+            .entries()?
+            .into_iter()
+            .filter(|(_, entry)| crate::utils::value::get_path(entry, field_path) == Some(value))
+            .map(|(name, _)| name)
+            .collect())
+    }
+
     /// Check if a sub-setting key exists
     ///
     /// # Arguments
@@ -823,14 +1646,41 @@ impl<S: StorageBackend + Clone + 'static> SubSettings<S> {
     /// Returns an error if the store cannot be read or if an unexpected error occurs during lookup.
     pub fn exists(&self, name: &str) -> Result<bool> {
         let store = self.store.read_recovered()?;
-        if store.exists(name)? {
-            return Ok(true);
-        }
+        self.entry_exists(&**store, name)
+    }
 
-        if self.has_stored_secret_for_entry(name)? {
-            return Ok(true);
-        }
+    /// Whether `name` has a stored entry or any stored secret
+    fn entry_exists(&self, store: &dyn SubSettingsStore, name: &str) -> Result<bool> {
+        Ok(store.exists(name)? || self.has_stored_secret_for_entry(name)?)
+    }
+
+    /// Check whether any entry name matches `predicate`
+    ///
+    /// Only entry names are inspected, so no entry content is loaded. Stops at
+    /// the first match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be read.
+    pub fn exists_matching(&self, predicate: impl Fn(&str) -> bool) -> Result<bool> {
+        Ok(self.list()?.iter().any(|name| predicate(name)))
+    }
 
+    /// Check whether any entry value matches `predicate`
+    ///
+    /// Entries are loaded one at a time (as returned by
+    /// [`get_value`](Self::get_value)) and the scan stops at the first match,
+    /// so it is cheaper than loading every entry when a match exists early.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store or an entry cannot be read.
+    pub fn exists_where(&self, predicate: impl Fn(&str, &Value) -> bool) -> Result<bool> {
+        for name in self.list()? {
+            if predicate(&name, &self.get_value(&name)?) {
+                return Ok(true);
+            }
+        }
         Ok(false)
     }
 
@@ -8,6 +8,7 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 
 type SubSettingsMigrator = Arc<dyn Fn(Value) -> Value + Send + Sync>;
@@ -15,6 +16,59 @@ type SubSettingsMigrator = Arc<dyn Fn(Value) -> Value + Send + Sync>;
 enum CacheType {
     Full(HashMap<String, Value>),
     Lru(lru::LruCache<String, Value>),
+    Budget(BudgetCache),
+}
+
+/// Serialized size of a cached value
+fn entry_size(value: &Value) -> usize {
+    serde_json::to_vec(value).map_or(0, |bytes| bytes.len())
+}
+
+/// LRU cache holding at most `budget` bytes of serialized entries
+struct BudgetCache {
+    entries: lru::LruCache<String, (Value, usize)>,
+    bytes: usize,
+    budget: usize,
+}
+
+impl BudgetCache {
+    fn new(budget: usize) -> Self {
+        Self {
+            entries: lru::LruCache::unbounded(),
+            bytes: 0,
+            budget,
+        }
+    }
+
+    fn get(&mut self, key: &str) -> Option<&Value> {
+        self.entries.get(key).map(|(value, _)| value)
+    }
+
+    /// Insert `value` and evict least recently used entries until the cache
+    /// fits the budget again. Returns the number of evicted entries.
+    fn put(&mut self, key: String, value: Value) -> u64 {
+        let size = entry_size(&value);
+        if let Some((_, old_size)) = self.entries.put(key, (value, size)) {
+            self.bytes -= old_size;
+        }
+        self.bytes += size;
+
+        let mut evicted = 0;
+        while self.bytes > self.budget {
+            let Some((_, (_, size))) = self.entries.pop_lru() else {
+                break;
+            };
+            self.bytes -= size;
+            evicted += 1;
+        }
+        evicted
+    }
+
+    fn pop(&mut self, key: &str) {
+        if let Some((_, size)) = self.entries.pop(key) {
+            self.bytes -= size;
+        }
+    }
 }
 
 struct MultiFileStoreState {
@@ -30,6 +84,9 @@ pub struct MultiFileStore<S: StorageBackend> {
     migrator: Option<SubSettingsMigrator>,
     cache_strategy: CacheStrategy,
     state: RwLock<MultiFileStoreState>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
 }
 
 impl<S: StorageBackend> MultiFileStore<S> {
@@ -52,6 +109,9 @@ impl<S: StorageBackend> MultiFileStore<S> {
                 cache: None,
                 loaded_from_dir: false,
             }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
         }
     }
 
@@ -66,6 +126,7 @@ impl<S: StorageBackend> MultiFileStore<S> {
                 let cap = NonZeroUsize::new(size).unwrap_or(NonZeroUsize::new(100).unwrap());
                 CacheType::Lru(lru::LruCache::new(cap))
             }
+            CacheStrategy::MemoryBudget(bytes) => CacheType::Budget(BudgetCache::new(bytes)),
             CacheStrategy::None => {
                 unreachable!("Cache should not be initialized if strategy is None")
             }
@@ -90,8 +151,28 @@ impl<S: StorageBackend> MultiFileStore<S> {
         Ok(())
     }
 
+    /// Cache a value read from or written to disk, counting evictions
+    fn cache_value(&self, cache: &mut CacheType, key: &str, value: Value) {
+        let evicted = match cache {
+            CacheType::Full(cache) => {
+                cache.insert(key.to_string(), value);
+                0
+            }
+            CacheType::Lru(cache) => match cache.push(key.to_string(), value) {
+                Some((evicted, _)) if evicted != key => 1,
+                _ => 0,
+            },
+            CacheType::Budget(cache) => cache.put(key.to_string(), value),
+        };
+        self.evictions.fetch_add(evicted, Ordering::Relaxed);
+    }
+
     fn load_directory_into_cache_keys(&self) -> Result<()> {
-        if matches!(self.cache_strategy, CacheStrategy::None) {
+        // A byte budget holds values only; keys are always listed from disk
+        if matches!(
+            self.cache_strategy,
+            CacheStrategy::None | CacheStrategy::MemoryBudget(_)
+        ) {
             return Ok(());
         }
 
@@ -108,26 +189,7 @@ impl<S: StorageBackend> MultiFileStore<S> {
             state.cache = Some(self.create_cache());
         }
 
-        if !self.base_dir.exists() {
-            state.loaded_from_dir = true;
-            return Ok(());
-        }
-
-        let ext = format!(".{}", self.extension);
-        let mut keys = Vec::new();
-        for entry in std::fs::read_dir(&self.base_dir).map_err(|e| Error::DirectoryRead {
-            path: self.base_dir.clone(),
-            source: e,
-        })? {
-            let entry = entry.map_err(|e| Error::DirectoryRead {
-                path: self.base_dir.clone(),
-                source: e,
-            })?;
-            let name = entry.file_name().to_string_lossy().to_string();
-            if name.ends_with(&ext) {
-                keys.push(name.trim_end_matches(&ext).to_string());
-            }
-        }
+        let keys = self.storage.list_entries(&self.base_dir, &self.extension)?;
 
         match &mut state.cache {
             Some(CacheType::Full(cache)) => {
@@ -142,7 +204,7 @@ impl<S: StorageBackend> MultiFileStore<S> {
                     }
                 }
             }
-            None => {}
+            Some(CacheType::Budget(_)) | None => {}
         }
 
         state.loaded_from_dir = true;
@@ -161,21 +223,31 @@ impl<S: StorageBackend> SubSettingsStore for MultiFileStore<S> {
                     && let Some(val) = cache.get(key)
                     && !val.is_null()
                 {
+                    self.hits.fetch_add(1, Ordering::Relaxed);
                     return Ok(val.clone());
                 }
-            } else if matches!(self.cache_strategy, CacheStrategy::Lru(_)) {
+            } else if !matches!(self.cache_strategy, CacheStrategy::None) {
                 let mut state = self.state.write_recovered()?;
-                if let Some(CacheType::Lru(cache)) = &mut state.cache
-                    && let Some(val) = cache.get(key)
+                let cached = match &mut state.cache {
+                    Some(CacheType::Lru(cache)) => cache.get(key),
+                    Some(CacheType::Budget(cache)) => cache.get(key),
+                    _ => None,
+                };
+                if let Some(val) = cached
                     && !val.is_null()
                 {
+                    self.hits.fetch_add(1, Ordering::Relaxed);
                     return Ok(val.clone());
                 }
             }
+
+            if !matches!(self.cache_strategy, CacheStrategy::None) {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+            }
         }
 
         let path = self.file_path(key);
-        if !path.exists() {
+        if !self.storage.exists(&path) {
             if let Ok(mut state) = self.state.write_recovered()
                 && let Some(cache) = &mut state.cache
             {
@@ -186,6 +258,7 @@ impl<S: StorageBackend> SubSettingsStore for MultiFileStore<S> {
                     CacheType::Lru(c) => {
                         c.pop(key);
                     }
+                    CacheType::Budget(c) => c.pop(key),
                 }
             }
             return Err(Error::SubSettingsEntryNotFound(format!(
@@ -207,14 +280,8 @@ impl<S: StorageBackend> SubSettingsStore for MultiFileStore<S> {
 
         if !matches!(self.cache_strategy, CacheStrategy::None) {
             let mut state = self.state.write_recovered()?;
-            match &mut state.cache {
-                Some(CacheType::Full(cache)) => {
-                    cache.insert(key.to_string(), value.clone());
-                }
-                Some(CacheType::Lru(cache)) => {
-                    cache.put(key.to_string(), value.clone());
-                }
-                _ => {}
+            if let Some(cache) = &mut state.cache {
+                self.cache_value(cache, key, value.clone());
             }
         }
 
@@ -226,39 +293,32 @@ impl<S: StorageBackend> SubSettingsStore for MultiFileStore<S> {
             return self.remove(key);
         }
 
-        let path = self.file_path(key);
-
-        if !self.base_dir.exists() {
-            crate::utils::security::ensure_secure_dir(&self.base_dir)?;
-        }
-
-        self.storage.write(&path, &value)?;
+        // The backend creates the entry's directory (if it has one) on write.
+        self.storage.write(&self.file_path(key), &value)?;
 
         if !matches!(self.cache_strategy, CacheStrategy::None) {
             let mut state = self.state.write_recovered()?;
-            if state.cache.is_none() {
-                state.cache = Some(self.create_cache());
-            }
-            match &mut state.cache {
-                Some(CacheType::Full(cache)) => {
-                    cache.insert(key.to_string(), value);
-                }
-                Some(CacheType::Lru(cache)) => {
-                    cache.put(key.to_string(), value);
-                }
-                _ => {}
-            }
+            let cache = state.cache.get_or_insert_with(|| self.create_cache());
+            self.cache_value(cache, key, value);
         }
 
         Ok(())
     }
 
-    fn remove(&self, key: &str) -> Result<()> {
-        let path = self.file_path(key);
+    fn set_many(&self, entries: Vec<(String, Value)>) -> Result<()> {
+        self.storage.begin_batch()?;
 
-        if path.exists() {
-            std::fs::remove_file(&path).map_err(|e| Error::FileDelete { path, source: e })?;
-        }
+        let result = entries
+            .into_iter()
+            .try_for_each(|(key, value)| self.set(&key, value));
+
+        // The batch is always committed so writes that already happened stay durable
+        let committed = self.storage.commit_batch();
+        result.and(committed)
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        self.storage.remove(&self.file_path(key))?;
 
         let mut state = self.state.write_recovered()?;
         match &mut state.cache {
@@ -268,7 +328,8 @@ impl<S: StorageBackend> SubSettingsStore for MultiFileStore<S> {
             Some(CacheType::Lru(cache)) => {
                 cache.pop(key);
             }
-            _ => {}
+            Some(CacheType::Budget(cache)) => cache.pop(key),
+            None => {}
         }
 
         Ok(())
@@ -296,36 +357,24 @@ impl<S: StorageBackend> SubSettingsStore for MultiFileStore<S> {
                             return Ok(true);
                         }
                     }
+                    CacheType::Budget(c) => {
+                        if c.entries.contains(key) {
+                            return Ok(true);
+                        }
+                    }
                 }
             }
         }
 
-        Ok(self.file_path(key).exists())
+        Ok(self.storage.exists(&self.file_path(key)))
     }
 
     fn list(&self) -> Result<Vec<String>> {
-        if matches!(self.cache_strategy, CacheStrategy::None) {
-            if !self.base_dir.exists() {
-                return Ok(Vec::new());
-            }
-
-            let mut entries = Vec::new();
-            let ext = format!(".{}", self.extension);
-
-            for entry in std::fs::read_dir(&self.base_dir).map_err(|e| Error::DirectoryRead {
-                path: self.base_dir.clone(),
-                source: e,
-            })? {
-                let entry = entry.map_err(|e| Error::DirectoryRead {
-                    path: self.base_dir.clone(),
-                    source: e,
-                })?;
-                let name = entry.file_name().to_string_lossy().to_string();
-                if name.ends_with(&ext) {
-                    entries.push(name.trim_end_matches(&ext).to_string());
-                }
-            }
-
+        if matches!(
+            self.cache_strategy,
+            CacheStrategy::None | CacheStrategy::MemoryBudget(_)
+        ) {
+            let mut entries = self.storage.list_entries(&self.base_dir, &self.extension)?;
             entries.sort();
             return Ok(entries);
         }
@@ -368,6 +417,31 @@ impl<S: StorageBackend> SubSettingsStore for MultiFileStore<S> {
         }
     }
 
+    fn cache_stats(&self) -> Result<crate::CacheStats> {
+        let state = self.state.read_recovered()?;
+        let (entries, bytes) = match &state.cache {
+            Some(CacheType::Full(cache)) => cache
+                .values()
+                .filter(|value| !value.is_null())
+                .fold((0, 0), |(n, b), value| (n + 1, b + entry_size(value))),
+            Some(CacheType::Lru(cache)) => cache
+                .iter()
+                .map(|(_, value)| value)
+                .filter(|value| !value.is_null())
+                .fold((0, 0), |(n, b), value| (n + 1, b + entry_size(value))),
+            Some(CacheType::Budget(cache)) => (cache.entries.len(), cache.bytes),
+            None => (0, 0),
+        };
+
+        Ok(crate::CacheStats {
+            entries,
+            bytes,
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+        })
+    }
+
     fn base_path(&self) -> PathBuf {
         self.base_dir.clone()
     }
@@ -499,4 +573,45 @@ mod tests {
         store.get("a").unwrap();
         assert_eq!(reads.load(Ordering::SeqCst), 1);
     }
+
+    #[test]
+    fn test_multifile_memory_budget_eviction() {
+        let dir = tempfile::tempdir().unwrap();
+        let writes = Arc::new(AtomicUsize::new(0));
+        let reads = Arc::new(AtomicUsize::new(0));
+        let storage = CountingStorage::new(writes.clone(), reads.clone());
+
+        let small = json!({"t": "s3"});
+        let large = json!({"blob": "x".repeat(100)});
+        let budget = 2 * entry_size(&small) + 10;
+
+        let store = MultiFileStore::new(
+            "remotes".to_string(),
+            dir.path().to_path_buf(),
+            "json".to_string(),
+            storage,
+            None,
+            CacheStrategy::MemoryBudget(budget),
+        );
+
+        store.set("a", small.clone()).unwrap();
+        store.set("b", small.clone()).unwrap();
+        let stats = store.cache_stats().unwrap();
+        assert_eq!((stats.entries, stats.bytes), (2, 2 * entry_size(&small)));
+
+        // The large entry exceeds the budget alone, so everything is evicted
+        store.set("c", large).unwrap();
+        let stats = store.cache_stats().unwrap();
+        assert_eq!((stats.entries, stats.bytes, stats.evictions), (0, 0, 3));
+
+        // Keys are listed from disk even though nothing is cached
+        assert_eq!(store.list().unwrap(), vec!["a", "b", "c"]);
+
+        store.get("a").unwrap();
+        store.get("a").unwrap();
+        assert_eq!(reads.load(Ordering::SeqCst), 1);
+        let stats = store.cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+        assert_eq!(stats.bytes, entry_size(&small));
+    }
 }
@@ -0,0 +1,116 @@
+//! Cache of secret values injected into sub-settings entries
+//!
+//! Reading an entry with secret fields costs one credential-store lookup per
+//! secret field, which is slow with the OS keychain. The resolved values are
+//! cached per (profile, entry) and dropped whenever the entry is written or
+//! deleted through [`SubSettings`](super::SubSettings).
+
+use crate::CacheStrategy;
+use crate::error::{Error, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+/// Resolved secret fields of one entry: (field path, value)
+pub(crate) type EntrySecrets = Vec<(String, Value)>;
+
+/// Cache key: (active profile, entry name)
+type SecretKey = (Option<String>, String);
+
+enum CacheType {
+    Full(HashMap<SecretKey, EntrySecrets>),
+    Lru(lru::LruCache<SecretKey, EntrySecrets>),
+}
+
+pub(crate) struct SecretCache {
+    /// `None` when caching is disabled (`CacheStrategy::None`)
+    cache: Option<Mutex<CacheType>>,
+}
+
+impl SecretCache {
+    pub(crate) fn new(strategy: CacheStrategy) -> Self {
+        let cache = match strategy {
+            // Secrets are small, so a byte budget is not worth enforcing here
+            CacheStrategy::Full | CacheStrategy::MemoryBudget(_) => {
+                Some(CacheType::Full(HashMap::new()))
+            }
+            CacheStrategy::Lru(size) => {
+                let cap = NonZeroUsize::new(size).unwrap_or(NonZeroUsize::MIN);
+                Some(CacheType::Lru(lru::LruCache::new(cap)))
+            }
+            CacheStrategy::None => None,
+        };
+        Self {
+            cache: cache.map(Mutex::new),
+        }
+    }
+
+    pub(crate) fn get(&self, profile: Option<&str>, entry: &str) -> Result<Option<EntrySecrets>> {
+        let Some(cache) = &self.cache else {
+            return Ok(None);
+        };
+        let key = (profile.map(ToString::to_string), entry.to_string());
+        let mut cache = cache.lock().map_err(|_| Error::LockPoisoned)?;
+        Ok(match &mut *cache {
+            CacheType::Full(map) => map.get(&key).cloned(),
+            CacheType::Lru(lru) => lru.get(&key).cloned(),
+        })
+    }
+
+    pub(crate) fn insert(
+        &self,
+        profile: Option<&str>,
+        entry: &str,
+        secrets: EntrySecrets,
+    ) -> Result<()> {
+        let Some(cache) = &self.cache else {
+            return Ok(());
+        };
+        let key = (profile.map(ToString::to_string), entry.to_string());
+        let mut cache = cache.lock().map_err(|_| Error::LockPoisoned)?;
+        match &mut *cache {
+            CacheType::Full(map) => {
+                map.insert(key, secrets);
+            }
+            CacheType::Lru(lru) => {
+                lru.put(key, secrets);
+            }
+        }
+        Ok(())
+    }
+
+    /// Drop the cached secrets of `entry` in every profile
+    pub(crate) fn invalidate(&self, entry: &str) -> Result<()> {
+        let Some(cache) = &self.cache else {
+            return Ok(());
+        };
+        let mut cache = cache.lock().map_err(|_| Error::LockPoisoned)?;
+        match &mut *cache {
+            CacheType::Full(map) => map.retain(|(_, name), _| name != entry),
+            CacheType::Lru(lru) => {
+                let stale: Vec<SecretKey> = lru
+                    .iter()
+                    .filter(|((_, name), _)| name == entry)
+                    .map(|(key, _)| key.clone())
+                    .collect();
+                for key in stale {
+                    lru.pop(&key);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn clear(&self) -> Result<()> {
+        let Some(cache) = &self.cache else {
+            return Ok(());
+        };
+        let mut cache = cache.lock().map_err(|_| Error::LockPoisoned)?;
+        match &mut *cache {
+            CacheType::Full(map) => map.clear(),
+            CacheType::Lru(lru) => lru.clear(),
+        }
+        Ok(())
+    }
+}
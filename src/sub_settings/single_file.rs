@@ -63,15 +63,13 @@ impl<S: StorageBackend> SingleFileStore<S> {
 
         let path = self.file_path();
 
-        let mut file_data = match std::fs::metadata(&path) {
-            Ok(_) => self.storage.read::<Value>(&path)?,
-            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
-                state.loaded_from_disk = true;
-                state.cache = Some(HashMap::new());
-                return Ok(());
-            }
-            Err(e) => return Err(Error::FileRead { path, source: e }),
-        };
+        if !self.storage.exists(&path) {
+            state.loaded_from_disk = true;
+            state.cache = Some(HashMap::new());
+            return Ok(());
+        }
+
+        let mut file_data = self.storage.read::<Value>(&path)?;
 
         if let Some(migrator) = &self.migrator {
             let original = file_data.clone();
@@ -96,15 +94,7 @@ impl<S: StorageBackend> SingleFileStore<S> {
     }
 
     fn save_to_disk(&self, cache: &HashMap<String, Value>) -> Result<()> {
-        let path = self.file_path();
-
-        if let Some(parent) = path.parent()
-            && !parent.exists()
-        {
-            crate::utils::security::ensure_secure_dir(parent)?;
-        }
-
-        self.storage.write(&path, cache)?;
+        self.storage.write(&self.file_path(), cache)?;
         Ok(())
     }
 }
@@ -153,6 +143,33 @@ impl<S: StorageBackend> SubSettingsStore for SingleFileStore<S> {
         Ok(())
     }
 
+    fn set_many(&self, entries: Vec<(String, Value)>) -> Result<()> {
+        self.ensure_loaded()?;
+
+        let mut state = self.state.write_recovered()?;
+        let cache = state.cache.get_or_insert_with(HashMap::new);
+
+        let mut changed = false;
+        for (key, value) in entries {
+            if value.is_null() {
+                changed |= cache.remove(&key).is_some();
+            } else if cache.get(&key) != Some(&value) {
+                cache.insert(key, value);
+                changed = true;
+            }
+        }
+
+        // All entries live in one file, so a single write covers the whole batch
+        if changed {
+            self.storage.begin_batch()?;
+            let result = self.save_to_disk(cache);
+            let committed = self.storage.commit_batch();
+            return result.and(committed);
+        }
+
+        Ok(())
+    }
+
     fn remove(&self, key: &str) -> Result<()> {
         self.ensure_loaded()?;
 
@@ -298,6 +315,30 @@ mod tests {
         assert_eq!(writes.load(Ordering::SeqCst), 0);
     }
 
+    #[test]
+    fn test_set_many_writes_file_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let writes = Arc::new(AtomicUsize::new(0));
+        let storage = CountingStorage::new(writes.clone());
+        let store = SingleFileStore::new(
+            "backends".to_string(),
+            dir.path().to_path_buf(),
+            "json".to_string(),
+            storage,
+            None,
+        );
+
+        store
+            .set_many(vec![
+                ("a".to_string(), json!({"host": "a"})),
+                ("b".to_string(), json!({"host": "b"})),
+                ("c".to_string(), json!({"host": "c"})),
+            ])
+            .unwrap();
+        assert_eq!(writes.load(Ordering::SeqCst), 1);
+        assert_eq!(store.list().unwrap(), vec!["a", "b", "c"]);
+    }
+
     #[test]
     fn test_scalar_string_value_round_trips() {
         let dir = tempfile::tempdir().unwrap();
@@ -9,9 +9,31 @@ pub trait SubSettingsStore: Send + Sync {
     /// Set an entry
     fn set(&self, key: &str, value: Value) -> Result<()>;
 
+    /// Set several entries at once
+    ///
+    /// The default implementation calls [`set`](Self::set) for each entry.
+    fn set_many(&self, entries: Vec<(String, Value)>) -> Result<()> {
+        for (key, value) in entries {
+            self.set(&key, value)?;
+        }
+        Ok(())
+    }
+
     /// Remove an entry
     fn remove(&self, key: &str) -> Result<()>;
 
+    /// Move the entry stored under `from` to `to`
+    ///
+    /// The default implementation writes the entry under `to` and removes
+    /// `from` in one [`set_many`](Self::set_many) batch.
+    fn rename(&self, from: &str, to: &str) -> Result<()> {
+        let value = self.get(from)?;
+        self.set_many(vec![
+            (to.to_string(), value),
+            (from.to_string(), Value::Null),
+        ])
+    }
+
     /// Check if an entry exists (without reading its content)
     fn exists(&self, key: &str) -> Result<bool>;
 
@@ -24,6 +46,11 @@ pub trait SubSettingsStore: Send + Sync {
     /// Invalidate any internal cache
     fn invalidate_cache(&self);
 
+    /// Cache counters (all zero for stores without a tunable cache)
+    fn cache_stats(&self) -> Result<crate::CacheStats> {
+        Ok(crate::CacheStats::default())
+    }
+
     /// Base path (directory) of the store
     fn base_path(&self) -> std::path::PathBuf;
 
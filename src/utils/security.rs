@@ -270,6 +270,27 @@ fn set_secure_acl(_path: &Path) -> Result<()> {
     Ok(())
 }
 
+// =============================================================================
+// Secret Masking
+// =============================================================================
+
+/// Mask a secret for display, revealing only a short prefix and suffix.
+///
+/// Secrets of at least 12 characters keep their first 3 and last 4 characters
+/// (`sk-...wxyz`); shorter ones are fully hidden so little of them is exposed.
+#[must_use]
+pub fn mask_secret(secret: &str) -> String {
+    const MIN_REVEAL_LEN: usize = 12;
+
+    let chars: Vec<char> = secret.chars().collect();
+    if chars.len() < MIN_REVEAL_LEN {
+        return "********".to_string();
+    }
+    let prefix: String = chars[..3].iter().collect();
+    let suffix: String = chars[chars.len() - 4..].iter().collect();
+    format!("{prefix}...{suffix}")
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -297,6 +318,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mask_secret() {
+        assert_eq!(mask_secret("sk-abcdefghijklmnop1234"), "sk-...1234");
+        assert_eq!(mask_secret("short-pass1"), "********");
+        assert_eq!(mask_secret(""), "********");
+        assert_eq!(mask_secret("ключ-секрет-ёжик"), "клю...ёжик");
+    }
+
     #[test]
     fn test_secure_dir_permissions() {
         let dir = tempdir().unwrap();
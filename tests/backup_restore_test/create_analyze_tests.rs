@@ -105,3 +105,115 @@ fn test_analyze_encrypted_backup() {
     assert!(analysis.requires_password);
     assert_eq!(analysis.manifest.backup.app_name, "test-app");
 }
+
+#[test]
+fn test_list_backups_newest_first() {
+    let fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+    let backup = fixture.manager.backup();
+
+    assert!(
+        backup
+            .list(&backup_dir.path().join("missing"))
+            .unwrap()
+            .is_empty()
+    );
+
+    let older = backup
+        .create(
+            &BackupOptions::new()
+                .output_dir(backup_dir.path())
+                .filename_suffix("older")
+                .note("before update"),
+        )
+        .unwrap();
+    let newer = backup
+        .create(
+            &BackupOptions::new()
+                .output_dir(backup_dir.path())
+                .filename_suffix("newer")
+                .password("test_password_123"),
+        )
+        .unwrap();
+    fs::write(backup_dir.path().join("broken.rcman"), "not a zip").unwrap();
+    fs::write(backup_dir.path().join("notes.txt"), "ignored").unwrap();
+
+    let listed = backup.list(backup_dir.path()).unwrap();
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed[0].path, newer);
+    assert!(listed[0].encrypted);
+    assert_eq!(listed[0].note, None);
+    assert_eq!(listed[1].path, older);
+    assert!(!listed[1].encrypted);
+    assert_eq!(listed[1].note.as_deref(), Some("before update"));
+    assert_eq!(listed[1].app_version, "1.0.0");
+    assert_eq!(listed[1].size_bytes, fs::metadata(&older).unwrap().len());
+    assert!(listed[0].created_at >= listed[1].created_at);
+}
+
+#[test]
+fn test_prune_keeps_newest_backups() {
+    let fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+    let backup = fixture.manager.backup();
+
+    let paths: Vec<_> = ["a", "b", "c"]
+        .iter()
+        .map(|suffix| {
+            backup
+                .create(
+                    &BackupOptions::new()
+                        .output_dir(backup_dir.path())
+                        .filename_suffix(*suffix),
+                )
+                .unwrap()
+        })
+        .collect();
+    let unreadable = backup_dir.path().join("broken.rcman");
+    fs::write(&unreadable, "not a zip").unwrap();
+
+    let removed = backup.prune(backup_dir.path(), 1).unwrap();
+    assert_eq!(removed, vec![paths[1].clone(), paths[0].clone()]);
+    assert!(!paths[0].exists());
+    assert!(!paths[1].exists());
+    assert!(paths[2].exists());
+    assert!(unreadable.exists());
+
+    assert!(backup.prune(backup_dir.path(), 1).unwrap().is_empty());
+}
+
+#[test]
+fn test_create_to_writer() {
+    let fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+
+    let mut buffer = std::io::Cursor::new(Vec::new());
+    let manifest = fixture
+        .manager
+        .backup()
+        .create_to_writer(&mut buffer, &BackupOptions::new().note("streamed"))
+        .unwrap();
+    assert_eq!(manifest.backup.user_note.as_deref(), Some("streamed"));
+    assert!(fs::read_dir(backup_dir.path()).unwrap().next().is_none());
+
+    let backup_path = backup_dir.path().join("streamed.rcman");
+    fs::write(&backup_path, buffer.into_inner()).unwrap();
+
+    let analysis = fixture.manager.backup().analyze(&backup_path).unwrap();
+    assert!(analysis.is_valid);
+    assert_eq!(
+        analysis.manifest.backup.user_note.as_deref(),
+        Some("streamed")
+    );
+    assert_eq!(
+        analysis.manifest.integrity.sha256,
+        manifest.integrity.sha256
+    );
+    assert!(
+        analysis
+            .manifest
+            .contents
+            .sub_settings
+            .contains_key("remotes")
+    );
+}
@@ -1,5 +1,5 @@
 use super::*;
-use rcman::{BackupOptions, RestoreOptions};
+use rcman::{BackupCipher, BackupOptions, RestoreMode, RestoreOptions};
 use tempfile::TempDir;
 
 #[test]
@@ -37,6 +37,197 @@ fn test_restore_backup() {
     assert_eq!(remotes.get_value("s3").unwrap()["type"], "s3");
 }
 
+#[test]
+fn test_restore_merge_mode_combines_with_current_data() {
+    let original_fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+
+    let backup_path = original_fixture
+        .manager
+        .backup()
+        .create(&BackupOptions::new().output_dir(backup_dir.path()))
+        .unwrap();
+
+    let new_fixture = TestFixture::with_sub_settings();
+    let manager = &new_fixture.manager;
+    manager
+        .save_setting("general", "language", &json!("de"))
+        .unwrap();
+    manager
+        .save_setting("ui", "font_size", &json!(20.0))
+        .unwrap();
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes
+        .set("gdrive", &json!({"type": "drive", "account": "me"}))
+        .unwrap();
+
+    let options = RestoreOptions::from_path(&backup_path).mode(RestoreMode::Merge);
+    assert_eq!(options.flags.control.mode(), RestoreMode::Merge);
+    let result = manager.backup().restore(&options).unwrap();
+    assert!(result.restored.contains(&"settings.json".to_string()));
+
+    manager.invalidate_cache();
+    let settings = manager.get_all().unwrap();
+    // Backup wins on conflicts, keys only in the current data are kept
+    assert_eq!(settings.ui.theme, "light");
+    assert!((settings.ui.font_size - 18.0).abs() < f64::EPSILON);
+    assert_eq!(settings.general.language, "de");
+
+    assert_eq!(
+        remotes.get_value("gdrive").unwrap(),
+        json!({"type": "drive", "scope": "drive", "account": "me"})
+    );
+    assert_eq!(remotes.get_value("s3").unwrap()["bucket"], "my-bucket");
+}
+
+#[test]
+fn test_dry_run_reports_value_changes() {
+    let original_fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+
+    let backup_path = original_fixture
+        .manager
+        .backup()
+        .create(&BackupOptions::new().output_dir(backup_dir.path()))
+        .unwrap();
+
+    let new_fixture = TestFixture::with_sub_settings();
+    let manager = &new_fixture.manager;
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes.set("gdrive", &json!({"type": "drive"})).unwrap();
+
+    let result = manager
+        .backup()
+        .restore(
+            &RestoreOptions::from_path(&backup_path)
+                .overwrite(true)
+                .dry_run(true),
+        )
+        .unwrap();
+    assert!(result.is_dry_run);
+
+    let change = |key: &str| {
+        result
+            .changes
+            .iter()
+            .find(|change| change.key == key)
+            .map(|change| (change.current.clone(), change.incoming.clone()))
+    };
+    assert_eq!(change("ui.font_size"), Some((json!(14.0), json!(18.0))));
+    assert_eq!(change("ui.theme"), None);
+    assert_eq!(
+        change("remotes/gdrive"),
+        Some((
+            json!({"type": "drive"}),
+            json!({"type": "drive", "scope": "drive"})
+        ))
+    );
+    assert_eq!(
+        change("remotes/s3"),
+        Some((json!(null), json!({"type": "s3", "bucket": "my-bucket"})))
+    );
+
+    // Nothing was written
+    assert!((manager.get_all().unwrap().ui.font_size - 14.0).abs() < f64::EPSILON);
+    assert!(!remotes.exists("s3").unwrap());
+
+    let result = manager
+        .backup()
+        .restore(&RestoreOptions::from_path(&backup_path).overwrite(true))
+        .unwrap();
+    assert!(result.changes.is_empty());
+}
+
+#[test]
+fn test_dry_run_compares_effective_values() {
+    let original_fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+
+    let backup_path = original_fixture
+        .manager
+        .backup()
+        .create(&BackupOptions::new().output_dir(backup_dir.path()))
+        .unwrap();
+
+    // The environment keeps overriding font_size after a restore, so
+    // restoring a different stored value changes nothing the app sees
+    let fixture = TestFixture::with_env_prefix("DRYRUN");
+    fixture.env_source.set("DRYRUN_UI_FONT_SIZE", "20");
+
+    let result = fixture
+        .manager
+        .backup()
+        .restore(
+            &RestoreOptions::from_path(&backup_path)
+                .overwrite(true)
+                .dry_run(true),
+        )
+        .unwrap();
+
+    let keys: Vec<&str> = result
+        .changes
+        .iter()
+        .map(|change| change.key.as_str())
+        .collect();
+    assert!(keys.contains(&"ui.theme"));
+    assert!(!keys.contains(&"ui.font_size"));
+}
+
+#[test]
+fn test_restore_backup_created_from_snapshot() {
+    let fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+
+    let snapshot = fixture.manager.snapshot().unwrap();
+
+    // Only stored values are captured, not the defaults
+    assert_eq!(
+        *snapshot.settings(),
+        json!({"ui": {"theme": "light", "font_size": 18.0}})
+    );
+    assert_eq!(
+        snapshot.sub_settings("remotes").unwrap()["s3"]["bucket"],
+        "my-bucket"
+    );
+
+    // Changes after the snapshot must not end up in the backup
+    fixture
+        .manager
+        .save_setting("ui", "theme", &json!("dark"))
+        .unwrap();
+    let remotes = fixture.manager.sub_settings("remotes").unwrap();
+    remotes.delete("s3").unwrap();
+    remotes.set("box", &json!({"type": "box"})).unwrap();
+
+    let backup_path = fixture
+        .manager
+        .backup()
+        .create_from_snapshot(
+            &snapshot,
+            &BackupOptions::new().output_dir(backup_dir.path()),
+        )
+        .unwrap();
+
+    let new_fixture = TestFixture::with_sub_settings();
+    new_fixture
+        .manager
+        .backup()
+        .restore(&RestoreOptions::from_path(&backup_path).overwrite(true))
+        .unwrap();
+    new_fixture.manager.invalidate_cache();
+
+    let metadata = new_fixture.manager.metadata().unwrap();
+    assert_eq!(metadata["ui.theme"].value, Some(json!("light")));
+    assert_eq!(metadata["ui.font_size"].value, Some(json!(18.0)));
+
+    let restored = new_fixture.manager.sub_settings("remotes").unwrap();
+    assert_eq!(restored.get_value("s3").unwrap()["bucket"], "my-bucket");
+    assert!(!restored.exists("box").unwrap());
+}
+
 #[test]
 fn test_restore_encrypted_backup() {
     let original_fixture = create_fixture_with_data();
@@ -100,6 +291,138 @@ fn test_restore_wrong_password_fails() {
     assert!(result.is_err());
 }
 
+#[cfg(feature = "backup-chacha")]
+#[test]
+fn test_restore_chacha20_poly1305_backup() {
+    let original_fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+    let password = "chacha_password";
+
+    let backup_path = original_fixture
+        .manager
+        .backup()
+        .create(
+            &BackupOptions::new()
+                .output_dir(backup_dir.path())
+                .password(password)
+                .cipher(BackupCipher::ChaCha20Poly1305),
+        )
+        .unwrap();
+
+    let new_fixture = TestFixture::with_sub_settings();
+    let backup = new_fixture.manager.backup();
+
+    let analysis = backup.analyze(&backup_path).unwrap();
+    assert!(analysis.requires_password);
+    assert!(analysis.warnings.iter().all(|w| !w.contains("encrypted")));
+    assert_eq!(
+        analysis.manifest.backup.cipher,
+        Some(BackupCipher::ChaCha20Poly1305)
+    );
+
+    let wrong = backup.restore(
+        &RestoreOptions::from_path(&backup_path)
+            .password("wrong_password")
+            .overwrite(true),
+    );
+    assert!(matches!(wrong, Err(rcman::Error::InvalidPassword)));
+
+    backup
+        .restore(
+            &RestoreOptions::from_path(&backup_path)
+                .password(password)
+                .overwrite(true),
+        )
+        .unwrap();
+
+    new_fixture.manager.invalidate_cache();
+    assert_eq!(new_fixture.manager.get_all().unwrap().ui.theme, "light");
+    let remotes = new_fixture.manager.sub_settings("remotes").unwrap();
+    assert_eq!(remotes.get_value("s3").unwrap()["bucket"], "my-bucket");
+}
+
+#[cfg(not(feature = "backup-chacha"))]
+#[test]
+fn test_chacha20_poly1305_backup_requires_feature() {
+    let fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+
+    let result = fixture.manager.backup().create(
+        &BackupOptions::new()
+            .output_dir(backup_dir.path())
+            .password("chacha_password")
+            .cipher(BackupCipher::ChaCha20Poly1305),
+    );
+    assert!(matches!(result, Err(rcman::Error::BackupFailed(_))));
+}
+
+#[test]
+fn test_encrypted_backup_without_recorded_cipher_uses_aes() {
+    use std::io::{Read, Write};
+
+    let original_fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+    let password = "aes_password";
+
+    let backup_path = original_fixture
+        .manager
+        .backup()
+        .create(
+            &BackupOptions::new()
+                .output_dir(backup_dir.path())
+                .password(password),
+        )
+        .unwrap();
+
+    // Rewrite the container like one written before the cipher was recorded
+    let mut container = zip::ZipArchive::new(fs::File::open(&backup_path).unwrap()).unwrap();
+    let mut manifest: serde_json::Value =
+        serde_json::from_reader(container.by_name("manifest.json").unwrap()).unwrap();
+    assert_eq!(manifest["backup"]["cipher"], "aes256");
+    manifest["backup"].as_object_mut().unwrap().remove("cipher");
+    let mut data = Vec::new();
+    container
+        .by_name("data.zip")
+        .unwrap()
+        .read_to_end(&mut data)
+        .unwrap();
+
+    let legacy_path = backup_dir.path().join("legacy.rcman");
+    let mut legacy = zip::ZipWriter::new(fs::File::create(&legacy_path).unwrap());
+    let stored =
+        zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    legacy.start_file("manifest.json", stored).unwrap();
+    legacy
+        .write_all(serde_json::to_string(&manifest).unwrap().as_bytes())
+        .unwrap();
+    legacy.start_file("data.zip", stored).unwrap();
+    legacy.write_all(&data).unwrap();
+    legacy.finish().unwrap();
+
+    let new_fixture = TestFixture::with_sub_settings();
+    let backup = new_fixture.manager.backup();
+    let analysis = backup.analyze(&legacy_path).unwrap();
+    assert!(analysis.requires_password);
+    assert_eq!(analysis.manifest.backup.cipher, None);
+    assert_eq!(
+        analysis.manifest.backup.effective_cipher(),
+        Some(BackupCipher::Aes256)
+    );
+
+    backup
+        .restore(
+            &RestoreOptions::from_path(&legacy_path)
+                .password(password)
+                .overwrite(true),
+        )
+        .unwrap();
+
+    new_fixture.manager.invalidate_cache();
+    assert_eq!(new_fixture.manager.get_all().unwrap().ui.theme, "light");
+    let remotes = new_fixture.manager.sub_settings("remotes").unwrap();
+    assert_eq!(remotes.get_value("s3").unwrap()["bucket"], "my-bucket");
+}
+
 #[test]
 fn test_restore_without_password_when_encrypted_fails() {
     let original_fixture = create_fixture_with_data();
@@ -215,3 +538,401 @@ fn test_restore_overwrite_existing() {
     let theme_value = metadata.get("ui.theme").unwrap().value.clone();
     assert_eq!(theme_value, Some(json!("light")));
 }
+
+#[test]
+fn test_resume_restore_after_failure() {
+    use rcman::{RestoreJournal, RestoreSkipReason, SettingsManager, SubSettingsConfig};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let temp = TempDir::new().unwrap();
+    let external_source = temp.path().join("external.conf");
+    fs::write(&external_source, "token=abc123\n").unwrap();
+
+    let source_manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path().join("source"))
+        .with_schema::<common::TestSettings>()
+        .with_sub_settings(SubSettingsConfig::new("remotes"))
+        .with_external_config(ExternalConfig::new("external_cfg", &external_source))
+        .build()
+        .unwrap();
+    source_manager
+        .sub_settings("remotes")
+        .unwrap()
+        .set("gdrive", &json!({"type": "drive"}))
+        .unwrap();
+
+    let backup_path = source_manager
+        .backup()
+        .create(
+            &BackupOptions::new()
+                .output_dir(temp.path().join("backups"))
+                .include_sub_settings("remotes")
+                .include_external("external_cfg"),
+        )
+        .unwrap();
+
+    // The external handler fails on the first attempt, after everything else was restored
+    let fail = Arc::new(AtomicBool::new(true));
+    let handler_fail = Arc::clone(&fail);
+    let restore_manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path().join("restore"))
+        .with_schema::<common::TestSettings>()
+        .with_sub_settings(SubSettingsConfig::new("remotes"))
+        .with_external_config(
+            ExternalConfig::new("external_cfg", &external_source).import_handler(move |_| {
+                if handler_fail.load(Ordering::SeqCst) {
+                    Err(rcman::Error::RestoreFailed("disk full".into()))
+                } else {
+                    Ok(())
+                }
+            }),
+        )
+        .build()
+        .unwrap();
+
+    let journal_path = temp.path().join("restore.journal");
+    let options = RestoreOptions::from_path(&backup_path)
+        .overwrite(true)
+        .journal(&journal_path);
+
+    assert!(restore_manager.backup().restore(&options).is_err());
+
+    let journal = RestoreJournal::load(&journal_path).unwrap();
+    assert_eq!(journal.backup_path, backup_path);
+    assert!(journal.completed.iter().any(|id| id == "remotes/gdrive"));
+    assert!(!journal.completed.iter().any(|id| id == "external_cfg"));
+
+    fail.store(false, Ordering::SeqCst);
+    let result = restore_manager
+        .backup()
+        .resume_restore(&options, &journal)
+        .unwrap();
+
+    assert_eq!(result.restored, vec!["external_cfg".to_string()]);
+    assert_eq!(
+        result.skipped_ids_by_reason(RestoreSkipReason::RestoredInPreviousAttempt),
+        vec!["settings.json", "remotes/gdrive"]
+    );
+    assert!(!journal_path.exists());
+}
+
+#[test]
+fn test_restore_journal_records_each_item() {
+    use rcman::{RestoreJournal, SettingsManager};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let temp = TempDir::new().unwrap();
+    let first_source = temp.path().join("first.conf");
+    let second_source = temp.path().join("second.conf");
+    fs::write(&first_source, "a=1\n").unwrap();
+    fs::write(&second_source, "b=2\n").unwrap();
+
+    let source_manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path().join("source"))
+        .with_schema::<common::TestSettings>()
+        .with_external_config(ExternalConfig::new("first_cfg", &first_source))
+        .with_external_config(ExternalConfig::new("second_cfg", &second_source))
+        .build()
+        .unwrap();
+    let backup_path = source_manager
+        .backup()
+        .create(
+            &BackupOptions::new()
+                .output_dir(temp.path().join("backups"))
+                .include_external("first_cfg")
+                .include_external("second_cfg"),
+        )
+        .unwrap();
+
+    // Whichever external config is restored second brings the process down
+    let imports = Arc::new(AtomicUsize::new(0));
+    let handler = |imports: Arc<AtomicUsize>| {
+        move |_: &[u8]| {
+            assert_eq!(imports.fetch_add(1, Ordering::SeqCst), 0, "crashed");
+            Ok(())
+        }
+    };
+    let restore_manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path().join("restore"))
+        .with_schema::<common::TestSettings>()
+        .with_external_config(
+            ExternalConfig::new("first_cfg", &first_source)
+                .import_handler(handler(Arc::clone(&imports))),
+        )
+        .with_external_config(
+            ExternalConfig::new("second_cfg", &second_source)
+                .import_handler(handler(Arc::clone(&imports))),
+        )
+        .build()
+        .unwrap();
+
+    let journal_path = temp.path().join("restore.journal");
+    let options = RestoreOptions::from_path(&backup_path)
+        .overwrite(true)
+        .journal(&journal_path);
+    let crashed = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        restore_manager.backup().restore(&options)
+    }));
+    assert!(crashed.is_err());
+
+    // The config restored before the crash is already in the journal
+    let journal = RestoreJournal::load(&journal_path).unwrap();
+    let externals: Vec<_> = journal
+        .completed
+        .iter()
+        .filter(|id| id.ends_with("_cfg"))
+        .collect();
+    assert_eq!(externals.len(), 1);
+}
+
+#[test]
+fn test_restore_journal_write_failure_keeps_original_error() {
+    use rcman::SettingsManager;
+
+    let temp = TempDir::new().unwrap();
+    let external_source = temp.path().join("external.conf");
+    fs::write(&external_source, "token=abc123\n").unwrap();
+
+    let source_manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path().join("source"))
+        .with_schema::<common::TestSettings>()
+        .with_external_config(ExternalConfig::new("external_cfg", &external_source))
+        .build()
+        .unwrap();
+    let backup_path = source_manager
+        .backup()
+        .create(
+            &BackupOptions::new()
+                .output_dir(temp.path().join("backups"))
+                .include_external("external_cfg"),
+        )
+        .unwrap();
+
+    let restore_manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path().join("restore"))
+        .with_schema::<common::TestSettings>()
+        .with_external_config(
+            ExternalConfig::new("external_cfg", &external_source)
+                .import_handler(|_| Err(rcman::Error::RestoreFailed("disk full".into()))),
+        )
+        .build()
+        .unwrap();
+
+    // A directory in place of the journal file makes every journal write fail
+    let journal_path = temp.path().join("restore.journal");
+    fs::create_dir(&journal_path).unwrap();
+    let options = RestoreOptions::from_path(&backup_path)
+        .overwrite(true)
+        .journal(&journal_path);
+
+    let err = restore_manager.backup().restore(&options).unwrap_err();
+    assert!(
+        matches!(&err, rcman::Error::RestoreFailed(msg) if msg == "disk full"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn test_resume_restore_rejects_foreign_journal() {
+    let fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+    let backup_path = fixture
+        .manager
+        .backup()
+        .create(&BackupOptions::new().output_dir(backup_dir.path()))
+        .unwrap();
+
+    let journal = rcman::RestoreJournal {
+        backup_path: backup_dir.path().join("other.rcman"),
+        completed: Vec::new(),
+    };
+
+    let err = fixture
+        .manager
+        .backup()
+        .resume_restore(&RestoreOptions::from_path(&backup_path), &journal)
+        .unwrap_err();
+    assert!(matches!(err, rcman::Error::RestoreFailed(_)));
+}
+
+#[test]
+fn test_customizations_only_backup_layers_on_defaults() {
+    let fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+
+    let backup_path = fixture
+        .manager
+        .backup()
+        .create(
+            &BackupOptions::new()
+                .output_dir(backup_dir.path())
+                .customizations_only()
+                .include_sub_settings_items("remotes", &["gdrive"]),
+        )
+        .unwrap();
+
+    let analysis = fixture.manager.backup().analyze(&backup_path).unwrap();
+    assert!(analysis.manifest.contents.settings);
+    assert!(analysis.manifest.contents.external_configs.is_empty());
+    assert!(
+        backup_path
+            .to_string_lossy()
+            .ends_with("_customizations.rcman")
+    );
+
+    let new_fixture = TestFixture::with_sub_settings();
+    new_fixture
+        .manager
+        .backup()
+        .restore(&RestoreOptions::from_path(&backup_path).overwrite(true))
+        .unwrap();
+    new_fixture.manager.invalidate_cache();
+
+    let stored = common::read_settings_file(&new_fixture).unwrap();
+    assert_eq!(stored, json!({"ui": {"theme": "light", "font_size": 18.0}}));
+
+    let metadata = new_fixture.manager.metadata().unwrap();
+    assert_eq!(
+        metadata.get("ui.theme").unwrap().value,
+        Some(json!("light"))
+    );
+    assert_eq!(
+        metadata.get("general.language").unwrap().value,
+        Some(json!("en"))
+    );
+
+    let remotes = new_fixture.manager.sub_settings("remotes").unwrap();
+    assert_eq!(remotes.get_value("gdrive").unwrap()["type"], "drive");
+    assert!(!remotes.exists("s3").unwrap());
+}
+
+#[test]
+fn test_export_diff_as_backup_skips_sub_settings() {
+    let fixture = create_fixture_with_data();
+    let backup_dir = TempDir::new().unwrap();
+
+    let backup_path = fixture
+        .manager
+        .export_diff_as_backup(backup_dir.path())
+        .unwrap();
+
+    let analysis = fixture.manager.backup().analyze(&backup_path).unwrap();
+    assert!(analysis.manifest.contents.settings);
+    assert!(analysis.manifest.contents.sub_settings.is_empty());
+}
+
+#[test]
+fn test_incremental_backup_round_trip() {
+    let original_fixture = create_fixture_with_data();
+    let manager = &original_fixture.manager;
+    let backup_dir = TempDir::new().unwrap();
+
+    let base_path = manager
+        .backup()
+        .create(
+            &BackupOptions::new()
+                .output_dir(backup_dir.path())
+                .filename_suffix("base"),
+        )
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes
+        .set("gdrive", &json!({"type": "drive", "scope": "drive.file"}))
+        .unwrap();
+    remotes.delete("s3").unwrap();
+
+    let incremental_path = manager
+        .backup()
+        .create(
+            &BackupOptions::new()
+                .output_dir(backup_dir.path())
+                .filename_suffix("incremental")
+                .base(&base_path),
+        )
+        .unwrap();
+
+    let base_analysis = manager.backup().analyze(&base_path).unwrap();
+    let analysis = manager.backup().analyze(&incremental_path).unwrap();
+    let base = analysis.manifest.base.as_ref().unwrap();
+    assert_eq!(
+        Some(&base.sha256),
+        base_analysis.manifest.integrity.sha256.as_ref()
+    );
+    assert_eq!(base.removed_files, vec!["remotes/s3.json".to_string()]);
+    assert!(analysis.manifest.integrity.size_bytes < base_analysis.manifest.integrity.size_bytes);
+
+    let new_fixture = TestFixture::with_sub_settings();
+    let err = new_fixture
+        .manager
+        .backup()
+        .restore(&RestoreOptions::from_path(&incremental_path))
+        .unwrap_err();
+    assert!(matches!(err, rcman::Error::MissingBaseBackup(_)));
+
+    new_fixture
+        .manager
+        .backup()
+        .restore(&RestoreOptions::from_path(&incremental_path).base(&base_path))
+        .unwrap();
+
+    new_fixture.manager.invalidate_cache();
+    let settings = new_fixture.manager.get_all().unwrap();
+    assert_eq!(settings.ui.theme, "light");
+    assert!((settings.ui.font_size - 18.0).abs() < f64::EPSILON);
+
+    let restored = new_fixture.manager.sub_settings("remotes").unwrap();
+    assert_eq!(
+        restored.get_value("gdrive").unwrap(),
+        json!({"type": "drive", "scope": "drive.file"})
+    );
+    assert!(!restored.exists("s3").unwrap());
+}
+
+#[test]
+fn test_prune_keeps_base_of_retained_incremental() {
+    let fixture = create_fixture_with_data();
+    let manager = &fixture.manager;
+    let backup_dir = TempDir::new().unwrap();
+    let create = |options: BackupOptions| {
+        manager
+            .backup()
+            .create(&options.output_dir(backup_dir.path()))
+            .unwrap()
+    };
+
+    let older_path = create(BackupOptions::new().filename_suffix("older"));
+    let base_path = create(BackupOptions::new().filename_suffix("base"));
+    manager
+        .sub_settings("remotes")
+        .unwrap()
+        .set("gdrive", &json!({"type": "drive", "scope": "drive.file"}))
+        .unwrap();
+    let incremental_path = create(
+        BackupOptions::new()
+            .filename_suffix("incremental")
+            .base(&base_path),
+    );
+
+    let removed = manager.backup().prune(backup_dir.path(), 1).unwrap();
+    assert_eq!(removed, vec![older_path]);
+    assert!(base_path.exists());
+
+    let new_fixture = TestFixture::with_sub_settings();
+    new_fixture
+        .manager
+        .backup()
+        .restore(&RestoreOptions::from_path(&incremental_path).base(&base_path))
+        .unwrap();
+    assert_eq!(
+        new_fixture
+            .manager
+            .sub_settings("remotes")
+            .unwrap()
+            .get_value("gdrive")
+            .unwrap(),
+        json!({"type": "drive", "scope": "drive.file"})
+    );
+}
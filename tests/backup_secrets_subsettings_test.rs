@@ -211,6 +211,48 @@ fn test_sub_settings_secrets_encrypted_with_password() {
     }
 }
 
+#[test]
+fn test_snapshot_backup_keeps_secrets_of_snapshot_time() {
+    let fixture = setup_fixture();
+    let temp_dir = tempfile::tempdir().unwrap();
+    let remotes = fixture.manager.sub_settings("remotes").unwrap();
+    remotes
+        .set(
+            "gdrive",
+            &json!({"type": "drive", "client_secret": "old-secret"}),
+        )
+        .unwrap();
+
+    let snapshot = fixture.manager.snapshot().unwrap();
+    remotes
+        .set(
+            "gdrive",
+            &json!({"type": "drive", "client_secret": "new-secret"}),
+        )
+        .unwrap();
+
+    let backup_path = fixture
+        .manager
+        .backup()
+        .create_from_snapshot(
+            &snapshot,
+            &rcman::BackupOptions::new()
+                .output_dir(temp_dir.path())
+                .secret_policy(SecretBackupPolicy::Include),
+        )
+        .expect("Backup creation failed");
+    assert!(backup_path.exists(), "Backup file should exist");
+
+    #[cfg(any(feature = "encrypted-file", feature = "keychain"))]
+    {
+        assert!(!format!("{snapshot:?}").contains("old-secret"));
+
+        let gdrive = extract_remotes_from_backup(&backup_path, "gdrive", None)
+            .expect("Should be able to extract gdrive from backup");
+        assert_eq!(gdrive["client_secret"], "old-secret");
+    }
+}
+
 #[test]
 fn test_mixed_secrets_in_connections() {
     let fixture = setup_fixture();
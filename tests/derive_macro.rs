@@ -106,6 +106,88 @@ fn test_select_options() {
     assert_eq!(options[0].label, "Debug");
 }
 
+#[derive(Serialize, Deserialize, DeriveSettingsSchema)]
+#[schema(category = "view")]
+struct ColumnSettings {
+    #[setting(options(("name", "Name"), ("size", "Size"), ("modified", "Modified")))]
+    columns: Vec<String>,
+}
+
+impl Default for ColumnSettings {
+    fn default() -> Self {
+        Self {
+            columns: vec!["name".into()],
+        }
+    }
+}
+
+#[test]
+fn test_multi_select_options() {
+    let metadata = ColumnSettings::get_metadata();
+    let columns = metadata.get("view.columns").unwrap();
+    assert_eq!(columns.setting_type, rcman::SettingType::MultiSelect);
+    assert_eq!(columns.default, serde_json::json!(["name"]));
+    assert_eq!(columns.constraints.options.as_ref().unwrap().len(), 3);
+
+    let temp = tempfile::tempdir().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<ColumnSettings>()
+        .build()
+        .unwrap();
+    manager
+        .save_setting("view", "columns", &serde_json::json!(["size", "modified"]))
+        .unwrap();
+    assert!(
+        manager
+            .save_setting("view", "columns", &serde_json::json!(["size", "owner"]))
+            .is_err()
+    );
+
+    let reloaded = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<ColumnSettings>()
+        .build()
+        .unwrap();
+    assert_eq!(
+        reloaded.get::<Vec<String>>("view.columns").unwrap(),
+        vec!["size", "modified"]
+    );
+}
+
+#[derive(Serialize, Deserialize, DeriveSettingsSchema)]
+#[schema(category = "theme")]
+struct ColorSettings {
+    #[setting(color, label = "Accent")]
+    accent: String,
+    #[setting(color)]
+    highlight: Option<String>,
+}
+
+impl Default for ColorSettings {
+    fn default() -> Self {
+        Self {
+            accent: "#1E90FF".into(),
+            highlight: None,
+        }
+    }
+}
+
+#[test]
+fn test_color_attribute() {
+    let metadata = ColorSettings::get_metadata();
+
+    let accent = metadata.get("theme.accent").unwrap();
+    assert_eq!(accent.setting_type, rcman::SettingType::Color);
+    assert_eq!(accent.default, serde_json::json!("#1E90FF"));
+    assert!(accent.validate(&serde_json::json!("blue")).is_err());
+
+    let highlight = metadata.get("theme.highlight").unwrap();
+    assert_eq!(highlight.setting_type, rcman::SettingType::Color);
+    assert!(highlight.nullable);
+    assert!(highlight.validate(&serde_json::Value::Null).is_ok());
+}
+
 // =============================================================================
 // Skip Field Tests
 // =============================================================================
@@ -203,6 +285,410 @@ fn test_explicit_nested() {
     assert!(m.contains_key("main.normal"));
 }
 
+// =============================================================================
+// Required Attribute Tests
+// =============================================================================
+
+#[derive(Default, Serialize, Deserialize, DeriveSettingsSchema)]
+#[schema(category = "api")]
+struct RequiredSettings {
+    #[setting(required)]
+    endpoint: String,
+
+    timeout: u32,
+}
+
+#[test]
+fn test_required_attribute() {
+    let m = RequiredSettings::get_metadata();
+    let endpoint = m.get("api.endpoint").unwrap();
+    assert!(endpoint.required);
+    assert!(endpoint.validate(&serde_json::json!("")).is_err());
+    assert!(!m.get("api.timeout").unwrap().required);
+}
+
+// =============================================================================
+// Requires Restart Tests
+// =============================================================================
+
+#[derive(Default, Serialize, Deserialize, DeriveSettingsSchema)]
+#[schema(category = "server")]
+struct RestartSettings {
+    #[setting(requires_restart)]
+    port: u32,
+
+    motd: String,
+}
+
+#[test]
+fn test_requires_restart_surfaced_on_save() {
+    let m = RestartSettings::get_metadata();
+    assert!(m.get("server.port").unwrap().is_restart_required());
+    assert!(!m.get("server.motd").unwrap().is_restart_required());
+
+    let temp = tempfile::tempdir().unwrap();
+    let manager = rcman::SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<RestartSettings>()
+        .build()
+        .unwrap();
+
+    let outcome = manager
+        .save_setting("server", "motd", &serde_json::json!("hi"))
+        .unwrap();
+    assert!(outcome.changed && !outcome.requires_restart);
+    assert!(!manager.needs_restart());
+
+    let outcome = manager
+        .save_setting("server", "port", &serde_json::json!(8080))
+        .unwrap();
+    assert!(outcome.changed && outcome.requires_restart);
+
+    // Saving the same value again is not a change
+    let outcome = manager
+        .save_setting("server", "port", &serde_json::json!(8080))
+        .unwrap();
+    assert_eq!(outcome, rcman::SaveOutcome::default());
+
+    assert_eq!(manager.restart_required_keys(), vec!["server.port"]);
+    manager.clear_restart_required();
+    assert!(!manager.needs_restart());
+}
+
+// =============================================================================
+// Tags Attribute Tests
+// =============================================================================
+
+#[derive(Default, Serialize, Deserialize, DeriveSettingsSchema)]
+#[schema(category = "net")]
+struct TaggedSettings {
+    #[setting(tags("performance", "experimental"))]
+    parallel_uploads: u32,
+
+    #[setting(tags("experimental"))]
+    http3: bool,
+
+    proxy: String,
+}
+
+#[test]
+fn test_tags_attribute() {
+    let m = TaggedSettings::get_metadata();
+    assert_eq!(
+        m.get("net.parallel_uploads").unwrap().tags,
+        vec!["performance", "experimental"]
+    );
+    assert!(m.get("net.http3").unwrap().has_tag("experimental"));
+    assert!(m.get("net.proxy").unwrap().tags.is_empty());
+
+    let temp = tempfile::tempdir().unwrap();
+    let manager = rcman::SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<TaggedSettings>()
+        .build()
+        .unwrap();
+    assert_eq!(
+        manager.find_by_tag("experimental"),
+        vec!["net.http3", "net.parallel_uploads"]
+    );
+    assert!(manager.find_by_tag("missing").is_empty());
+}
+
+// =============================================================================
+// Widget Attribute Tests
+// =============================================================================
+
+#[derive(Default, Serialize, Deserialize, DeriveSettingsSchema)]
+#[schema(category = "audio")]
+struct WidgetSettings {
+    #[setting(min = 0, max = 100, widget = "slider")]
+    volume: u8,
+
+    #[setting(widget = "spin_box")]
+    buffer_ms: u32,
+
+    latency: u32,
+}
+
+#[test]
+fn test_widget_attribute() {
+    use rcman::Widget;
+
+    let m = WidgetSettings::get_metadata();
+    let volume = m.get("audio.volume").unwrap();
+    assert_eq!(volume.constraints.number.widget, Some(Widget::Slider));
+    assert_eq!(
+        m.get("audio.buffer_ms").unwrap().constraints.number.widget,
+        Some(Widget::SpinBox)
+    );
+    assert_eq!(
+        m.get("audio.latency").unwrap().constraints.number.widget,
+        None
+    );
+
+    // The hint survives a serialization round trip
+    let json = serde_json::to_value(volume).unwrap();
+    assert_eq!(json["widget"], "slider");
+    let restored: rcman::SettingMetadata = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.constraints.number.widget, Some(Widget::Slider));
+
+    let stepper = rcman::SettingMetadata::number(1).as_stepper();
+    assert_eq!(stepper.constraints.number.widget, Some(Widget::Stepper));
+    assert!(stepper.validate(&serde_json::json!(5)).is_ok());
+}
+
+// =============================================================================
+// Clamp Attribute Tests
+// =============================================================================
+
+#[derive(Serialize, Deserialize, DeriveSettingsSchema)]
+#[schema(category = "video")]
+struct ClampSettings {
+    #[setting(min = 30, max = 144, clamp)]
+    fps: u32,
+
+    #[setting(min = 0, max = 10)]
+    quality: u32,
+}
+
+impl Default for ClampSettings {
+    fn default() -> Self {
+        Self {
+            fps: 60,
+            quality: 5,
+        }
+    }
+}
+
+#[test]
+fn test_clamp_attribute_on_load() {
+    let m = ClampSettings::get_metadata();
+    assert!(m.get("video.fps").unwrap().constraints.number.clamp);
+    assert!(!m.get("video.quality").unwrap().constraints.number.clamp);
+
+    // Stored before the range was tightened
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("settings.json"),
+        r#"{"video": {"fps": 240, "quality": 20}}"#,
+    )
+    .unwrap();
+
+    let manager = rcman::SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<ClampSettings>()
+        .build()
+        .unwrap();
+
+    let metadata = manager.metadata().unwrap();
+    let fps = &metadata["video.fps"];
+    assert_eq!(fps.value, Some(serde_json::json!(144)));
+    assert_eq!(fps.get_meta_bool(rcman::meta::CLAMPED), Some(true));
+
+    // Without `clamp` the stored value is returned as-is
+    let quality = &metadata["video.quality"];
+    assert_eq!(quality.value, Some(serde_json::json!(20)));
+    assert_eq!(quality.get_meta_bool(rcman::meta::CLAMPED), None);
+
+    // Saving a new value clears the flag
+    manager
+        .save_setting("video", "fps", &serde_json::json!(120))
+        .unwrap();
+    let fps = &manager.metadata().unwrap()["video.fps"];
+    assert_eq!(fps.get_meta_bool(rcman::meta::CLAMPED), None);
+}
+
+// =============================================================================
+// Enum Options Tests
+// =============================================================================
+
+#[derive(Default, Serialize, Deserialize, rcman::DeriveSettingsEnum)]
+#[serde(rename_all = "snake_case")]
+enum Theme {
+    Light,
+    #[default]
+    DarkBlue,
+    #[serde(rename = "auto")]
+    System,
+}
+
+#[derive(Default, Serialize, Deserialize, DeriveSettingsSchema)]
+#[schema(category = "ui")]
+struct EnumSettings {
+    #[setting(enum_options, label = "Theme")]
+    theme: Theme,
+
+    #[setting(enum_options)]
+    fallback_theme: Option<Theme>,
+}
+
+#[test]
+fn test_enum_options_attribute() {
+    use rcman::SettingType;
+
+    let m = EnumSettings::get_metadata();
+    let theme = m.get("ui.theme").unwrap();
+    assert_eq!(theme.setting_type, SettingType::Select);
+    assert_eq!(theme.default, serde_json::json!("dark_blue"));
+    assert_eq!(theme.get_meta_str("label"), Some("Theme"));
+
+    let options = theme.constraints.options.as_ref().unwrap();
+    let pairs: Vec<(&str, &str)> = options
+        .iter()
+        .map(|o| (o.value.as_str().unwrap(), o.label.as_str()))
+        .collect();
+    assert_eq!(
+        pairs,
+        vec![
+            ("light", "Light"),
+            ("dark_blue", "Dark Blue"),
+            ("auto", "System")
+        ]
+    );
+
+    let fallback = m.get("ui.fallback_theme").unwrap();
+    assert!(fallback.nullable);
+    assert_eq!(fallback.default, serde_json::Value::Null);
+
+    // Option values match what serde writes, so saved values round-trip
+    let temp = tempfile::tempdir().unwrap();
+    let manager = rcman::SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<EnumSettings>()
+        .build()
+        .unwrap();
+    manager
+        .save_setting("ui", "theme", &serde_json::json!("auto"))
+        .unwrap();
+    assert!(
+        manager
+            .save_setting("ui", "theme", &serde_json::json!("System"))
+            .is_err()
+    );
+    let stored = manager.get_all_data().unwrap()["ui"]["theme"].clone();
+    assert!(matches!(
+        serde_json::from_value::<Theme>(stored).unwrap(),
+        Theme::System
+    ));
+}
+
+// =============================================================================
+// Option Field Tests
+// =============================================================================
+
+#[derive(Serialize, Deserialize, DeriveSettingsSchema)]
+#[schema(category = "profile")]
+struct OptionalSettings {
+    #[setting(label = "Website")]
+    website: Option<String>,
+
+    #[setting(min = 1, max = 10)]
+    retries: Option<u32>,
+}
+
+impl Default for OptionalSettings {
+    fn default() -> Self {
+        Self {
+            website: None,
+            retries: Some(3),
+        }
+    }
+}
+
+#[test]
+fn test_option_fields() {
+    use rcman::SettingType;
+
+    let m = OptionalSettings::get_metadata();
+    let website = m.get("profile.website").unwrap();
+    assert_eq!(website.setting_type, SettingType::Text);
+    assert_eq!(website.default, serde_json::Value::Null);
+    assert!(website.nullable);
+    assert_eq!(website.get_meta_str("label"), Some("Website"));
+
+    let retries = m.get("profile.retries").unwrap();
+    assert_eq!(retries.setting_type, SettingType::Number);
+    assert_eq!(retries.default, serde_json::json!(3.0));
+    assert!(retries.nullable);
+
+    let temp = tempfile::tempdir().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<OptionalSettings>()
+        .build()
+        .unwrap();
+    manager
+        .save_setting(
+            "profile",
+            "website",
+            &serde_json::json!("https://example.com"),
+        )
+        .unwrap();
+    manager
+        .save_setting("profile", "website", &serde_json::Value::Null)
+        .unwrap();
+    let settings: OptionalSettings = manager.get_all().unwrap();
+    assert_eq!(settings.website, None);
+    assert_eq!(settings.retries, Some(3));
+}
+
+// =============================================================================
+// Serde Flatten Tests
+// =============================================================================
+
+#[derive(Default, Serialize, Deserialize, DeriveSettingsSchema)]
+struct NetworkGroup {
+    network: NetworkSection,
+}
+
+#[derive(Default, Serialize, Deserialize, DeriveSettingsSchema)]
+#[schema(category = "network")]
+struct NetworkSection {
+    port: u16,
+}
+
+#[derive(Default, Serialize, Deserialize, DeriveSettingsSchema)]
+struct FlattenedRoot {
+    ui: UiSettings,
+
+    #[serde(flatten)]
+    group: NetworkGroup,
+}
+
+#[derive(Default, Serialize, Deserialize, DeriveSettingsSchema)]
+struct CommonFields {
+    verbose: bool,
+}
+
+#[derive(Default, Serialize, Deserialize, DeriveSettingsSchema)]
+#[schema(category = "logging")]
+struct FlattenedCategory {
+    #[serde(flatten)]
+    common: CommonFields,
+
+    level: String,
+}
+
+#[test]
+fn test_serde_flatten_keeps_nested_keys() {
+    let m = FlattenedRoot::get_metadata();
+    assert!(m.contains_key("ui.theme"));
+    assert!(m.contains_key("network.port"));
+    assert!(!m.contains_key("port"));
+    assert!(!m.contains_key("group.port"));
+
+    let serialized = serde_json::to_value(FlattenedRoot::default()).unwrap();
+    assert!(serialized["network"]["port"].is_number());
+}
+
+#[test]
+fn test_serde_flatten_into_category() {
+    let m = FlattenedCategory::get_metadata();
+    assert!(m.contains_key("logging.verbose"));
+    assert!(m.contains_key("logging.level"));
+    assert!(!m.contains_key("verbose"));
+}
+
 #[test]
 fn test_metadata_attributes() {
     use rcman::{DeriveSettingsSchema, SettingsSchema};
@@ -11,7 +11,7 @@
 mod common;
 
 use common::TestFixture;
-use rcman::{SettingsConfig, SettingsManager};
+use rcman::{CorruptPolicy, SettingsConfig, SettingsManager};
 use serde_json::json;
 use std::fs;
 use std::sync::Arc;
@@ -52,6 +52,23 @@ fn test_save_invalid_nested_key() {
     assert!(err_msg.contains("not found") || err_msg.contains("invalid_key"));
 }
 
+#[test]
+fn test_error_codes_are_stable() {
+    let fixture = TestFixture::new();
+    let _ = fixture.manager.get_all().unwrap();
+
+    let err = fixture
+        .manager
+        .save_setting("ui", "invalid_key", &json!("value"))
+        .unwrap_err();
+    assert_eq!(err.code(), "RCMAN_SETTING_NOT_FOUND");
+
+    let Err(err) = fixture.manager.sub_settings("missing") else {
+        panic!("expected unregistered sub-settings to fail");
+    };
+    assert_eq!(err.code(), "RCMAN_SUB_SETTINGS_NOT_REGISTERED");
+}
+
 #[test]
 fn test_deeply_nested_invalid_path() {
     let fixture = TestFixture::new();
@@ -360,6 +377,106 @@ fn test_load_truncated_json() {
     let _ = manager.metadata();
 }
 
+#[test]
+fn test_strict_validation_blocks_startup_on_invalid_value() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("settings.json"),
+        br#"{"ui": {"theme": "neon"}}"#,
+    )
+    .unwrap();
+
+    // Default mode starts with the stored value as-is
+    let lenient = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<common::TestSettings>()
+        .build()
+        .unwrap();
+    assert_eq!(lenient.get_all().unwrap().ui.theme, "neon");
+
+    let err = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<common::TestSettings>()
+        .strict_validation()
+        .build()
+        .err()
+        .expect("strict mode should reject the stored value");
+    match err {
+        rcman::Error::InvalidStoredValue { key, .. } => assert_eq!(key, "ui.theme"),
+        other => panic!("unexpected error: {other}"),
+    }
+}
+
+#[test]
+fn test_strict_validation_rejects_corrupted_file() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("settings.json"),
+        b"{invalid json content",
+    )
+    .unwrap();
+
+    let result = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<common::TestSettings>()
+        .strict_validation()
+        .build();
+    assert!(result.is_err());
+
+    // A missing file is not an error
+    let empty_dir = TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(empty_dir.path())
+        .with_schema::<common::TestSettings>()
+        .strict_validation()
+        .build()
+        .unwrap();
+    assert_eq!(manager.get_all().unwrap().ui.theme, "dark");
+}
+
+#[test]
+fn test_corrupt_policy_fail_fast_and_quarantine() {
+    let temp_dir = TempDir::new().unwrap();
+    let settings_file = temp_dir.path().join("settings.json");
+    fs::write(&settings_file, b"{\"ui\": {\"theme\":").unwrap();
+
+    // Failing fast is the default
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<common::TestSettings>()
+        .build()
+        .unwrap();
+    assert!(manager.get_all().is_err());
+    assert!(settings_file.exists());
+
+    // Strict mode still boots when the file is quarantined
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<common::TestSettings>()
+        .strict_validation()
+        .on_corrupt(CorruptPolicy::QuarantineAndReset)
+        .build()
+        .unwrap();
+    assert_eq!(manager.get_all().unwrap().ui.theme, "dark");
+    assert!(!settings_file.exists());
+
+    let quarantined: Vec<_> = fs::read_dir(temp_dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|name| name.starts_with("settings.json.corrupt-"))
+        .collect();
+    assert_eq!(quarantined.len(), 1);
+    assert_eq!(
+        fs::read(temp_dir.path().join(&quarantined[0])).unwrap(),
+        b"{\"ui\": {\"theme\":"
+    );
+
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    assert!(settings_file.exists());
+}
+
 #[test]
 fn test_save_to_readonly_directory() {
     // Create a temp directory and make it readonly
@@ -7,7 +7,8 @@ mod common;
 
 use common::TestFixture;
 use rcman::{HotReloadBackend, HotReloadConfig, HotReloadEvent, HotReloadRuntime};
-use std::sync::{Arc, mpsc};
+use serde_json::json;
+use std::sync::{Arc, Mutex, mpsc};
 use std::time::{Duration, Instant};
 
 #[test]
@@ -26,6 +27,15 @@ fn test_hot_reload_applies_external_file_change() {
         backend: HotReloadBackend::Poll,
     };
 
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_clone = Arc::clone(&changes);
+    manager.events().on_change(move |key, old, new| {
+        changes_clone
+            .lock()
+            .unwrap()
+            .push((key.to_string(), old.clone(), new.clone()));
+    });
+
     let mut runtime = HotReloadRuntime::start(Arc::clone(&manager), config, callback).unwrap();
 
     // Give the watcher thread a brief warm-up window before mutating files.
@@ -61,6 +71,12 @@ fn test_hot_reload_applies_external_file_change() {
     assert!(reloaded, "expected at least one Reloaded event");
     assert_eq!(manager.get::<String>("ui.theme").unwrap(), "light");
 
+    // Only the key whose effective value changed is reported
+    assert_eq!(
+        *changes.lock().unwrap(),
+        vec![("ui.theme".to_string(), json!("dark"), json!("light"))]
+    );
+
     runtime.stop();
 }
 
@@ -145,3 +161,61 @@ fn test_hot_reload_rapid_consecutive_changes_apply_latest_value() {
 
     runtime.stop();
 }
+
+#[test]
+fn test_hot_reload_merges_deferred_writes_into_external_change() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let manager = Arc::new(
+        rcman::SettingsManager::builder("test-app", "1.0.0")
+            .with_config_dir(temp.path())
+            .with_schema::<common::TestSettings>()
+            .deferred_writes(Duration::from_secs(3600))
+            .build()
+            .unwrap(),
+    );
+
+    let (event_tx, event_rx) = mpsc::channel::<HotReloadEvent>();
+    let callback = move |event: HotReloadEvent| {
+        let _ = event_tx.send(event);
+    };
+    let config = HotReloadConfig {
+        debounce_ms: 50,
+        poll_interval_ms: 50,
+        backend: HotReloadBackend::Poll,
+    };
+    let mut runtime = HotReloadRuntime::start(Arc::clone(&manager), config, callback).unwrap();
+    std::thread::sleep(Duration::from_millis(250));
+
+    // A change waiting for the deferred write, then an edit by another process
+    manager
+        .save_setting("ui", "font_size", &json!(17.0))
+        .unwrap();
+    let settings_path = manager.config().settings_path();
+    std::fs::write(&settings_path, r#"{"ui":{"theme":"light"}}"#).unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(4);
+    let mut reloaded = false;
+    while !reloaded && Instant::now() < deadline {
+        match event_rx.recv_timeout(Duration::from_millis(150)) {
+            Ok(HotReloadEvent::Reloaded { .. }) => reloaded = true,
+            Ok(event) => panic!("unexpected hot-reload event: {event:?}"),
+            Err(_) => {}
+        }
+    }
+    assert!(reloaded, "expected a Reloaded event");
+
+    // Both the external edit and the pending change survive
+    let settings = manager.get_all().unwrap();
+    assert_eq!(settings.ui.theme, "light");
+    assert!((settings.ui.font_size - 17.0).abs() < f64::EPSILON);
+    assert!(!manager.has_unflushed_changes());
+
+    let on_disk: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&settings_path).unwrap()).unwrap();
+    assert_eq!(
+        on_disk,
+        json!({"ui": {"theme": "light", "font_size": 17.0}})
+    );
+
+    runtime.stop();
+}
@@ -0,0 +1,115 @@
+//! In-memory storage integration tests
+//!
+//! Runs the full `SettingsManager` stack on [`MemoryStorage`] and checks that
+//! settings, sub-settings and profiles behave as with the file backends while
+//! no file is written.
+
+mod common;
+
+use common::TestSettings;
+use rcman::{MemoryStorage, SettingsConfig, SettingsManager, SubSettingsConfig};
+use serde_json::json;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn manager_with(
+    dir: &Path,
+    storage: &MemoryStorage,
+) -> SettingsManager<MemoryStorage, TestSettings> {
+    let config = SettingsConfig::builder("memory-app", "1.0.0")
+        .with_config_dir(dir)
+        .with_schema::<TestSettings>()
+        .with_storage_backend(storage.clone())
+        .build();
+    let manager = SettingsManager::new(config).unwrap();
+    manager
+        .register_sub_settings(SubSettingsConfig::new("remotes"))
+        .unwrap();
+    manager
+        .register_sub_settings(SubSettingsConfig::singlefile("backends"))
+        .unwrap();
+    manager
+}
+
+fn files_below(dir: &Path) -> usize {
+    std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .map(|path| if path.is_dir() { files_below(&path) } else { 1 })
+        .sum()
+}
+
+#[test]
+fn memory_settings_and_sub_settings_roundtrip() {
+    let temp = TempDir::new().unwrap();
+    let storage = MemoryStorage::new();
+    let manager = manager_with(temp.path(), &storage);
+
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes.set("gdrive", &json!({"type": "drive"})).unwrap();
+    remotes.set("s3", &json!({"type": "s3"})).unwrap();
+    remotes.delete("s3").unwrap();
+    let backends = manager.sub_settings("backends").unwrap();
+    backends.set("local", &json!({"port": 51900})).unwrap();
+
+    assert_eq!(files_below(temp.path()), 0);
+    assert!(storage.paths().contains(&temp.path().join("settings.json")));
+
+    // A second manager on the same storage sees everything
+    let reloaded = manager_with(temp.path(), &storage);
+    assert_eq!(reloaded.get_all().unwrap().ui.theme, "light");
+    let remotes = reloaded.sub_settings("remotes").unwrap();
+    assert_eq!(remotes.list().unwrap(), vec!["gdrive"]);
+    assert_eq!(
+        remotes.get_value("gdrive").unwrap(),
+        json!({"type": "drive"})
+    );
+    let backends = reloaded.sub_settings("backends").unwrap();
+    assert_eq!(backends.list().unwrap(), vec!["local"]);
+}
+
+#[cfg(feature = "profiles")]
+#[test]
+fn memory_profiles_move_entries_with_the_profile() {
+    let temp = TempDir::new().unwrap();
+    let storage = MemoryStorage::new();
+    let manager = SettingsManager::builder("memory-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_storage_backend(storage.clone())
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_profiles())
+        .build()
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    let profiles = remotes.profiles().unwrap();
+    remotes.set("personal", &json!({"type": "drive"})).unwrap();
+
+    profiles.create("work").unwrap();
+    remotes.switch_profile("work").unwrap();
+    assert!(remotes.list().unwrap().is_empty());
+    remotes.set("office", &json!({"type": "s3"})).unwrap();
+
+    profiles.duplicate("work", "backup").unwrap();
+    profiles.rename("backup", "archive").unwrap();
+    remotes.switch_profile("archive").unwrap();
+    assert_eq!(remotes.list().unwrap(), vec!["office"]);
+
+    remotes.switch_profile("default").unwrap();
+    assert_eq!(remotes.list().unwrap(), vec!["personal"]);
+    profiles.delete("archive").unwrap();
+    profiles.create("archive").unwrap();
+    remotes.switch_profile("archive").unwrap();
+    assert!(remotes.list().unwrap().is_empty());
+
+    // The manifest is kept in memory as well
+    assert!(
+        storage
+            .paths()
+            .iter()
+            .any(|path| path.ends_with(".profiles.json"))
+    );
+    assert_eq!(files_below(temp.path()), 0);
+}
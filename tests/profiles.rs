@@ -205,6 +205,62 @@ fn test_duplicate_profile() {
     assert!(backup_dir.join("gdrive.json").exists());
 }
 
+#[test]
+fn test_copy_into_existing_profile() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_profiles())
+        .build()
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    let profiles = remotes.profiles().unwrap();
+    profiles.create("work").unwrap();
+    profiles.create("personal").unwrap();
+
+    remotes.switch_profile("work").unwrap();
+    remotes.set("gdrive", &json!({"type": "drive"})).unwrap();
+    remotes.set("s3", &json!({"type": "s3"})).unwrap();
+    remotes.switch_profile("personal").unwrap();
+    remotes
+        .set("s3", &json!({"type": "s3", "bucket": "mine"}))
+        .unwrap();
+    remotes.set("local", &json!({"type": "local"})).unwrap();
+    remotes.switch_profile("default").unwrap();
+
+    // Conflicting entries are kept without overwrite
+    profiles.copy_into("work", "personal", false).unwrap();
+    remotes.switch_profile("personal").unwrap();
+    let mut names = remotes.list().unwrap();
+    names.sort();
+    assert_eq!(names, vec!["gdrive", "local", "s3"]);
+    assert_eq!(
+        remotes.get_value("s3").unwrap(),
+        json!({"type": "s3", "bucket": "mine"})
+    );
+    remotes.switch_profile("default").unwrap();
+
+    profiles.copy_into("work", "personal", true).unwrap();
+    remotes.switch_profile("personal").unwrap();
+    assert_eq!(remotes.get_value("s3").unwrap(), json!({"type": "s3"}));
+    assert_eq!(
+        remotes.get_value("local").unwrap(),
+        json!({"type": "local"})
+    );
+
+    // Both profiles must exist
+    assert!(matches!(
+        profiles.copy_into("work", "missing", true),
+        Err(rcman::Error::ProfileNotFound(name)) if name == "missing"
+    ));
+    assert!(matches!(
+        profiles.copy_into("missing", "work", true),
+        Err(rcman::Error::ProfileNotFound(name)) if name == "missing"
+    ));
+}
+
 // =============================================================================
 // Profile Events
 // =============================================================================
@@ -466,6 +522,96 @@ fn test_main_settings_profiles() {
     assert_eq!(settings.general.theme, "ocean");
 }
 
+#[test]
+fn test_start_in_default_profile() {
+    let temp_dir = TempDir::new().unwrap();
+    let build = |start_in_default: bool| {
+        let builder = SettingsManager::builder("test-app", "1.0.0")
+            .with_config_dir(temp_dir.path())
+            .with_schema::<common::TestSettings>()
+            .with_profiles();
+        let builder = if start_in_default {
+            builder.start_in_default_profile()
+        } else {
+            builder
+        };
+        builder.build().unwrap()
+    };
+
+    let manager = build(false);
+    manager.create_profile("personal").unwrap();
+    manager.create_profile("work").unwrap();
+    let profiles = manager.profiles().unwrap();
+    assert_eq!(profiles.default_profile().unwrap(), "default");
+    assert!(matches!(
+        profiles.set_default("missing"),
+        Err(rcman::Error::ProfileNotFound(_))
+    ));
+    profiles.set_default("personal").unwrap();
+    assert_eq!(manager.active_profile().unwrap(), "default");
+
+    manager.switch_profile("work").unwrap();
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    drop(manager);
+
+    // Without the option the last active profile is reopened
+    assert_eq!(build(false).active_profile().unwrap(), "work");
+
+    let manager = build(true);
+    assert_eq!(manager.active_profile().unwrap(), "personal");
+    assert_eq!(manager.get_all().unwrap().ui.theme, "dark");
+
+    // The default follows renames, and falls back to the active profile on delete
+    let profiles = manager.profiles().unwrap();
+    manager.switch_profile("work").unwrap();
+    profiles.rename("personal", "home").unwrap();
+    assert_eq!(profiles.default_profile().unwrap(), "home");
+    profiles.delete("home").unwrap();
+    assert_eq!(profiles.default_profile().unwrap(), "work");
+}
+
+#[test]
+fn test_start_in_default_profile_applies_to_sub_settings() {
+    let temp_dir = TempDir::new().unwrap();
+    let build = |start_in_default: bool| {
+        let builder = SettingsManager::builder("test-app", "1.0.0")
+            .with_config_dir(temp_dir.path())
+            .with_profiles()
+            .with_sub_settings(SubSettingsConfig::new("remotes").with_profiles());
+        let builder = if start_in_default {
+            builder.start_in_default_profile()
+        } else {
+            builder
+        };
+        builder.build().unwrap()
+    };
+
+    let manager = build(false);
+    manager.create_profile("personal").unwrap();
+    manager.create_profile("work").unwrap();
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes.profiles().unwrap().set_default("personal").unwrap();
+    remotes.switch_profile("work").unwrap();
+    remotes.set("office", &json!({"type": "s3"})).unwrap();
+    drop(remotes);
+    drop(manager);
+
+    // Without the option the last active profile is reopened
+    let manager = build(false);
+    let remotes = manager.sub_settings("remotes").unwrap();
+    assert_eq!(remotes.profiles().unwrap().active().unwrap(), "work");
+    assert!(remotes.exists("office").unwrap());
+    drop(remotes);
+    drop(manager);
+
+    let manager = build(true);
+    let remotes = manager.sub_settings("remotes").unwrap();
+    assert_eq!(remotes.profiles().unwrap().active().unwrap(), "personal");
+    assert!(!remotes.exists("office").unwrap());
+}
+
 #[test]
 fn test_main_profile_switch_emits_changed_setting_callbacks() {
     use rcman::{SettingMetadata, SettingsSchema};
@@ -678,6 +824,86 @@ fn test_main_profile_propagation_with_mixed_sub_settings() {
     ));
 }
 
+#[test]
+fn test_subscribe_all_mutations_reports_profile_switches() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_profiles()
+        .build()
+        .unwrap();
+    let mutations = manager.subscribe_all_mutations();
+
+    manager.create_profile("work").unwrap();
+    manager.switch_profile("work").unwrap();
+
+    let switches: Vec<rcman::Mutation> = mutations
+        .try_iter()
+        .filter(|m| matches!(m, rcman::Mutation::ProfileSwitch { .. }))
+        .collect();
+    assert_eq!(
+        switches,
+        vec![rcman::Mutation::ProfileSwitch {
+            target: "settings".into(),
+            from: "default".into(),
+            to: "work".into(),
+        }]
+    );
+}
+
+#[test]
+fn test_on_any_profile_switch_aggregates_all_targets() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_profiles()
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_profiles())
+        .build()
+        .unwrap();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = Arc::clone(&events);
+    manager.on_any_profile_switch(move |target, from, to| {
+        events_clone
+            .lock()
+            .unwrap()
+            .push(format!("{target}:{from}->{to}"));
+    });
+
+    // Registered after the listener, still reported
+    manager
+        .register_sub_settings(SubSettingsConfig::new("backends").with_profiles())
+        .unwrap();
+
+    manager.create_profile("work").unwrap();
+    manager.switch_profile("work").unwrap();
+
+    {
+        let mut seen = events.lock().unwrap();
+        assert_eq!(seen.len(), 3);
+        assert_eq!(seen.last().unwrap(), "settings:default->work");
+        seen.sort();
+        assert_eq!(
+            *seen,
+            vec![
+                "backends:default->work",
+                "remotes:default->work",
+                "settings:default->work",
+            ]
+        );
+        seen.clear();
+    }
+
+    // Switching a single sub-settings type is reported on its own
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes.switch_profile("default").unwrap();
+    // Switching to the already-active profile is not a switch
+    remotes.switch_profile("default").unwrap();
+    assert_eq!(*events.lock().unwrap(), vec!["remotes:work->default"]);
+}
+
 #[cfg(any(feature = "keychain", feature = "encrypted-file"))]
 #[test]
 #[cfg_attr(
@@ -11,6 +11,7 @@ mod common;
 
 use common::{TestFixture, read_settings_file};
 use serde_json::json;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 // =============================================================================
@@ -82,6 +83,85 @@ fn test_save_and_reload_persists() {
     }
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Default)]
+struct NestedSettings {
+    ui: NestedUi,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+struct NestedUi {
+    sidebar: serde_json::Value,
+}
+
+impl Default for NestedUi {
+    fn default() -> Self {
+        Self {
+            sidebar: json!({"width": 200, "collapsed": false, "panel": {"dock": "left", "pinned": true}}),
+        }
+    }
+}
+
+impl rcman::SettingsSchema for NestedSettings {
+    fn get_metadata() -> std::collections::HashMap<String, rcman::SettingMetadata> {
+        rcman::settings! {
+            "ui.sidebar" => rcman::SettingMetadata::object(NestedUi::default().sidebar)
+        }
+    }
+}
+
+#[test]
+fn test_load_deep_merges_nested_stored_values() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("settings.json"),
+        r#"{"ui": {"sidebar": {"width": 300, "panel": {"dock": "right"}}}}"#,
+    )
+    .unwrap();
+
+    let manager = rcman::SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<NestedSettings>()
+        .build()
+        .unwrap();
+
+    let expected =
+        json!({"width": 300, "collapsed": false, "panel": {"dock": "right", "pinned": true}});
+    assert_eq!(manager.get_all().unwrap().ui.sidebar, expected);
+    assert_eq!(manager.get_value("ui.sidebar").unwrap(), expected);
+    assert_eq!(
+        manager.metadata().unwrap()["ui.sidebar"].value,
+        Some(expected)
+    );
+}
+
+#[test]
+fn test_unknown_stored_keys_and_prune() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("settings.json"),
+        r#"{"ui": {"theme": "light", "old_color": "red"}, "legacy": {"flag": true}, "stray": 1}"#,
+    )
+    .unwrap();
+    let manager = rcman::SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<common::TestSettings>()
+        .build()
+        .unwrap();
+
+    let expected = vec!["legacy.flag", "stray", "ui.old_color"];
+    assert_eq!(manager.unknown_stored_keys().unwrap(), expected);
+    assert_eq!(manager.prune_unknown().unwrap(), expected);
+
+    let stored: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(temp_dir.path().join("settings.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(stored, json!({"ui": {"theme": "light"}}));
+    assert!(manager.unknown_stored_keys().unwrap().is_empty());
+    assert!(manager.prune_unknown().unwrap().is_empty());
+    assert_eq!(manager.get_all().unwrap().ui.theme, "light");
+}
+
 // =============================================================================
 // Reset Functionality
 // =============================================================================
@@ -137,6 +217,47 @@ fn test_reset_all_settings() {
     assert_eq!(settings.general.language, "en");
 }
 
+#[test]
+fn test_reset_all_confirmed_rejects_stale_token() {
+    let fixture = TestFixture::new();
+    fixture
+        .manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    fixture
+        .manager
+        .save_setting("general", "language", &json!("tr"))
+        .unwrap();
+
+    let token = fixture.manager.prepare_reset_all().unwrap();
+    assert_eq!(token.customized_settings, 2);
+    assert_eq!(token.secrets, 0);
+    assert_eq!(token.sub_settings_secrets, 0);
+    assert!(!format!("{token:?}").contains("state"));
+
+    // A change after preparing invalidates the token
+    fixture
+        .manager
+        .save_setting("ui", "font_size", &json!(20.0))
+        .unwrap();
+    let err = fixture.manager.reset_all_confirmed(&token).unwrap_err();
+    assert!(matches!(err, rcman::Error::StaleResetToken));
+    assert_eq!(fixture.manager.get_all().unwrap().ui.theme, "light");
+
+    let token = fixture.manager.prepare_reset_all().unwrap();
+    assert_eq!(token.customized_settings, 3);
+    fixture.manager.reset_all_confirmed(&token).unwrap();
+    assert_eq!(fixture.manager.get_all().unwrap().ui.theme, "dark");
+    assert_eq!(
+        fixture
+            .manager
+            .prepare_reset_all()
+            .unwrap()
+            .customized_settings,
+        0
+    );
+}
+
 #[test]
 fn test_reset_all_emits_callbacks_for_changed_keys() {
     let fixture = TestFixture::new();
@@ -285,7 +406,7 @@ fn test_invalid_select_option_rejected() {
 
     assert!(result.is_err());
     let err = result.unwrap_err();
-    assert!(err.to_string().contains("available options"));
+    assert!(err.to_string().contains("is not a valid option"));
 }
 
 #[test]
@@ -301,6 +422,279 @@ fn test_setting_not_found_error() {
     assert!(result.is_err());
 }
 
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct RequiredSchema {
+    api: RequiredApi,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct RequiredApi {
+    endpoint: String,
+}
+
+impl rcman::SettingsSchema for RequiredSchema {
+    fn get_metadata() -> std::collections::HashMap<String, rcman::SettingMetadata> {
+        rcman::settings! {
+            "api.endpoint" => rcman::SettingMetadata::text("").required(),
+        }
+    }
+}
+
+#[test]
+fn test_required_setting_rejects_blank_but_allows_reset() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let manager = rcman::SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<RequiredSchema>()
+        .build()
+        .unwrap();
+
+    let result = manager.save_setting("api", "endpoint", &json!(""));
+    assert!(result.unwrap_err().to_string().contains("required"));
+
+    manager
+        .save_setting("api", "endpoint", &json!("https://api.example.com"))
+        .unwrap();
+
+    // Resetting a required setting back to its empty default is still allowed
+    manager.reset_setting("api", "endpoint").unwrap();
+    assert_eq!(manager.get_value("api.endpoint").unwrap(), json!(""));
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Default)]
+struct ProxySettings {
+    network: ProxyNetwork,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Default)]
+struct ProxyNetwork {
+    use_proxy: bool,
+    proxy_host: String,
+}
+
+impl rcman::SettingsSchema for ProxySettings {
+    fn get_metadata() -> std::collections::HashMap<String, rcman::SettingMetadata> {
+        rcman::settings! {
+            "network.use_proxy" => rcman::SettingMetadata::toggle(false),
+            "network.proxy_host" => rcman::SettingMetadata::text("")
+                .required_when("network.use_proxy", true)
+        }
+    }
+}
+
+#[test]
+fn test_required_when_depends_on_other_setting() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let manager = rcman::SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<ProxySettings>()
+        .build()
+        .unwrap();
+
+    // Inactive condition: blank is fine
+    manager
+        .save_setting("network", "proxy_host", &json!(" "))
+        .unwrap();
+    assert!(manager.validate_all().unwrap().is_empty());
+
+    // A batch is checked against its own result
+    let result = manager.save_settings_batch(&[
+        ("network", "use_proxy", json!(true)),
+        ("network", "proxy_host", json!("")),
+    ]);
+    assert!(matches!(
+        result,
+        Err(rcman::Error::InvalidSettingValue { ref key, .. }) if key == "network.proxy_host"
+    ));
+    assert!(matches!(
+        manager.save_settings_batch(&[("network", "use_proxy", json!(true))]),
+        Err(rcman::Error::InvalidSettingValue { .. })
+    ));
+
+    // Saving the toggle alone is allowed, but the gap is reported
+    manager
+        .save_setting("network", "use_proxy", &json!(true))
+        .unwrap();
+    let problems = manager.validate_all().unwrap();
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].0, "network.proxy_host");
+    assert!(
+        manager
+            .save_setting("network", "proxy_host", &json!(""))
+            .is_err()
+    );
+
+    manager
+        .save_setting("network", "proxy_host", &json!("proxy.local"))
+        .unwrap();
+    assert!(manager.validate_all().unwrap().is_empty());
+
+    // Resetting to the default is always allowed
+    manager.reset_setting("network", "proxy_host").unwrap();
+}
+
+#[test]
+fn test_is_active_follows_depends_on() {
+    #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+    struct DependentSchema {}
+
+    impl rcman::SettingsSchema for DependentSchema {
+        fn get_metadata() -> std::collections::HashMap<String, rcman::SettingMetadata> {
+            rcman::settings! {
+                "proxy.enabled" => rcman::SettingMetadata::toggle(false),
+                "proxy.mode" => rcman::SettingMetadata::text("http")
+                    .depends_on("proxy.enabled", true),
+                "proxy.host" => rcman::SettingMetadata::text("")
+                    .depends_on("proxy.mode", "http"),
+            }
+        }
+    }
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let manager = rcman::SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<DependentSchema>()
+        .build()
+        .unwrap();
+
+    assert!(manager.is_active("proxy.enabled").unwrap());
+    assert!(!manager.is_active("proxy.mode").unwrap());
+    // Inactive because its controlling setting is inactive
+    assert!(!manager.is_active("proxy.host").unwrap());
+
+    // Advisory only: saving an inactive setting works
+    manager
+        .save_setting("proxy", "host", &json!("proxy.local"))
+        .unwrap();
+
+    manager
+        .save_setting("proxy", "enabled", &json!(true))
+        .unwrap();
+    assert!(manager.is_active("proxy.mode").unwrap());
+    assert!(manager.is_active("proxy.host").unwrap());
+
+    manager
+        .save_setting("proxy", "mode", &json!("socks"))
+        .unwrap();
+    assert!(!manager.is_active("proxy.host").unwrap());
+
+    assert!(matches!(
+        manager.is_active("proxy.missing"),
+        Err(rcman::Error::SettingNotFound(_))
+    ));
+}
+
+// Without credential features, secrets are kept in the settings file, which
+// is enough to exercise masking.
+#[cfg(not(any(feature = "keychain", feature = "encrypted-file")))]
+#[test]
+fn test_masked_value() {
+    #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+    struct MaskedSchema {
+        api: MaskedApi,
+    }
+
+    #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+    struct MaskedApi {
+        token: String,
+        endpoint: String,
+        retries: f64,
+    }
+
+    impl rcman::SettingsSchema for MaskedSchema {
+        fn get_metadata() -> std::collections::HashMap<String, rcman::SettingMetadata> {
+            rcman::settings! {
+                "api.token" => rcman::SettingMetadata::text("").secret(),
+                "api.endpoint" => rcman::SettingMetadata::text(""),
+                "api.retries" => rcman::SettingMetadata::number(3),
+            }
+        }
+    }
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let manager = rcman::SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<MaskedSchema>()
+        .build()
+        .unwrap();
+
+    // Unset secret
+    assert_eq!(manager.masked_value("api.token").unwrap(), None);
+
+    manager
+        .save_setting("api", "token", &json!("sk-live-0123456789abcd"))
+        .unwrap();
+    manager
+        .save_setting("api", "endpoint", &json!("https://api.example.com"))
+        .unwrap();
+
+    assert_eq!(
+        manager.masked_value("api.token").unwrap().as_deref(),
+        Some("sk-...abcd")
+    );
+    assert_eq!(
+        manager.masked_value("api.endpoint").unwrap().as_deref(),
+        Some("https://api.example.com")
+    );
+    assert_eq!(
+        manager.masked_value("api.retries").unwrap().as_deref(),
+        Some("3.0")
+    );
+    assert!(manager.masked_value("api.missing").is_err());
+}
+
+#[test]
+fn test_effective_settings_redacted() {
+    #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+    struct LoggedSchema {}
+
+    impl rcman::SettingsSchema for LoggedSchema {
+        fn get_metadata() -> std::collections::HashMap<String, rcman::SettingMetadata> {
+            rcman::settings! {
+                "api.token" => rcman::SettingMetadata::text("").secret(),
+                "api.endpoint" => rcman::SettingMetadata::text("https://api.example.com"),
+                "ui.theme" => rcman::SettingMetadata::text("dark"),
+            }
+        }
+    }
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let env_source = std::sync::Arc::new(common::MockEnvSource::new());
+    let config = rcman::SettingsConfig::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<LoggedSchema>()
+        .with_credentials()
+        .with_env_prefix("LOGGED")
+        .with_env_source(env_source.clone() as std::sync::Arc<dyn rcman::EnvSource>)
+        .build();
+    let manager = rcman::SettingsManager::new(config).unwrap();
+
+    manager
+        .save_setting("api", "token", &json!("sk-live-0123456789abcd"))
+        .unwrap();
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    env_source.set("LOGGED_API_ENDPOINT", "https://staging.example.com");
+
+    let snapshot = manager.effective_settings_redacted().unwrap();
+    assert_eq!(
+        snapshot,
+        json!({
+            "api": {"token": rcman::REDACTED_SECRET, "endpoint": "https://staging.example.com"},
+            "ui": {"theme": "light"},
+        })
+    );
+    assert!(!snapshot.to_string().contains("sk-live"));
+
+    // The stored secret is customized whether it lives in the settings file
+    // or the credential store
+    assert_eq!(
+        manager.diff_from_defaults().unwrap()["api.token"],
+        (json!(""), json!(rcman::REDACTED_SECRET))
+    );
+}
+
 // =============================================================================
 // Environment Variable Overrides
 // =============================================================================
@@ -358,6 +752,253 @@ fn test_env_override_priority() {
     assert_eq!(theme_meta.value, Some(json!("system")));
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Default)]
+struct TimeoutSettings {}
+
+impl rcman::SettingsSchema for TimeoutSettings {
+    fn get_metadata() -> std::collections::HashMap<String, rcman::SettingMetadata> {
+        rcman::settings! {
+            "net.timeout" => rcman::SettingMetadata::duration(std::time::Duration::from_secs(30))
+        }
+    }
+}
+
+#[test]
+fn test_duration_env_override_accepts_units() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let env_source = std::sync::Arc::new(common::MockEnvSource::new());
+    let config = rcman::SettingsConfig::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<TimeoutSettings>()
+        .with_env_prefix("DURATION")
+        .with_env_source(env_source.clone() as std::sync::Arc<dyn rcman::EnvSource>)
+        .build();
+    let manager = rcman::SettingsManager::new(config).unwrap();
+
+    assert_eq!(manager.get::<u64>("net.timeout").unwrap(), 30_000);
+    manager
+        .save_setting("net", "timeout", &json!(45_000))
+        .unwrap();
+    assert_eq!(manager.get::<u64>("net.timeout").unwrap(), 45_000);
+
+    env_source.set("DURATION_NET_TIMEOUT", "2m");
+    assert_eq!(manager.get::<u64>("net.timeout").unwrap(), 120_000);
+    env_source.set("DURATION_NET_TIMEOUT", "1500");
+    assert_eq!(manager.get::<u64>("net.timeout").unwrap(), 1500);
+}
+
+#[test]
+fn test_get_single_setting() {
+    let fixture = TestFixture::with_env_prefix("SINGLE");
+    let manager = &fixture.manager;
+
+    // Schema default, then stored value, then env override
+    assert_eq!(manager.get::<String>("ui.theme").unwrap(), "dark");
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    assert_eq!(manager.get::<String>("ui.theme").unwrap(), "light");
+    fixture.env_source.set("SINGLE_UI_THEME", "system");
+    assert_eq!(manager.get::<String>("ui.theme").unwrap(), "system");
+
+    assert!((manager.get::<f64>("ui.font_size").unwrap() - 14.0).abs() < f64::EPSILON);
+    assert!(matches!(
+        manager.get::<String>("ui.missing"),
+        Err(rcman::Error::SettingNotFound(_))
+    ));
+    assert!(matches!(
+        manager.get::<bool>("ui.theme"),
+        Err(rcman::Error::Parse(_))
+    ));
+}
+
+// =============================================================================
+// Flat Export / Import
+// =============================================================================
+
+#[test]
+fn test_export_flat_only_non_defaults() {
+    let fixture = TestFixture::new();
+
+    fixture
+        .manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    fixture
+        .manager
+        .save_setting("general", "language", &json!("de"))
+        .unwrap();
+
+    let flat = fixture.manager.export_flat(false).unwrap();
+    assert_eq!(flat.len(), 2);
+    assert_eq!(flat.get("ui.theme"), Some(&json!("light")));
+    assert_eq!(flat.get("general.language"), Some(&json!("de")));
+}
+
+#[test]
+fn test_export_flat_redacted() {
+    let fixture = TestFixture::new();
+    let manager = &fixture.manager;
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    manager
+        .save_setting("paths", "config_dir", &json!("/home/alice/.config"))
+        .unwrap();
+    manager
+        .save_setting("paths", "log_file", &json!("/home/alice/app.log"))
+        .unwrap();
+
+    let export = manager
+        .export_flat_redacted(|key, value| match key {
+            "paths.config_dir" => Some(json!("<redacted>")),
+            "paths.log_file" => None,
+            _ => Some(value.clone()),
+        })
+        .unwrap();
+
+    assert!(export.is_sanitized());
+    assert_eq!(export.redacted, vec!["paths.config_dir", "paths.log_file"]);
+    assert_eq!(export.values.len(), 2);
+    assert_eq!(export.values.get("ui.theme"), Some(&json!("light")));
+    assert_eq!(
+        export.values.get("paths.config_dir"),
+        Some(&json!("<redacted>"))
+    );
+
+    let untouched = manager
+        .export_flat_redacted(|_, value| Some(value.clone()))
+        .unwrap();
+    assert!(!untouched.is_sanitized());
+    assert_eq!(untouched.values, manager.export_flat(false).unwrap());
+}
+
+#[test]
+fn test_diff_from_defaults_includes_env_overrides() {
+    let fixture = TestFixture::with_env_prefix("DIFF");
+    let manager = &fixture.manager;
+    assert!(manager.diff_from_defaults().unwrap().is_empty());
+
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    fixture.env_source.set("DIFF_GENERAL_LANGUAGE", "de");
+
+    let diff = manager.diff_from_defaults().unwrap();
+    assert_eq!(diff.len(), 2);
+    assert_eq!(diff["ui.theme"], (json!("dark"), json!("light")));
+    assert_eq!(diff["general.language"], (json!("en"), json!("de")));
+
+    // Saving the default value again drops the key from the diff
+    manager.save_setting("ui", "theme", &json!("dark")).unwrap();
+    assert!(
+        !manager
+            .diff_from_defaults()
+            .unwrap()
+            .contains_key("ui.theme")
+    );
+}
+
+#[test]
+fn test_config_fingerprint_depends_only_on_customized_keys() {
+    let first = TestFixture::new();
+    let second = TestFixture::new();
+    let untouched = first.manager.config_fingerprint().unwrap();
+    assert_eq!(untouched, second.manager.config_fingerprint().unwrap());
+
+    first
+        .manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    second
+        .manager
+        .save_setting("ui", "theme", &json!("system"))
+        .unwrap();
+
+    let fingerprint = first.manager.config_fingerprint().unwrap();
+    assert_ne!(fingerprint, untouched);
+    assert_eq!(fingerprint.len(), 16);
+    assert_eq!(fingerprint, second.manager.config_fingerprint().unwrap());
+
+    first.manager.reset_setting("ui", "theme").unwrap();
+    assert_eq!(first.manager.config_fingerprint().unwrap(), untouched);
+}
+
+#[test]
+fn test_import_flat_round_trip() {
+    let source = TestFixture::new();
+    source
+        .manager
+        .save_setting("ui", "font_size", &json!(18.0))
+        .unwrap();
+    let flat = source.manager.export_flat(false).unwrap();
+
+    let target = TestFixture::new();
+    target.manager.import_flat(&flat).unwrap();
+
+    let settings = target.manager.get_all().unwrap();
+    assert!((settings.ui.font_size - 18.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_import_flat_rejects_invalid_without_writing() {
+    let fixture = TestFixture::new();
+
+    let mut flat = std::collections::HashMap::new();
+    flat.insert("ui.theme".to_string(), json!("light"));
+    flat.insert("ui.font_size".to_string(), json!(1000.0));
+
+    assert!(fixture.manager.import_flat(&flat).is_err());
+    assert_eq!(fixture.manager.get_all().unwrap().ui.theme, "dark");
+
+    let mut unknown = std::collections::HashMap::new();
+    unknown.insert("nope".to_string(), json!(1));
+    assert!(fixture.manager.import_flat(&unknown).is_err());
+}
+
+#[test]
+fn test_validate_import_reports_every_problem() {
+    let fixture = TestFixture::new();
+    let document = r#"{
+        "ui": {"theme": "purple", "font_size": 1000.0},
+        "general.tray_enabled": false,
+        "general.missing": 1,
+        "orphan": 1
+    }"#;
+
+    let problems = fixture.manager.validate_import(document).unwrap();
+    let keys: Vec<&str> = problems.iter().map(|(key, _)| key.as_str()).collect();
+    assert_eq!(
+        keys,
+        vec!["general.missing", "orphan", "ui.font_size", "ui.theme"]
+    );
+
+    // Nothing was written, and the import itself refuses the document
+    assert!(fixture.manager.import_json(document).is_err());
+    assert_eq!(fixture.manager.get_all().unwrap().ui.theme, "dark");
+
+    assert!(fixture.manager.validate_import("[1, 2]").is_err());
+}
+
+#[test]
+fn test_import_json_applies_valid_document() {
+    let fixture = TestFixture::new();
+    let document = r#"{"ui": {"theme": "light"}, "general.tray_enabled": false}"#;
+
+    assert!(
+        fixture
+            .manager
+            .validate_import(document)
+            .unwrap()
+            .is_empty()
+    );
+    fixture.manager.import_json(document).unwrap();
+
+    let settings = fixture.manager.get_all().unwrap();
+    assert_eq!(settings.ui.theme, "light");
+    assert!(!settings.general.tray_enabled);
+}
+
 // =============================================================================
 // Cache Invalidation
 // =============================================================================
@@ -469,3 +1110,701 @@ fn test_concurrent_access() {
     let theme = metadata.get("ui.theme").unwrap();
     assert!(theme.value.is_some());
 }
+
+#[test]
+fn test_category_metadata() {
+    use rcman::{CategoryMeta, SettingsManager};
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<common::TestSettings>()
+        .with_category(
+            "ui",
+            CategoryMeta::new("Appearance")
+                .description("Look and feel")
+                .icon("palette")
+                .order(1),
+        )
+        .build()
+        .unwrap();
+
+    let categories = manager.category_metadata();
+
+    let ui = &categories["ui"];
+    assert_eq!(ui.label, "Appearance");
+    assert_eq!(ui.description.as_deref(), Some("Look and feel"));
+    assert_eq!(ui.icon.as_deref(), Some("palette"));
+    assert_eq!(ui.order, 1);
+
+    // Schema categories without registered metadata fall back to their name
+    assert_eq!(categories["general"], CategoryMeta::new("general"));
+    assert!(categories.contains_key("paths"));
+}
+
+#[test]
+fn test_save_setting_debounced_coalesces_writes() {
+    use rcman::SettingsManager;
+    use std::time::Duration;
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let manager = Arc::new(
+        SettingsManager::builder("test-app", "1.0.0")
+            .with_config_dir(temp.path())
+            .with_schema::<common::TestSettings>()
+            .build()
+            .unwrap(),
+    );
+
+    let writes = Arc::new(Mutex::new(Vec::new()));
+    let writes_clone = Arc::clone(&writes);
+    manager.events().on_change(move |_key, _old, new| {
+        writes_clone.lock().unwrap().push(new.clone());
+    });
+
+    // Invalid values are rejected up front
+    assert!(
+        manager
+            .save_setting_debounced("ui", "font_size", &json!(1000.0), Duration::ZERO)
+            .is_err()
+    );
+
+    // A burst of saves within the window is written once, with the last value
+    for size in [15.0, 16.0, 17.0] {
+        manager
+            .save_setting_debounced("ui", "font_size", &json!(size), Duration::from_secs(60))
+            .unwrap();
+    }
+    assert!(manager.has_pending("ui", "font_size"));
+    assert!(writes.lock().unwrap().is_empty());
+
+    manager.flush_pending().unwrap();
+    assert!(!manager.has_pending("ui", "font_size"));
+    assert_eq!(*writes.lock().unwrap(), vec![json!(17.0)]);
+
+    // Without a flush the write happens once the delay elapses
+    manager
+        .save_setting_debounced("ui", "theme", &json!("light"), Duration::from_millis(20))
+        .unwrap();
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while manager.has_pending("ui", "theme") && std::time::Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(manager.get_all().unwrap().ui.theme, "light");
+}
+
+#[test]
+fn test_save_setting_debounced_yields_to_direct_saves_and_flushes_on_drop() {
+    use rcman::SettingsManager;
+    use std::time::Duration;
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let build = || {
+        Arc::new(
+            SettingsManager::builder("test-app", "1.0.0")
+                .with_config_dir(temp.path())
+                .with_schema::<common::TestSettings>()
+                .build()
+                .unwrap(),
+        )
+    };
+
+    // A direct save replaces the value still waiting to be written
+    let manager = build();
+    manager
+        .save_setting_debounced("ui", "font_size", &json!(15.0), Duration::from_millis(50))
+        .unwrap();
+    manager
+        .save_setting("ui", "font_size", &json!(16.0))
+        .unwrap();
+    assert!(!manager.has_pending("ui", "font_size"));
+    std::thread::sleep(Duration::from_millis(150));
+    assert!((manager.get_all().unwrap().ui.font_size - 16.0).abs() < f64::EPSILON);
+
+    // Dropping the manager writes what is still pending
+    manager
+        .save_setting_debounced("ui", "theme", &json!("light"), Duration::from_secs(3600))
+        .unwrap();
+    drop(manager);
+    assert_eq!(build().get_all().unwrap().ui.theme, "light");
+}
+
+#[test]
+fn test_watch_category_fires_immediately() {
+    let fixture = TestFixture::new();
+    fixture
+        .manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let calls_clone = calls.clone();
+    fixture
+        .manager
+        .watch_category("ui", true, move |key, old, new| {
+            calls_clone
+                .lock()
+                .unwrap()
+                .push((key.to_string(), old.clone(), new.clone()));
+        })
+        .unwrap();
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec![
+            ("ui.font_size".to_string(), json!(14.0), json!(14.0)),
+            ("ui.theme".to_string(), json!("light"), json!("light")),
+        ]
+    );
+
+    fixture
+        .manager
+        .save_setting("ui", "theme", &json!("dark"))
+        .unwrap();
+    fixture
+        .manager
+        .save_setting("general", "language", &json!("de"))
+        .unwrap();
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 3);
+    assert_eq!(
+        calls[2],
+        ("ui.theme".to_string(), json!("light"), json!("dark"))
+    );
+
+    assert!(
+        fixture
+            .manager
+            .watch_category("missing", true, |_, _, _| {})
+            .is_err()
+    );
+    assert!(
+        fixture
+            .manager
+            .watch_key("ui.missing", false, |_, _, _| {})
+            .is_err()
+    );
+}
+
+#[test]
+fn test_fallback_source_between_stored_and_defaults() {
+    use rcman::{ConfigSource, SettingsManager};
+
+    struct OrgDefaults;
+
+    impl ConfigSource for OrgDefaults {
+        fn get(&self, key: &str) -> Option<serde_json::Value> {
+            match key {
+                "ui.theme" => Some(json!("light")),
+                // Invalid values are ignored
+                "ui.font_size" => Some(json!("huge")),
+                _ => None,
+            }
+        }
+    }
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<common::TestSettings>()
+        .with_fallback_source(Arc::new(OrgDefaults))
+        .build()
+        .unwrap();
+
+    assert_eq!(manager.get_value("ui.theme").unwrap(), json!("light"));
+    assert_eq!(manager.get_value("ui.font_size").unwrap(), json!(14.0));
+    assert_eq!(manager.get_all().unwrap().ui.theme, "light");
+
+    // Fallback source values are defaults, not customizations
+    assert!(manager.export_flat(false).unwrap().is_empty());
+    assert!(manager.diff_from_defaults().unwrap().is_empty());
+
+    // A local override wins, even when it equals the schema default
+    manager.save_setting("ui", "theme", &json!("dark")).unwrap();
+    assert_eq!(manager.get_value("ui.theme").unwrap(), json!("dark"));
+    assert_eq!(
+        manager.export_flat(false).unwrap(),
+        HashMap::from([("ui.theme".to_string(), json!("dark"))])
+    );
+    assert_eq!(
+        manager.diff_from_defaults().unwrap()["ui.theme"],
+        (json!("light"), json!("dark"))
+    );
+    let stored: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(temp.path().join("settings.json")).unwrap())
+            .unwrap();
+    assert_eq!(stored["ui"]["theme"], "dark");
+
+    // Resetting drops the override and falls back to the source again
+    assert_eq!(
+        manager.reset_setting("ui", "theme").unwrap(),
+        json!("light")
+    );
+    assert_eq!(manager.get_value("ui.theme").unwrap(), json!("light"));
+}
+
+#[test]
+fn test_listener_can_save_settings() {
+    use rcman::SettingsManager;
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let manager = Arc::new(
+        SettingsManager::builder("test-app", "1.0.0")
+            .with_config_dir(temp.path())
+            .with_schema::<common::TestSettings>()
+            .build()
+            .unwrap(),
+    );
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    manager.events().on_change(move |key, _old, new| {
+        seen_clone
+            .lock()
+            .unwrap()
+            .push((key.to_string(), new.clone()));
+    });
+
+    // Follow the theme with the language; this saves from inside a listener
+    let weak = Arc::downgrade(&manager);
+    manager.events().watch("ui.theme", move |_key, _old, _new| {
+        if let Some(manager) = weak.upgrade() {
+            manager
+                .save_setting("general", "language", &json!("de"))
+                .unwrap();
+        }
+    });
+
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+
+    // The nested change is delivered after the outer one
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![
+            ("ui.theme".to_string(), json!("light")),
+            ("general.language".to_string(), json!("de")),
+        ]
+    );
+    assert_eq!(manager.get_all().unwrap().general.language, "de");
+}
+
+#[test]
+fn test_deferred_writes_flush_on_demand_timer_and_drop() {
+    use rcman::SettingsManager;
+    use std::time::Duration;
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let settings_path = temp.path().join("settings.json");
+    let build = |interval| {
+        SettingsManager::builder("test-app", "1.0.0")
+            .with_config_dir(temp.path())
+            .with_schema::<common::TestSettings>()
+            .deferred_writes(interval)
+            .build()
+            .unwrap()
+    };
+    let file_contains = |text: &str| {
+        std::fs::read_to_string(&settings_path).is_ok_and(|content| content.contains(text))
+    };
+
+    let manager = build(Duration::from_secs(3600));
+    for size in [15.0, 16.0, 17.0] {
+        manager
+            .save_setting("ui", "font_size", &json!(size))
+            .unwrap();
+    }
+
+    // Reads see the change before it reaches the disk
+    assert!((manager.get_all().unwrap().ui.font_size - 17.0).abs() < f64::EPSILON);
+    assert!(manager.has_unflushed_changes());
+    assert!(!file_contains("font_size"));
+
+    manager.flush().unwrap();
+    assert!(!manager.has_unflushed_changes());
+    assert!(file_contains("17"));
+
+    // Dropping the manager writes what is left
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    assert!(!file_contains("light"));
+    drop(manager);
+    assert!(file_contains("light"));
+
+    // The timer writes without an explicit flush
+    let manager = build(Duration::from_millis(20));
+    manager
+        .save_setting("general", "language", &json!("de"))
+        .unwrap();
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while manager.has_unflushed_changes() && std::time::Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert!(file_contains("\"de\""));
+}
+
+#[test]
+fn test_default_overrides_replace_schema_defaults() {
+    use rcman::SettingsManager;
+    use std::collections::HashMap;
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<common::TestSettings>()
+        .with_default_overrides(HashMap::from([("ui.theme".to_string(), json!("light"))]))
+        .build()
+        .unwrap();
+
+    assert_eq!(manager.get_all().unwrap().ui.theme, "light");
+    assert_eq!(
+        manager.metadata().unwrap()["ui.theme"].default,
+        json!("light")
+    );
+
+    // The schema default is now a customization and the override is not
+    manager.save_setting("ui", "theme", &json!("dark")).unwrap();
+    assert!(
+        std::fs::read_to_string(temp.path().join("settings.json"))
+            .unwrap()
+            .contains("dark")
+    );
+    assert_eq!(
+        manager.reset_setting("ui", "theme").unwrap(),
+        json!("light")
+    );
+    assert!(manager.export_flat(false).unwrap().is_empty());
+
+    let unknown = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<common::TestSettings>()
+        .with_default_overrides(HashMap::from([("ui.missing".to_string(), json!(1))]))
+        .build();
+    assert!(unknown.is_err());
+}
+
+/// Minimal executor for futures that never wait on a waker
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    loop {
+        if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        std::thread::yield_now();
+    }
+}
+
+#[test]
+fn test_save_setting_async_runs_async_validators() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let fixture = TestFixture::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = calls.clone();
+    fixture
+        .manager
+        .events()
+        .add_async_validator("general.language", move |value| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                if value == json!("tr") {
+                    Err("language not available".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+        });
+
+    // Sync saves must not bypass the async check
+    let err = fixture
+        .manager
+        .save_setting("general", "language", &json!("de"))
+        .unwrap_err();
+    assert!(matches!(err, rcman::Error::AsyncValidationRequired(_)));
+
+    let rejected = block_on(fixture.manager.save_setting_async(
+        "general",
+        "language",
+        &json!("tr"),
+    ));
+    assert!(matches!(
+        rejected,
+        Err(rcman::Error::InvalidSettingValue { .. })
+    ));
+    assert_eq!(fixture.manager.get_all().unwrap().general.language, "en");
+
+    // Schema validation runs first, so the async validator is not reached
+    assert!(
+        block_on(
+            fixture
+                .manager
+                .save_setting_async("general", "language", &json!(42))
+        )
+        .is_err()
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    let outcome = block_on(
+        fixture
+            .manager
+            .save_setting_async("general", "language", &json!("de")),
+    )
+    .unwrap();
+    assert!(outcome.changed);
+    assert_eq!(fixture.manager.get_all().unwrap().general.language, "de");
+
+    // Settings without async validators still save synchronously
+    fixture
+        .manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+}
+
+#[test]
+fn test_save_settings_batch_writes_once() {
+    use rcman::{InstrumentedStorage, JsonStorage, SettingsManager};
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_storage_backend(InstrumentedStorage::new(JsonStorage::new()))
+        .with_schema::<common::TestSettings>()
+        .build()
+        .unwrap();
+    manager
+        .save_setting("ui", "font_size", &json!(20.0))
+        .unwrap();
+
+    let changed = Arc::new(Mutex::new(Vec::new()));
+    let changed_clone = changed.clone();
+    manager.events().on_change(move |key, _old, _new| {
+        changed_clone.lock().unwrap().push(key.to_string());
+    });
+
+    // One invalid change rejects the whole batch
+    let writes_before = manager.storage_stats().writes;
+    let result = manager.save_settings_batch(&[
+        ("ui", "theme", json!("light")),
+        ("ui", "font_size", json!(100.0)),
+    ]);
+    assert!(matches!(
+        result,
+        Err(rcman::Error::InvalidSettingValue { .. })
+    ));
+    assert_eq!(manager.get_all().unwrap().ui.theme, "dark");
+    assert_eq!(manager.storage_stats().writes, writes_before);
+
+    // A single save reports the same error as the batch
+    assert!(matches!(
+        manager.save_setting("ui", "font_size", &json!(100.0)),
+        Err(rcman::Error::InvalidSettingValue { ref key, .. }) if key == "ui.font_size"
+    ));
+
+    manager
+        .save_settings_batch(&[
+            ("ui", "theme", json!("light")),
+            ("ui", "font_size", json!(14.0)),
+            ("general", "language", json!("de")),
+            ("general", "tray_enabled", json!(true)),
+        ])
+        .unwrap();
+    assert_eq!(manager.storage_stats().writes, writes_before + 1);
+    assert_eq!(
+        *changed.lock().unwrap(),
+        vec!["ui.theme", "ui.font_size", "general.language"]
+    );
+
+    // font_size went back to its default and is no longer stored
+    let stored: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(temp.path().join("settings.json")).unwrap())
+            .unwrap();
+    assert_eq!(
+        stored,
+        json!({"ui": {"theme": "light"}, "general": {"language": "de"}})
+    );
+}
+
+#[test]
+fn test_reset_category() {
+    use rcman::{InstrumentedStorage, JsonStorage, SettingsManager};
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_storage_backend(InstrumentedStorage::new(JsonStorage::new()))
+        .with_schema::<common::TestSettings>()
+        .build()
+        .unwrap();
+    manager
+        .save_settings_batch(&[
+            ("ui", "theme", json!("light")),
+            ("ui", "font_size", json!(20.0)),
+            ("general", "language", json!("de")),
+        ])
+        .unwrap();
+
+    let writes_before = manager.storage_stats().writes;
+    let reset = manager.reset_category("ui").unwrap();
+    assert_eq!(reset, vec!["ui.font_size", "ui.theme"]);
+    assert_eq!(manager.storage_stats().writes, writes_before + 1);
+
+    let settings = manager.get_all().unwrap();
+    assert_eq!(settings.ui.theme, "dark");
+    assert_eq!(settings.general.language, "de");
+
+    // Nothing left to reset
+    assert!(manager.reset_category("ui").unwrap().is_empty());
+    assert!(matches!(
+        manager.reset_category("missing"),
+        Err(rcman::Error::SettingNotFound(_))
+    ));
+}
+
+#[test]
+fn test_replace_category() {
+    use rcman::{InstrumentedStorage, JsonStorage, SettingsManager};
+    use std::collections::HashMap;
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_storage_backend(InstrumentedStorage::new(JsonStorage::new()))
+        .with_schema::<common::TestSettings>()
+        .build()
+        .unwrap();
+    manager
+        .save_settings_batch(&[
+            ("general", "language", json!("de")),
+            ("general", "tray_enabled", json!(false)),
+            ("ui", "theme", json!("light")),
+        ])
+        .unwrap();
+
+    let changed = Arc::new(Mutex::new(Vec::new()));
+    let changed_clone = changed.clone();
+    manager.events().on_change(move |key, _old, _new| {
+        changed_clone.lock().unwrap().push(key.to_string());
+    });
+
+    // Invalid values and unknown names reject the whole replacement
+    let writes_before = manager.storage_stats().writes;
+    let invalid = HashMap::from([("language".to_string(), json!("xx"))]);
+    assert!(manager.replace_category("general", &invalid).is_err());
+    let unknown = HashMap::from([("theme".to_string(), json!("light"))]);
+    assert!(matches!(
+        manager.replace_category("general", &unknown),
+        Err(rcman::Error::SettingNotFound(_))
+    ));
+    assert_eq!(manager.storage_stats().writes, writes_before);
+
+    // tray_enabled is left out, so it returns to its default
+    let values = HashMap::from([("language".to_string(), json!("tr"))]);
+    let result = manager.replace_category("general", &values).unwrap();
+    assert_eq!(result, vec!["general.language", "general.tray_enabled"]);
+    assert_eq!(manager.storage_stats().writes, writes_before + 1);
+    assert_eq!(changed.lock().unwrap().len(), 2);
+
+    let settings = manager.get_all().unwrap();
+    assert_eq!(settings.general.language, "tr");
+    assert!(settings.general.tray_enabled);
+    assert_eq!(settings.ui.theme, "light");
+
+    // Same contents again: nothing changes
+    assert!(
+        manager
+            .replace_category("general", &values)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_parse_and_save_from_text() {
+    let fixture = TestFixture::new();
+    let manager = &fixture.manager;
+
+    assert!(
+        manager
+            .parse_and_save("general.tray_enabled", "off")
+            .unwrap()
+            .changed
+    );
+    manager.parse_and_save("ui.font_size", "18").unwrap();
+    manager.parse_and_save("general.language", "de").unwrap();
+
+    let settings = manager.get_all().unwrap();
+    assert!(!settings.general.tray_enabled);
+    assert!((settings.ui.font_size - 18.0).abs() < f64::EPSILON);
+    assert_eq!(settings.general.language, "de");
+
+    // Parse errors and schema violations are both rejected
+    assert!(matches!(
+        manager.parse_and_save("ui.font_size", "big"),
+        Err(rcman::Error::InvalidSettingValue { .. })
+    ));
+    assert!(manager.parse_and_save("general.language", "xx").is_err());
+    assert!(manager.parse_and_save("ui.missing", "1").is_err());
+    assert!(manager.parse_and_save("theme", "dark").is_err());
+}
+
+#[test]
+fn test_cross_validator_sees_pending_change() {
+    let fixture = TestFixture::new();
+    let manager = &fixture.manager;
+
+    // The light theme needs a readable font size
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = seen.clone();
+    let handle =
+        manager
+            .events()
+            .add_cross_validator(&["ui.theme", "ui.font_size"], move |values| {
+                seen_clone.lock().unwrap().push(values.clone());
+                let small = values["ui.font_size"].as_f64().unwrap_or_default() < 12.0;
+                if values["ui.theme"] == json!("light") && small {
+                    return Err("light theme needs font_size >= 12".into());
+                }
+                Ok(())
+            });
+
+    manager
+        .save_setting("ui", "font_size", &json!(10.0))
+        .unwrap();
+    assert_eq!(
+        seen.lock().unwrap()[0],
+        HashMap::from([
+            ("ui.theme".to_string(), json!("dark")),
+            ("ui.font_size".to_string(), json!(10.0)),
+        ])
+    );
+
+    let result = manager.save_setting("ui", "theme", &json!("light"));
+    assert!(matches!(
+        result,
+        Err(rcman::Error::InvalidSettingValue { ref key, .. }) if key == "ui.theme"
+    ));
+    assert_eq!(manager.get_all().unwrap().ui.theme, "dark");
+
+    // Unrelated keys don't run it; a batch is checked as a whole
+    let calls = seen.lock().unwrap().len();
+    manager
+        .save_setting("general", "language", &json!("de"))
+        .unwrap();
+    assert_eq!(seen.lock().unwrap().len(), calls);
+    manager
+        .save_settings_batch(&[
+            ("ui", "theme", json!("light")),
+            ("ui", "font_size", json!(16.0)),
+        ])
+        .unwrap();
+    assert_eq!(manager.get_all().unwrap().ui.theme, "light");
+
+    assert!(manager.events().remove_listener(handle));
+    manager
+        .save_setting("ui", "font_size", &json!(8.0))
+        .unwrap();
+}
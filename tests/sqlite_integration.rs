@@ -128,6 +128,57 @@ fn sqlite_sub_settings_multi_file() {
     assert_eq!(remotes2.list().unwrap().len(), 2);
 }
 
+#[test]
+fn sqlite_sub_settings_shared_database() {
+    let temp = TempDir::new().unwrap();
+    let storage = SqliteStorage::new().with_database(temp.path().join("rcman.db"));
+    let manager = SettingsManager::builder("sqlite-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<TestSettings>()
+        .with_storage_backend(storage.clone())
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_no_cache())
+        .build()
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes
+        .set_many(&[
+            ("gdrive", json!({"type": "drive"})),
+            ("s3", json!({"type": "s3"})),
+        ])
+        .unwrap();
+    remotes.set("b2", &json!({"type": "b2"})).unwrap();
+    remotes.delete("b2").unwrap();
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+
+    // Entries and main settings are rows in one database, not separate files
+    assert!(temp.path().join("rcman.db").exists());
+    assert!(!temp.path().join("remotes").exists());
+    assert!(!temp.path().join("settings.db").exists());
+
+    assert_eq!(remotes.list().unwrap(), vec!["gdrive", "s3"]);
+    assert!(remotes.exists("s3").unwrap());
+    remotes.delete("s3").unwrap();
+    assert!(!remotes.exists("s3").unwrap());
+    assert_eq!(remotes.get_value("gdrive").unwrap()["type"], "drive");
+
+    // Reload from the shared database
+    let manager2 = SettingsManager::builder("sqlite-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_schema::<TestSettings>()
+        .with_storage_backend(storage)
+        .with_sub_settings(SubSettingsConfig::new("remotes"))
+        .build()
+        .unwrap();
+    assert_eq!(manager2.get_all().unwrap().ui.theme, "light");
+    assert_eq!(
+        manager2.sub_settings("remotes").unwrap().list().unwrap(),
+        vec!["gdrive"]
+    );
+}
+
 // =============================================================================
 // Sub-Settings (Single-File Mode)
 // =============================================================================
@@ -199,3 +250,94 @@ fn sqlite_backend_custom_key_shares_database() {
     assert_eq!(a["who"], "alpha");
     assert_eq!(b["who"], "beta");
 }
+
+#[cfg(feature = "profiles")]
+#[test]
+fn sqlite_shared_database_profile_operations() {
+    let temp = TempDir::new().unwrap();
+    let manager = SettingsManager::builder("sqlite-app", "1.0.0")
+        .with_config_dir(temp.path())
+        .with_storage_backend(SqliteStorage::new().with_database(temp.path().join("rcman.db")))
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_profiles())
+        .build()
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes.set("gdrive", &json!({"type": "drive"})).unwrap();
+
+    let profiles = remotes.profiles().unwrap();
+    profiles.duplicate("default", "work").unwrap();
+    profiles.rename("work", "office").unwrap();
+    profiles.create("spare").unwrap();
+    profiles.copy_into("office", "spare", false).unwrap();
+    profiles.delete("office").unwrap();
+
+    remotes.switch_profile("spare").unwrap();
+    assert_eq!(remotes.list().unwrap(), vec!["gdrive"]);
+    assert_eq!(remotes.get_value("gdrive").unwrap()["type"], "drive");
+
+    // The deleted profile's rows are gone, so recreating it starts empty
+    profiles.create("office").unwrap();
+    remotes.switch_profile("office").unwrap();
+    assert!(remotes.list().unwrap().is_empty());
+}
+
+// =============================================================================
+// Performance (shared database vs JSON multi-file)
+// =============================================================================
+
+/// Time `set_many`, `list` and a full round of `get_value` for `count`
+/// uncached entries.
+fn time_sub_settings_ops(
+    remotes: &rcman::SubSettings<impl StorageBackend + 'static>,
+    count: usize,
+) -> (std::time::Duration, std::time::Duration) {
+    let entries: Vec<(String, serde_json::Value)> = (0..count)
+        .map(|i| (format!("remote{i:05}"), json!({"type": "s3", "index": i})))
+        .collect();
+    let refs: Vec<(&str, serde_json::Value)> = entries
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.clone()))
+        .collect();
+    remotes.set_many(&refs).unwrap();
+
+    let start = std::time::Instant::now();
+    assert_eq!(remotes.list().unwrap().len(), count);
+    let list = start.elapsed();
+
+    let start = std::time::Instant::now();
+    for (key, _) in &entries {
+        let _ = remotes.get_value(key).unwrap();
+    }
+    (list, start.elapsed())
+}
+
+#[test]
+#[ignore = "Performance test"]
+fn sqlite_shared_database_vs_json_multi_file() {
+    const COUNT: usize = 5_000;
+
+    let json_dir = TempDir::new().unwrap();
+    let json_manager = SettingsManager::builder("bench-app", "1.0.0")
+        .with_config_dir(json_dir.path())
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_no_cache())
+        .build()
+        .unwrap();
+    let (json_list, json_get) =
+        time_sub_settings_ops(&json_manager.sub_settings("remotes").unwrap(), COUNT);
+
+    let sqlite_dir = TempDir::new().unwrap();
+    let sqlite_manager = SettingsManager::builder("bench-app", "1.0.0")
+        .with_config_dir(sqlite_dir.path())
+        .with_storage_backend(
+            SqliteStorage::new().with_database(sqlite_dir.path().join("rcman.db")),
+        )
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_no_cache())
+        .build()
+        .unwrap();
+    let (sqlite_list, sqlite_get) =
+        time_sub_settings_ops(&sqlite_manager.sub_settings("remotes").unwrap(), COUNT);
+
+    println!("{COUNT} entries, list: json {json_list:?} / sqlite {sqlite_list:?}");
+    println!("{COUNT} entries, get:  json {json_get:?} / sqlite {sqlite_get:?}");
+}
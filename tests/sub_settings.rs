@@ -11,8 +11,8 @@ mod common;
 
 use common::TestFixture;
 use rcman::{
-    SettingMetadata, SettingsManager, SettingsSchema, SubSettingsAction, SubSettingsConfig, opt,
-    settings,
+    SettingMetadata, SettingsManager, SettingsSchema, StrictRead, SubSettingsAction,
+    SubSettingsConfig, opt, settings,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -49,6 +49,17 @@ impl SettingsSchema for RemoteEntrySchema {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct RequiredRemoteSchema;
+
+impl SettingsSchema for RequiredRemoteSchema {
+    fn get_metadata() -> HashMap<String, SettingMetadata> {
+        settings! {
+            "endpoint" => SettingMetadata::text("").required(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 struct InvalidRemoteSchema;
 
@@ -287,6 +298,145 @@ fn test_set_field_propagates_store_errors() {
     assert!(!err.is_not_found());
 }
 
+#[test]
+fn test_set_and_get_returns_persisted_entry() {
+    let temp_dir = TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_sub_settings(
+            SubSettingsConfig::new("remotes")
+                .with_schema::<RemoteEntrySchema>()
+                .with_defaults(true)
+                .with_versioned_migrator(1, |value, _| value),
+        )
+        .build()
+        .unwrap();
+    let remotes = manager.sub_settings("remotes").unwrap();
+
+    let persisted = remotes
+        .set_and_get("gdrive", &json!({"type": "s3"}))
+        .unwrap();
+    assert_eq!(
+        persisted,
+        json!({"type": "s3", "endpoint": "https://example.com"})
+    );
+    assert_eq!(persisted, remotes.get_value("gdrive").unwrap());
+
+    // The version stamp is stored but not returned
+    let on_disk: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(temp_dir.path().join("remotes/gdrive.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(on_disk[rcman::SCHEMA_VERSION_KEY], json!(1));
+
+    assert!(
+        remotes
+            .set_and_get("bad", &json!({"endpoint": "ftp://nope"}))
+            .is_err()
+    );
+    assert!(!remotes.exists("bad").unwrap());
+}
+
+#[test]
+fn test_set_many_creates_all_entries() {
+    let fixture = TestFixture::with_sub_settings();
+    let backends = fixture.manager.sub_settings("backends").unwrap();
+
+    backends
+        .set_many(&[
+            ("local", json!({"host": "127.0.0.1"})),
+            ("remote", json!({"host": "10.0.0.2"})),
+        ])
+        .unwrap();
+
+    assert_eq!(backends.list().unwrap(), vec!["local", "remote"]);
+    assert_eq!(backends.get_value("remote").unwrap()["host"], "10.0.0.2");
+}
+
+#[test]
+fn test_set_many_rejects_batch_with_invalid_entry() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_schema::<RemoteEntrySchema>())
+        .build()
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    let result = remotes.set_many(&[
+        ("good", json!({"type": "s3"})),
+        ("bad", json!({"type": "unsupported"})),
+    ]);
+
+    assert!(result.is_err());
+    assert!(remotes.list().unwrap().is_empty());
+}
+
+#[test]
+fn test_replace_all_diffs_entries() {
+    let fixture = TestFixture::with_sub_settings();
+
+    for name in ["remotes", "backends"] {
+        let subs = fixture.manager.sub_settings(name).unwrap();
+        subs.set("keep", &json!({"type": "old"})).unwrap();
+        subs.set("stale", &json!({"type": "old"})).unwrap();
+
+        let actions = Arc::new(Mutex::new(Vec::new()));
+        let actions_clone = Arc::clone(&actions);
+        subs.set_on_change(move |entry, action| {
+            actions_clone
+                .lock()
+                .unwrap()
+                .push((entry.to_string(), action));
+        })
+        .unwrap();
+
+        let incoming = HashMap::from([
+            ("keep".to_string(), json!({"type": "new"})),
+            ("fresh".to_string(), json!({"type": "new"})),
+        ]);
+        let report = subs.replace_all(incoming).unwrap();
+
+        assert_eq!(report.added, vec!["fresh"], "{name}");
+        assert_eq!(report.updated, vec!["keep"], "{name}");
+        assert_eq!(report.removed, vec!["stale"], "{name}");
+        assert_eq!(subs.list().unwrap(), vec!["fresh", "keep"], "{name}");
+        assert_eq!(subs.get_value("keep").unwrap()["type"], "new", "{name}");
+        assert_eq!(
+            *actions.lock().unwrap(),
+            vec![
+                ("fresh".to_string(), SubSettingsAction::Created),
+                ("keep".to_string(), SubSettingsAction::Updated),
+                ("stale".to_string(), SubSettingsAction::Deleted),
+            ],
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn test_replace_all_rejects_invalid_entry_without_changes() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_schema::<RemoteEntrySchema>())
+        .build()
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes.set("existing", &json!({"type": "s3"})).unwrap();
+
+    let result = remotes.replace_all(HashMap::from([(
+        "bad".to_string(),
+        json!({"type": "unsupported"}),
+    )]));
+
+    assert!(result.is_err());
+    assert_eq!(remotes.list().unwrap(), vec!["existing"]);
+}
+
 #[test]
 fn test_delete_entry() {
     let fixture = TestFixture::with_sub_settings();
@@ -316,6 +466,62 @@ fn test_delete_removes_file_in_multi_file_mode() {
     assert!(!file_path.exists());
 }
 
+#[test]
+fn test_rename_entry() {
+    let fixture = TestFixture::with_sub_settings();
+    let remotes = fixture.manager.sub_settings("remotes").unwrap();
+    let backends = fixture.manager.sub_settings("backends").unwrap();
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_clone = changes.clone();
+    remotes
+        .set_on_change(move |name, action| {
+            changes_clone
+                .lock()
+                .unwrap()
+                .push((name.to_string(), action));
+        })
+        .unwrap();
+
+    remotes.set("gdrive", &json!({"type": "drive"})).unwrap();
+    remotes.set("other", &json!({"type": "s3"})).unwrap();
+    remotes.rename("gdrive", "work-drive").unwrap();
+
+    let dir = fixture.config_dir().join("remotes");
+    assert!(!dir.join("gdrive.json").exists());
+    assert!(dir.join("work-drive.json").exists());
+    assert_eq!(
+        remotes.get_value("work-drive").unwrap(),
+        json!({"type": "drive"})
+    );
+    assert_eq!(
+        changes.lock().unwrap()[2..],
+        [
+            ("gdrive".to_string(), SubSettingsAction::Deleted),
+            ("work-drive".to_string(), SubSettingsAction::Created),
+        ]
+    );
+
+    assert!(matches!(
+        remotes.rename("work-drive", "other"),
+        Err(rcman::Error::SubSettingsEntryExists(name)) if name == "other"
+    ));
+    assert!(matches!(
+        remotes.rename("missing", "new"),
+        Err(rcman::Error::SubSettingsEntryNotFound(_))
+    ));
+    assert_eq!(remotes.get_value("other").unwrap(), json!({"type": "s3"}));
+
+    // Single-file mode moves the key within the file
+    backends.set("local", &json!({"path": "/tmp"})).unwrap();
+    backends.rename("local", "scratch").unwrap();
+    assert_eq!(backends.list().unwrap(), vec!["scratch"]);
+    assert_eq!(
+        backends.get_value("scratch").unwrap(),
+        json!({"path": "/tmp"})
+    );
+}
+
 // =============================================================================
 // List Entries
 // =============================================================================
@@ -368,6 +574,199 @@ fn test_exists_returns_false_for_missing() {
     assert!(!remotes.exists("missing").unwrap());
 }
 
+#[test]
+fn test_exists_matching_and_where() {
+    let fixture = TestFixture::with_sub_settings();
+    let remotes = fixture.manager.sub_settings("remotes").unwrap();
+
+    assert!(!remotes.exists_matching(|_| true).unwrap());
+
+    remotes
+        .set("work-drive", &json!({"type": "drive"}))
+        .unwrap();
+    remotes.set("home", &json!({"type": "s3"})).unwrap();
+
+    assert!(
+        remotes
+            .exists_matching(|name| name.starts_with("work-"))
+            .unwrap()
+    );
+    assert!(
+        !remotes
+            .exists_matching(|name| name.starts_with("play-"))
+            .unwrap()
+    );
+
+    assert!(
+        remotes
+            .exists_where(|_, value| value["type"] == "s3")
+            .unwrap()
+    );
+    assert!(
+        !remotes
+            .exists_where(|_, value| value["type"] == "ftp")
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_entries_returns_sorted_values_and_fills_cache() {
+    let temp_dir = TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_lru_cache(10))
+        .with_sub_settings(SubSettingsConfig::singlefile("backends"))
+        .build()
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes.set("work", &json!({"type": "drive"})).unwrap();
+    remotes.set("home", &json!({"type": "s3"})).unwrap();
+    remotes.invalidate_cache();
+
+    assert_eq!(
+        remotes.entries().unwrap(),
+        vec![
+            ("home".to_string(), json!({"type": "s3"})),
+            ("work".to_string(), json!({"type": "drive"})),
+        ]
+    );
+    let stats = remotes.cache_stats().unwrap();
+    assert_eq!(stats.entries, 2);
+    remotes.get_value("work").unwrap();
+    assert_eq!(remotes.cache_stats().unwrap().hits, stats.hits + 1);
+
+    let backends = manager.sub_settings("backends").unwrap();
+    assert!(backends.entries().unwrap().is_empty());
+    backends.set("b", &json!(2)).unwrap();
+    backends.set("a", &json!(1)).unwrap();
+    assert_eq!(
+        backends.entries().unwrap(),
+        vec![("a".to_string(), json!(1)), ("b".to_string(), json!(2))]
+    );
+}
+
+#[test]
+fn test_find_by_field_value() {
+    let fixture = TestFixture::with_sub_settings();
+
+    for sub in ["remotes", "backends"] {
+        let entries = fixture.manager.sub_settings(sub).unwrap();
+        entries
+            .set("s3-a", &json!({"type": "s3", "opts": {"region": "eu"}}))
+            .unwrap();
+        entries.set("drive", &json!({"type": "drive"})).unwrap();
+        entries.set("s3-b", &json!({"type": "s3"})).unwrap();
+        entries.set("scalar", &json!("s3")).unwrap();
+
+        assert_eq!(
+            entries.find_by("type", &json!("s3")).unwrap(),
+            vec!["s3-a", "s3-b"]
+        );
+        assert_eq!(
+            entries.find_by("opts.region", &json!("eu")).unwrap(),
+            vec!["s3-a"]
+        );
+        assert!(
+            entries
+                .find_by("missing.path", &json!(1))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
+
+#[test]
+fn test_max_entries_limits_creation() {
+    let temp_dir = TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_sub_settings(SubSettingsConfig::new("profiles").with_max_entries(2))
+        .build()
+        .unwrap();
+    let profiles = manager.sub_settings("profiles").unwrap();
+
+    profiles.set("one", &json!({"n": 1})).unwrap();
+    profiles.set("two", &json!({"n": 2})).unwrap();
+
+    let result = profiles.set("three", &json!({"n": 3}));
+    assert!(matches!(
+        result,
+        Err(rcman::Error::SubSettingsLimitReached { ref name, limit: 2 }) if name == "profiles"
+    ));
+    assert!(!profiles.exists("three").unwrap());
+    assert!(
+        profiles
+            .set_many(&[("two", json!({"n": 22})), ("three", json!({"n": 3}))])
+            .is_err()
+    );
+
+    // Updates are always allowed
+    profiles.set("two", &json!({"n": 22})).unwrap();
+
+    profiles.delete("one").unwrap();
+    profiles.set("three", &json!({"n": 3})).unwrap();
+    assert_eq!(profiles.list().unwrap(), vec!["three", "two"]);
+}
+
+#[test]
+fn test_max_entries_holds_under_concurrent_creates() {
+    let temp_dir = TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_sub_settings(SubSettingsConfig::new("profiles").with_max_entries(2))
+        .build()
+        .unwrap();
+    let profiles = manager.sub_settings("profiles").unwrap();
+
+    std::thread::scope(|scope| {
+        for i in 0..8 {
+            let profiles = &profiles;
+            scope.spawn(move || {
+                let _ = profiles.set(&format!("p{i}"), &json!({"n": i}));
+            });
+        }
+    });
+    assert_eq!(profiles.list().unwrap().len(), 2);
+}
+
+#[test]
+fn test_import_dir() {
+    let fixture = TestFixture::with_sub_settings();
+    let remotes = fixture.manager.sub_settings("remotes").unwrap();
+    remotes.set("work", &json!({"type": "s3"})).unwrap();
+
+    let import = TempDir::new().unwrap();
+    std::fs::write(import.path().join("gdrive.json"), r#"{"type": "drive"}"#).unwrap();
+    std::fs::write(import.path().join("work.json"), r#"{"type": "ftp"}"#).unwrap();
+    std::fs::write(import.path().join("notes.txt"), "not an entry").unwrap();
+
+    assert_eq!(
+        remotes.import_dir(import.path(), false).unwrap(),
+        vec!["gdrive"]
+    );
+    assert_eq!(remotes.get_value("work").unwrap()["type"], json!("s3"));
+    assert_eq!(remotes.list().unwrap(), vec!["gdrive", "work"]);
+
+    assert_eq!(
+        remotes.import_dir(import.path(), true).unwrap(),
+        vec!["gdrive", "work"]
+    );
+    assert_eq!(remotes.get_value("work").unwrap()["type"], json!("ftp"));
+
+    // One invalid file rejects the whole import
+    let temp_dir = TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_schema::<RemoteEntrySchema>())
+        .build()
+        .unwrap();
+    let remotes = manager.sub_settings("remotes").unwrap();
+    std::fs::write(import.path().join("work.json"), r#"{"type": "ftp"}"#).unwrap();
+    assert!(remotes.import_dir(import.path(), true).is_err());
+    assert!(remotes.list().unwrap().is_empty());
+}
+
 // =============================================================================
 // Migration Support
 // =============================================================================
@@ -442,6 +841,97 @@ fn test_migrator_upgrades_schema() {
     assert_eq!(loaded["type"], json!("drive"));
 }
 
+#[test]
+fn test_versioned_migrator_runs_once_per_entry() {
+    let temp_dir = TempDir::new().unwrap();
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let build = |config: SubSettingsConfig| {
+        let calls = Arc::clone(&calls);
+        SettingsManager::builder("test-app", "1.0.0")
+            .with_config_dir(temp_dir.path())
+            .with_sub_settings(config.with_versioned_migrator(2, move |mut value, from| {
+                calls.lock().unwrap().push(from);
+                if from < 1 {
+                    value["type"] = value["remote_type"].take();
+                    value.as_object_mut().unwrap().remove("remote_type");
+                }
+                if from < 2 {
+                    value["enabled"] = json!(true);
+                }
+                value
+            }))
+            .build()
+            .unwrap()
+    };
+
+    let remotes_dir = temp_dir.path().join("remotes");
+    std::fs::create_dir_all(&remotes_dir).unwrap();
+    std::fs::write(remotes_dir.join("old.json"), r#"{"remote_type": "drive"}"#).unwrap();
+    std::fs::write(
+        remotes_dir.join("v1.json"),
+        r#"{"type": "s3", "_schema_version": 1}"#,
+    )
+    .unwrap();
+
+    let manager = build(SubSettingsConfig::new("remotes"));
+    let remotes = manager.sub_settings("remotes").unwrap();
+    assert_eq!(
+        remotes.get_value("old").unwrap(),
+        json!({"type": "drive", "enabled": true})
+    );
+    assert_eq!(
+        remotes.get_value("v1").unwrap(),
+        json!({"type": "s3", "enabled": true})
+    );
+    remotes.set("new", &json!({"type": "local"})).unwrap();
+
+    let mut seen = calls.lock().unwrap().clone();
+    seen.sort_unstable();
+    assert_eq!(seen, vec![0, 1]);
+
+    // The stamp is persisted, so a fresh manager does not migrate again
+    let on_disk: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(remotes_dir.join("old.json")).unwrap())
+            .unwrap();
+    assert_eq!(on_disk[rcman::SCHEMA_VERSION_KEY], json!(2));
+
+    calls.lock().unwrap().clear();
+    let manager = build(SubSettingsConfig::new("remotes"));
+    let remotes = manager.sub_settings("remotes").unwrap();
+    assert_eq!(remotes.get_all_values().unwrap().len(), 3);
+    assert!(calls.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_versioned_migrator_single_file() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("backends.json"),
+        r#"{"old": {"port": 1}, "current": {"port": 2, "_schema_version": 1}, "_active": "old"}"#,
+    )
+    .unwrap();
+
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_sub_settings(
+            SubSettingsConfig::singlefile("backends").with_versioned_migrator(1, |mut value, _| {
+                value["host"] = json!("localhost");
+                value
+            }),
+        )
+        .build()
+        .unwrap();
+
+    let backends = manager.sub_settings("backends").unwrap();
+    assert_eq!(
+        backends.get_value("old").unwrap(),
+        json!({"port": 1, "host": "localhost"})
+    );
+    assert_eq!(backends.get_value("current").unwrap(), json!({"port": 2}));
+    assert_eq!(backends.get_value("_active").unwrap(), json!("old"));
+}
+
 // =============================================================================
 // Change Callbacks
 // =============================================================================
@@ -617,6 +1107,26 @@ fn test_sub_settings_schema_rejects_invalid_entry() {
     );
 }
 
+#[test]
+fn test_sub_settings_schema_rejects_missing_required_field() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_schema::<RequiredRemoteSchema>())
+        .build()
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    assert!(remotes.set("missing", &json!({})).is_err());
+    assert!(remotes.set("blank", &json!({"endpoint": "  "})).is_err());
+    assert!(
+        remotes
+            .set("ok", &json!({"endpoint": "https://example.com"}))
+            .is_ok()
+    );
+}
+
 #[test]
 fn test_sub_settings_schema_rejects_unknown_root_field() {
     let temp_dir = TempDir::new().unwrap();
@@ -663,6 +1173,99 @@ fn test_sub_settings_schema_allows_unknown_root_field_when_disabled() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_sub_settings_strict_read_modes() {
+    let temp_dir = TempDir::new().unwrap();
+    let build = |mode: StrictRead| {
+        SettingsManager::builder("test-app", "1.0.0")
+            .with_config_dir(temp_dir.path())
+            .with_sub_settings(
+                SubSettingsConfig::new("remotes")
+                    .with_schema::<RemoteEntrySchema>()
+                    .with_strict_read(mode),
+            )
+            .build()
+            .unwrap()
+    };
+
+    // Lenient by default: the unknown field is written and read back
+    let manager = build(StrictRead::Off);
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes
+        .set("legacy", &json!({"type": "s3", "old_flag": true}))
+        .unwrap();
+    remotes.set("clean", &json!({"type": "drive"})).unwrap();
+    assert_eq!(
+        remotes.get_value("legacy").unwrap()["old_flag"],
+        json!(true)
+    );
+
+    let manager = build(StrictRead::Error);
+    let remotes = manager.sub_settings("remotes").unwrap();
+    let err = remotes.get_value("legacy").unwrap_err();
+    assert!(err.to_string().contains("remotes.legacy.old_flag"));
+    assert!(remotes.get_value("clean").is_ok());
+    let err = remotes.get_all_values().unwrap_err();
+    assert!(err.to_string().contains("remotes.legacy.old_flag"));
+
+    let manager = build(StrictRead::Strip);
+    let remotes = manager.sub_settings("remotes").unwrap();
+    assert_eq!(remotes.get_value("legacy").unwrap(), json!({"type": "s3"}));
+}
+
+#[test]
+fn test_subscribe_all_mutations() {
+    use rcman::Mutation;
+
+    let temp_dir = TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_schema::<common::TestSettings>()
+        .build()
+        .unwrap();
+    let mutations = manager.subscribe_all_mutations();
+
+    // Registered after subscribing, still covered
+    manager
+        .register_sub_settings(SubSettingsConfig::new("remotes"))
+        .unwrap();
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes.set("gdrive", &json!({"type": "drive"})).unwrap();
+    remotes.delete("gdrive").unwrap();
+
+    let received: Vec<Mutation> = mutations.try_iter().collect();
+    assert_eq!(
+        received,
+        vec![
+            Mutation::Setting {
+                key: "ui.theme".into(),
+                old_value: json!("dark"),
+                new_value: json!("light"),
+            },
+            Mutation::SubSetting {
+                settings_type: "remotes".into(),
+                name: "gdrive".into(),
+                action: SubSettingsAction::Created,
+            },
+            Mutation::SubSetting {
+                settings_type: "remotes".into(),
+                name: "gdrive".into(),
+                action: SubSettingsAction::Deleted,
+            },
+        ]
+    );
+
+    // Dropping the receiver ends the subscription without disturbing saves
+    drop(mutations);
+    manager.save_setting("ui", "theme", &json!("dark")).unwrap();
+    manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+}
+
 #[test]
 fn test_sub_settings_invalid_schema_rejected_on_registration() {
     let temp_dir = TempDir::new().unwrap();
@@ -773,3 +1376,308 @@ fn test_exists_recognizes_secret_only_entry_without_file() {
     remotes.delete("secure").unwrap();
     assert!(!remotes.exists("secure").unwrap());
 }
+
+#[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+#[test]
+#[cfg_attr(
+    feature = "keychain",
+    ignore = "Requires Secret Service daemon (not available in CI)"
+)]
+fn test_sub_settings_cached_secret_invalidated_on_write() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let manager = SettingsManager::builder("test-app-sub-secret-cache", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_credentials()
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_schema::<SecretRemoteSchema>())
+        .build()
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes
+        .set("secure", &json!({"host": "localhost", "token": "first"}))
+        .unwrap();
+    assert_eq!(
+        remotes.get_value("secure").unwrap()["token"],
+        json!("first")
+    );
+    assert_eq!(
+        remotes.get_value("secure").unwrap()["token"],
+        json!("first")
+    );
+
+    remotes
+        .set("secure", &json!({"host": "localhost", "token": "second"}))
+        .unwrap();
+    assert_eq!(
+        remotes.get_value("secure").unwrap()["token"],
+        json!("second")
+    );
+
+    remotes.delete("secure").unwrap();
+    remotes
+        .set("secure", &json!({"host": "localhost"}))
+        .unwrap();
+    assert_eq!(remotes.get_value("secure").unwrap()["token"], json!(""));
+}
+
+#[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+#[test]
+#[cfg_attr(
+    feature = "keychain",
+    ignore = "Requires Secret Service daemon (not available in CI)"
+)]
+fn test_rename_entry_moves_secrets() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let manager = SettingsManager::builder("test-app-sub-rename", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_credentials()
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_schema::<SecretRemoteSchema>())
+        .build()
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes
+        .set(
+            "gdrive",
+            &json!({"host": "localhost", "token": "super-secret"}),
+        )
+        .unwrap();
+    remotes.rename("gdrive", "work-drive").unwrap();
+
+    let renamed_file = temp_dir.path().join("remotes").join("work-drive.json");
+    assert!(
+        !std::fs::read_to_string(renamed_file)
+            .unwrap()
+            .contains("super-secret")
+    );
+    assert_eq!(
+        remotes.get_value("work-drive").unwrap()["token"],
+        json!("super-secret")
+    );
+    assert!(!remotes.exists("gdrive").unwrap());
+}
+
+#[test]
+fn test_import_bundle_applies_settings_and_sub_settings() {
+    let fixture = TestFixture::with_sub_settings();
+
+    let report = fixture
+        .manager
+        .import_bundle(
+            json!({
+                "settings": {"ui": {"theme": "light"}},
+                "sub_settings": {
+                    "remotes": {"gdrive": {"type": "drive"}, "s3": {"type": "s3"}},
+                    "backends": {"local": {"path": "/tmp"}}
+                }
+            }),
+            &rcman::ImportOptions::new(),
+        )
+        .unwrap();
+
+    assert_eq!(report.settings, vec!["ui.theme"]);
+    assert_eq!(
+        report.sub_settings,
+        vec!["backends/local", "remotes/gdrive", "remotes/s3"]
+    );
+    assert!(report.skipped.is_empty());
+    assert_eq!(
+        fixture.manager.get_value("ui.theme").unwrap(),
+        json!("light")
+    );
+    let remotes = fixture.manager.sub_settings("remotes").unwrap();
+    assert_eq!(remotes.get_value("s3").unwrap()["type"], json!("s3"));
+
+    let report = fixture
+        .manager
+        .import_bundle(
+            json!({
+                "sub_settings": {
+                    "remotes": {"gdrive": {"type": "changed"}, "b2": {"type": "b2"}},
+                    "missing": {"x": {}}
+                }
+            }),
+            &rcman::ImportOptions::new()
+                .overwrite_existing(false)
+                .skip_unknown_types(true),
+        )
+        .unwrap();
+
+    assert_eq!(report.sub_settings, vec!["remotes/b2"]);
+    assert_eq!(report.skipped, vec!["missing", "remotes/gdrive"]);
+    assert_eq!(remotes.get_value("gdrive").unwrap()["type"], json!("drive"));
+
+    let result = fixture
+        .manager
+        .import_bundle(json!({"unexpected": {}}), &rcman::ImportOptions::new());
+    assert!(result.is_err());
+}
+
+#[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+#[test]
+#[cfg_attr(
+    feature = "keychain",
+    ignore = "Requires Secret Service daemon (not available in CI)"
+)]
+fn test_sub_settings_invalid_secret_not_stored() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let manager = SettingsManager::builder("test-app-sub-secret-invalid", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_credentials()
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_metadata(settings! {
+            "host" => SettingMetadata::text("localhost"),
+            "token" => SettingMetadata::text("").secret().pattern("^(sk-.+)?$"),
+        }))
+        .build()
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    let result = remotes.set("secure", &json!({"host": "localhost", "token": "oops"}));
+    assert!(matches!(
+        result,
+        Err(rcman::Error::InvalidSettingValue { ref key, .. }) if key == "remotes.secure.token"
+    ));
+    assert!(!remotes.exists("secure").unwrap());
+
+    remotes
+        .set("secure", &json!({"host": "localhost", "token": "sk-valid"}))
+        .unwrap();
+    assert_eq!(
+        remotes.get_value("secure").unwrap()["token"],
+        json!("sk-valid")
+    );
+}
+
+#[test]
+fn test_unregister_sub_settings_keeps_files() {
+    let fixture = TestFixture::with_sub_settings();
+
+    let remotes = fixture.manager.sub_settings("remotes").unwrap();
+    remotes.set("gdrive", &json!({"type": "drive"})).unwrap();
+    drop(remotes);
+
+    fixture.manager.unregister_sub_settings("remotes").unwrap();
+    assert!(!fixture.manager.has_sub_settings("remotes"));
+    assert!(fixture.manager.sub_settings("remotes").is_err());
+    assert!(fixture.manager.unregister_sub_settings("remotes").is_err());
+    assert!(
+        fixture
+            .config_dir()
+            .join("remotes")
+            .join("gdrive.json")
+            .exists()
+    );
+
+    fixture
+        .manager
+        .register_sub_settings(SubSettingsConfig::new("remotes"))
+        .unwrap();
+    let remotes = fixture.manager.sub_settings("remotes").unwrap();
+    assert_eq!(remotes.list().unwrap(), vec!["gdrive"]);
+}
+
+#[test]
+fn test_with_defaults_fills_missing_fields_on_read() {
+    #[derive(Debug, Deserialize)]
+    struct Remote {
+        #[serde(rename = "type")]
+        remote_type: String,
+        endpoint: String,
+    }
+
+    let temp_dir = TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_schema::<RemoteEntrySchema>())
+        .with_sub_settings(
+            SubSettingsConfig::new("filled")
+                .with_schema::<RemoteEntrySchema>()
+                .with_defaults(true),
+        )
+        .build()
+        .unwrap();
+
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes.set("old", &json!({"type": "s3"})).unwrap();
+    assert!(remotes.get_value("old").unwrap().get("endpoint").is_none());
+    assert!(remotes.get::<Remote>("old").is_err());
+
+    let filled = manager.sub_settings("filled").unwrap();
+    filled.set("old", &json!({"type": "s3"})).unwrap();
+    let remote: Remote = filled.get("old").unwrap();
+    assert_eq!(remote.remote_type, "s3");
+    assert_eq!(remote.endpoint, "https://example.com");
+    assert_eq!(
+        filled.get_all_values().unwrap()["old"]["endpoint"],
+        json!("https://example.com")
+    );
+
+    // Stored entries are not rewritten
+    let content = std::fs::read_to_string(temp_dir.path().join("filled").join("old.json")).unwrap();
+    assert!(!content.contains("endpoint"));
+}
+
+#[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+#[test]
+#[cfg_attr(
+    feature = "keychain",
+    ignore = "Requires Secret Service daemon (not available in CI)"
+)]
+fn test_max_entries_counts_secret_only_entries() {
+    let temp_dir = TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app-sub-limit", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_credentials()
+        .with_sub_settings(
+            SubSettingsConfig::new("remotes")
+                .with_schema::<SecretRemoteSchema>()
+                .with_max_entries(1)
+                .with_no_cache(),
+        )
+        .build()
+        .unwrap();
+    let remotes = manager.sub_settings("remotes").unwrap();
+
+    // Only the secret is left of this entry, but it still exists
+    remotes.set("vault", &json!({"token": "sk-1"})).unwrap();
+    std::fs::remove_file(temp_dir.path().join("remotes").join("vault.json")).unwrap();
+    assert!(remotes.list().unwrap().is_empty());
+    assert!(remotes.exists("vault").unwrap());
+
+    assert!(matches!(
+        remotes.set("other", &json!({"host": "example.com"})),
+        Err(rcman::Error::SubSettingsLimitReached { limit: 1, .. })
+    ));
+}
+
+#[cfg(any(feature = "keychain", feature = "encrypted-file"))]
+#[test]
+#[cfg_attr(
+    feature = "keychain",
+    ignore = "Requires Secret Service daemon (not available in CI)"
+)]
+fn test_reset_token_counts_sub_settings_secrets() {
+    let temp_dir = TempDir::new().unwrap();
+    let manager = SettingsManager::builder("test-app-sub-reset", "1.0.0")
+        .with_config_dir(temp_dir.path())
+        .with_credentials()
+        .with_sub_settings(SubSettingsConfig::new("remotes").with_schema::<SecretRemoteSchema>())
+        .build()
+        .unwrap();
+    let remotes = manager.sub_settings("remotes").unwrap();
+    remotes
+        .set("vault", &json!({"host": "example.com", "token": "sk-1"}))
+        .unwrap();
+
+    let token = manager.prepare_reset_all().unwrap();
+    assert_eq!(token.sub_settings_secrets, 1);
+    manager.reset_all_confirmed(&token).unwrap();
+
+    // The entry survives the reset, its secret does not
+    let vault = remotes.get_value("vault").unwrap();
+    assert_eq!(vault["host"], json!("example.com"));
+    assert_eq!(vault["token"], json!(""));
+}
@@ -0,0 +1,89 @@
+//! Tokio integration tests
+//!
+//! These tests run the `spawn_blocking` variants of the settings I/O on a
+//! current-thread runtime, mixed with the sync methods.
+
+mod common;
+
+use common::TestFixture;
+use serde_json::json;
+use std::sync::Arc;
+
+fn runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_async_load_and_save_mix_with_sync() {
+    let fixture = TestFixture::new();
+    fixture
+        .manager
+        .save_setting("ui", "theme", &json!("light"))
+        .unwrap();
+    let manager = Arc::new(fixture.manager);
+
+    runtime().block_on(async {
+        manager.load_startup_async().await.unwrap();
+        assert_eq!(
+            manager.load_settings_async().await.unwrap().ui.theme,
+            "light"
+        );
+
+        let outcome = manager
+            .save_setting_spawned("ui", "font_size", json!(18.0))
+            .await
+            .unwrap();
+        assert!(outcome.changed);
+        assert!(
+            !manager
+                .save_setting_spawned("ui", "font_size", json!(18.0))
+                .await
+                .unwrap()
+                .changed
+        );
+
+        assert!(
+            manager
+                .save_setting_spawned("ui", "font_size", json!(100.0))
+                .await
+                .is_err()
+        );
+    });
+
+    let settings = manager.get_all().unwrap();
+    assert!((settings.ui.font_size - 18.0).abs() < f64::EPSILON);
+    assert_eq!(settings.ui.theme, "light");
+}
+
+#[test]
+fn test_save_setting_spawned_runs_async_validators() {
+    let fixture = TestFixture::new();
+    let manager = Arc::new(fixture.manager);
+    manager
+        .events()
+        .add_async_validator("general.language", |value| {
+            Box::pin(async move {
+                if value == json!("tr") {
+                    Err("language not available".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+        });
+
+    runtime().block_on(async {
+        let err = manager
+            .save_setting_spawned("general", "language", json!("tr"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, rcman::Error::InvalidSettingValue { .. }));
+
+        manager
+            .save_setting_spawned("general", "language", json!("de"))
+            .await
+            .unwrap();
+    });
+    assert_eq!(manager.get_all().unwrap().general.language, "de");
+}
@@ -574,6 +574,72 @@ fn test_toml_null_value_handling() {
     );
 }
 
+/// Settings with a `null` default round-trip by omission: saving the default
+/// removes the key from the TOML file, and a missing key reads back as `null`.
+#[test]
+fn test_toml_null_default_round_trip() {
+    use rcman::{SettingMetadata, SettingsSchema};
+    use std::collections::HashMap;
+
+    #[derive(Serialize, Deserialize, Default)]
+    struct ProfileSettings {
+        profile: ProfileSection,
+    }
+
+    #[derive(Serialize, Deserialize, Default)]
+    struct ProfileSection {
+        website: Option<String>,
+    }
+
+    impl SettingsSchema for ProfileSettings {
+        fn get_metadata() -> HashMap<String, SettingMetadata> {
+            let mut website = SettingMetadata::text("").nullable(true);
+            website.default = serde_json::Value::Null;
+            HashMap::from([("profile.website".to_string(), website)])
+        }
+    }
+
+    let temp_dir = TempDir::new().unwrap();
+    let settings_file = temp_dir.path().join("settings.toml");
+    let build = || {
+        SettingsManager::builder("test-app", "1.0.0")
+            .with_config_dir(temp_dir.path())
+            .with_storage::<TomlStorage>()
+            .with_schema::<ProfileSettings>()
+            .build()
+            .unwrap()
+    };
+
+    let manager = build();
+    assert_eq!(
+        manager.get_all_data().unwrap()["profile"]["website"],
+        json!(null)
+    );
+
+    manager
+        .save_setting("profile", "website", &json!("https://example.com"))
+        .unwrap();
+    let content = std::fs::read_to_string(&settings_file).unwrap();
+    assert!(content.contains("website = \"https://example.com\""));
+    assert_eq!(
+        build().get_all().unwrap().profile.website.as_deref(),
+        Some("https://example.com")
+    );
+
+    // Saving null (the default) removes the key instead of writing a null
+    manager
+        .save_setting("profile", "website", &json!(null))
+        .unwrap();
+    let content = std::fs::read_to_string(&settings_file).unwrap_or_default();
+    assert!(!content.contains("website"));
+    let reloaded = build();
+    assert_eq!(
+        reloaded.get_all_data().unwrap()["profile"]["website"],
+        json!(null)
+    );
+    assert_eq!(reloaded.get_all().unwrap().profile.website, None);
+}
+
 #[test]
 fn test_toml_concurrent_writes() {
     use std::sync::Arc;